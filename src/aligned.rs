@@ -0,0 +1,173 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Padded companion types matching GPU buffer layout rules.
+//!
+//! In std140 and std430 layouts (and in WGSL uniform and storage buffers) a `vec3`
+//! is aligned and padded to the size of a `vec4`, while `Point3D` and `Vector3D`
+//! are tightly packed. Using [`AlignedPoint3D`] and [`AlignedVector3D`] in
+//! GPU-visible structs gives them the expected 16 byte alignment and size for `f32`
+//! components (32 bytes for `f64`).
+
+use crate::num::Zero;
+use crate::point::Point3D;
+use crate::vector::Vector3D;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! aligned_3d {
+    ($(#[$attr:meta])* $name:ident => $inner:ident, $to:ident) => {
+        $(#[$attr])*
+        #[repr(C, align(16))]
+        pub struct $name<T, U> {
+            pub x: T,
+            pub y: T,
+            pub z: T,
+            #[doc(hidden)]
+            pub _padding: T,
+            #[doc(hidden)]
+            pub _unit: PhantomData<U>,
+        }
+
+        impl<T: Zero, U> $name<T, U> {
+            /// Constructor taking scalar values directly.
+            #[inline]
+            pub fn new(x: T, y: T, z: T) -> Self {
+                $name {
+                    x,
+                    y,
+                    z,
+                    _padding: T::zero(),
+                    _unit: PhantomData,
+                }
+            }
+        }
+
+        impl<T, U> $name<T, U> {
+            /// Drops the padding.
+            #[inline]
+            pub fn $to(self) -> $inner<T, U> {
+                $inner::new(self.x, self.y, self.z)
+            }
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                $name {
+                    x: self.x.clone(),
+                    y: self.y.clone(),
+                    z: self.z.clone(),
+                    _padding: self._padding.clone(),
+                    _unit: PhantomData,
+                }
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.x == other.x && self.y == other.y && self.z == other.z
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.x.hash(h);
+                self.y.hash(h);
+                self.z.hash(h);
+            }
+        }
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple("")
+                    .field(&self.x)
+                    .field(&self.y)
+                    .field(&self.z)
+                    .finish()
+            }
+        }
+
+        impl<T: Zero, U> Default for $name<T, U> {
+            fn default() -> Self {
+                $name::new(T::zero(), T::zero(), T::zero())
+            }
+        }
+
+        impl<T: Zero, U> From<$inner<T, U>> for $name<T, U> {
+            fn from(v: $inner<T, U>) -> Self {
+                $name::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl<T, U> From<$name<T, U>> for $inner<T, U> {
+            fn from(v: $name<T, U>) -> Self {
+                v.$to()
+            }
+        }
+
+        // Only implemented for component types that are at least 4 bytes, so that
+        // four of them fill the 16 byte alignment without implicit padding.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T: Zeroable, U> Zeroable for $name<T, U> {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<U: 'static> Pod for $name<f32, U> {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<U: 'static> Pod for $name<f64, U> {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<U: 'static> Pod for $name<i32, U> {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<U: 'static> Pod for $name<u32, U> {}
+    };
+}
+
+aligned_3d!(
+    /// A `Point3D` padded to the size and alignment of a 4 component vector.
+    AlignedPoint3D => Point3D, to_point3d
+);
+
+aligned_3d!(
+    /// A `Vector3D` padded to the size and alignment of a 4 component vector.
+    AlignedVector3D => Vector3D, to_vector3d
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{point3, vec3};
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(size_of::<AlignedPoint3D<f32, ()>>(), 16);
+        assert_eq!(align_of::<AlignedPoint3D<f32, ()>>(), 16);
+        assert_eq!(size_of::<AlignedVector3D<f64, ()>>(), 32);
+        assert_eq!(size_of::<[AlignedVector3D<f32, ()>; 3]>(), 48);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p: default::Point3D<f32> = point3(1.0, 2.0, 3.0);
+        let a: AlignedPoint3D<f32, _> = p.into();
+        assert_eq!(a._padding, 0.0);
+        assert_eq!(default::Point3D::from(a), p);
+
+        let v: default::Vector3D<i32> = vec3(1, 2, 3);
+        assert_eq!(AlignedVector3D::from(v).to_vector3d(), v);
+    }
+}
@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use crate::approxeq::ApproxEq;
+use crate::num::Pi;
 use crate::trig::Trig;
 
 use core::cmp::{Eq, PartialEq};
@@ -18,11 +19,18 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, S
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 use num_traits::real::Real;
-use num_traits::{Float, FloatConst, NumCast, One, Zero};
+#[cfg(feature = "fast-math")]
+use num_traits::FloatConst;
+use num_traits::{Float, NumCast, One, Zero};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// An angle in radians
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Angle<T> {
@@ -52,9 +60,31 @@ where
     }
 }
 
+macro_rules! angle_consts {
+    ($ty:ident) => {
+        impl Angle<$ty> {
+            /// The zero angle.
+            pub const ZERO: Self = Angle::radians(0.0);
+            /// A half turn, π radians.
+            pub const PI: Self = Angle::radians(core::$ty::consts::PI);
+            /// A full turn, 2π radians.
+            pub const TWO_PI: Self = Angle::radians(2.0 * core::$ty::consts::PI);
+            /// A quarter turn, π/2 radians.
+            pub const FRAC_PI_2: Self = Angle::radians(core::$ty::consts::FRAC_PI_2);
+            /// π/3 radians.
+            pub const FRAC_PI_3: Self = Angle::radians(core::$ty::consts::FRAC_PI_3);
+            /// π/4 radians.
+            pub const FRAC_PI_4: Self = Angle::radians(core::$ty::consts::FRAC_PI_4);
+        }
+    };
+}
+
+angle_consts!(f32);
+angle_consts!(f64);
+
 impl<T> Angle<T> {
     #[inline]
-    pub fn radians(radians: T) -> Self {
+    pub const fn radians(radians: T) -> Self {
         Angle { radians }
     }
 
@@ -83,11 +113,11 @@ where
 
 impl<T> Angle<T>
 where
-    T: Rem<Output = T> + Sub<Output = T> + Add<Output = T> + Zero + FloatConst + PartialOrd + Copy,
+    T: Rem<Output = T> + Sub<Output = T> + Add<Output = T> + Zero + Pi + PartialOrd + Copy,
 {
     /// Returns this angle in the [0..2*PI[ range.
     pub fn positive(&self) -> Self {
-        let two_pi = T::PI() + T::PI();
+        let two_pi = T::pi() + T::pi();
         let mut a = self.radians % two_pi;
         if a < T::zero() {
             a = a + two_pi;
@@ -103,20 +133,14 @@ where
 
 impl<T> Angle<T>
 where
-    T: Rem<Output = T>
-        + Mul<Output = T>
-        + Sub<Output = T>
-        + Add<Output = T>
-        + One
-        + FloatConst
-        + Copy,
+    T: Rem<Output = T> + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + One + Pi + Copy,
 {
     /// Returns the shortest signed angle between two angles.
     ///
     /// Takes wrapping and signs into account.
     pub fn angle_to(&self, to: Self) -> Self {
         let two = T::one() + T::one();
-        let max = T::PI() * two;
+        let max = T::pi() * two;
         let d = (to.radians - self.radians) % max;
 
         Angle::radians(two * d % max - d)
@@ -149,6 +173,68 @@ where
     }
 }
 
+/// Approximate trigonometry for workloads that don't need the precision of the
+/// standard functions, such as per-frame animations.
+#[cfg(feature = "fast-math")]
+impl<T> Angle<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns an approximation of `sin(self)`.
+    ///
+    /// The angle is reduced to `[-PI/2, PI/2]` where a polynomial is evaluated. The
+    /// absolute error is below `1e-6` for `f32` angles in `[-4*PI, 4*PI]`, and grows
+    /// with the magnitude of the angle as the reduction loses precision.
+    #[inline]
+    pub fn fast_sin(self) -> T {
+        self.fast_sin_cos().0
+    }
+
+    /// Returns an approximation of `cos(self)`, with the same error bounds as
+    /// [`Angle::fast_sin`]. The result is exactly `1` for a zero angle.
+    #[inline]
+    pub fn fast_cos(self) -> T {
+        self.fast_sin_cos().1
+    }
+
+    /// Returns `(self.fast_sin(), self.fast_cos())`.
+    pub fn fast_sin_cos(self) -> (T, T) {
+        let c = |v: f64| T::from(v).unwrap();
+        let (pi, frac_pi_2) = (T::PI(), T::FRAC_PI_2());
+        let two_pi = pi + pi;
+
+        // Reduce to [-PI, PI], then to [-PI/2, PI/2] where the sine is unchanged
+        // and the cosine changes sign.
+        let mut x = self.radians - (self.radians / two_pi).round() * two_pi;
+        let mut cos_sign = T::one();
+        if x > frac_pi_2 {
+            x = pi - x;
+            cos_sign = -cos_sign;
+        } else if x < -frac_pi_2 {
+            x = -pi - x;
+            cos_sign = -cos_sign;
+        }
+
+        // Taylor series, with the last coefficients adjusted so that the
+        // polynomials are exact at PI/2.
+        let x2 = x * x;
+        let s = c(2.694_884_624_256_989e-6);
+        let s = s * x2 - c(1.0 / 5040.0);
+        let s = s * x2 + c(1.0 / 120.0);
+        let s = s * x2 - c(1.0 / 6.0);
+        let sin = x + x * x2 * s;
+
+        let k = c(-2.704_911_813_982_724e-7);
+        let k = k * x2 + c(1.0 / 40320.0);
+        let k = k * x2 - c(1.0 / 720.0);
+        let k = k * x2 + c(1.0 / 24.0);
+        let k = k * x2 - c(0.5);
+        let cos = T::one() + x2 * k;
+
+        (sin, cos * cos_sign)
+    }
+}
+
 impl<T> Angle<T>
 where
     T: Zero,
@@ -160,26 +246,26 @@ where
 
 impl<T> Angle<T>
 where
-    T: FloatConst + Add<Output = T>,
+    T: Pi + Add<Output = T>,
 {
     pub fn pi() -> Self {
-        Angle::radians(T::PI())
+        Angle::radians(T::pi())
     }
 
     pub fn two_pi() -> Self {
-        Angle::radians(T::PI() + T::PI())
+        Angle::radians(T::pi() + T::pi())
     }
 
     pub fn frac_pi_2() -> Self {
-        Angle::radians(T::FRAC_PI_2())
+        Angle::radians(T::frac_pi_2())
     }
 
     pub fn frac_pi_3() -> Self {
-        Angle::radians(T::FRAC_PI_3())
+        Angle::radians(T::frac_pi_3())
     }
 
     pub fn frac_pi_4() -> Self {
-        Angle::radians(T::FRAC_PI_4())
+        Angle::radians(T::frac_pi_4())
     }
 }
 
@@ -211,6 +297,13 @@ where
     pub fn to_f64(&self) -> Angle<f64> {
         self.cast()
     }
+
+    /// Cast angle `f16`.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(&self) -> Angle<half::f16> {
+        self.cast()
+    }
 }
 
 impl<T: Add<T, Output = T>> Add for Angle<T> {
@@ -372,6 +465,35 @@ fn lerp() {
     assert!(a
         .lerp(b + A::two_pi() * 5.0, 0.75)
         .approx_eq(&Angle::radians(1.75)));
+
+    // Across the discontinuity at PI.
+    let a = A::radians(3.0);
+    let b = A::radians(-3.0);
+    assert!(a.lerp(b, 0.5).approx_eq(&A::pi()));
+    assert!(b.lerp(a, 0.5).signed().approx_eq(&A::pi()));
+}
+
+#[test]
+fn angle_to() {
+    type A = Angle<f32>;
+
+    let a = A::degrees(10.0);
+    assert!(a.angle_to(A::degrees(30.0)).approx_eq(&A::degrees(20.0)));
+    assert!(a.angle_to(A::degrees(-30.0)).approx_eq(&A::degrees(-40.0)));
+    assert!(a.angle_to(A::degrees(350.0)).approx_eq(&A::degrees(-20.0)));
+    assert!(A::degrees(350.0)
+        .angle_to(A::degrees(10.0))
+        .approx_eq(&A::degrees(20.0)));
+    assert!(a.angle_to(A::degrees(730.0)).approx_eq(&A::zero()));
+}
+
+#[test]
+fn sin_cos() {
+    let (sin, cos) = Angle::<f64>::frac_pi_2().sin_cos();
+    assert!(sin.approx_eq(&1.0f64));
+    assert!(cos.approx_eq(&0.0));
+    let (sin, cos) = Angle::radians(1.0f32).sin_cos();
+    assert_eq!((sin, cos), (1.0f32.sin(), 1.0f32.cos()));
 }
 
 #[test]
@@ -381,3 +503,22 @@ fn sum() {
     let sum = A::radians(6.0);
     assert_eq!(angles.iter().sum::<A>(), sum);
 }
+
+#[cfg(feature = "fast-math")]
+#[test]
+fn fast_trig() {
+    use core::f32::consts::PI;
+
+    let mut max_error: f32 = 0.0;
+    for i in -4000..=4000 {
+        let a = Angle::radians(i as f32 * PI / 1000.0);
+        max_error = max_error
+            .max((a.fast_sin() - a.radians.sin()).abs())
+            .max((a.fast_cos() - a.radians.cos()).abs());
+    }
+    assert!(max_error < 1e-6, "{}", max_error);
+
+    let a = Angle::radians(1.0f64);
+    assert!((a.fast_sin() - a.radians.sin()).abs() < 1e-6);
+    assert_eq!(Angle::radians(0.0f32).fast_sin_cos(), (0.0, 1.0));
+}
@@ -40,3 +40,6 @@ macro_rules! approx_eq {
 
 approx_eq!(f32, 1.0e-6);
 approx_eq!(f64, 1.0e-6);
+// The precision of `f16` is about three decimal digits.
+#[cfg(feature = "half")]
+approx_eq!(half::f16, half::f16::from_f32_const(1.0e-3));
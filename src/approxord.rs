@@ -10,6 +10,8 @@
 //! Utilities for testing approximate ordering - especially true for
 //! floating point types, where NaN's cannot be ordered.
 
+use core::ops::Sub;
+
 pub fn min<T: PartialOrd>(x: T, y: T) -> T {
     if x <= y {
         x
@@ -26,6 +28,16 @@ pub fn max<T: PartialOrd>(x: T, y: T) -> T {
     }
 }
 
+/// Returns `|x - y|`, subtracting the smaller value from the larger one so that the
+/// result doesn't underflow for unsigned integers.
+pub fn abs_diff<T: PartialOrd + Sub<Output = T>>(x: T, y: T) -> T {
+    if x >= y {
+        x - y
+    } else {
+        y - x
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +53,10 @@ mod tests {
         assert!(max(0u32, 1u32) == 1u32);
         assert!(max(-1.0f32, 0.0f32) == 0.0f32);
     }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_eq!(abs_diff(3u8, 250u8), 247);
+        assert_eq!(abs_diff(-1.5f32, 1.0), 2.5);
+    }
 }
@@ -0,0 +1,556 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The arithmetic kernels of the transforms, selected by scalar type.
+//!
+//! The methods of [`SimdScalar`] are the scalar implementations of the matrix and
+//! quaternion products, of the point transforms, of the interpolations and of the batch
+//! methods of `Transform2D`, `Transform3D`, `Rotation3D` and the points and vectors.
+//! `f32` overrides them with the kernels of the `simd` feature when it is enabled, `f32`
+//! and `f64` override them with fused multiply-add operations when the `fma` feature is
+//! enabled, and every other scalar type keeps the scalar code.
+
+use crate::matrix::{affine_then, Matrix};
+use crate::num::{One, Zero};
+use crate::{Point3D, Transform3D, Vector3D};
+use core::ops::{Add, Div, Mul, Sub};
+
+#[cfg(feature = "fma")]
+use crate::matrix::{vec_mul_add, vec_mul_fused};
+#[cfg(feature = "simd")]
+use crate::{simd, vec3, HomogeneousVector, Vector3Dx4};
+#[cfg(feature = "fma")]
+use num_traits::MulAdd;
+
+/// The scalar types of the transform kernels.
+///
+/// Products of transforms and rotations, point transforms and interpolations dispatch
+/// on this trait, so that `f32` can use the SIMD kernels of the `simd` feature, and `f32`
+/// and `f64` the fused multiply-add operations of the `fma` feature. Every method has a
+/// default implementation with the plain arithmetic operators, so a custom scalar type
+/// only needs an empty implementation:
+///
+/// ```
+/// use euclid::default::Transform3D;
+/// use euclid::SimdScalar;
+/// # use core::ops::{Add, Mul};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct Meters(f64);
+/// # impl Add for Meters { type Output = Self; fn add(self, o: Self) -> Self { Meters(self.0 + o.0) } }
+/// # impl Mul for Meters { type Output = Self; fn mul(self, o: Self) -> Self { Meters(self.0 * o.0) } }
+///
+/// impl SimdScalar for Meters {}
+///
+/// let m = Transform3D::from_array([Meters(2.0); 16]);
+/// assert_eq!(m.then(&m).m11, Meters(16.0));
+/// ```
+pub trait SimdScalar: Sized {
+    /// Computes the product of the row-major matrices `a` and `b`, for
+    /// `Transform3D::then`.
+    #[inline]
+    fn mat4_mul(a: &[[Self; 4]; 4], b: &[[Self; 4]; 4]) -> [[Self; 4]; 4]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        Matrix(*a).mul(&Matrix(*b)).0
+    }
+
+    /// Computes the homogeneous coordinates of the 3d point `p` transformed by the
+    /// row-major matrix `m`, for `Transform3D::transform_point3d`.
+    #[inline]
+    fn mat4_point3d(m: &[[Self; 4]; 4], p: [Self; 3]) -> [Self; 4]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let [x, y, z] = p;
+        [0, 1, 2, 3].map(|c| x * m[0][c] + y * m[1][c] + z * m[2][c] + m[3][c])
+    }
+
+    /// Computes the homogeneous coordinates of the 2d point `p` transformed by the
+    /// row-major matrix `m`, for `Transform3D::transform_point2d`.
+    #[inline]
+    fn mat4_point2d(m: &[[Self; 4]; 4], p: [Self; 2]) -> [Self; 4]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let [x, y] = p;
+        [0, 1, 2, 3].map(|c| x * m[0][c] + y * m[1][c] + m[3][c])
+    }
+
+    /// Computes the 3d vector `v` transformed by the row-major matrix `m`, for
+    /// `Transform3D::transform_vector3d`.
+    #[inline]
+    fn mat4_vector3d(m: &[[Self; 4]; 4], v: [Self; 3]) -> [Self; 3]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let [x, y, z] = v;
+        [0, 1, 2].map(|c| x * m[0][c] + y * m[1][c] + z * m[2][c])
+    }
+
+    /// Computes the Hamilton product `a * b` of the `[i, j, k, r]` quaternions `a` and
+    /// `b`, for `Rotation3D::then`.
+    #[inline]
+    fn quat_mul(a: [Self; 4], b: [Self; 4]) -> [Self; 4]
+    where
+        Self: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        let [ai, aj, ak, ar] = a;
+        let [bi, bj, bk, br] = b;
+        [
+            ai * br + ar * bi + aj * bk - ak * bj,
+            aj * br + ar * bj + ak * bi - ai * bk,
+            ak * br + ar * bk + ai * bj - aj * bi,
+            ar * br - ai * bi - aj * bj - ak * bk,
+        ]
+    }
+
+    /// Composes the affine transforms stored as row-major 3x2 matrices `a` and `b`, in
+    /// that order, for `Transform2D::then`.
+    #[inline]
+    fn affine2_then(a: &[[Self; 2]; 3], b: &[[Self; 2]; 3]) -> [[Self; 2]; 3]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let (linear, translation) =
+            affine_then(&Matrix([a[0], a[1]]), a[2], &Matrix([b[0], b[1]]), b[2]);
+        [linear.0[0], linear.0[1], translation]
+    }
+
+    /// Computes the point `p` transformed by the row-major 3x2 matrix `m`, for
+    /// `Transform2D::transform_point`.
+    #[inline]
+    fn affine2_point(m: &[[Self; 2]; 3], p: [Self; 2]) -> [Self; 2]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let [x, y] = p;
+        [0, 1].map(|c| x * m[0][c] + y * m[1][c] + m[2][c])
+    }
+
+    /// Computes the vector `v` transformed by the row-major 3x2 matrix `m`, for
+    /// `Transform2D::transform_vector`.
+    #[inline]
+    fn affine2_vector(m: &[[Self; 2]; 3], v: [Self; 2]) -> [Self; 2]
+    where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        let [x, y] = v;
+        [0, 1].map(|c| x * m[0][c] + y * m[1][c])
+    }
+
+    /// Interpolates linearly between `a` and `b`, for the `lerp` methods of the points
+    /// and vectors.
+    #[inline]
+    fn lerp(a: Self, b: Self, t: Self) -> Self
+    where
+        Self: Clone + One + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        let one_t = Self::one() - t.clone();
+        one_t * a + t * b
+    }
+
+    /// Implements `Transform3D::transform_points3d`, for slices of the same length.
+    #[inline]
+    fn transform_points3d<Src, Dst>(
+        m: &Transform3D<Self, Src, Dst>,
+        points: &[Point3D<Self, Src>],
+        out: &mut [Option<Point3D<Self, Dst>>],
+    ) where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+        Self: Div<Output = Self> + Zero + PartialOrd,
+    {
+        for (p, o) in points.iter().zip(out) {
+            *o = m.transform_point3d(*p);
+        }
+    }
+
+    /// Implements `Transform3D::transform_vectors3d`, for slices of the same length.
+    #[inline]
+    fn transform_vectors3d<Src, Dst>(
+        m: &Transform3D<Self, Src, Dst>,
+        vectors: &[Vector3D<Self, Src>],
+        out: &mut [Vector3D<Self, Dst>],
+    ) where
+        Self: Copy + Add<Output = Self> + Mul<Output = Self>,
+    {
+        for (v, o) in vectors.iter().zip(out) {
+            *o = m.transform_vector3d(*v);
+        }
+    }
+
+    /// Implements `Rotation3D::rotate_vectors` for the `[i, j, k, r]` quaternion `q`, with
+    /// the two cross products formulation.
+    #[inline]
+    fn rotate_vectors<U>(q: [Self; 4], vectors: &mut [Vector3D<Self, U>])
+    where
+        Self: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        let [i, j, k, r] = q;
+        for v in vectors {
+            let (x, y, z) = (v.x, v.y, v.z);
+            let (cx, cy, cz) = (j * z - k * y, k * x - i * z, i * y - j * x);
+            let (cx, cy, cz) = (cx + cx, cy + cy, cz + cz);
+            *v = Vector3D::new(
+                x + r * cx + j * cz - k * cy,
+                y + r * cy + k * cx - i * cz,
+                z + r * cz + i * cy - j * cx,
+            );
+        }
+    }
+}
+
+/// The number of elements that the in-place slice transforms copy at a time.
+pub(crate) const CHUNK: usize = 16;
+
+/// Calls `f` with a copy of each chunk of `items` and the chunk itself, so that the in-place
+/// slice transforms can go through the ones that write to another slice.
+pub(crate) fn in_place<T: Copy>(items: &mut [T], mut f: impl FnMut(&[T], &mut [T])) {
+    let mut copy = match items.first() {
+        Some(first) => [*first; CHUNK],
+        None => return,
+    };
+    for chunk in items.chunks_mut(CHUNK) {
+        let copy = &mut copy[..chunk.len()];
+        copy.copy_from_slice(chunk);
+        f(copy, chunk);
+    }
+}
+
+/// Overrides the products, the point transforms and the interpolations with fused
+/// multiply-add operations.
+///
+/// They are faster and more precise than the plain operations on targets with hardware
+/// FMA support, for example when compiling for x86_64 with `-C target-feature=+fma`.
+/// Elsewhere `f32::mul_add` and `f64::mul_add` are slow software implementations.
+#[cfg(feature = "fma")]
+macro_rules! fused {
+    ($ty:ty) => {
+        #[inline]
+        fn affine2_then(a: &[[$ty; 2]; 3], b: &[[$ty; 2]; 3]) -> [[$ty; 2]; 3] {
+            let b_linear = Matrix([b[0], b[1]]);
+            let linear = Matrix([a[0], a[1]]).mul_fused(&b_linear);
+            [linear.0[0], linear.0[1], vec_mul_add(a[2], &b_linear, b[2])]
+        }
+
+        #[inline]
+        fn affine2_point(m: &[[$ty; 2]; 3], p: [$ty; 2]) -> [$ty; 2] {
+            vec_mul_add(p, &Matrix([m[0], m[1]]), m[2])
+        }
+
+        #[inline]
+        fn affine2_vector(m: &[[$ty; 2]; 3], v: [$ty; 2]) -> [$ty; 2] {
+            vec_mul_fused(v, &Matrix([m[0], m[1]]))
+        }
+
+        #[inline]
+        fn lerp(a: $ty, b: $ty, t: $ty) -> $ty {
+            MulAdd::mul_add(1.0 - t, a, t * b)
+        }
+    };
+}
+
+/// The 4x4 counterpart of `fused!`, which `f32` only uses without the `simd` feature.
+#[cfg(feature = "fma")]
+macro_rules! fused_mat4 {
+    ($ty:ty) => {
+        #[inline]
+        fn mat4_mul(a: &[[$ty; 4]; 4], b: &[[$ty; 4]; 4]) -> [[$ty; 4]; 4] {
+            Matrix(*a).mul_fused(&Matrix(*b)).0
+        }
+
+        #[inline]
+        fn mat4_point3d(m: &[[$ty; 4]; 4], p: [$ty; 3]) -> [$ty; 4] {
+            vec_mul_add(p, &Matrix([m[0], m[1], m[2]]), m[3])
+        }
+
+        #[inline]
+        fn mat4_point2d(m: &[[$ty; 4]; 4], p: [$ty; 2]) -> [$ty; 4] {
+            vec_mul_add(p, &Matrix([m[0], m[1]]), m[3])
+        }
+
+        #[inline]
+        fn mat4_vector3d(m: &[[$ty; 4]; 4], v: [$ty; 3]) -> [$ty; 3] {
+            let [x, y, z, _] = vec_mul_fused(v, &Matrix([m[0], m[1], m[2]]));
+            [x, y, z]
+        }
+    };
+}
+
+macro_rules! scalar {
+    ($($ty:ty),*) => {$(
+        impl SimdScalar for $ty {}
+    )*};
+}
+
+scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "half")]
+impl SimdScalar for half::f16 {}
+
+impl SimdScalar for f64 {
+    #[cfg(feature = "fma")]
+    fused!(f64);
+    #[cfg(feature = "fma")]
+    fused_mat4!(f64);
+}
+
+/// With the `simd` feature, the products and point transforms sum the terms in the same
+/// order as the scalar code, and take precedence over the fused multiply-add operations.
+/// The matrix is loaded in vector registers once for a whole slice, and vectors are
+/// rotated four at a time.
+impl SimdScalar for f32 {
+    #[cfg(feature = "fma")]
+    fused!(f32);
+    #[cfg(all(feature = "fma", not(feature = "simd")))]
+    fused_mat4!(f32);
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn mat4_mul(a: &[[f32; 4]; 4], b: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+        simd::mat4_mul(a, b)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn mat4_point3d(m: &[[f32; 4]; 4], p: [f32; 3]) -> [f32; 4] {
+        simd::Mat4::load(m).mul_point(p)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn mat4_point2d(m: &[[f32; 4]; 4], p: [f32; 2]) -> [f32; 4] {
+        simd::Mat4::load(m).mul_point(p)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn mat4_vector3d(m: &[[f32; 4]; 4], v: [f32; 3]) -> [f32; 3] {
+        let [x, y, z, _] = simd::Mat4::load(m).mul_vec(v);
+        [x, y, z]
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn quat_mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        simd::quat_mul(a, b)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn transform_points3d<Src, Dst>(
+        m: &Transform3D<f32, Src, Dst>,
+        points: &[Point3D<f32, Src>],
+        out: &mut [Option<Point3D<f32, Dst>>],
+    ) {
+        let m = simd::Mat4::load(&m.to_arrays());
+        for (p, o) in points.iter().zip(out) {
+            let [x, y, z, w] = m.mul_point([p.x, p.y, p.z]);
+            *o = HomogeneousVector::new(x, y, z, w).to_point3d();
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn transform_vectors3d<Src, Dst>(
+        m: &Transform3D<f32, Src, Dst>,
+        vectors: &[Vector3D<f32, Src>],
+        out: &mut [Vector3D<f32, Dst>],
+    ) {
+        let m = simd::Mat4::load(&m.to_arrays());
+        for (v, o) in vectors.iter().zip(out) {
+            let [x, y, z, _] = m.mul_vec([v.x, v.y, v.z]);
+            *o = vec3(x, y, z);
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn rotate_vectors<U>(q: [f32; 4], vectors: &mut [Vector3D<f32, U>]) {
+        let rotate = |v: &mut Vector3Dx4<f32, U>| {
+            let [x, y, z] = simd::quat_rotate_x4(q, [v.x, v.y, v.z]);
+            v.x = x;
+            v.y = y;
+            v.z = z;
+        };
+
+        let mut chunks = vectors.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let mut v = Vector3Dx4::gather(chunk);
+            rotate(&mut v);
+            v.scatter(chunk);
+        }
+
+        // Pad the last vectors into a final block.
+        let rest = chunks.into_remainder();
+        if let Some(first) = rest.first() {
+            let mut v = Vector3Dx4::splat(*first);
+            for (l, r) in rest.iter().enumerate() {
+                v.set_lane(l, *r);
+            }
+            rotate(&mut v);
+            for (l, r) in rest.iter_mut().enumerate() {
+                *r = v.lane(l);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimdScalar;
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Rotation3D, Transform2D, Transform3D};
+    use crate::num::One;
+    use crate::{vec2, vec3, Angle};
+    use core::ops::{Add, Mul, Sub};
+
+    /// An `f32` that keeps the default implementations, to check the `f32` kernels against.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Plain(f32);
+
+    impl Add for Plain {
+        type Output = Plain;
+        fn add(self, other: Plain) -> Plain {
+            Plain(self.0 + other.0)
+        }
+    }
+
+    impl Sub for Plain {
+        type Output = Plain;
+        fn sub(self, other: Plain) -> Plain {
+            Plain(self.0 - other.0)
+        }
+    }
+
+    impl Mul for Plain {
+        type Output = Plain;
+        fn mul(self, other: Plain) -> Plain {
+            Plain(self.0 * other.0)
+        }
+    }
+
+    impl One for Plain {
+        fn one() -> Plain {
+            Plain(1.0)
+        }
+    }
+
+    impl SimdScalar for Plain {}
+
+    fn plain<const N: usize>(v: [f32; N]) -> [Plain; N] {
+        v.map(Plain)
+    }
+
+    fn assert_close<const N: usize>(a: [f32; N], b: [Plain; N]) {
+        for (a, b) in a.iter().zip(&b) {
+            assert!(a.approx_eq_eps(&b.0, &1e-5), "{:?} != {:?}", a, b.0);
+        }
+    }
+
+    fn matrices() -> [[[f32; 4]; 4]; 4] {
+        [
+            Transform3D::identity(),
+            Transform3D::translation(1.0, -2.0, 3.5),
+            Transform3D::rotation(0.5, 1.0, 2.0, Angle::radians(0.7)).then_scale(2.0, 3.0, 0.5),
+            Transform3D::perspective(5.0).then_translate(vec3(0.25, 0.5, -1.0)),
+        ]
+        .map(|m| m.to_arrays())
+    }
+
+    #[test]
+    fn test_mat4_kernels() {
+        let points = [[1.5, -2.0, 0.25], [0.0, 0.0, 0.0], [-3.0, 4.0, 10.0]];
+        for a in &matrices() {
+            let pa = a.map(plain);
+            for b in &matrices() {
+                let pb = b.map(plain);
+                let m = f32::mat4_mul(a, b);
+                let expected = Plain::mat4_mul(&pa, &pb);
+                for (row, expected) in m.iter().zip(&expected) {
+                    assert_close(*row, *expected);
+                }
+            }
+            for p in &points {
+                assert_close(
+                    f32::mat4_point3d(a, *p),
+                    Plain::mat4_point3d(&pa, plain(*p)),
+                );
+                assert_close(
+                    f32::mat4_vector3d(a, *p),
+                    Plain::mat4_vector3d(&pa, plain(*p)),
+                );
+                let p = [p[0], p[1]];
+                assert_close(f32::mat4_point2d(a, p), Plain::mat4_point2d(&pa, plain(p)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quat_mul() {
+        let rotations = [
+            Rotation3D::identity(),
+            Rotation3D::around_x(Angle::radians(0.5)),
+            Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.3)),
+            Rotation3D::euler(
+                Angle::radians(-1.0),
+                Angle::radians(0.25),
+                Angle::radians(3.0),
+            ),
+        ]
+        .map(|r: Rotation3D<f32>| [r.i, r.j, r.k, r.r]);
+        for a in &rotations {
+            for b in &rotations {
+                assert_close(f32::quat_mul(*a, *b), Plain::quat_mul(plain(*a), plain(*b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_affine2_kernels() {
+        let matrices = [
+            Transform2D::identity(),
+            Transform2D::rotation(Angle::radians(0.7)).then_translate(vec2(1.0, 2.0)),
+            Transform2D::scale(2.0, 0.5).then_rotate(Angle::radians(-1.1)),
+        ]
+        .map(|m| m.to_arrays());
+        for a in &matrices {
+            let pa = a.map(plain);
+            for b in &matrices {
+                let m = f32::affine2_then(a, b);
+                let expected = Plain::affine2_then(&pa, &b.map(plain));
+                for (row, expected) in m.iter().zip(&expected) {
+                    assert_close(*row, *expected);
+                }
+            }
+            let p = [4.0, -5.0];
+            assert_close(
+                f32::affine2_point(a, p),
+                Plain::affine2_point(&pa, plain(p)),
+            );
+            assert_close(
+                f32::affine2_vector(a, p),
+                Plain::affine2_vector(&pa, plain(p)),
+            );
+        }
+    }
+
+    #[test]
+    fn test_lerp() {
+        for &t in &[-1.0, 0.0, 0.3, 0.5, 1.0, 2.0] {
+            for &(a, b) in &[(0.0, 10.0), (8.0, -4.0), (-1.5, 1e6)] {
+                assert_close(
+                    [f32::lerp(a, b, t)],
+                    [Plain::lerp(Plain(a), Plain(b), Plain(t))],
+                );
+            }
+        }
+    }
+}
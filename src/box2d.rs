@@ -9,17 +9,22 @@
 
 use super::UnknownUnit;
 use crate::approxord::{max, min};
+use crate::batch::SimdScalar;
+use crate::cast::{cast_component, round_component, saturate_component, CastError, RoundingMode};
+use crate::circle::Circle;
 use crate::num::*;
 use crate::point::{point2, Point2D};
 use crate::rect::Rect;
-use crate::scale::Scale;
+use crate::scale::{Scale, Scale2D};
 use crate::side_offsets::SideOffsets2D;
 use crate::size::Size2D;
+use crate::validated::not_nan;
 use crate::vector::{vec2, Vector2D};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-use num_traits::{Float, NumCast};
+use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, Float, NumCast};
+use num_traits::{SaturatingAdd, SaturatingSub};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -58,6 +63,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 /// [`union`]: Self::union
 /// [`size`]: Self::size
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -126,6 +135,12 @@ impl<T, U> Box2D<T, U> {
         Box2D { min, max }
     }
 
+    /// Constructor taking a tuple with the coordinates of `min` and `max`.
+    #[inline]
+    pub fn from_tuple((min, max): ((T, T), (T, T))) -> Self {
+        Box2D::new(Point2D::from_tuple(min), Point2D::from_tuple(max))
+    }
+
     /// Constructor.
     #[inline]
     pub fn from_origin_and_size(origin: Point2D<T, U>, size: Size2D<T, U>) -> Self
@@ -138,6 +153,17 @@ impl<T, U> Box2D<T, U> {
         }
     }
 
+    /// Creates a box spanning a range on each axis.
+    ///
+    /// This is the inverse of [`x_range`](Self::x_range) and [`y_range`](Self::y_range).
+    #[inline]
+    pub fn from_ranges(x: Range<T>, y: Range<T>) -> Self {
+        Box2D {
+            min: point2(x.start, y.start),
+            max: point2(x.end, y.end),
+        }
+    }
+
     /// Creates a `Box2D` of the given size, at offset zero.
     #[inline]
     pub fn from_size(size: Size2D<T, U>) -> Self
@@ -252,20 +278,65 @@ where
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + PartialOrd,
+    T: Clone + PartialOrd + Add<T, Output = T>,
 {
+    /// Returns the index of the first box of `sorted_boxes` that intersects `query`.
+    ///
+    /// See [`Box2D::intersecting_indices`] for the requirements on `sorted_boxes` and
+    /// `max_width`.
+    #[inline]
+    pub fn first_intersecting(sorted_boxes: &[Self], query: &Self, max_width: T) -> Option<usize> {
+        Self::intersecting_indices(sorted_boxes, query, max_width).next()
+    }
+
+    /// Returns an iterator over the indices of the boxes of `sorted_boxes` that
+    /// intersect `query`, in increasing order.
+    ///
+    /// `sorted_boxes` must be sorted by increasing `min.x`, and `max_width` must be at
+    /// least the width of each of them. Only the boxes whose `min.x` is greater than
+    /// `query.min.x - max_width` and less than `query.max.x` can intersect `query`:
+    /// both ends of that range are found with a binary search, and the boxes in
+    /// between are tested in order. Nothing is allocated.
+    pub fn intersecting_indices<'a>(
+        sorted_boxes: &'a [Self],
+        query: &'a Self,
+        max_width: T,
+    ) -> impl Iterator<Item = usize> + 'a {
+        // `b.max.x <= b.min.x + max_width`, so the boxes before `start` end at or
+        // before the left edge of `query`.
+        let start =
+            sorted_boxes.partition_point(|b| b.min.x.clone() + max_width.clone() <= query.min.x);
+        let end = sorted_boxes.partition_point(|b| b.min.x < query.max.x);
+        (start..end.max(start)).filter(move |&i| sorted_boxes[i].intersects(query))
+    }
+}
+
+impl<T, U> Box2D<T, U>
+where
+    T: Clone + PartialOrd,
+{
+    /// Returns `true` if none of the coordinates is NaN.
+    #[inline]
+    fn not_nan(&self) -> bool {
+        not_nan(&[&self.min.x, &self.min.y, &self.max.x, &self.max.y])
+    }
+
     #[inline]
     pub fn to_non_empty(&self) -> Option<Self> {
         if self.is_empty() {
             return None;
         }
 
-        Some(*self)
+        Some(self.clone())
     }
 
     /// Computes the intersection of two boxes, returning `None` if the boxes do not intersect.
     #[inline]
     pub fn intersection(&self, other: &Self) -> Option<Self> {
+        validate_invariant!(
+            self.not_nan() && other.not_nan(),
+            "Box2D::intersection: NaN component"
+        );
         let b = self.intersection_unchecked(other);
 
         if b.is_empty() {
@@ -284,8 +355,14 @@ where
     #[inline]
     pub fn intersection_unchecked(&self, other: &Self) -> Self {
         Box2D {
-            min: point2(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
-            max: point2(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+            min: point2(
+                max(self.min.x.clone(), other.min.x.clone()),
+                max(self.min.y.clone(), other.min.y.clone()),
+            ),
+            max: point2(
+                min(self.max.x.clone(), other.max.x.clone()),
+                min(self.max.y.clone(), other.max.y.clone()),
+            ),
         }
     }
 
@@ -294,41 +371,116 @@ where
     /// If either of the boxes is empty, the other one is returned.
     #[inline]
     pub fn union(&self, other: &Self) -> Self {
+        validate_invariant!(
+            self.not_nan() && other.not_nan(),
+            "Box2D::union: NaN component"
+        );
         if other.is_empty() {
-            return *self;
+            return self.clone();
         }
         if self.is_empty() {
-            return *other;
+            return other.clone();
         }
 
         Box2D {
-            min: point2(min(self.min.x, other.min.x), min(self.min.y, other.min.y)),
-            max: point2(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
+            min: point2(
+                min(self.min.x.clone(), other.min.x.clone()),
+                min(self.min.y.clone(), other.min.y.clone()),
+            ),
+            max: point2(
+                max(self.max.x.clone(), other.max.x.clone()),
+                max(self.max.y.clone(), other.max.y.clone()),
+            ),
         }
     }
+
+    /// Grows the box in place so that it contains `p`, including on its maximum edges.
+    ///
+    /// Each coordinate of `min` and `max` is moved independently, so a box whose `min`
+    /// is greater than its `max` in both directions, like one from the largest to the
+    /// smallest value of `T`, grows to exactly the first point it is given.
+    #[inline]
+    pub fn expand_to_include(&mut self, p: Point2D<T, U>) {
+        self.min = self.min.clone().min(p.clone());
+        self.max = self.max.clone().max(p);
+    }
+
+    /// Grows the box in place so that it contains `other`.
+    ///
+    /// This is the in-place version of [`union`](Self::union), so an empty `other`
+    /// leaves the box unchanged, and an empty box is replaced by `other`.
+    #[inline]
+    pub fn expand_to_include_box(&mut self, other: &Self) {
+        *self = self.union(other);
+    }
+
+    /// Returns the parts of the box that `other` doesn't cover, as up to four
+    /// non-overlapping boxes.
+    ///
+    /// The boxes are in the order top, left, right, bottom, for a y axis pointing down.
+    /// The top and bottom boxes span the full width of `self`. Empty boxes are skipped, so
+    /// nothing is returned if `other` covers the box entirely or the box is empty.
+    ///
+    /// ```
+    /// use euclid::default::Box2D;
+    /// use euclid::point2;
+    ///
+    /// let b = Box2D::new(point2(0, 0), point2(10, 10));
+    /// let hole = Box2D::new(point2(2, 5), point2(12, 8));
+    /// let parts: Vec<_> = b.difference(&hole).collect();
+    /// assert_eq!(parts, vec![
+    ///     Box2D::new(point2(0, 0), point2(10, 5)),
+    ///     Box2D::new(point2(0, 5), point2(2, 8)),
+    ///     Box2D::new(point2(0, 8), point2(10, 10)),
+    /// ]);
+    /// ```
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let mut parts = [None, None, None, None];
+        match self.intersection(other) {
+            None => parts[0] = self.to_non_empty(),
+            Some(i) => {
+                let (lo, hi) = (&self.min, &self.max);
+                parts = [
+                    Box2D::new(lo.clone(), point2(hi.x.clone(), i.min.y.clone())),
+                    Box2D::new(
+                        point2(lo.x.clone(), i.min.y.clone()),
+                        point2(i.min.x, i.max.y.clone()),
+                    ),
+                    Box2D::new(
+                        point2(i.max.x, i.min.y),
+                        point2(hi.x.clone(), i.max.y.clone()),
+                    ),
+                    Box2D::new(point2(lo.x.clone(), i.max.y), hi.clone()),
+                ]
+                .map(|b| b.to_non_empty());
+            }
+        }
+
+        IntoIterator::into_iter(parts).flatten()
+    }
 }
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + Add<T, Output = T>,
+    T: Clone + Add<T, Output = T>,
 {
     /// Returns the same box, translated by a vector.
     #[inline]
     pub fn translate(&self, by: Vector2D<T, U>) -> Self {
         Box2D {
-            min: self.min + by,
-            max: self.max + by,
+            min: self.min.clone() + by.clone(),
+            max: self.max.clone() + by,
         }
     }
 }
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + Sub<T, Output = T>,
+    T: Clone + Sub<T, Output = T>,
 {
     #[inline]
     pub fn size(&self) -> Size2D<T, U> {
-        (self.max - self.min).to_size()
+        (self.max.clone() - self.min.clone()).to_size()
     }
 
     /// Change the size of the box by adjusting the max endpoint
@@ -341,18 +493,18 @@ where
 
     #[inline]
     pub fn width(&self) -> T {
-        self.max.x - self.min.x
+        self.max.x.clone() - self.min.x.clone()
     }
 
     #[inline]
     pub fn height(&self) -> T {
-        self.max.y - self.min.y
+        self.max.y.clone() - self.min.y.clone()
     }
 
     #[inline]
     pub fn to_rect(&self) -> Rect<T, U> {
         Rect {
-            origin: self.min,
+            origin: self.min.clone(),
             size: self.size(),
         }
     }
@@ -360,15 +512,18 @@ where
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + Add<T, Output = T> + Sub<T, Output = T>,
+    T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
 {
     /// Inflates the box by the specified sizes on each side respectively.
     #[inline]
     #[must_use]
     pub fn inflate(&self, width: T, height: T) -> Self {
         Box2D {
-            min: point2(self.min.x - width, self.min.y - height),
-            max: point2(self.max.x + width, self.max.y + height),
+            min: point2(
+                self.min.x.clone() - width.clone(),
+                self.min.y.clone() - height.clone(),
+            ),
+            max: point2(self.max.x.clone() + width, self.max.y.clone() + height),
         }
     }
 
@@ -378,8 +533,8 @@ where
     /// and applicate offsets must not be larger than the original side length.
     pub fn inner_box(&self, offsets: SideOffsets2D<T, U>) -> Self {
         Box2D {
-            min: self.min + vec2(offsets.left, offsets.top),
-            max: self.max - vec2(offsets.right, offsets.bottom),
+            min: self.min.clone() + vec2(offsets.left, offsets.top),
+            max: self.max.clone() - vec2(offsets.right, offsets.bottom),
         }
     }
 
@@ -388,8 +543,8 @@ where
     /// Add the offsets to all sides. The expanded box is returned.
     pub fn outer_box(&self, offsets: SideOffsets2D<T, U>) -> Self {
         Box2D {
-            min: self.min - vec2(offsets.left, offsets.top),
-            max: self.max + vec2(offsets.right, offsets.bottom),
+            min: self.min.clone() - vec2(offsets.left, offsets.top),
+            max: self.max.clone() + vec2(offsets.right, offsets.bottom),
         }
     }
 }
@@ -457,6 +612,11 @@ where
             None => return Box2D::zero(),
         };
 
+        validate_invariant!(
+            not_nan(&[min_x, min_y]),
+            "Box2D::from_points: NaN component"
+        );
+
         let (mut max_x, mut max_y) = (min_x, min_y);
         for point in points {
             let p = point.borrow();
@@ -479,26 +639,91 @@ where
             max: point2(max_x, max_y),
         }
     }
+
+    /// Returns the smallest box enclosing all of the points of the array.
+    ///
+    /// This gives the same result as [`Box2D::from_points`], but the number of points
+    /// is known at compile time so the loop is fully unrolled into straight-line code.
+    ///
+    /// ```
+    /// use euclid::default::{Box2D, Point2D};
+    ///
+    /// let points = [Point2D::new(3, 1), Point2D::new(-1, 4), Point2D::new(2, -5)];
+    /// let b = Box2D::from_point_array(&points);
+    /// assert_eq!(b, Box2D::new(Point2D::new(-1, -5), Point2D::new(3, 4)));
+    /// ```
+    #[inline]
+    pub fn from_point_array<const N: usize>(points: &[Point2D<T, U>; N]) -> Self {
+        let mut b = match points.first() {
+            Some(&first) => Box2D::new(first, first),
+            None => return Box2D::zero(),
+        };
+        for p in &points[1..] {
+            b = b.with_point(*p);
+        }
+        b
+    }
 }
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: Copy + PartialOrd,
 {
-    /// Linearly interpolate between this box and another box.
+    /// Returns the smallest box enclosing the two points.
+    ///
+    /// Same as `Box2D::from_points([a, b])`, without the iterator.
     #[inline]
-    pub fn lerp(&self, other: Self, t: T) -> Self {
-        Self::new(self.min.lerp(other.min, t), self.max.lerp(other.max, t))
+    pub fn from_two_points(a: Point2D<T, U>, b: Point2D<T, U>) -> Self {
+        Box2D::new(a, a).with_point(b)
+    }
+
+    /// Returns the smallest box enclosing the four points, typically the transformed
+    /// corners of another box.
+    ///
+    /// Same as `Box2D::from_points([a, b, c, d])`, without the iterator.
+    #[inline]
+    pub fn from_four_points(
+        a: Point2D<T, U>,
+        b: Point2D<T, U>,
+        c: Point2D<T, U>,
+        d: Point2D<T, U>,
+    ) -> Self {
+        Box2D::new(a, a).with_point(b).with_point(c).with_point(d)
+    }
+
+    /// Grows the box to include `p`, keeping the current bounds when a comparison
+    /// fails (for example with NaN), like [`Box2D::from_points`] does.
+    #[inline]
+    fn with_point(self, p: Point2D<T, U>) -> Self {
+        Box2D {
+            min: point2(min(p.x, self.min.x), min(p.y, self.min.y)),
+            max: point2(max(p.x, self.max.x), max(p.y, self.max.y)),
+        }
     }
 }
 
 impl<T, U> Box2D<T, U>
 where
-    T: Copy + One + Add<Output = T> + Div<Output = T>,
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + SimdScalar,
 {
+    /// Linearly interpolate between this box and another box.
+    #[inline]
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        Self::new(self.min.lerp(other.min, t), self.max.lerp(other.max, t))
+    }
+}
+
+impl<T: Copy + Midpoint, U> Box2D<T, U> {
+    /// Returns the center of the box.
+    ///
+    /// This is computed with [`Midpoint`], so it doesn't overflow, even for coordinates
+    /// close to the limits of `T`. Integer centers are rounded towards negative infinity
+    /// like [`Point2D::midpoint`], so the center of `[-3, 0]` is `-2`.
     pub fn center(&self) -> Point2D<T, U> {
-        let two = T::one() + T::one();
-        (self.min + self.max.to_vector()) / two
+        point2(
+            Midpoint::midpoint(self.min.x, self.max.x),
+            Midpoint::midpoint(self.min.y, self.max.y),
+        )
     }
 }
 
@@ -513,6 +738,148 @@ where
     }
 }
 
+/// Overflow-checked operations, for integer coordinates.
+///
+/// These return `None` instead of wrapping around or panicking when a coordinate or the
+/// size doesn't fit in `T`.
+impl<T, U> Box2D<T, U>
+where
+    T: Copy + CheckedAdd + CheckedSub,
+{
+    /// Same as [`translate`](Self::translate), returning `None` on overflow.
+    #[inline]
+    pub fn checked_translate(&self, by: Vector2D<T, U>) -> Option<Self> {
+        Some(Box2D::new(
+            point2(
+                self.min.x.checked_add(&by.x)?,
+                self.min.y.checked_add(&by.y)?,
+            ),
+            point2(
+                self.max.x.checked_add(&by.x)?,
+                self.max.y.checked_add(&by.y)?,
+            ),
+        ))
+    }
+
+    /// Same as [`inflate`](Self::inflate), returning `None` on overflow.
+    #[inline]
+    pub fn checked_inflate(&self, width: T, height: T) -> Option<Self> {
+        Some(Box2D::new(
+            point2(
+                self.min.x.checked_sub(&width)?,
+                self.min.y.checked_sub(&height)?,
+            ),
+            point2(
+                self.max.x.checked_add(&width)?,
+                self.max.y.checked_add(&height)?,
+            ),
+        ))
+    }
+
+    /// Same as [`size`](Self::size), returning `None` on overflow.
+    ///
+    /// The size of a box spanning more than half of the range of a signed type, like from
+    /// `i32::MIN` to `i32::MAX`, doesn't fit in the type.
+    #[inline]
+    pub fn checked_size(&self) -> Option<Size2D<T, U>> {
+        Some(Size2D::new(
+            self.max.x.checked_sub(&self.min.x)?,
+            self.max.y.checked_sub(&self.min.y)?,
+        ))
+    }
+
+    /// Same as [`to_rect`](Self::to_rect), returning `None` if the size overflows.
+    #[inline]
+    pub fn checked_to_rect(&self) -> Option<Rect<T, U>> {
+        Some(Rect::new(self.min, self.checked_size()?))
+    }
+
+    /// Same as [`area`](Self::area), returning `None` on overflow.
+    #[inline]
+    pub fn checked_area(&self) -> Option<T>
+    where
+        T: CheckedMul,
+    {
+        let size = self.checked_size()?;
+        size.width.checked_mul(&size.height)
+    }
+}
+
+/// Saturating operations, for integer coordinates.
+///
+/// These clamp the coordinates to the range of `T` instead of wrapping around or
+/// panicking, so that the box doesn't end up inverted.
+impl<T, U> Box2D<T, U>
+where
+    T: Copy + SaturatingAdd + SaturatingSub,
+{
+    /// Same as [`translate`](Self::translate), clamping the coordinates.
+    ///
+    /// ```
+    /// use euclid::default::Box2D;
+    /// use euclid::{point2, vec2};
+    ///
+    /// let b: Box2D<i32> = Box2D::new(point2(0, 0), point2(10, 10));
+    /// let moved = b.saturating_translate(vec2(i32::MAX - 5, 0));
+    /// assert_eq!(moved, Box2D::new(point2(i32::MAX - 5, 0), point2(i32::MAX, 10)));
+    /// ```
+    #[inline]
+    pub fn saturating_translate(&self, by: Vector2D<T, U>) -> Self {
+        Box2D::new(
+            point2(
+                self.min.x.saturating_add(&by.x),
+                self.min.y.saturating_add(&by.y),
+            ),
+            point2(
+                self.max.x.saturating_add(&by.x),
+                self.max.y.saturating_add(&by.y),
+            ),
+        )
+    }
+
+    /// Same as [`inflate`](Self::inflate), clamping the coordinates.
+    #[inline]
+    pub fn saturating_inflate(&self, width: T, height: T) -> Self {
+        Box2D::new(
+            point2(
+                self.min.x.saturating_sub(&width),
+                self.min.y.saturating_sub(&height),
+            ),
+            point2(
+                self.max.x.saturating_add(&width),
+                self.max.y.saturating_add(&height),
+            ),
+        )
+    }
+
+    /// Same as [`union`](Self::union).
+    ///
+    /// The union of two boxes only picks existing coordinates, so it can't overflow. This
+    /// is the counterpart of [`Rect::saturating_union`].
+    #[inline]
+    pub fn saturating_union(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.union(other)
+    }
+
+    /// Same as [`to_rect`](Self::to_rect), clamping the size.
+    ///
+    /// The size of a box spanning more than half of the range of a signed type doesn't fit
+    /// in the type, so the resulting rectangle is cut at its maximum edges.
+    #[inline]
+    pub fn saturating_to_rect(&self) -> Rect<T, U> {
+        Rect::new(
+            self.min,
+            Size2D::new(
+                self.max.x.saturating_sub(&self.min.x),
+                self.max.y.saturating_sub(&self.min.y),
+            ),
+        )
+    }
+}
+
 impl<T, U> Box2D<T, U>
 where
     T: Zero,
@@ -523,68 +890,102 @@ where
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Box2D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Box2D<T, U> {
     type Output = Box2D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        Box2D::new(self.min * scale, self.max * scale)
+        Box2D::new(self.min * scale.clone(), self.max * scale)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Box2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Box2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
         *self *= Scale::new(scale);
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Box2D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Box2D<T, U> {
     type Output = Box2D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        Box2D::new(self.min / scale, self.max / scale)
+        Box2D::new(self.min / scale.clone(), self.max / scale)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Box2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Box2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
         *self /= Scale::new(scale);
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Box2D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Box2D<T, U1> {
     type Output = Box2D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        Box2D::new(self.min * scale, self.max * scale)
+        Box2D::new(self.min * scale.clone(), self.max * scale)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Box2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Box2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
-        self.min *= scale;
+        self.min *= scale.clone();
         self.max *= scale;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Box2D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Box2D<T, U2> {
     type Output = Box2D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        Box2D::new(self.min / scale, self.max / scale)
+        Box2D::new(self.min / scale.clone(), self.max / scale)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Box2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Box2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
-        self.min /= scale;
+        self.min /= scale.clone();
+        self.max /= scale;
+    }
+}
+
+impl<T: Clone + Mul, U1, U2> Mul<Scale2D<T, U1, U2>> for Box2D<T, U1> {
+    type Output = Box2D<T::Output, U2>;
+
+    #[inline]
+    fn mul(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        Box2D::new(self.min * scale.clone(), self.max * scale)
+    }
+}
+
+impl<T: Clone + MulAssign, U> MulAssign<Scale2D<T, U, U>> for Box2D<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.min *= scale.clone();
+        self.max *= scale;
+    }
+}
+
+impl<T: Clone + Div, U1, U2> Div<Scale2D<T, U1, U2>> for Box2D<T, U2> {
+    type Output = Box2D<T::Output, U1>;
+
+    #[inline]
+    fn div(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        Box2D::new(self.min / scale.clone(), self.max / scale)
+    }
+}
+
+impl<T: Clone + DivAssign, U> DivAssign<Scale2D<T, U, U>> for Box2D<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.min /= scale.clone();
         self.max /= scale;
     }
 }
@@ -603,6 +1004,61 @@ where
         self.min.y..self.max.y
     }
 
+    /// Returns the corner with the smallest coordinates, which is `min`.
+    ///
+    /// The names of the corners assume that the y axis points down.
+    #[inline]
+    pub fn top_left(&self) -> Point2D<T, U> {
+        self.min
+    }
+
+    /// Returns the corner with the largest x and the smallest y coordinate.
+    #[inline]
+    pub fn top_right(&self) -> Point2D<T, U> {
+        point2(self.max.x, self.min.y)
+    }
+
+    /// Returns the corner with the smallest x and the largest y coordinate.
+    #[inline]
+    pub fn bottom_left(&self) -> Point2D<T, U> {
+        point2(self.min.x, self.max.y)
+    }
+
+    /// Returns the corner with the largest coordinates, which is `max`.
+    #[inline]
+    pub fn bottom_right(&self) -> Point2D<T, U> {
+        self.max
+    }
+
+    /// Returns the four corners, clockwise from the top left one when the y axis points
+    /// down.
+    ///
+    /// ```
+    /// use euclid::default::Box2D;
+    /// use euclid::point2;
+    ///
+    /// let b = Box2D::new(point2(0, 0), point2(2, 1));
+    /// assert_eq!(
+    ///     b.corners(),
+    ///     [point2(0, 0), point2(2, 0), point2(2, 1), point2(0, 1)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn corners(&self) -> [Point2D<T, U>; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// Cast into a tuple with the coordinates of `min` and `max`.
+    #[inline]
+    pub fn to_tuple(&self) -> ((T, T), (T, T)) {
+        (self.min.to_tuple(), self.max.to_tuple())
+    }
+
     /// Drop the units, preserving only the numeric value.
     #[inline]
     pub fn to_untyped(&self) -> Box2D<T, UnknownUnit> {
@@ -664,6 +1120,69 @@ impl<T: NumCast + Copy, U> Box2D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Box2D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(&self) -> Result<Box2D<NewT, U>, CastError> {
+        Ok(Box2D::new(
+            Point2D::new(
+                cast_component(self.min.x, "min.x")?,
+                cast_component(self.min.y, "min.y")?,
+            ),
+            Point2D::new(
+                cast_component(self.max.x, "max.x")?,
+                cast_component(self.max.y, "max.y")?,
+            ),
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(&self, mode: RoundingMode) -> Box2D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Box2D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        &self,
+        mode: RoundingMode,
+    ) -> Option<Box2D<NewT, U>> {
+        Some(Box2D::new(
+            Point2D::new(
+                round_component(self.min.x, mode)?,
+                round_component(self.min.y, mode)?,
+            ),
+            Point2D::new(
+                round_component(self.max.x, mode)?,
+                round_component(self.max.y, mode)?,
+            ),
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Box2D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(&self) -> Box2D<NewT, U> {
+        Box2D::new(
+            Point2D::new(
+                saturate_component(self.min.x),
+                saturate_component(self.min.y),
+            ),
+            Point2D::new(
+                saturate_component(self.max.x),
+                saturate_component(self.max.y),
+            ),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` box.
@@ -678,6 +1197,13 @@ impl<T: NumCast + Copy, U> Box2D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` box.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(&self) -> Box2D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` box, truncating decimals if any.
     ///
     /// When casting from floating point boxes, it is worth considering whether
@@ -717,6 +1243,46 @@ impl<T: NumCast + Copy, U> Box2D<T, U> {
     pub fn to_i64(&self) -> Box2D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` box, truncating decimals if any.
+    ///
+    /// When casting from floating point boxes, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(&self) -> Box2D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` box, truncating decimals if any.
+    ///
+    /// When casting from floating point boxes, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(&self) -> Box2D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` box, truncating decimals if any.
+    ///
+    /// When casting from floating point boxes, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(&self) -> Box2D<u64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` box, truncating decimals if any.
+    ///
+    /// When casting from floating point boxes, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(&self) -> Box2D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Box2D<T, U> {
@@ -727,6 +1293,15 @@ impl<T: Float, U> Box2D<T, U> {
     }
 }
 
+impl<T: RealScalar, U> Box2D<T, U> {
+    /// Returns the smallest circle containing the box.
+    #[inline]
+    pub fn bounding_circle(&self) -> Circle<T, U> {
+        let two = T::one() + T::one();
+        Circle::new(self.center(), (self.max - self.min).length() / two)
+    }
+}
+
 impl<T, U> Box2D<T, U>
 where
     T: Round,
@@ -787,9 +1362,77 @@ impl<T: Default, U> Default for Box2D<T, U> {
     }
 }
 
+/// Shorthand for `Box2D::new(Point2D::new(min_x, min_y), Point2D::new(max_x, max_y))`.
+pub const fn box2d<T, U>(min_x: T, min_y: T, max_x: T, max_y: T) -> Box2D<T, U> {
+    Box2D::new(Point2D::new(min_x, min_y), Point2D::new(max_x, max_y))
+}
+
+impl<T, U> From<(Point2D<T, U>, Point2D<T, U>)> for Box2D<T, U> {
+    fn from((min, max): (Point2D<T, U>, Point2D<T, U>)) -> Self {
+        Box2D::new(min, max)
+    }
+}
+
+impl<T, U> From<Box2D<T, U>> for (Point2D<T, U>, Point2D<T, U>) {
+    fn from(b: Box2D<T, U>) -> Self {
+        (b.min, b.max)
+    }
+}
+
+impl<T, U> From<(Range<T>, Range<T>)> for Box2D<T, U> {
+    fn from((x, y): (Range<T>, Range<T>)) -> Self {
+        Box2D::from_ranges(x, y)
+    }
+}
+
+impl<T, U> From<Box2D<T, U>> for (Range<T>, Range<T>) {
+    fn from(b: Box2D<T, U>) -> Self {
+        (b.min.x..b.max.x, b.min.y..b.max.y)
+    }
+}
+
+macro_rules! int_clip {
+    ($($ty:ty),+) => {$(
+        impl<U> Box2D<$ty, U> {
+            /// Returns the part of this box that is inside `clip`.
+            ///
+            /// Unlike [`Box2D::intersection`], this doesn't branch on whether the boxes
+            /// overlap: it only uses integer `min`/`max`, which compile to conditional
+            /// moves. When the boxes don't overlap the result is an empty box with
+            /// `min <= max`, so its size never underflows.
+            #[inline]
+            pub fn clip(&self, clip: &Self) -> Self {
+                let max = point2(
+                    Ord::min(self.max.x, clip.max.x),
+                    Ord::min(self.max.y, clip.max.y),
+                );
+                let min = point2(
+                    Ord::min(Ord::max(self.min.x, clip.min.x), max.x),
+                    Ord::min(Ord::max(self.min.y, clip.min.y), max.y),
+                );
+                Box2D { min, max }
+            }
+
+            /// Returns the point of the box closest to `p`, including points on the
+            /// bottom and right edges.
+            ///
+            /// The box must not be negative.
+            #[inline]
+            pub fn clamp_point(&self, p: Point2D<$ty, U>) -> Point2D<$ty, U> {
+                point2(
+                    Ord::min(Ord::max(p.x, self.min.x), self.max.x),
+                    Ord::min(Ord::max(p.y, self.min.y), self.max.y),
+                )
+            }
+        }
+    )+};
+}
+
+int_clip!(i32, u32);
+
 #[cfg(test)]
 mod tests {
-    use crate::default::Box2D;
+    use crate::default::{Box2D, Rect};
     use crate::side_offsets::SideOffsets2D;
     use crate::{point2, size2, vec2, Point2D};
     //use super::*;
@@ -812,6 +1455,26 @@ mod tests {
     fn test_center() {
         let b = Box2D::new(point2(-10.0, -10.0), point2(10.0, 10.0));
         assert_eq!(b.center(), Point2D::zero());
+
+        let b: Box2D<i32> = Box2D::new(point2(2_000_000_000, 0), point2(2_100_000_000, 10));
+        assert_eq!(b.center(), point2(2_050_000_000, 5));
+
+        // Integer centers are rounded towards negative infinity, like `Point2D::midpoint`.
+        let b: Box2D<i32> = Box2D::new(point2(-3, 1), point2(0, 4));
+        assert_eq!(b.center(), point2(-2, 2));
+        assert_eq!(b.center(), b.min.midpoint(b.max));
+
+        // The size of the box overflows.
+        let b: Box2D<i32> = Box2D::new(
+            point2(-2_000_000_000, i32::MIN),
+            point2(2_000_000_000, i32::MAX),
+        );
+        assert_eq!(b.center(), point2(0, -1));
+        let b = Box2D::new(
+            point2(-f32::MAX, f32::MAX / 2.0),
+            point2(f32::MAX, f32::MAX),
+        );
+        assert_eq!(b.center(), point2(0.0, f32::MAX * 0.75));
     }
 
     #[test]
@@ -827,6 +1490,27 @@ mod tests {
         assert_eq!(b.max, point2(100.0, 160.0));
     }
 
+    #[test]
+    fn test_from_point_array() {
+        let points = [
+            point2(50.0, 160.0),
+            point2(100.0, 25.0),
+            point2(-3.0, f32::NAN),
+            point2(75.0, 30.0),
+        ];
+        let b = Box2D::from_points(&points);
+        assert_eq!(Box2D::from_point_array(&points), b);
+        assert_eq!(
+            Box2D::from_four_points(points[0], points[1], points[2], points[3]),
+            b
+        );
+        assert_eq!(
+            Box2D::from_two_points(points[1], points[0]),
+            Box2D::from_points(&points[..2])
+        );
+        assert_eq!(Box2D::<i32>::from_point_array(&[]), Box2D::zero());
+    }
+
     #[test]
     fn test_round_in() {
         let b = Box2D::from_points(&[point2(-25.5, -40.4), point2(60.3, 36.5)]).round_in();
@@ -971,6 +1655,93 @@ mod tests {
         assert!(b1.contains_box(&b2));
     }
 
+    #[test]
+    fn test_checked() {
+        let b: Box2D<i32> = Box2D::new(point2(-10, 0), point2(10, 5));
+        assert_eq!(
+            b.checked_translate(vec2(1, 2)),
+            Some(b.translate(vec2(1, 2)))
+        );
+        assert_eq!(b.checked_inflate(1, 2), Some(b.inflate(1, 2)));
+        assert_eq!(b.checked_size(), Some(size2(20, 5)));
+        assert_eq!(b.checked_area(), Some(100));
+        assert_eq!(b.checked_to_rect(), Some(b.to_rect()));
+
+        assert_eq!(b.checked_translate(vec2(i32::MAX - 5, 0)), None);
+        assert_eq!(b.checked_inflate(0, i32::MIN), None);
+        let wide = Box2D::new(point2(i32::MIN, 0), point2(i32::MAX, 1));
+        assert_eq!(wide.checked_size(), None);
+        assert_eq!(wide.checked_to_rect(), None);
+        assert_eq!(wide.checked_area(), None);
+        assert_eq!(
+            Box2D::new(point2(0u8, 0), point2(16, 16)).checked_area(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_difference() {
+        let b: Box2D<i32> = Box2D::new(point2(0, 0), point2(10, 10));
+        let hole = Box2D::new(point2(3, 4), point2(5, 7));
+        let parts: Vec<_> = b.difference(&hole).collect();
+        assert_eq!(
+            parts,
+            vec![
+                Box2D::new(point2(0, 0), point2(10, 4)),
+                Box2D::new(point2(0, 4), point2(3, 7)),
+                Box2D::new(point2(5, 4), point2(10, 7)),
+                Box2D::new(point2(0, 7), point2(10, 10)),
+            ]
+        );
+        assert_eq!(parts.iter().map(|p| p.area()).sum::<i32>(), 100 - 6);
+        for (i, p) in parts.iter().enumerate() {
+            assert!(!p.intersects(&hole));
+            assert!(parts[i + 1..].iter().all(|q| !p.intersects(q)));
+        }
+
+        let far = Box2D::new(point2(20, 20), point2(30, 30));
+        assert_eq!(b.difference(&far).collect::<Vec<_>>(), vec![b]);
+        let edge = Box2D::new(point2(10, 0), point2(20, 10));
+        assert_eq!(b.difference(&edge).collect::<Vec<_>>(), vec![b]);
+        let cover = Box2D::new(point2(-1, -1), point2(11, 11));
+        assert_eq!(b.difference(&cover).count(), 0);
+        assert_eq!(hole.difference(&b).count(), 0);
+        let empty = Box2D::new(point2(5, 5), point2(5, 8));
+        assert_eq!(empty.difference(&far).count(), 0);
+        assert_eq!(b.difference(&empty).collect::<Vec<_>>(), vec![b]);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let b: Box2D<i32> = Box2D::new(point2(-10, 0), point2(10, 5));
+        assert_eq!(b.saturating_translate(vec2(1, 2)), b.translate(vec2(1, 2)));
+        assert_eq!(b.saturating_inflate(1, 2), b.inflate(1, 2));
+        assert_eq!(b.saturating_to_rect(), b.to_rect());
+        let far = Box2D::new(point2(i32::MIN, 0), point2(i32::MIN + 1, 1));
+        assert_eq!(b.saturating_union(&far), b.union(&far));
+
+        assert_eq!(
+            b.saturating_translate(vec2(i32::MAX - 5, i32::MIN)),
+            Box2D::new(
+                point2(i32::MAX - 15, i32::MIN),
+                point2(i32::MAX, i32::MIN + 5)
+            )
+        );
+        assert_eq!(
+            b.saturating_inflate(i32::MAX, 0),
+            Box2D::new(point2(i32::MIN, 0), point2(i32::MAX, 5))
+        );
+        let wide = Box2D::new(point2(i32::MIN, 0), point2(i32::MAX, 1));
+        assert_eq!(
+            wide.saturating_to_rect(),
+            Rect::new(point2(i32::MIN, 0), size2(i32::MAX, 1))
+        );
+        assert_eq!(
+            Box2D::new(point2(2u8, 2), point2(4, 4)).saturating_inflate(3, 3),
+            Box2D::new(point2(0, 0), point2(7, 7))
+        );
+    }
+
     #[test]
     fn test_inflate() {
         let b = Box2D::from_points(&[point2(-20.0, -20.0), point2(20.0, 20.0)]);
@@ -1020,4 +1791,204 @@ mod tests {
         assert_eq!(b.min, point2(1.0, 2.0));
         assert_eq!(b.size(), size2(5.0, 6.0));
     }
+
+    #[test]
+    fn test_from_tuple() {
+        let b: Box2D<i32> = (point2(1, 2), point2(3, 4)).into();
+        assert_eq!(b, Box2D::new(point2(1, 2), point2(3, 4)));
+        let (min, max) = b.into();
+        assert_eq!((min, max), (point2(1, 2), point2(3, 4)));
+    }
+
+    #[test]
+    fn test_to_tuple() {
+        let b: Box2D<i32> = Box2D::from_tuple(((1, 2), (3, 4)));
+        let ((x0, y0), (x1, y1)) = b.to_tuple();
+        assert_eq!((x0, y0, x1, y1), (1, 2, 3, 4));
+        let ((x, y), (w, h)) = b.to_rect().to_tuple();
+        assert_eq!((x, y, w, h), (1, 2, 2, 2));
+    }
+
+    #[test]
+    fn test_corners() {
+        let b: Box2D<i32> = Box2D::new(point2(1, 2), point2(4, 6));
+        assert_eq!(b.top_left(), point2(1, 2));
+        assert_eq!(b.top_right(), point2(4, 2));
+        assert_eq!(b.bottom_left(), point2(1, 6));
+        assert_eq!(b.bottom_right(), point2(4, 6));
+        assert_eq!(b.to_rect().corners(), b.corners());
+        assert_eq!(Box2D::from_points(b.corners()), b);
+    }
+
+    #[test]
+    fn test_expand_to_include() {
+        let points = [point2(1.0, -2.0), point2(-3.0, 0.5), point2(2.0, 4.0)];
+        let mut b: Box2D<f64> = Box2D::new(
+            point2(f64::INFINITY, f64::INFINITY),
+            point2(f64::NEG_INFINITY, f64::NEG_INFINITY),
+        );
+        b.expand_to_include(points[0]);
+        assert_eq!(b, Box2D::new(points[0], points[0]));
+        for p in &points[1..] {
+            b.expand_to_include(*p);
+        }
+        assert_eq!(b, Box2D::from_points(points));
+        assert!(b.contains_inclusive(points[2]));
+
+        let mut acc = Box2D::zero();
+        acc.expand_to_include_box(&b);
+        assert_eq!(acc, b);
+        acc.expand_to_include_box(&Box2D::new(point2(0.0, 0.0), point2(5.0, 1.0)));
+        assert_eq!(acc, Box2D::new(point2(-3.0, -2.0), point2(5.0, 4.0)));
+        acc.expand_to_include_box(&Box2D::zero());
+        assert_eq!(acc.max, point2(5.0, 4.0));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let b: Box2D<i32> = Box2D::from_ranges(1..3, -2..4);
+        assert_eq!(b, Box2D::new(point2(1, -2), point2(3, 4)));
+        assert_eq!((b.x_range(), b.y_range()), (1..3, -2..4));
+        assert_eq!(Box2D::from((1..3, -2..4)), b);
+        let (x, y) = b.into();
+        assert_eq!((x, y), (1..3, -2..4));
+    }
+
+    #[test]
+    fn test_intersecting_indices() {
+        fn check(boxes: &[Box2D<i32>]) {
+            let max_width = boxes.iter().map(|b| b.width()).max().unwrap();
+            for query in &[
+                Box2D::new(point2(10, 0), point2(20, 2)),
+                Box2D::new(point2(-20, 5), point2(0, 6)),
+                Box2D::new(point2(50, 1), point2(60, 3)),
+                Box2D::new(point2(-20, 0), point2(-10, 4)),
+                Box2D::new(point2(40, 0), point2(30, 4)),
+            ] {
+                let expected: Vec<usize> = (0..boxes.len())
+                    .filter(|&i| boxes[i].intersects(query))
+                    .collect();
+                let found: Vec<usize> =
+                    Box2D::intersecting_indices(boxes, query, max_width).collect();
+                assert_eq!(found, expected);
+                assert_eq!(
+                    Box2D::first_intersecting(boxes, query, max_width),
+                    expected.first().copied()
+                );
+            }
+        }
+
+        let mut boxes: Vec<Box2D<i32>> = (0..20)
+            .map(|i| {
+                Box2D::new(
+                    point2(i * 3, i % 4),
+                    point2(i * 3 + 2 + i % 5 * 4, i % 4 + 1),
+                )
+            })
+            .collect();
+        check(&boxes);
+
+        // A wide box keeps all the boxes before the query in the range to test.
+        boxes.push(Box2D::new(point2(-10, 0), point2(100, 4)));
+        boxes.sort_by_key(|b| b.min.x);
+        check(&boxes);
+    }
+
+    #[test]
+    fn test_clip() {
+        let clip: Box2D<i32> = Box2D::new(point2(0, 0), point2(100, 50));
+        let boxes: [Box2D<i32>; 4] = [
+            Box2D::new(point2(-10, 10), point2(40, 70)),
+            Box2D::new(point2(120, 10), point2(140, 20)),
+            Box2D::new(point2(10, -40), point2(20, -30)),
+            Box2D::new(point2(10, 10), point2(20, 20)),
+        ];
+        for b in &boxes {
+            let clipped = b.clip(&clip);
+            assert!(clipped.min.x <= clipped.max.x && clipped.min.y <= clipped.max.y);
+            match b.intersection(&clip) {
+                Some(i) => assert_eq!(clipped, i),
+                None => assert!(clipped.is_empty()),
+            }
+        }
+
+        let clip: Box2D<u32> = Box2D::new(point2(10, 10), point2(20, 20));
+        let clipped = Box2D::new(point2(30u32, 0), point2(40, 5)).clip(&clip);
+        assert!(clipped.is_empty());
+        assert_eq!(clipped.size(), size2(0, 0));
+        assert_eq!(clip.clamp_point(point2(25, 0)), point2(20, 10));
+        assert_eq!(clip.clamp_point(point2(15, 12)), point2(15, 12));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        let boxes: [Box2D<f32>; 2] = [
+            Box2D::new(point2(1.0, 2.0), point2(3.0, 4.0)),
+            Box2D::new(point2(-1.0, -2.0), point2(0.0, 0.5)),
+        ];
+        let floats: &[f32] = bytemuck::cast_slice(&boxes);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, -1.0, -2.0, 0.0, 0.5]);
+
+        let points: &[crate::default::Point2D<f32>] = bytemuck::cast_slice(&boxes);
+        assert_eq!(
+            points,
+            &[boxes[0].min, boxes[0].max, boxes[1].min, boxes[1].max]
+        );
+
+        let rects: &[Rect<f32>] = bytemuck::cast_slice(floats);
+        assert_eq!(rects[1], Rect::new(point2(-1.0, -2.0), size2(0.0, 0.5)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+        let point = |name: &'static str, x: f32, y: f32| {
+            [
+                Token::Str(name),
+                Token::Struct {
+                    name: "Point2D",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::F32(x),
+                Token::Str("y"),
+                Token::F32(y),
+                Token::StructEnd,
+            ]
+        };
+        let b: Box2D<f32> = Box2D::new(point2(1.0, 2.0), point2(3.0, 4.0));
+
+        let mut readable = vec![Token::Struct {
+            name: "Box2D",
+            len: 2,
+        }];
+        readable.extend_from_slice(&point("min", 1.0, 2.0));
+        readable.extend_from_slice(&point("max", 3.0, 4.0));
+        readable.push(Token::StructEnd);
+        assert_tokens(&b.readable(), &readable);
+
+        let compact = [
+            Token::Struct {
+                name: "Box2D",
+                len: 2,
+            },
+            Token::Str("min"),
+            Token::Tuple { len: 2 },
+            Token::F32(1.0),
+            Token::F32(2.0),
+            Token::TupleEnd,
+            Token::Str("max"),
+            Token::Tuple { len: 2 },
+            Token::F32(3.0),
+            Token::F32(4.0),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ];
+        assert_tokens(&b.compact(), &compact);
+        // Human-readable formats still accept the tuple encoding.
+        assert_de_tokens(&b.readable(), &compact);
+    }
 }
@@ -9,15 +9,19 @@
 
 use super::UnknownUnit;
 use crate::approxord::{max, min};
+use crate::batch::SimdScalar;
+use crate::cast::{cast_component, round_component, saturate_component, CastError, RoundingMode};
+use crate::circle::Sphere;
 use crate::num::*;
 use crate::point::{point3, Point3D};
 use crate::scale::Scale;
 use crate::size::Size3D;
+use crate::validated::not_nan;
 use crate::vector::Vector3D;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-use num_traits::{Float, NumCast};
+use num_traits::{Bounded, Float, NumCast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +33,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 
 /// An axis aligned 3D box represented by its minimum and maximum coordinates.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -97,6 +105,12 @@ impl<T, U> Box3D<T, U> {
         Box3D { min, max }
     }
 
+    /// Constructor taking a tuple with the coordinates of `min` and `max`.
+    #[inline]
+    pub fn from_tuple((min, max): ((T, T, T), (T, T, T))) -> Self {
+        Box3D::new(Point3D::from_tuple(min), Point3D::from_tuple(max))
+    }
+
     /// Constructor.
     #[inline]
     pub fn from_origin_and_size(origin: Point3D<T, U>, size: Size3D<T, U>) -> Self
@@ -113,6 +127,15 @@ impl<T, U> Box3D<T, U> {
         }
     }
 
+    /// Creates a box spanning a range on each axis.
+    #[inline]
+    pub fn from_ranges(x: Range<T>, y: Range<T>, z: Range<T>) -> Self {
+        Box3D {
+            min: point3(x.start, y.start, z.start),
+            max: point3(x.end, y.end, z.end),
+        }
+    }
+
     /// Creates a `Box3D` of the given size, at offset zero.
     #[inline]
     pub fn from_size(size: Size3D<T, U>) -> Self
@@ -242,19 +265,36 @@ where
 
 impl<T, U> Box3D<T, U>
 where
-    T: Copy + PartialOrd,
+    T: Clone + PartialOrd,
 {
+    /// Returns `true` if none of the coordinates is NaN.
+    #[inline]
+    fn not_nan(&self) -> bool {
+        not_nan(&[
+            &self.min.x,
+            &self.min.y,
+            &self.min.z,
+            &self.max.x,
+            &self.max.y,
+            &self.max.z,
+        ])
+    }
+
     #[inline]
     pub fn to_non_empty(&self) -> Option<Self> {
         if self.is_empty() {
             return None;
         }
 
-        Some(*self)
+        Some(self.clone())
     }
 
     #[inline]
     pub fn intersection(&self, other: &Self) -> Option<Self> {
+        validate_invariant!(
+            self.not_nan() && other.not_nan(),
+            "Box3D::intersection: NaN component"
+        );
         let b = self.intersection_unchecked(other);
 
         if b.is_empty() {
@@ -266,15 +306,15 @@ where
 
     pub fn intersection_unchecked(&self, other: &Self) -> Self {
         let intersection_min = Point3D::new(
-            max(self.min.x, other.min.x),
-            max(self.min.y, other.min.y),
-            max(self.min.z, other.min.z),
+            max(self.min.x.clone(), other.min.x.clone()),
+            max(self.min.y.clone(), other.min.y.clone()),
+            max(self.min.z.clone(), other.min.z.clone()),
         );
 
         let intersection_max = Point3D::new(
-            min(self.max.x, other.max.x),
-            min(self.max.y, other.max.y),
-            min(self.max.z, other.max.z),
+            min(self.max.x.clone(), other.max.x.clone()),
+            min(self.max.y.clone(), other.max.y.clone()),
+            min(self.max.z.clone(), other.max.z.clone()),
         );
 
         Box3D::new(intersection_min, intersection_max)
@@ -285,83 +325,118 @@ where
     /// If either of the boxes is empty, the other one is returned.
     #[inline]
     pub fn union(&self, other: &Self) -> Self {
+        validate_invariant!(
+            self.not_nan() && other.not_nan(),
+            "Box3D::union: NaN component"
+        );
         if other.is_empty() {
-            return *self;
+            return self.clone();
         }
         if self.is_empty() {
-            return *other;
+            return other.clone();
         }
 
         Box3D::new(
             Point3D::new(
-                min(self.min.x, other.min.x),
-                min(self.min.y, other.min.y),
-                min(self.min.z, other.min.z),
+                min(self.min.x.clone(), other.min.x.clone()),
+                min(self.min.y.clone(), other.min.y.clone()),
+                min(self.min.z.clone(), other.min.z.clone()),
             ),
             Point3D::new(
-                max(self.max.x, other.max.x),
-                max(self.max.y, other.max.y),
-                max(self.max.z, other.max.z),
+                max(self.max.x.clone(), other.max.x.clone()),
+                max(self.max.y.clone(), other.max.y.clone()),
+                max(self.max.z.clone(), other.max.z.clone()),
             ),
         )
     }
+
+    /// Grows the box in place so that it contains `p`, including on its maximum faces.
+    ///
+    /// See [`Box2D::expand_to_include`](crate::Box2D::expand_to_include).
+    #[inline]
+    pub fn expand_to_include(&mut self, p: Point3D<T, U>) {
+        self.min = self.min.clone().min(p.clone());
+        self.max = self.max.clone().max(p);
+    }
+
+    /// Grows the box in place so that it contains `other`, like [`union`](Self::union).
+    #[inline]
+    pub fn expand_to_include_box(&mut self, other: &Self) {
+        *self = self.union(other);
+    }
 }
 
 impl<T, U> Box3D<T, U>
 where
-    T: Copy + Add<T, Output = T>,
+    T: Clone + Add<T, Output = T>,
 {
     /// Returns the same box3d, translated by a vector.
     #[inline]
     #[must_use]
     pub fn translate(&self, by: Vector3D<T, U>) -> Self {
         Box3D {
-            min: self.min + by,
-            max: self.max + by,
+            min: self.min.clone() + by.clone(),
+            max: self.max.clone() + by,
         }
     }
 }
 
 impl<T, U> Box3D<T, U>
 where
-    T: Copy + Sub<T, Output = T>,
+    T: Clone + Sub<T, Output = T>,
 {
     #[inline]
     pub fn size(&self) -> Size3D<T, U> {
         Size3D::new(
-            self.max.x - self.min.x,
-            self.max.y - self.min.y,
-            self.max.z - self.min.z,
+            self.max.x.clone() - self.min.x.clone(),
+            self.max.y.clone() - self.min.y.clone(),
+            self.max.z.clone() - self.min.z.clone(),
         )
     }
 
     #[inline]
     pub fn width(&self) -> T {
-        self.max.x - self.min.x
+        self.max.x.clone() - self.min.x.clone()
     }
 
     #[inline]
     pub fn height(&self) -> T {
-        self.max.y - self.min.y
+        self.max.y.clone() - self.min.y.clone()
     }
 
     #[inline]
     pub fn depth(&self) -> T {
-        self.max.z - self.min.z
+        self.max.z.clone() - self.min.z.clone()
+    }
+
+    /// Returns the `min` corner and the size of the box.
+    ///
+    /// This is the inverse of [`from_origin_and_size`](Self::from_origin_and_size).
+    #[inline]
+    pub fn to_origin_and_size(&self) -> (Point3D<T, U>, Size3D<T, U>) {
+        (self.min.clone(), self.size())
     }
 }
 
 impl<T, U> Box3D<T, U>
 where
-    T: Copy + Add<T, Output = T> + Sub<T, Output = T>,
+    T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
 {
     /// Inflates the box by the specified sizes on each side respectively.
     #[inline]
     #[must_use]
     pub fn inflate(&self, width: T, height: T, depth: T) -> Self {
         Box3D::new(
-            Point3D::new(self.min.x - width, self.min.y - height, self.min.z - depth),
-            Point3D::new(self.max.x + width, self.max.y + height, self.max.z + depth),
+            Point3D::new(
+                self.min.x.clone() - width.clone(),
+                self.min.y.clone() - height.clone(),
+                self.min.z.clone() - depth.clone(),
+            ),
+            Point3D::new(
+                self.max.x.clone() + width,
+                self.max.y.clone() + height,
+                self.max.z.clone() + depth,
+            ),
         )
     }
 }
@@ -429,6 +504,10 @@ where
             Some(first) => first.borrow().to_tuple(),
             None => return Box3D::zero(),
         };
+        validate_invariant!(
+            not_nan(&[min_x, min_y, min_z]),
+            "Box3D::from_points: NaN component"
+        );
         let (mut max_x, mut max_y, mut max_z) = (min_x, min_y, min_z);
 
         for point in points {
@@ -458,11 +537,28 @@ where
             max: point3(max_x, max_y, max_z),
         }
     }
+
+    /// Returns the smallest box enclosing all of the points of the array.
+    ///
+    /// This gives the same result as [`Box3D::from_points`], but the number of points
+    /// is known at compile time so the loop is fully unrolled into straight-line code.
+    #[inline]
+    pub fn from_point_array<const N: usize>(points: &[Point3D<T, U>; N]) -> Self {
+        let (mut b_min, mut b_max) = match points.first() {
+            Some(&first) => (first, first),
+            None => return Box3D::zero(),
+        };
+        for p in &points[1..] {
+            b_min = point3(min(p.x, b_min.x), min(p.y, b_min.y), min(p.z, b_min.z));
+            b_max = point3(max(p.x, b_max.x), max(p.y, b_max.y), max(p.z, b_max.z));
+        }
+        Box3D::new(b_min, b_max)
+    }
 }
 
 impl<T, U> Box3D<T, U>
 where
-    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + SimdScalar,
 {
     /// Linearly interpolate between this box3d and another box3d.
     #[inline]
@@ -471,13 +567,18 @@ where
     }
 }
 
-impl<T, U> Box3D<T, U>
-where
-    T: Copy + One + Add<Output = T> + Div<Output = T>,
-{
+impl<T: Copy + Midpoint, U> Box3D<T, U> {
+    /// Returns the center of the box.
+    ///
+    /// This is computed with [`Midpoint`], so it doesn't overflow, even for coordinates
+    /// close to the limits of `T`. Integer centers are rounded towards negative infinity
+    /// like [`Point3D::midpoint`], so the center of `[-3, 0]` is `-2`.
     pub fn center(&self) -> Point3D<T, U> {
-        let two = T::one() + T::one();
-        (self.min + self.max.to_vector()) / two
+        point3(
+            Midpoint::midpoint(self.min.x, self.max.x),
+            Midpoint::midpoint(self.min.y, self.max.y),
+            Midpoint::midpoint(self.min.z, self.max.z),
+        )
     }
 }
 
@@ -520,24 +621,24 @@ where
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Box3D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Box3D<T, U> {
     type Output = Box3D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        Box3D::new(self.min * scale, self.max * scale)
+        Box3D::new(self.min * scale.clone(), self.max * scale)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Box3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Box3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
-        self.min *= scale;
+        self.min *= scale.clone();
         self.max *= scale;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Box3D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Box3D<T, U> {
     type Output = Box3D<T::Output, U>;
 
     #[inline]
@@ -546,15 +647,15 @@ impl<T: Copy + Div, U> Div<T> for Box3D<T, U> {
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Box3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Box3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
-        self.min /= scale;
+        self.min /= scale.clone();
         self.max /= scale;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Box3D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Box3D<T, U1> {
     type Output = Box3D<T::Output, U2>;
 
     #[inline]
@@ -563,7 +664,7 @@ impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Box3D<T, U1> {
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Box3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Box3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
         self.min *= scale.clone();
@@ -571,7 +672,7 @@ impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Box3D<T, U> {
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Box3D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Box3D<T, U2> {
     type Output = Box3D<T::Output, U1>;
 
     #[inline]
@@ -580,7 +681,7 @@ impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Box3D<T, U2> {
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Box3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Box3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
         self.min /= scale.clone();
@@ -607,6 +708,12 @@ where
         self.min.z..self.max.z
     }
 
+    /// Cast into a tuple with the coordinates of `min` and `max`.
+    #[inline]
+    pub fn to_tuple(&self) -> ((T, T, T), (T, T, T)) {
+        (self.min.to_tuple(), self.max.to_tuple())
+    }
+
     /// Drop the units, preserving only the numeric value.
     #[inline]
     pub fn to_untyped(&self) -> Box3D<T, UnknownUnit> {
@@ -674,6 +781,75 @@ impl<T: NumCast + Copy, U> Box3D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Box3D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(&self) -> Result<Box3D<NewT, U>, CastError> {
+        Ok(Box3D::new(
+            Point3D::new(
+                cast_component(self.min.x, "min.x")?,
+                cast_component(self.min.y, "min.y")?,
+                cast_component(self.min.z, "min.z")?,
+            ),
+            Point3D::new(
+                cast_component(self.max.x, "max.x")?,
+                cast_component(self.max.y, "max.y")?,
+                cast_component(self.max.z, "max.z")?,
+            ),
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(&self, mode: RoundingMode) -> Box3D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Box3D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        &self,
+        mode: RoundingMode,
+    ) -> Option<Box3D<NewT, U>> {
+        Some(Box3D::new(
+            Point3D::new(
+                round_component(self.min.x, mode)?,
+                round_component(self.min.y, mode)?,
+                round_component(self.min.z, mode)?,
+            ),
+            Point3D::new(
+                round_component(self.max.x, mode)?,
+                round_component(self.max.y, mode)?,
+                round_component(self.max.z, mode)?,
+            ),
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Box3D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(&self) -> Box3D<NewT, U> {
+        Box3D::new(
+            Point3D::new(
+                saturate_component(self.min.x),
+                saturate_component(self.min.y),
+                saturate_component(self.min.z),
+            ),
+            Point3D::new(
+                saturate_component(self.max.x),
+                saturate_component(self.max.y),
+                saturate_component(self.max.z),
+            ),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` box3d.
@@ -688,6 +864,13 @@ impl<T: NumCast + Copy, U> Box3D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` box3d.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(&self) -> Box3D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` box3d, truncating decimals if any.
     ///
     /// When casting from floating point cuboids, it is worth considering whether
@@ -727,6 +910,46 @@ impl<T: NumCast + Copy, U> Box3D<T, U> {
     pub fn to_i64(&self) -> Box3D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` box3d, truncating decimals if any.
+    ///
+    /// When casting from floating point cuboids, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(&self) -> Box3D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` box3d, truncating decimals if any.
+    ///
+    /// When casting from floating point cuboids, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(&self) -> Box3D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` box3d, truncating decimals if any.
+    ///
+    /// When casting from floating point cuboids, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(&self) -> Box3D<u64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` box3d, truncating decimals if any.
+    ///
+    /// When casting from floating point cuboids, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(&self) -> Box3D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Box3D<T, U> {
@@ -737,6 +960,23 @@ impl<T: Float, U> Box3D<T, U> {
     }
 }
 
+impl<T: RealScalar, U> Box3D<T, U> {
+    /// Returns the smallest sphere containing the box.
+    ///
+    /// ```
+    /// use euclid::default::{Box3D, Sphere};
+    /// use euclid::point3;
+    ///
+    /// let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 4.0, 4.0));
+    /// assert_eq!(b.bounding_sphere(), Sphere::new(point3(1.0, 2.0, 2.0), 3.0));
+    /// ```
+    #[inline]
+    pub fn bounding_sphere(&self) -> Sphere<T, U> {
+        let two = T::one() + T::one();
+        Sphere::new(self.center(), (self.max - self.min).length() / two)
+    }
+}
+
 impl<T, U> Box3D<T, U>
 where
     T: Round,
@@ -800,7 +1040,7 @@ impl<T: Default, U> Default for Box3D<T, U> {
 }
 
 /// Shorthand for `Box3D::new(Point3D::new(x1, y1, z1), Point3D::new(x2, y2, z2))`.
-pub fn box3d<T: Copy, U>(
+pub const fn box3d<T, U>(
     min_x: T,
     min_y: T,
     min_z: T,
@@ -814,10 +1054,34 @@ pub fn box3d<T: Copy, U>(
     )
 }
 
+impl<T, U> From<(Point3D<T, U>, Point3D<T, U>)> for Box3D<T, U> {
+    fn from((min, max): (Point3D<T, U>, Point3D<T, U>)) -> Self {
+        Box3D::new(min, max)
+    }
+}
+
+impl<T, U> From<Box3D<T, U>> for (Point3D<T, U>, Point3D<T, U>) {
+    fn from(b: Box3D<T, U>) -> Self {
+        (b.min, b.max)
+    }
+}
+
+impl<T, U> From<(Range<T>, Range<T>, Range<T>)> for Box3D<T, U> {
+    fn from((x, y, z): (Range<T>, Range<T>, Range<T>)) -> Self {
+        Box3D::from_ranges(x, y, z)
+    }
+}
+
+impl<T, U> From<Box3D<T, U>> for (Range<T>, Range<T>, Range<T>) {
+    fn from(b: Box3D<T, U>) -> Self {
+        (b.min.x..b.max.x, b.min.y..b.max.y, b.min.z..b.max.z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::default::{Box3D, Point3D};
-    use crate::{point3, size3, vec3};
+    use crate::{box3d, point3, size3, vec3};
 
     #[test]
     fn test_new() {
@@ -830,6 +1094,16 @@ mod tests {
         assert!(b.max.z == 1.0);
     }
 
+    #[test]
+    fn test_const() {
+        static BOXES: [Box3D<i32>; 2] = [
+            box3d(0, 0, 0, 1, 1, 1),
+            Box3D::new(Point3D::new(-1, -2, -3), Point3D::new(1, 2, 3)),
+        ];
+        assert_eq!(BOXES[0], Box3D::new(point3(0, 0, 0), point3(1, 1, 1)));
+        assert_eq!(BOXES[1].size(), size3(2, 4, 6));
+    }
+
     #[test]
     fn test_size() {
         let b = Box3D::new(point3(-10.0, -10.0, -10.0), point3(10.0, 10.0, 10.0));
@@ -838,6 +1112,16 @@ mod tests {
         assert!(b.size().depth == 20.0);
     }
 
+    #[test]
+    fn test_origin_and_size() {
+        let b = Box3D::from_origin_and_size(point3(1.0, 2.0, 3.0), size3(4.0, 5.0, 6.0));
+        assert_eq!(b.max, point3(5.0, 7.0, 9.0));
+        assert_eq!(
+            b.to_origin_and_size(),
+            (point3(1.0, 2.0, 3.0), size3(4.0, 5.0, 6.0))
+        );
+    }
+
     #[test]
     fn test_width_height_depth() {
         let b = Box3D::new(point3(-10.0, -10.0, -10.0), point3(10.0, 10.0, 10.0));
@@ -846,10 +1130,27 @@ mod tests {
         assert!(b.depth() == 20.0);
     }
 
+    #[test]
+    fn test_ranges() {
+        let b: Box3D<i32> = (0..1, 2..3, 4..5).into();
+        assert_eq!(b, Box3D::new(point3(0, 2, 4), point3(1, 3, 5)));
+        assert_eq!((b.x_range(), b.y_range(), b.z_range()), b.into());
+    }
+
     #[test]
     fn test_center() {
         let b = Box3D::new(point3(-10.0, -10.0, -10.0), point3(10.0, 10.0, 10.0));
         assert!(b.center() == Point3D::zero());
+
+        let b: Box3D<i32> = Box3D::new(point3(-3, 1, i32::MAX - 10), point3(0, 4, i32::MAX));
+        assert_eq!(b.center(), point3(-2, 2, i32::MAX - 5));
+        assert_eq!(b.center(), b.min.midpoint(b.max));
+
+        // The size of the box overflows.
+        let b: Box3D<i32> = Box3D::new(point3(i32::MIN, 0, 0), point3(i32::MAX, 1, 1));
+        assert_eq!(b.center(), point3(-1, 0, 0));
+        let b = Box3D::new(point3(-f64::MAX, 0.0, 0.0), point3(f64::MAX, 1.0, 1.0));
+        assert_eq!(b.center(), point3(0.0, 0.5, 0.5));
     }
 
     #[test]
@@ -873,6 +1174,20 @@ mod tests {
         assert!(b.max == point3(100.0, 160.0, 200.0));
     }
 
+    #[test]
+    fn test_from_point_array() {
+        let points = [
+            point3(50.0, 160.0, 12.5),
+            point3(100.0, 25.0, 200.0),
+            point3(-3.0, 40.0, f32::NAN),
+        ];
+        assert_eq!(
+            Box3D::from_point_array(&points),
+            Box3D::from_points(&points)
+        );
+        assert_eq!(Box3D::<i32>::from_point_array(&[]), Box3D::zero());
+    }
+
     #[test]
     fn test_min_max() {
         let b = Box3D::from_points(&[point3(50.0, 25.0, 12.5), point3(100.0, 160.0, 200.0)]);
@@ -0,0 +1,326 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A container of boxes laid out for batch queries.
+
+extern crate alloc;
+
+use crate::{point2, Box2D};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+
+const LANES: usize = 4;
+
+/// Four boxes stored as one array per coordinate.
+#[derive(Clone, Copy)]
+struct Block<T> {
+    min_x: [T; LANES],
+    min_y: [T; LANES],
+    max_x: [T; LANES],
+    max_y: [T; LANES],
+}
+
+/// A list of `Box2D`s stored in blocks of four, with the coordinates of each
+/// block in separate arrays.
+///
+/// Compared to a `Vec<Box2D<T, U>>`, testing all boxes against the same query
+/// reads the coordinates in a layout the compiler can vectorize, which matters
+/// for broad-phase culling over many thousands of boxes.
+///
+/// ```
+/// use euclid::default::{Box2D, BoxList2D};
+/// use euclid::point2;
+///
+/// let boxes: BoxList2D<f32> = (0..10)
+///     .map(|i| Box2D::new(point2(i as f32, 0.0), point2(i as f32 + 1.0, 1.0)))
+///     .collect();
+/// let clip = Box2D::new(point2(2.5, 0.0), point2(4.5, 1.0));
+/// let hits = boxes.intersects_all(&clip);
+/// assert_eq!(hits.iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// ```
+pub struct BoxList2D<T, U> {
+    blocks: Vec<Block<T>>,
+    len: usize,
+    _unit: PhantomData<U>,
+}
+
+impl<T, U> BoxList2D<T, U> {
+    /// Creates an empty list.
+    #[inline]
+    pub const fn new() -> Self {
+        BoxList2D {
+            blocks: Vec::new(),
+            len: 0,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Creates an empty list with space for at least `capacity` boxes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        BoxList2D {
+            blocks: Vec::with_capacity((capacity + LANES - 1) / LANES),
+            len: 0,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the number of boxes in the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no boxes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all boxes, keeping the allocated memory.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.blocks.clear();
+        self.len = 0;
+    }
+}
+
+impl<T: Copy, U> BoxList2D<T, U> {
+    /// Appends a box to the list.
+    pub fn push(&mut self, b: Box2D<T, U>) {
+        let lane = self.len % LANES;
+        if lane == 0 {
+            // Unused lanes are filled with copies of the box and never reported.
+            self.blocks.push(Block {
+                min_x: [b.min.x; LANES],
+                min_y: [b.min.y; LANES],
+                max_x: [b.max.x; LANES],
+                max_y: [b.max.y; LANES],
+            });
+        } else {
+            let block = self.blocks.last_mut().unwrap();
+            block.min_x[lane] = b.min.x;
+            block.min_y[lane] = b.min.y;
+            block.max_x[lane] = b.max.x;
+            block.max_y[lane] = b.max.y;
+        }
+        self.len += 1;
+    }
+
+    /// Returns the box at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Box2D<T, U>> {
+        if index >= self.len {
+            return None;
+        }
+        let block = &self.blocks[index / LANES];
+        let lane = index % LANES;
+        Some(Box2D::new(
+            point2(block.min_x[lane], block.min_y[lane]),
+            point2(block.max_x[lane], block.max_y[lane]),
+        ))
+    }
+
+    /// Returns an iterator over the boxes of the list.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Box2D<T, U>> + '_ {
+        (0..self.len).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Tests every box of the list against `query`, with the same semantics as
+    /// [`Box2D::intersects`].
+    pub fn intersects_all(&self, query: &Box2D<T, U>) -> BoxMask
+    where
+        T: PartialOrd,
+    {
+        let mut mask = BoxMask {
+            words: Vec::with_capacity((self.len + 63) / 64),
+            len: self.len,
+        };
+        let mut word = 0u64;
+        for (b, block) in self.blocks.iter().enumerate() {
+            let mut bits = 0u64;
+            for lane in 0..LANES {
+                // Use bitwise and instead of && to avoid emitting branches.
+                let hit = (block.min_x[lane] < query.max.x)
+                    & (block.max_x[lane] > query.min.x)
+                    & (block.min_y[lane] < query.max.y)
+                    & (block.max_y[lane] > query.min.y);
+                bits |= (hit as u64) << lane;
+            }
+            let shift = (b * LANES) % 64;
+            word |= bits << shift;
+            if shift + LANES == 64 {
+                mask.words.push(word);
+                word = 0;
+            }
+        }
+        if (self.blocks.len() * LANES) % 64 != 0 {
+            mask.words.push(word);
+        }
+        if self.len % 64 != 0 {
+            // Clear the bits of the unused lanes of the last block.
+            *mask.words.last_mut().unwrap() &= (1u64 << (self.len % 64)) - 1;
+        }
+        mask
+    }
+}
+
+impl<T: Clone, U> Clone for BoxList2D<T, U> {
+    fn clone(&self) -> Self {
+        BoxList2D {
+            blocks: self.blocks.clone(),
+            len: self.len,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Default for BoxList2D<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + fmt::Debug, U> fmt::Debug for BoxList2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Copy, U> Extend<Box2D<T, U>> for BoxList2D<T, U> {
+    fn extend<I: IntoIterator<Item = Box2D<T, U>>>(&mut self, iter: I) {
+        for b in iter {
+            self.push(b);
+        }
+    }
+}
+
+impl<T: Copy, U> FromIterator<Box2D<T, U>> for BoxList2D<T, U> {
+    fn from_iter<I: IntoIterator<Item = Box2D<T, U>>>(iter: I) -> Self {
+        let mut list = BoxList2D::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T: Copy, U> From<&'a [Box2D<T, U>]> for BoxList2D<T, U> {
+    fn from(boxes: &'a [Box2D<T, U>]) -> Self {
+        let mut list = BoxList2D::with_capacity(boxes.len());
+        list.extend(boxes.iter().copied());
+        list
+    }
+}
+
+/// One bit per box of a [`BoxList2D`], as returned by
+/// [`intersects_all`](BoxList2D::intersects_all).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BoxMask {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BoxMask {
+    /// Returns the number of bits, which is the length of the list it was computed from.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the mask has no bits.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "BoxMask index out of bounds: {}", index);
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if any bit is set.
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|w| *w != 0)
+    }
+
+    /// Returns an iterator over the indices of the set bits, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    /// Returns the bits packed in 64 bit words, least significant bit first.
+    #[inline]
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+
+    fn boxes(n: usize) -> Vec<default::Box2D<i32>> {
+        (0..n as i32)
+            .map(|i| Box2D::new(point2(i, -i), point2(i + 2, 2 - i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_push_get() {
+        let boxes = boxes(7);
+        let list = BoxList2D::from(&boxes[..]);
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.iter().collect::<Vec<_>>(), boxes);
+        assert_eq!(list.get(7), None);
+    }
+
+    #[test]
+    fn test_intersects_all() {
+        let query = Box2D::new(point2(10, -20), point2(75, 1));
+        for &n in &[0, 1, 4, 62, 63, 64, 65, 130] {
+            let boxes = boxes(n);
+            let list: default::BoxList2D<i32> = boxes.iter().copied().collect();
+            let mask = list.intersects_all(&query);
+            assert_eq!(mask.len(), n);
+            let expected: Vec<usize> = (0..n).filter(|&i| boxes[i].intersects(&query)).collect();
+            assert_eq!(mask.iter().collect::<Vec<_>>(), expected);
+            assert_eq!(mask.count(), expected.len());
+            for (i, b) in boxes.iter().enumerate() {
+                assert_eq!(mask.get(i), b.intersects(&query));
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for the `cast_checked`, `cast_with_rounding` and `cast_saturating` methods.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+use num_traits::{Bounded, Float, NumCast};
+
+/// Why a component could not be cast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CastErrorKind {
+    /// The value is NaN and the target type can't represent it.
+    NaN,
+    /// The value, possibly infinite, is out of the range of the target type.
+    Overflow,
+}
+
+/// The error returned by the `cast_checked` methods, identifying the first component
+/// that could not be cast.
+///
+/// ```
+/// use euclid::default::Rect;
+/// use euclid::{point2, size2, CastErrorKind};
+///
+/// let r = Rect::new(point2(0.0, 1.0), size2(3.0e10, f32::NAN));
+/// let err = r.cast_checked::<i32>().unwrap_err();
+/// assert_eq!(err.component, "size.width");
+/// assert_eq!(err.kind, CastErrorKind::Overflow);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CastError {
+    /// The name of the component, for example `"x"` or `"size.height"`.
+    pub component: &'static str,
+    pub kind: CastErrorKind,
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            CastErrorKind::NaN => "is NaN",
+            CastErrorKind::Overflow => "is out of range",
+        };
+        write!(
+            f,
+            "cannot cast component `{}`: value {}",
+            self.component, reason
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CastError {}
+
+/// Casts one component, naming it in the error.
+#[inline]
+pub(crate) fn cast_component<T: NumCast, NewT: NumCast>(
+    value: T,
+    component: &'static str,
+) -> Result<NewT, CastError> {
+    let is_nan = value.to_f64().map_or(false, f64::is_nan);
+    NumCast::from(value).ok_or(CastError {
+        component,
+        kind: if is_nan {
+            CastErrorKind::NaN
+        } else {
+            CastErrorKind::Overflow
+        },
+    })
+}
+
+/// How fractional values are rounded by the `cast_with_rounding` methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward negative infinity, like `floor()`.
+    Floor,
+    /// Round toward positive infinity, like `ceil()`.
+    Ceil,
+    /// Round to the nearest integer, with ties toward positive infinity, like `round()`.
+    Round,
+    /// Round to the nearest integer, with ties toward the even integer.
+    RoundTiesEven,
+    /// Round toward zero, like a plain `cast()`.
+    Trunc,
+}
+
+/// Rounds one component and casts it.
+///
+/// Values without a fractional part, which includes all integers, are cast directly
+/// so that large integers don't lose precision through `f64`.
+#[inline]
+pub(crate) fn round_component<T: NumCast, NewT: NumCast>(
+    value: T,
+    mode: RoundingMode,
+) -> Option<NewT> {
+    let v = value.to_f64()?;
+    if Float::fract(v) == 0.0 || !v.is_finite() {
+        return NumCast::from(value);
+    }
+    let rounded = match mode {
+        RoundingMode::Floor => Float::floor(v),
+        RoundingMode::Ceil => Float::ceil(v),
+        RoundingMode::Round => Float::floor(v + 0.5),
+        RoundingMode::RoundTiesEven => {
+            let r = Float::round(v);
+            if Float::abs(r - v) == 0.5 && r % 2.0 != 0.0 {
+                r - Float::signum(v)
+            } else {
+                r
+            }
+        }
+        RoundingMode::Trunc => Float::trunc(v),
+    };
+    NumCast::from(rounded)
+}
+
+/// Casts one component, clamping it to the range of the target type.
+///
+/// NaN becomes zero, like with the `as` operator.
+#[inline]
+pub(crate) fn saturate_component<T: NumCast, NewT: NumCast + Bounded>(value: T) -> NewT {
+    let v = value.to_f64();
+    if let Some(cast) = NumCast::from(value) {
+        return cast;
+    }
+    match v {
+        Some(v) if v.is_nan() => NumCast::from(0).unwrap(),
+        Some(v) if v < 0.0 => NewT::min_value(),
+        _ => NewT::max_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default::{Box2D, Point2D, Point3D, Rect, Vector2D};
+    use crate::{point2, rect};
+
+    #[test]
+    fn test_cast_checked() {
+        let p = Point3D::new(1.0, f64::NAN, 1.0e20);
+        let err = p.cast_checked::<i32>().unwrap_err();
+        assert_eq!(err.component, "y");
+        assert_eq!(err.kind, CastErrorKind::NaN);
+        assert_eq!(err.to_string(), "cannot cast component `y`: value is NaN");
+
+        let b = Box2D::new(point2(0.0, 0.0), point2(f32::INFINITY, 1.0));
+        assert_eq!(
+            b.cast_checked::<u16>(),
+            Err(CastError {
+                component: "max.x",
+                kind: CastErrorKind::Overflow
+            })
+        );
+
+        let v = Vector2D::new(-1i32, 7);
+        assert_eq!(v.cast_checked::<i8>(), Ok(Vector2D::new(-1, 7)));
+        assert_eq!(v.cast_checked::<u8>().unwrap_err().component, "x");
+    }
+
+    #[test]
+    fn test_cast_with_rounding() {
+        let p = Point2D::new(2.5f32, -2.5);
+        assert_eq!(
+            p.cast_with_rounding(RoundingMode::Floor),
+            Point2D::new(2, -3)
+        );
+        assert_eq!(
+            p.cast_with_rounding(RoundingMode::Ceil),
+            Point2D::new(3, -2)
+        );
+        assert_eq!(
+            p.cast_with_rounding(RoundingMode::Round),
+            Point2D::new(3, -2)
+        );
+        assert_eq!(
+            p.cast_with_rounding(RoundingMode::RoundTiesEven),
+            Point2D::new(2, -2)
+        );
+        assert_eq!(
+            p.cast_with_rounding(RoundingMode::Trunc),
+            Point2D::new(2, -2)
+        );
+        assert_eq!(
+            Point2D::new(3.5, -0.4).cast_with_rounding(RoundingMode::RoundTiesEven),
+            Point2D::new(4, 0)
+        );
+
+        let r: Rect<f64> = rect(0.25, 0.75, 10.5, 3.0);
+        assert_eq!(
+            r.cast_with_rounding::<i32>(RoundingMode::Round),
+            rect(0, 1, 11, 3)
+        );
+
+        let big = Box2D::new(point2(0i64, 0), point2(i64::MAX, 1));
+        assert_eq!(big.cast_with_rounding::<i64>(RoundingMode::Floor), big);
+        assert_eq!(
+            Box2D::new(point2(0.0, 0.0), point2(f32::NAN, 1.0))
+                .try_cast_with_rounding::<i32>(RoundingMode::Floor),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cast_saturating() {
+        let p = Point2D::new(1.0e10f64, -1.0e10);
+        assert_eq!(p.cast_saturating::<i32>(), Point2D::new(i32::MAX, i32::MIN));
+        assert_eq!(p.cast_saturating::<u16>(), Point2D::new(u16::MAX, 0));
+
+        let r: Rect<f32> = rect(f32::NAN, -1.0e10, f32::INFINITY, 12.7);
+        assert_eq!(r.cast_saturating::<i16>(), rect(0, i16::MIN, 0, 0));
+        let r: Rect<f32> = rect(-2.5, 1.0e10, 12.7, 10.0);
+        assert_eq!(r.cast_saturating::<i32>(), rect(-2, i32::MAX, 12, 0));
+        assert_eq!(r.cast_saturating::<i32>().max_y(), i32::MAX);
+
+        let b = Box2D::new(point2(-5i64, 0), point2(300, 70000));
+        assert_eq!(
+            b.cast_saturating::<u8>(),
+            Box2D::new(point2(0, 0), point2(255, 255))
+        );
+    }
+}
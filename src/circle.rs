@@ -0,0 +1,362 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::vector::{Vector2D, Vector3D};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+/// A circle defined by its center and radius.
+///
+/// Points on the circle itself are considered inside, so that a circle with a radius of
+/// zero still contains its center.
+///
+/// ```
+/// use euclid::default::{Box2D, Circle};
+/// use euclid::point2;
+///
+/// let c = Circle::new(point2(0.0, 0.0), 2.0);
+/// assert!(c.contains(point2(1.0, 1.0)));
+/// assert!(!c.contains(point2(2.0, 2.0)));
+/// assert!(c.intersects_box(&Box2D::new(point2(1.0, 1.0), point2(3.0, 3.0))));
+/// assert_eq!(c.bounding_box(), Box2D::new(point2(-2.0, -2.0), point2(2.0, 2.0)));
+/// ```
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Circle<T, U> {
+    pub center: Point2D<T, U>,
+    pub radius: T,
+}
+
+/// A sphere defined by its center and radius.
+///
+/// See [`Circle`].
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Sphere<T, U> {
+    pub center: Point3D<T, U>,
+    pub radius: T,
+}
+
+macro_rules! circle_impls {
+    ($name:ident, $point:ident, $vector:ident, $box:ident, [$($field:ident),+]) => {
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.center.hash(h);
+                self.radius.hash(h);
+            }
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                Self::new(self.center.clone(), self.radius.clone())
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.center.eq(&other.center) && self.radius.eq(&other.radius)
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.center)
+                    .field(&self.radius)
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a, T, U> arbitrary::Arbitrary<'a> for $name<T, U>
+        where
+            T: arbitrary::Arbitrary<'a>,
+        {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok($name::new(
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                ))
+            }
+        }
+
+        impl<T, U> $name<T, U> {
+            /// Constructor.
+            #[inline]
+            pub const fn new(center: $point<T, U>, radius: T) -> Self {
+                $name { center, radius }
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// Returns the smallest box containing the shape.
+            #[inline]
+            pub fn bounding_box(&self) -> $box<T, U>
+            where
+                T: Add<Output = T> + Sub<Output = T>,
+            {
+                let r = self.radius;
+                $box::new(
+                    $point::new($(self.center.$field - r),+),
+                    $point::new($(self.center.$field + r),+),
+                )
+            }
+
+            /// Returns the same shape, moved by a vector.
+            #[inline]
+            pub fn translate(&self, by: $vector<T, U>) -> Self
+            where
+                T: Add<Output = T>,
+            {
+                $name::new(self.center + by, self.radius)
+            }
+
+            /// Returns `true` if the point is inside of the shape or on its boundary.
+            #[inline]
+            pub fn contains(&self, p: $point<T, U>) -> bool
+            where
+                T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+            {
+                (p - self.center).square_length() <= self.radius * self.radius
+            }
+
+            /// Returns `true` if the two shapes overlap or touch.
+            #[inline]
+            pub fn intersects(&self, other: &Self) -> bool
+            where
+                T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+            {
+                let r = self.radius + other.radius;
+                (other.center - self.center).square_length() <= r * r
+            }
+
+            /// Returns `true` if the shape overlaps or touches the box.
+            ///
+            /// Negative boxes don't intersect anything.
+            #[inline]
+            pub fn intersects_box(&self, b: &$box<T, U>) -> bool
+            where
+                T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd,
+            {
+                if b.is_negative() {
+                    return false;
+                }
+
+                self.contains(self.center.clamp(b.min, b.max))
+            }
+
+            /// Drop the units, preserving only the numeric value.
+            #[inline]
+            pub fn to_untyped(&self) -> $name<T, UnknownUnit> {
+                $name::new(self.center.to_untyped(), self.radius)
+            }
+
+            /// Tag a unitless value with units.
+            #[inline]
+            pub fn from_untyped(c: &$name<T, UnknownUnit>) -> Self {
+                $name::new($point::from_untyped(c.center), c.radius)
+            }
+        }
+
+        impl<T: RealScalar, U> $name<T, U> {
+            /// Returns a shape containing all of the points, or `None` if there are none.
+            ///
+            /// This uses Ritter's algorithm, which goes over the points three times. The
+            /// result usually isn't the smallest possible, but is typically within a few
+            /// percent of it.
+            pub fn from_points<I>(points: I) -> Option<Self>
+            where
+                I: IntoIterator,
+                I::IntoIter: Clone,
+                I::Item: Borrow<$point<T, U>>,
+            {
+                let points = points.into_iter();
+                let farthest_from = |from: $point<T, U>| {
+                    let mut farthest = from;
+                    let mut max_distance = T::zero();
+                    for p in points.clone() {
+                        let p = *p.borrow();
+                        let d = (p - from).square_length();
+                        if d > max_distance {
+                            farthest = p;
+                            max_distance = d;
+                        }
+                    }
+                    farthest
+                };
+
+                let first = *points.clone().next()?.borrow();
+                let a = farthest_from(first);
+                let b = farthest_from(a);
+                let two = T::one() + T::one();
+                let mut result = $name::new(a + (b - a) / two, (b - a).length() / two);
+
+                // Grow the shape just enough to include the points that are still outside.
+                for p in points {
+                    let p = *p.borrow();
+                    let d = (p - result.center).length();
+                    if d > result.radius {
+                        let radius = (result.radius + d) / two;
+                        let shift = (p - result.center) * ((radius - result.radius) / d);
+                        result = $name::new(result.center + shift, radius);
+                    }
+                }
+
+                Some(result)
+            }
+        }
+    };
+}
+
+circle_impls!(Circle, Point2D, Vector2D, Box2D, [x, y]);
+circle_impls!(Sphere, Point3D, Vector3D, Box3D, [x, y, z]);
+
+impl<T: RealScalar, U> Circle<T, U> {
+    /// Returns the area of the circle.
+    #[inline]
+    pub fn area(&self) -> T {
+        T::PI() * self.radius * self.radius
+    }
+}
+
+impl<T: RealScalar, U> Sphere<T, U> {
+    /// Returns the volume of the sphere.
+    #[inline]
+    pub fn volume(&self) -> T {
+        let three = T::one() + T::one() + T::one();
+        let four = three + T::one();
+        four / three * T::PI() * self.radius * self.radius * self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Box3D, Circle, Point3D, Sphere};
+    use crate::{point2, point3, vec2, vec3};
+
+    #[test]
+    fn test_circle() {
+        let c = Circle::new(point2(10, 10), 5);
+        assert!(c.contains(point2(10, 10)));
+        assert!(c.contains(point2(13, 14)));
+        assert!(c.contains(point2(15, 10)));
+        assert!(!c.contains(point2(14, 14)));
+        assert_eq!(c.bounding_box(), Box2D::new(point2(5, 5), point2(15, 15)));
+        assert_eq!(c.translate(vec2(1, -1)), Circle::new(point2(11, 9), 5));
+
+        assert!(c.intersects(&Circle::new(point2(20, 10), 5)));
+        assert!(c.intersects(&Circle::new(point2(10, 10), 1)));
+        assert!(!c.intersects(&Circle::new(point2(20, 20), 5)));
+
+        // Overlaps the bounding box of the circle, but not the circle itself.
+        let corner = Box2D::new(point2(0, 0), point2(6, 6));
+        assert!(!c.intersects_box(&corner));
+        assert!(c.intersects_box(&corner.translate(vec2(1, 1))));
+        assert!(c.intersects_box(&Box2D::new(point2(0, 0), point2(100, 100))));
+        assert!(c.intersects_box(&Box2D::new(point2(15, 0), point2(20, 20))));
+        assert!(!c.intersects_box(&Box2D::new(point2(16, 0), point2(20, 20))));
+        assert!(!c.intersects_box(&Box2D::new(point2(12, 12), point2(8, 8))));
+
+        let unit = Circle::new(point2(0.0, 0.0), 1.0);
+        assert!(unit.area().approx_eq(&core::f64::consts::PI));
+    }
+
+    #[test]
+    fn test_sphere() {
+        let s = Sphere::new(point3(0.0, 0.0, 0.0), 3.0);
+        assert!(s.contains(point3(1.0, 2.0, 2.0)));
+        assert!(!s.contains(point3(2.0, 2.0, 2.0)));
+        assert_eq!(
+            s.bounding_box(),
+            Box3D::new(point3(-3.0, -3.0, -3.0), point3(3.0, 3.0, 3.0))
+        );
+        assert!(s.intersects(&Sphere::new(point3(0.0, 4.0, 4.0), 3.0)));
+        assert!(!s.intersects(&Sphere::new(point3(0.0, 4.0, 4.0), 2.0)));
+
+        let b = Box3D::new(point3(2.0, 2.0, 2.0), point3(4.0, 4.0, 4.0));
+        assert!(!s.intersects_box(&b));
+        assert!(s.translate(vec3(1.0, 0.0, 0.0)).intersects_box(&b));
+
+        assert!(s.volume().approx_eq(&(36.0 * core::f64::consts::PI)));
+    }
+
+    #[test]
+    fn test_from_points() {
+        assert_eq!(
+            Sphere::from_points(core::iter::empty::<Point3D<f32>>()),
+            None
+        );
+        let p = point3(1.0, 2.0, 3.0);
+        assert_eq!(Sphere::from_points([p]), Some(Sphere::new(p, 0.0)));
+
+        let points: [Point3D<f64>; 7] = [
+            point3(0.0, 0.0, 0.0),
+            point3(4.0, 0.0, 0.0),
+            point3(0.0, 4.0, 0.0),
+            point3(0.0, 0.0, 4.0),
+            point3(4.0, 4.0, 4.0),
+            point3(1.0, 3.0, 2.0),
+            point3(-1.0, 2.0, 5.0),
+        ];
+        let s = Sphere::from_points(points).unwrap();
+        let loose = Sphere::new(s.center, s.radius + 1e-9);
+        assert!(points.iter().all(|p| loose.contains(*p)));
+        // The smallest enclosing sphere is at least as wide as the bounding box.
+        let b = Box3D::from_points(points);
+        assert!(s.radius * 2.0 >= b.width().max(b.height()).max(b.depth()));
+        assert!(s.radius <= b.bounding_sphere().radius);
+
+        // Ritter's algorithm is exact when two of the points are opposite each other.
+        let points = [
+            point2(-2.0, 0.0),
+            point2(2.0, 0.0),
+            point2(0.0, 1.0),
+            point2(1.0, -1.0),
+        ];
+        assert_eq!(
+            Circle::from_points(points),
+            Some(Circle::new(point2(0.0, 0.0), 2.0))
+        );
+
+        let b = Box2D::new(point2(0.0, 0.0), point2(6.0, 8.0));
+        assert_eq!(b.bounding_circle(), Circle::new(point2(3.0, 4.0), 5.0));
+    }
+}
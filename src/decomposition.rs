@@ -0,0 +1,262 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Transforms broken down into components that can be interpolated.
+
+use crate::num::*;
+use crate::{vec2, vec3, Angle, HomogeneousVector, Rotation3D, UnknownUnit, Vector2D, Vector3D};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 2d affine transform broken down into a scale, a skew, a rotation and a
+/// translation, applied in this order.
+///
+/// This is returned by [`Transform2D::decompose`](crate::Transform2D::decompose) and
+/// turned back into a transform by [`Transform2D::recompose`](crate::Transform2D::recompose).
+/// Interpolating the components with [`lerp`](Self::lerp) gives a natural looking
+/// animation between two transforms, unlike interpolating the matrices directly.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Decomposition2D<T, Src, Dst> {
+    /// The translation, applied last.
+    pub translation: Vector2D<T, Dst>,
+    /// The rotation, applied after the skew.
+    pub angle: Angle<T>,
+    /// The horizontal skew, as the tangent of the skew angle, applied after the scale.
+    pub skew: T,
+    /// The scale along the x and y axes, applied first.
+    pub scale: Vector2D<T, UnknownUnit>,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> Decomposition2D<T, Src, Dst> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        translation: Vector2D<T, Dst>,
+        angle: Angle<T>,
+        skew: T,
+        scale: Vector2D<T, UnknownUnit>,
+    ) -> Self {
+        Decomposition2D {
+            translation,
+            angle,
+            skew,
+            scale,
+            _unit: PhantomData,
+        }
+    }
+
+    /// The decomposition of the identity transform.
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Decomposition2D::new(
+            vec2(T::zero(), T::zero()),
+            Angle::radians(T::zero()),
+            T::zero(),
+            vec2(T::one(), T::one()),
+        )
+    }
+}
+
+impl<T: RealScalar, Src, Dst> Decomposition2D<T, Src, Dst> {
+    /// Linearly interpolates each component between `self` and `other`.
+    ///
+    /// The rotation goes along the shortest arc between the two angles.
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Decomposition2D::new(
+            self.translation.lerp(other.translation, t),
+            self.angle.lerp(other.angle, t),
+            self.skew + (other.skew - self.skew) * t,
+            self.scale.lerp(other.scale, t),
+        )
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for Decomposition2D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for Decomposition2D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Decomposition2D::new(
+            self.translation.clone(),
+            self.angle.clone(),
+            self.skew.clone(),
+            self.scale.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Decomposition2D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.translation == other.translation
+            && self.angle == other.angle
+            && self.skew == other.skew
+            && self.scale == other.scale
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for Decomposition2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Decomposition2D")
+            .field("translation", &self.translation)
+            .field("angle", &self.angle)
+            .field("skew", &self.skew)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+impl<T: Zero + One, Src, Dst> Default for Decomposition2D<T, Src, Dst> {
+    /// Returns the [identity decomposition](Decomposition2D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// A 3d transform broken down into a scale, a skew, a rotation, a translation and a
+/// perspective, applied in this order.
+///
+/// This follows the decomposition of matrices in the CSS Transforms Module Level 2. It is
+/// returned by [`Transform3D::decompose`](crate::Transform3D::decompose) and turned back
+/// into a transform by [`Transform3D::recompose`](crate::Transform3D::recompose).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Decomposition3D<T, Src, Dst> {
+    /// The translation, applied after the rotation.
+    pub translation: Vector3D<T, Dst>,
+    /// The rotation, applied after the skew.
+    pub rotation: Rotation3D<T, UnknownUnit, UnknownUnit>,
+    /// The xy, xz and yz skews in the x, y and z components, applied after the scale.
+    pub skew: Vector3D<T, UnknownUnit>,
+    /// The scale along the x, y and z axes, applied first.
+    pub scale: Vector3D<T, UnknownUnit>,
+    /// The perspective, applied last.
+    pub perspective: HomogeneousVector<T, UnknownUnit>,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> Decomposition3D<T, Src, Dst> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(
+        translation: Vector3D<T, Dst>,
+        rotation: Rotation3D<T, UnknownUnit, UnknownUnit>,
+        skew: Vector3D<T, UnknownUnit>,
+        scale: Vector3D<T, UnknownUnit>,
+        perspective: HomogeneousVector<T, UnknownUnit>,
+    ) -> Self {
+        Decomposition3D {
+            translation,
+            rotation,
+            skew,
+            scale,
+            perspective,
+            _unit: PhantomData,
+        }
+    }
+
+    /// The decomposition of the identity transform.
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Decomposition3D::new(
+            vec3(T::zero(), T::zero(), T::zero()),
+            Rotation3D::quaternion(T::zero(), T::zero(), T::zero(), T::one()),
+            vec3(T::zero(), T::zero(), T::zero()),
+            vec3(T::one(), T::one(), T::one()),
+            HomogeneousVector::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+impl<T: RealScalar, Src, Dst> Decomposition3D<T, Src, Dst> {
+    /// Interpolates between `self` and `other`.
+    ///
+    /// The rotation is spherically interpolated along the shortest arc, and the other
+    /// components linearly.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        let (a, b) = (self.perspective, other.perspective);
+        let lerp = |a: T, b: T| a + (b - a) * t;
+        Decomposition3D::new(
+            self.translation.lerp(other.translation, t),
+            self.rotation.slerp(&other.rotation, t),
+            self.skew.lerp(other.skew, t),
+            self.scale.lerp(other.scale, t),
+            HomogeneousVector::new(
+                lerp(a.x, b.x),
+                lerp(a.y, b.y),
+                lerp(a.z, b.z),
+                lerp(a.w, b.w),
+            ),
+        )
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for Decomposition3D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for Decomposition3D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Decomposition3D::new(
+            self.translation.clone(),
+            self.rotation.clone(),
+            self.skew.clone(),
+            self.scale.clone(),
+            self.perspective.clone(),
+        )
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Decomposition3D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.translation == other.translation
+            && self.rotation == other.rotation
+            && self.skew == other.skew
+            && self.scale == other.scale
+            && self.perspective == other.perspective
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for Decomposition3D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Decomposition3D")
+            .field("translation", &self.translation)
+            .field("rotation", &self.rotation)
+            .field("skew", &self.skew)
+            .field("scale", &self.scale)
+            .field("perspective", &self.perspective)
+            .finish()
+    }
+}
+
+impl<T: Zero + One, Src, Dst> Default for Decomposition3D<T, Src, Dst> {
+    /// Returns the [identity decomposition](Decomposition3D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between the 2D and the 3D types.
+
+use crate::num::{One, RealScalar, Zero};
+use crate::{
+    Angle, Box2D, Box3D, Point2D, Point3D, Rect, Rotation2D, Rotation3D, Size2D, Size3D,
+    Transform2D, Transform3D, Translation2D, Translation3D, Vector2D, Vector3D,
+};
+use core::ops::Add;
+
+/// One of the three planes of 3D space containing two of the axes.
+///
+/// The x and y axes of the 2D types are mapped to the first and the second axis of
+/// the plane, in the order of the name. For example with [`AxisPlane::XZ`], the 2D
+/// point `(1, 2)` becomes the 3D point `(1, 0, 2)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AxisPlane {
+    /// The plane `z = 0`, which is what the `to_3d` and `to_2d` methods use.
+    XY,
+    /// The plane `y = 0`.
+    XZ,
+    /// The plane `x = 0`.
+    YZ,
+}
+
+impl AxisPlane {
+    /// Returns the indices of the 3D axes that the 2D x and y axes map to.
+    #[inline]
+    fn axes(self) -> (usize, usize) {
+        match self {
+            AxisPlane::XY => (0, 1),
+            AxisPlane::XZ => (0, 2),
+            AxisPlane::YZ => (1, 2),
+        }
+    }
+
+    #[inline]
+    fn lift<T: Copy + Zero>(self, [x, y]: [T; 2]) -> [T; 3] {
+        let (i, j) = self.axes();
+        let mut v = [T::zero(); 3];
+        v[i] = x;
+        v[j] = y;
+        v
+    }
+
+    #[inline]
+    fn project<T: Copy>(self, v: [T; 3]) -> [T; 2] {
+        let (i, j) = self.axes();
+        [v[i], v[j]]
+    }
+}
+
+/// Lifting 2D values into 3D space.
+///
+/// The values are embedded in one of the planes of [`AxisPlane`], with a zero
+/// coordinate along the remaining axis. Rectangles and boxes become boxes of zero
+/// thickness, transforms leave the remaining axis unchanged, and rotations become
+/// rotations around the remaining axis.
+///
+/// ```
+/// use euclid::default::{Box2D, Point2D, Transform2D};
+/// use euclid::{point2, point3, Angle, AxisPlane, To3D};
+///
+/// let p: Point2D<f32> = point2(1.0, 2.0);
+/// assert_eq!(To3D::to_3d(&p), point3(1.0, 2.0, 0.0));
+/// assert_eq!(p.to_3d_in(AxisPlane::XZ), point3(1.0, 0.0, 2.0));
+///
+/// let b = Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0)).to_3d_in(AxisPlane::YZ);
+/// assert_eq!((b.min, b.max), (point3(0.0, 0.0, 0.0), point3(0.0, 1.0, 1.0)));
+///
+/// let t = Transform2D::translation(5.0, 6.0).to_3d_in(AxisPlane::XZ);
+/// assert_eq!(t.transform_point3d(point3(0.0, 1.0, 0.0)), Some(point3(5.0, 1.0, 6.0)));
+/// ```
+pub trait To3D {
+    /// The 3D type.
+    type Output;
+
+    /// Embeds the value in the given plane.
+    fn to_3d_in(&self, plane: AxisPlane) -> Self::Output;
+
+    /// Embeds the value in the plane `z = 0`.
+    #[inline]
+    fn to_3d(&self) -> Self::Output {
+        self.to_3d_in(AxisPlane::XY)
+    }
+}
+
+/// Dropping 3D values to 2D.
+///
+/// This is the reverse of [`To3D`]: the coordinates along the axes of the plane are
+/// kept, and the remaining one is discarded. Transforms keep the terms that act within
+/// the plane, so they should only act within it, as checked by
+/// [`Transform3D::is_2d`] for the `z = 0` plane.
+///
+/// ```
+/// use euclid::default::Box3D;
+/// use euclid::{point2, point3, AxisPlane, To2D};
+///
+/// let b = Box3D::new(point3(1.0, 2.0, 3.0), point3(4.0, 5.0, 6.0));
+/// let b = b.to_2d_in(AxisPlane::YZ);
+/// assert_eq!((b.min, b.max), (point2(2.0, 3.0), point2(5.0, 6.0)));
+/// ```
+pub trait To2D {
+    /// The 2D type.
+    type Output;
+
+    /// Projects the value onto the given plane.
+    fn to_2d_in(&self, plane: AxisPlane) -> Self::Output;
+
+    /// Projects the value onto the plane `z = 0`.
+    #[inline]
+    fn to_2d(&self) -> Self::Output {
+        self.to_2d_in(AxisPlane::XY)
+    }
+}
+
+macro_rules! vector_like {
+    ($($ty2:ident <=> $ty3:ident),+) => {$(
+        impl<T: Copy + Zero, U> To3D for $ty2<T, U> {
+            type Output = $ty3<T, U>;
+
+            #[inline]
+            fn to_3d_in(&self, plane: AxisPlane) -> $ty3<T, U> {
+                plane.lift(self.to_array()).into()
+            }
+        }
+
+        impl<T: Copy, U> To2D for $ty3<T, U> {
+            type Output = $ty2<T, U>;
+
+            #[inline]
+            fn to_2d_in(&self, plane: AxisPlane) -> $ty2<T, U> {
+                plane.project(self.to_array()).into()
+            }
+        }
+    )+};
+}
+
+vector_like!(Point2D <=> Point3D, Vector2D <=> Vector3D, Size2D <=> Size3D);
+
+impl<T: Copy + Zero, Src, Dst> To3D for Translation2D<T, Src, Dst> {
+    type Output = Translation3D<T, Src, Dst>;
+
+    #[inline]
+    fn to_3d_in(&self, plane: AxisPlane) -> Translation3D<T, Src, Dst> {
+        plane.lift(self.to_array()).into()
+    }
+}
+
+impl<T: Copy, Src, Dst> To2D for Translation3D<T, Src, Dst> {
+    type Output = Translation2D<T, Src, Dst>;
+
+    #[inline]
+    fn to_2d_in(&self, plane: AxisPlane) -> Translation2D<T, Src, Dst> {
+        plane.project(self.to_array()).into()
+    }
+}
+
+impl<T: Copy + Zero, U> To3D for Box2D<T, U> {
+    type Output = Box3D<T, U>;
+
+    #[inline]
+    fn to_3d_in(&self, plane: AxisPlane) -> Box3D<T, U> {
+        Box3D::new(self.min.to_3d_in(plane), self.max.to_3d_in(plane))
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T>, U> To3D for Rect<T, U> {
+    type Output = Box3D<T, U>;
+
+    #[inline]
+    fn to_3d_in(&self, plane: AxisPlane) -> Box3D<T, U> {
+        self.to_box2d().to_3d_in(plane)
+    }
+}
+
+impl<T: Copy, U> To2D for Box3D<T, U> {
+    type Output = Box2D<T, U>;
+
+    #[inline]
+    fn to_2d_in(&self, plane: AxisPlane) -> Box2D<T, U> {
+        Box2D::new(self.min.to_2d_in(plane), self.max.to_2d_in(plane))
+    }
+}
+
+impl<T: Copy + Zero + One, Src, Dst> To3D for Transform2D<T, Src, Dst> {
+    type Output = Transform3D<T, Src, Dst>;
+
+    fn to_3d_in(&self, plane: AxisPlane) -> Transform3D<T, Src, Dst> {
+        let (i, j) = plane.axes();
+        let [r1, r2, r3] = self.to_arrays();
+        let mut m = Transform3D::<T, Src, Dst>::identity().to_arrays();
+        for (row, [a, b]) in [(i, r1), (j, r2), (3, r3)] {
+            m[row][i] = a;
+            m[row][j] = b;
+        }
+        Transform3D::from_arrays(m)
+    }
+}
+
+impl<T: Copy, Src, Dst> To2D for Transform3D<T, Src, Dst> {
+    type Output = Transform2D<T, Src, Dst>;
+
+    fn to_2d_in(&self, plane: AxisPlane) -> Transform2D<T, Src, Dst> {
+        let (i, j) = plane.axes();
+        let m = self.to_arrays();
+        Transform2D::from_arrays([[m[i][i], m[i][j]], [m[j][i], m[j][j]], [m[3][i], m[3][j]]])
+    }
+}
+
+impl<T: RealScalar, Src, Dst> To3D for Rotation2D<T, Src, Dst> {
+    type Output = Rotation3D<T, Src, Dst>;
+
+    #[inline]
+    fn to_3d_in(&self, plane: AxisPlane) -> Rotation3D<T, Src, Dst> {
+        // The rotation goes from the first axis of the plane towards the second one,
+        // which is clockwise around the y axis for the XZ plane.
+        match plane {
+            AxisPlane::XY => Rotation3D::around_z(self.get_angle()),
+            AxisPlane::XZ => Rotation3D::around_y(Angle::radians(-self.angle)),
+            AxisPlane::YZ => Rotation3D::around_x(self.get_angle()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approxeq::ApproxEq;
+    use crate::default;
+    use crate::{point2, rect, size2, size3, vec2};
+
+    const PLANES: [AxisPlane; 3] = [AxisPlane::XY, AxisPlane::XZ, AxisPlane::YZ];
+
+    #[test]
+    fn test_round_trip() {
+        let p: default::Point2D<i32> = point2(1, 2);
+        let s: default::Size2D<i32> = size2(3, 4);
+        let b: default::Box2D<i32> = Box2D::new(point2(1, 2), point2(3, 4));
+        for plane in PLANES {
+            assert_eq!(p.to_3d_in(plane).to_2d_in(plane), p);
+            assert_eq!(s.to_3d_in(plane).to_2d_in(plane), s);
+            assert_eq!(b.to_3d_in(plane).to_2d_in(plane), b);
+        }
+        assert_eq!(To3D::to_3d(&p), p.to_3d());
+        assert_eq!(s.to_3d_in(AxisPlane::YZ), size3(0, 3, 4));
+
+        let r: default::Rect<i32> = rect(1, 2, 3, 4);
+        let slab = r.to_3d_in(AxisPlane::XY);
+        assert_eq!(slab.to_2d(), r.to_box2d());
+        assert!(slab.is_empty());
+    }
+
+    #[test]
+    fn test_transform() {
+        let t = default::Transform2D::<f64>::rotation(Angle::degrees(30.0))
+            .then_scale(2.0, 3.0)
+            .then_translate(vec2(5.0, -1.0));
+        assert_eq!(To3D::to_3d(&t), t.to_3d());
+        assert_eq!(To2D::to_2d(&t.to_3d()), t);
+
+        let p = point2(1.0, 2.0);
+        for plane in PLANES {
+            let t3 = t.to_3d_in(plane);
+            assert_eq!(t3.to_2d_in(plane), t);
+            let q = t3.transform_point3d(p.to_3d_in(plane)).unwrap();
+            assert!(q.approx_eq(&t.transform_point(p).to_3d_in(plane)));
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        let r: Rotation2D<f64, (), ()> = Rotation2D::new(Angle::degrees(30.0));
+        assert_eq!(To3D::to_3d(&r), r.to_3d());
+
+        let v = vec2(1.0, 2.0);
+        for plane in PLANES {
+            let rotated = r.to_3d_in(plane).transform_vector3d(v.to_3d_in(plane));
+            assert!(rotated.approx_eq(&r.transform_vector(v).to_3d_in(plane)));
+        }
+    }
+}
@@ -0,0 +1,506 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rigid transforms represented as unit dual quaternions.
+//!
+//! Quaternion products in this module are Hamilton products, so `a * b` applies `b`
+//! before `a`, unlike the row-vector notation of the transform types.
+
+use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
+use crate::trig::Trig;
+use crate::{vec3, Point3D, RigidTransform3D, Rotation3D, Transform3D, UnknownUnit, Vector3D};
+
+use core::{fmt, hash};
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use num_traits::real::Real;
+use num_traits::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A rigid transformation represented as a unit dual quaternion `real + ε dual`.
+///
+/// The real part is the rotation and the dual part is `t * real / 2`, where `t` is the
+/// translation as a pure quaternion. As with [`RigidTransform3D`], the rotation applies
+/// first.
+///
+/// Unlike the rotation and translation pair of [`RigidTransform3D`], interpolating with
+/// [`sclerp`](Self::sclerp) moves along a screw motion with constant speed, and blending
+/// several dual quaternions and normalizing the result, as done for skinning, does not
+/// shrink the blended shapes.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>"
+    ))
+)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct DualQuaternion<T, Src, Dst> {
+    /// The real part, which is the rotation.
+    pub real: Rotation3D<T, Src, Dst>,
+    /// The dual part, which encodes the translation.
+    pub dual: Rotation3D<T, Src, Dst>,
+}
+
+impl<T, Src, Dst> DualQuaternion<T, Src, Dst> {
+    /// Construct a dual quaternion from its real and dual parts.
+    ///
+    /// Most methods expect the result to be normalized, see
+    /// [`normalize`](Self::normalize).
+    #[inline]
+    pub const fn new(real: Rotation3D<T, Src, Dst>, dual: Rotation3D<T, Src, Dst>) -> Self {
+        DualQuaternion { real, dual }
+    }
+}
+
+impl<T: Copy, Src, Dst> DualQuaternion<T, Src, Dst> {
+    /// Change the units of this dual quaternion.
+    #[inline]
+    pub fn cast_unit<Src2, Dst2>(&self) -> DualQuaternion<T, Src2, Dst2> {
+        DualQuaternion {
+            real: self.real.cast_unit(),
+            dual: self.dual.cast_unit(),
+        }
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> DualQuaternion<T, UnknownUnit, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(q: &DualQuaternion<T, UnknownUnit, UnknownUnit>) -> Self {
+        q.cast_unit()
+    }
+}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> DualQuaternion<T, Src, Dst> {
+    /// Construct the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        let zero = T::zero();
+        DualQuaternion {
+            real: Rotation3D::quaternion(zero, zero, zero, T::one()),
+            dual: Rotation3D::quaternion(zero, zero, zero, zero),
+        }
+    }
+
+    /// Construct a dual quaternion from a rigid transform.
+    pub fn from_rigid(rigid: &RigidTransform3D<T, Src, Dst>) -> Self {
+        let t = &rigid.translation;
+        let t: Rotation3D<T, Dst, Dst> = Rotation3D::quaternion(t.x, t.y, t.z, T::zero());
+        let half = T::one() / (T::one() + T::one());
+        DualQuaternion {
+            real: rigid.rotation,
+            dual: scale(&mul(&t, &rigid.rotation), half),
+        }
+    }
+
+    /// Returns the rotation and the translation of this transform.
+    pub fn to_rigid(&self) -> RigidTransform3D<T, Src, Dst> {
+        let t: Rotation3D<T, Dst, Dst> = mul(&self.dual, &self.real.inverse());
+        let two = T::one() + T::one();
+        RigidTransform3D::new(self.real, vec3(t.i * two, t.j * two, t.k * two))
+    }
+
+    /// Returns the translation of this transform, which applies after the rotation.
+    #[inline]
+    pub fn translation(&self) -> Vector3D<T, Dst> {
+        self.to_rigid().translation
+    }
+
+    /// Returns the matrix representation of this transform.
+    #[inline]
+    pub fn to_transform(&self) -> Transform3D<T, Src, Dst>
+    where
+        T: Trig + SimdScalar,
+    {
+        self.to_rigid().to_transform()
+    }
+
+    /// Returns the multiplication of the two transforms such that
+    /// other's transformation applies after self's transformation.
+    pub fn then<Dst2>(&self, other: &DualQuaternion<T, Dst, Dst2>) -> DualQuaternion<T, Src, Dst2> {
+        DualQuaternion {
+            real: mul(&other.real, &self.real),
+            dual: add(&mul(&other.real, &self.dual), &mul(&other.dual, &self.real)),
+        }
+    }
+
+    /// Inverts the transformation.
+    ///
+    /// This is the conjugate of both parts, which is only the inverse if this dual
+    /// quaternion is normalized.
+    #[inline]
+    pub fn inverse(&self) -> DualQuaternion<T, Dst, Src> {
+        DualQuaternion {
+            real: self.real.inverse(),
+            dual: self.dual.inverse(),
+        }
+    }
+
+    /// Returns a unit dual quaternion representing the same transform.
+    ///
+    /// Both parts are divided by the norm of the real part, and the dual part is made
+    /// orthogonal to the real part. This is the projection applied after blending
+    /// several dual quaternions.
+    pub fn normalize(&self) -> Self {
+        let real = scale(&self.real, T::one() / self.real.norm());
+        let dual = scale(&self.dual, T::one() / self.real.norm());
+        let dual = add(&dual, &scale(&real, -real.dot(&dual)));
+        DualQuaternion { real, dual }
+    }
+
+    /// Returns `true` if the real part has a norm of one and is orthogonal to the dual
+    /// part.
+    pub fn is_normalized(&self) -> bool {
+        let eps = NumCast::from(1.0e-5).unwrap();
+        self.real.is_normalized() && self.real.dot(&self.dual).approx_eq_eps(&T::zero(), &eps)
+    }
+
+    /// Returns the given point transformed by this transform.
+    #[inline]
+    pub fn transform_point3d(&self, point: Point3D<T, Src>) -> Point3D<T, Dst> {
+        self.to_rigid().transform_point3d(point)
+    }
+
+    /// Returns the given vector transformed by this transform, which only rotates it.
+    #[inline]
+    pub fn transform_vector3d(&self, vector: Vector3D<T, Src>) -> Vector3D<T, Dst> {
+        self.real.transform_vector3d(vector)
+    }
+
+    /// Screw linear interpolation between this transform and another one.
+    ///
+    /// The result moves along the screw motion going from `self` at `t = 0` to `other`
+    /// at `t = 1`, rotating around and translating along the same axis at constant
+    /// speed. Like [`Rotation3D::slerp`], it takes the shortest path.
+    pub fn sclerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.real.dot(&other.real) < T::zero() {
+            DualQuaternion {
+                real: scale(&other.real, -T::one()),
+                dual: scale(&other.dual, -T::one()),
+            }
+        } else {
+            *other
+        };
+        let difference: DualQuaternion<T, Src, Src> = other.then(&self.inverse());
+        difference.pow(t).then(self)
+    }
+
+    /// Raises this unit dual quaternion to the power `t`, scaling the angle and the
+    /// translation of its screw motion by `t`.
+    fn pow(&self, t: T) -> Self {
+        let (real, dual) = (&self.real, &self.dual);
+        let two = T::one() + T::one();
+        let sin = vec3::<T, UnknownUnit>(real.i, real.j, real.k).length();
+        if sin <= T::epsilon() {
+            // A pure translation, with no axis to rotate around.
+            return DualQuaternion {
+                real: *real,
+                dual: scale(dual, t),
+            };
+        }
+
+        // The rotation is around `axis` going through the point defined by `moment`, and
+        // `pitch` is the translation along the axis.
+        let half_angle = sin.atan2(real.r);
+        let axis = vec3::<T, UnknownUnit>(real.i, real.j, real.k) / sin;
+        let pitch = -two * dual.r / sin;
+        let moment = (vec3(dual.i, dual.j, dual.k) - axis * (pitch / two * real.r)) / sin;
+
+        let (sin, cos) = (half_angle * t).sin_cos();
+        let pitch = pitch * t;
+        let v = axis * sin;
+        let d = moment * sin + axis * (pitch / two * cos);
+        DualQuaternion {
+            real: Rotation3D::quaternion(v.x, v.y, v.z, cos),
+            dual: Rotation3D::quaternion(d.x, d.y, d.z, -pitch / two * sin),
+        }
+    }
+}
+
+/// The Hamilton product `a * b` of two quaternions.
+fn mul<T: Real, A, B, C, D, E, F>(
+    a: &Rotation3D<T, A, B>,
+    b: &Rotation3D<T, C, D>,
+) -> Rotation3D<T, E, F> {
+    Rotation3D::quaternion(
+        a.r * b.i + a.i * b.r + a.j * b.k - a.k * b.j,
+        a.r * b.j - a.i * b.k + a.j * b.r + a.k * b.i,
+        a.r * b.k + a.i * b.j - a.j * b.i + a.k * b.r,
+        a.r * b.r - a.i * b.i - a.j * b.j - a.k * b.k,
+    )
+}
+
+fn add<T: Real, Src, Dst>(
+    a: &Rotation3D<T, Src, Dst>,
+    b: &Rotation3D<T, Src, Dst>,
+) -> Rotation3D<T, Src, Dst> {
+    Rotation3D::quaternion(a.i + b.i, a.j + b.j, a.k + b.k, a.r + b.r)
+}
+
+fn scale<T: Real, Src, Dst>(q: &Rotation3D<T, Src, Dst>, factor: T) -> Rotation3D<T, Src, Dst> {
+    Rotation3D::quaternion(q.i * factor, q.j * factor, q.k * factor, q.r * factor)
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for DualQuaternion<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DualQuaternion")
+            .field("real", &self.real)
+            .field("dual", &self.dual)
+            .finish()
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for DualQuaternion<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.real == other.real && self.dual == other.dual
+    }
+}
+impl<T: Eq, Src, Dst> Eq for DualQuaternion<T, Src, Dst> {}
+
+impl<T: hash::Hash, Src, Dst> hash::Hash for DualQuaternion<T, Src, Dst> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.real.hash(state);
+        self.dual.hash(state);
+    }
+}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> Default for DualQuaternion<T, Src, Dst> {
+    /// Returns the [identity transform](DualQuaternion::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for DualQuaternion<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for DualQuaternion<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        DualQuaternion {
+            real: self.real.clone(),
+            dual: self.dual.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, Src, Dst> arbitrary::Arbitrary<'a> for DualQuaternion<T, Src, Dst>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DualQuaternion {
+            real: arbitrary::Arbitrary::arbitrary(u)?,
+            dual: arbitrary::Arbitrary::arbitrary(u)?,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, Src, Dst> Zeroable for DualQuaternion<T, Src, Dst> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, Src: 'static, Dst: 'static> Pod for DualQuaternion<T, Src, Dst> {}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> From<RigidTransform3D<T, Src, Dst>>
+    for DualQuaternion<T, Src, Dst>
+{
+    fn from(rigid: RigidTransform3D<T, Src, Dst>) -> Self {
+        Self::from_rigid(&rigid)
+    }
+}
+
+impl<T: Real + ApproxEq<T>, Src, Dst> From<DualQuaternion<T, Src, Dst>>
+    for RigidTransform3D<T, Src, Dst>
+{
+    fn from(q: DualQuaternion<T, Src, Dst>) -> Self {
+        q.to_rigid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{DualQuaternion, RigidTransform3D, Rotation3D, Vector3D};
+    use crate::{point3, vec3, Angle};
+
+    fn rigid_pair() -> (RigidTransform3D<f64>, RigidTransform3D<f64>) {
+        (
+            RigidTransform3D::new(
+                Rotation3D::unit_quaternion(0.5, -7.8, 2.2, 4.3),
+                Vector3D::new(12.1, 17.8, -5.5),
+            ),
+            RigidTransform3D::new(
+                Rotation3D::unit_quaternion(0.1, 0.2, 0.3, -0.4),
+                Vector3D::new(9.3, -3.9, 1.1),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_rigid_conversion() {
+        let (rigid, _) = rigid_pair();
+        let q = DualQuaternion::from_rigid(&rigid);
+        assert!(q.is_normalized());
+        assert!(q.to_rigid().to_transform().approx_eq(&rigid.to_transform()));
+        assert!(q.translation().approx_eq(&rigid.translation));
+
+        let p = point3(1.0, -2.0, 3.0);
+        assert!(q
+            .transform_point3d(p)
+            .approx_eq(&rigid.transform_point3d(p)));
+        let v = vec3(1.0, -2.0, 3.0);
+        assert!(q
+            .transform_vector3d(v)
+            .approx_eq(&rigid.transform_vector3d(v)));
+
+        assert_eq!(
+            DualQuaternion::<f64>::identity().to_rigid(),
+            RigidTransform3D::identity()
+        );
+    }
+
+    #[test]
+    fn test_then_and_inverse() {
+        let (a, b) = rigid_pair();
+        let qa = DualQuaternion::from(a);
+        let qb = DualQuaternion::from(b);
+
+        assert!(qa
+            .then(&qb)
+            .to_transform()
+            .approx_eq(&a.then(&b).to_transform()));
+        assert!(qa
+            .then(&qa.inverse())
+            .to_transform()
+            .approx_eq(&DualQuaternion::identity().to_transform()));
+        assert!(qa
+            .inverse()
+            .to_transform()
+            .approx_eq(&a.inverse().to_transform()));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (a, _) = rigid_pair();
+        let q = DualQuaternion::from(a);
+        let blended = DualQuaternion::new(
+            Rotation3D::quaternion(
+                q.real.i * 3.0,
+                q.real.j * 3.0,
+                q.real.k * 3.0,
+                q.real.r * 3.0,
+            ),
+            Rotation3D::quaternion(
+                q.dual.i * 3.0 + q.real.i,
+                q.dual.j * 3.0 + q.real.j,
+                q.dual.k * 3.0 + q.real.k,
+                q.dual.r * 3.0 + q.real.r,
+            ),
+        );
+        assert!(!blended.is_normalized());
+        let n = blended.normalize();
+        assert!(n.is_normalized());
+        assert!(n.to_transform().approx_eq(&a.to_transform()));
+    }
+
+    #[test]
+    fn test_sclerp() {
+        let (a, b) = rigid_pair();
+        let qa = DualQuaternion::from(a);
+        let qb = DualQuaternion::from(b);
+
+        assert!(qa
+            .sclerp(&qb, 0.0)
+            .to_transform()
+            .approx_eq(&a.to_transform()));
+        assert!(qa
+            .sclerp(&qb, 1.0)
+            .to_transform()
+            .approx_eq(&b.to_transform()));
+        let half = qa.sclerp(&qb, 0.5);
+        assert!(half.is_normalized());
+        assert!(half
+            .then(&qa.inverse())
+            .then(&half)
+            .to_transform()
+            .approx_eq(&qb.to_transform()));
+
+        // A screw motion around and along the z axis.
+        let start = DualQuaternion::identity();
+        let end = DualQuaternion::from(RigidTransform3D::new(
+            Rotation3D::around_z(Angle::degrees(90.0)),
+            vec3(0.0, 0.0, 4.0),
+        ));
+        let quarter = start.sclerp(&end, 0.5);
+        assert!(quarter
+            .transform_point3d(point3(1.0, 0.0, 0.0))
+            .approx_eq(&point3(0.5f64.sqrt(), 0.5f64.sqrt(), 2.0)));
+
+        // Pure translations are interpolated linearly.
+        let end = DualQuaternion::from(RigidTransform3D::from_translation(vec3(2.0, 4.0, 6.0)));
+        assert!(start
+            .sclerp(&end, 0.25)
+            .translation()
+            .approx_eq(&vec3(0.5, 1.0, 1.5)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_tokens, Token};
+
+        let quaternion = |name: &'static str, [i, j, k, r]: [f32; 4]| {
+            vec![
+                Token::Str(name),
+                Token::Struct {
+                    name: "Rotation3D",
+                    len: 5,
+                },
+                Token::Str("i"),
+                Token::F32(i),
+                Token::Str("j"),
+                Token::F32(j),
+                Token::Str("k"),
+                Token::F32(k),
+                Token::Str("r"),
+                Token::F32(r),
+                Token::Str("_unit"),
+                Token::UnitStruct {
+                    name: "PhantomData",
+                },
+                Token::StructEnd,
+            ]
+        };
+        let dq: DualQuaternion<f32> = DualQuaternion::new(
+            Rotation3D::quaternion(0.0, 0.0, 0.0, 1.0),
+            Rotation3D::quaternion(0.5, 1.0, 1.5, 0.0),
+        );
+        let mut tokens = vec![Token::Struct {
+            name: "DualQuaternion",
+            len: 2,
+        }];
+        tokens.extend(quaternion("real", [0.0, 0.0, 0.0, 1.0]));
+        tokens.extend(quaternion("dual", [0.5, 1.0, 1.5, 0.0]));
+        tokens.push(Token::StructEnd);
+        assert_tokens(&dq, &tokens);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The error type of the `*_checked` methods.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use crate::cast::CastError;
+use core::fmt;
+
+/// Why a fallible operation failed.
+///
+/// This is returned by the `*_checked` variants of the methods that otherwise return
+/// an `Option`, such as [`Transform3D::inverse_checked`](crate::Transform3D::inverse_checked),
+/// for code that needs to report why geometry it was given can't be used.
+///
+/// ```
+/// use euclid::default::{Transform2D, Vector2D};
+/// use euclid::{vec2, Error};
+///
+/// fn unit_direction(t: &Transform2D<f32>, v: Vector2D<f32>) -> Result<Vector2D<f32>, Error<f32>> {
+///     let inverse = t.inverse_checked()?;
+///     inverse.transform_vector(v).normalize_checked()
+/// }
+///
+/// let t = Transform2D::scale(2.0, 0.0);
+/// assert_eq!(
+///     unit_direction(&t, vec2(1.0, 0.0)),
+///     Err(Error::NonInvertible { determinant: 0.0 })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error<T> {
+    /// The transform has no inverse.
+    NonInvertible {
+        /// The determinant of the transform, which is zero.
+        determinant: T,
+    },
+    /// A component could not be cast to another numeric type.
+    Cast(CastError),
+    /// The input is degenerate, for example a vector of length zero that can't
+    /// be normalized.
+    DegenerateInput,
+}
+
+impl<T> From<CastError> for Error<T> {
+    fn from(err: CastError) -> Self {
+        Error::Cast(err)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for Error<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NonInvertible { determinant } => write!(
+                f,
+                "transform is not invertible (determinant is {:?})",
+                determinant
+            ),
+            Error::Cast(err) => err.fmt(f),
+            Error::DegenerateInput => write!(f, "degenerate input"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for Error<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cast(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default::{Point2D, Transform3D, Vector3D};
+    use crate::point2;
+
+    fn to_int(p: Point2D<f32>, t: &Transform3D<f32>) -> Result<Point2D<i32>, Error<f32>> {
+        let p = t.inverse_checked()?.transform_point2d(p);
+        Ok(p.ok_or(Error::DegenerateInput)?.cast_checked()?)
+    }
+
+    #[test]
+    fn test_error() {
+        let t = Transform3D::scale(2.0, 2.0, 1.0);
+        assert_eq!(to_int(point2(4.0, 6.0), &t), Ok(point2(2, 3)));
+        let err = to_int(point2(1.0e12, 0.0), &t).unwrap_err();
+        assert!(matches!(err, Error::Cast(CastError { component: "x", .. })));
+        assert_eq!(
+            to_int(point2(0.0, 0.0), &Transform3D::scale(1.0, 0.0, 1.0)),
+            Err(Error::NonInvertible { determinant: 0.0 })
+        );
+        assert_eq!(
+            Vector3D::<f32>::zero().normalize_checked(),
+            Err(Error::DegenerateInput)
+        );
+        assert_eq!(
+            format!("{}", Error::NonInvertible { determinant: 0.0 }),
+            "transform is not invertible (determinant is 0.0)"
+        );
+    }
+}
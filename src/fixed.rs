@@ -0,0 +1,640 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A fixed-point scalar type, for targets without a floating point unit.
+
+use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
+use crate::num::{Ceil, Floor, Midpoint, Pi, Round};
+use crate::trig::Trig;
+
+use num_traits::float::FloatCore;
+use num_traits::{NumCast, ToPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// A signed fixed-point number, stored as an integer `I` with `FRAC` fractional bits.
+///
+/// The value is `bits / 2^FRAC`, so for example `Fixed<i32, 16>` is the common 16.16
+/// format, with a range of ±32768 and a precision of `1 / 65536`. It is implemented for
+/// `i16`, `i32` and `i64` storage.
+///
+/// The arithmetic and the trigonometry only use integer operations, so that points,
+/// rectangles and transforms can be used on targets without a floating point unit.
+/// Like the integer types, the operations panic on overflow in debug builds.
+///
+/// ```
+/// use euclid::default::{Point2D, Transform2D};
+/// use euclid::{point2, Angle, Fixed};
+///
+/// type F = Fixed<i32, 16>;
+///
+/// let t: Transform2D<F> = Transform2D::rotation(Angle::degrees(F::from_int(90)))
+///     .then_translate(euclid::vec2(F::from_int(10), F::from_int(0)));
+/// let p: Point2D<F> = t.transform_point(point2(F::from_int(1), F::from_int(0)));
+/// assert_eq!(p.round(), point2(F::from_int(10), F::from_int(1)));
+/// assert!((p.y.to_f32() - 1.0).abs() < 0.001);
+/// ```
+///
+/// The trigonometric functions are computed with 30 fractional bits and have an error of
+/// a few units of the last place with up to 30 fractional bits. `fast_atan2` is an
+/// approximation, with an error of up to `2e-4` radians as for the floating point types.
+///
+/// `FRAC` must be smaller than the number of bits of `I` minus one, which is checked at
+/// compile time:
+///
+/// ```compile_fail
+/// let _ = euclid::Fixed::<i16, 15>::from_int(1);
+/// ```
+///
+/// ```compile_fail
+/// let _ = euclid::Fixed::<i16, 15>::default();
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+/// The number of fractional bits of the trigonometry.
+const Q: u32 = 30;
+
+/// π with 61 fractional bits, from which the constants of the other precisions are
+/// derived.
+const PI_Q61: i128 = 7_244_019_458_077_122_842;
+
+/// Shifts right, rounding to the nearest value.
+#[inline]
+fn round_shr(v: i128, shift: u32) -> i128 {
+    if shift == 0 {
+        v
+    } else {
+        (v + (1 << (shift - 1))) >> shift
+    }
+}
+
+/// Converts between `from` and `to` fractional bits.
+#[inline]
+fn rescale(v: i128, from: u32, to: u32) -> i128 {
+    if from >= to {
+        round_shr(v, from - to)
+    } else {
+        v << (to - from)
+    }
+}
+
+#[inline]
+fn mul_q(a: i128, b: i128) -> i128 {
+    (a * b) >> Q
+}
+
+#[inline]
+fn div_q(a: i128, b: i128) -> i128 {
+    (a << Q) / b
+}
+
+fn sin_q(x: i128) -> i128 {
+    let pi = rescale(PI_Q61, 61, Q);
+    let half_pi = pi / 2;
+
+    // Reduce to [-π/2, π/2], where the series converges quickly.
+    let mut x = x.rem_euclid(2 * pi);
+    if x > pi {
+        x -= 2 * pi;
+    }
+    if x > half_pi {
+        x = pi - x;
+    } else if x < -half_pi {
+        x = -pi - x;
+    }
+
+    // Taylor series up to x^15, evaluated from the last term.
+    let one = 1 << Q;
+    let x2 = mul_q(x, x);
+    let mut t = one;
+    for k in [14, 12, 10, 8, 6, 4, 2] {
+        t = one - mul_q(x2, t) / (k * (k + 1));
+    }
+    mul_q(x, t)
+}
+
+#[inline]
+fn cos_q(x: i128) -> i128 {
+    sin_q(x + rescale(PI_Q61, 61, Q) / 2)
+}
+
+/// The same approximation as the floating point `fast_atan2`.
+fn atan2_q(y: i128, x: i128) -> i128 {
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    if x_abs == 0 && y_abs == 0 {
+        return 0;
+    }
+
+    let pi = rescale(PI_Q61, 61, Q);
+    let a = div_q(x_abs.min(y_abs), x_abs.max(y_abs));
+    let s = mul_q(a, a);
+    let c0 = -49_925_210;
+    let c1 = 171_062_341;
+    let c2 = -351_782_264;
+    let mut result = mul_q(mul_q(mul_q(mul_q(c0, s) + c1, s) + c2, s), a) + a;
+    if y_abs > x_abs {
+        result = pi / 2 - result;
+    }
+    if x < 0 {
+        result = pi - result;
+    }
+    if y < 0 {
+        result = -result;
+    }
+
+    result
+}
+
+macro_rules! fixed {
+    ($int:ident, $wide:ident) => {
+        impl<const FRAC: u32> Fixed<$int, FRAC> {
+            /// The smallest positive value.
+            pub const EPSILON: Self = Self::from_bits(1);
+            /// The smallest value.
+            pub const MIN: Self = Self::from_bits($int::MIN);
+            /// The largest value.
+            pub const MAX: Self = Self::from_bits($int::MAX);
+
+            /// Fails to compile when `FRAC` leaves no room for the sign and the integer
+            /// part, by indexing past the end of a one-element array.
+            const CHECK_FRAC: () = [()][(FRAC >= $int::BITS - 1) as usize];
+
+            /// Creates a value from its underlying integer, `self * 2^FRAC`.
+            #[inline]
+            pub const fn from_bits(bits: $int) -> Self {
+                let () = Self::CHECK_FRAC;
+                Fixed(bits)
+            }
+
+            /// Returns the underlying integer, `self * 2^FRAC`.
+            #[inline]
+            pub const fn to_bits(self) -> $int {
+                self.0
+            }
+
+            /// Creates a value from an integer.
+            #[inline]
+            pub const fn from_int(v: $int) -> Self {
+                let () = Self::CHECK_FRAC;
+                Fixed(v << FRAC)
+            }
+
+            /// Returns the integer part, rounded towards zero.
+            #[inline]
+            pub const fn to_int(self) -> $int {
+                self.0 / (1 << FRAC)
+            }
+
+            /// Creates a value from a floating point number, rounded to the nearest
+            /// representable value.
+            #[inline]
+            pub fn from_f32(v: f32) -> Self {
+                Self::from_f64(v as f64)
+            }
+
+            /// Creates a value from a floating point number, rounded to the nearest
+            /// representable value.
+            ///
+            /// Panics in debug builds if the value is out of range, like the integer
+            /// operations do, and saturates in release builds.
+            #[inline]
+            pub fn from_f64(v: f64) -> Self {
+                let () = Self::CHECK_FRAC;
+                let bits = FloatCore::round(v * Self::scale());
+                debug_assert!(
+                    bits >= $int::MIN as f64 && bits <= $int::MAX as f64,
+                    "attempt to convert an out of range value to fixed-point"
+                );
+                Fixed(bits as $int)
+            }
+
+            /// Converts to a floating point number.
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            /// Converts to a floating point number.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::scale()
+            }
+
+            /// Returns the absolute value.
+            #[inline]
+            pub fn abs(self) -> Self {
+                Fixed(self.0.abs())
+            }
+
+            #[inline]
+            fn scale() -> f64 {
+                (1u64 << FRAC) as f64
+            }
+
+            #[inline]
+            fn to_q(self) -> i128 {
+                rescale(self.0 as i128, FRAC, Q)
+            }
+
+            #[inline]
+            fn from_q(v: i128) -> Self {
+                Fixed(rescale(v, Q, FRAC) as $int)
+            }
+
+            #[inline]
+            fn from_pi_q61(v: i128) -> Self {
+                Fixed(rescale(v, 61, FRAC) as $int)
+            }
+
+            /// Narrows the result of a multiplication or a division, panicking in debug
+            /// builds if it overflows like the integer operations do.
+            #[inline]
+            fn from_wide(v: $wide) -> Self {
+                debug_assert!(
+                    <$int>::try_from(v).is_ok(),
+                    "attempt to compute a fixed-point value with overflow"
+                );
+                Fixed(v as $int)
+            }
+        }
+
+        impl<const FRAC: u32> Add for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Fixed(self.0 + other.0)
+            }
+        }
+
+        impl<const FRAC: u32> Sub for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Fixed(self.0 - other.0)
+            }
+        }
+
+        impl<const FRAC: u32> Mul for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                Self::from_wide((self.0 as $wide * other.0 as $wide) >> FRAC)
+            }
+        }
+
+        impl<const FRAC: u32> Div for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                Self::from_wide(((self.0 as $wide) << FRAC) / other.0 as $wide)
+            }
+        }
+
+        impl<const FRAC: u32> Rem for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                Fixed(self.0 % other.0)
+            }
+        }
+
+        impl<const FRAC: u32> Neg for Fixed<$int, FRAC> {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Fixed(-self.0)
+            }
+        }
+
+        impl<const FRAC: u32> AddAssign for Fixed<$int, FRAC> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<const FRAC: u32> SubAssign for Fixed<$int, FRAC> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<const FRAC: u32> MulAssign for Fixed<$int, FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<const FRAC: u32> DivAssign for Fixed<$int, FRAC> {
+            #[inline]
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl<const FRAC: u32> num_traits::Zero for Fixed<$int, FRAC> {
+            #[inline]
+            fn zero() -> Self {
+                let () = Self::CHECK_FRAC;
+                Fixed(0)
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl<const FRAC: u32> Default for Fixed<$int, FRAC> {
+            #[inline]
+            fn default() -> Self {
+                let () = Self::CHECK_FRAC;
+                Fixed(0)
+            }
+        }
+
+        impl<const FRAC: u32> num_traits::One for Fixed<$int, FRAC> {
+            #[inline]
+            fn one() -> Self {
+                let () = Self::CHECK_FRAC;
+                Fixed(1 << FRAC)
+            }
+        }
+
+        impl<const FRAC: u32> Floor for Fixed<$int, FRAC> {
+            #[inline]
+            fn floor(self) -> Self {
+                Fixed(self.0 & !((1 << FRAC) - 1))
+            }
+        }
+
+        impl<const FRAC: u32> Ceil for Fixed<$int, FRAC> {
+            #[inline]
+            fn ceil(self) -> Self {
+                Fixed(self.0 + ((1 << FRAC) - 1)).floor()
+            }
+        }
+
+        impl<const FRAC: u32> Round for Fixed<$int, FRAC> {
+            #[inline]
+            fn round(self) -> Self {
+                Fixed(self.0 + (1 << FRAC >> 1)).floor()
+            }
+        }
+
+        impl<const FRAC: u32> Pi for Fixed<$int, FRAC> {
+            #[inline]
+            fn pi() -> Self {
+                Self::from_pi_q61(PI_Q61)
+            }
+            #[inline]
+            fn frac_pi_2() -> Self {
+                Self::from_pi_q61(PI_Q61 / 2)
+            }
+            #[inline]
+            fn frac_pi_3() -> Self {
+                Self::from_pi_q61(PI_Q61 / 3)
+            }
+            #[inline]
+            fn frac_pi_4() -> Self {
+                Self::from_pi_q61(PI_Q61 / 4)
+            }
+        }
+
+        impl<const FRAC: u32> Trig for Fixed<$int, FRAC> {
+            #[inline]
+            fn sin(self) -> Self {
+                Self::from_q(sin_q(self.to_q()))
+            }
+            #[inline]
+            fn cos(self) -> Self {
+                Self::from_q(cos_q(self.to_q()))
+            }
+            /// Saturates to the largest or the smallest value where the tangent is out
+            /// of range, depending on its sign.
+            fn tan(self) -> Self {
+                let x = self.to_q();
+                let (s, c) = (sin_q(x), cos_q(x));
+                if c == 0 {
+                    return if s > 0 { Self::MAX } else { Self::MIN };
+                }
+                let t = rescale(div_q(s, c), Q, FRAC);
+                Fixed(t.clamp($int::MIN as i128, $int::MAX as i128) as $int)
+            }
+            #[inline]
+            fn fast_atan2(y: Self, x: Self) -> Self {
+                Self::from_q(atan2_q(y.to_q(), x.to_q()))
+            }
+            #[inline]
+            fn degrees_to_radians(deg: Self) -> Self {
+                Fixed((deg.0 as i128 * (PI_Q61 >> 21) / (180 << 40)) as $int)
+            }
+            #[inline]
+            fn radians_to_degrees(rad: Self) -> Self {
+                Fixed((rad.0 as i128 * (180 << 40) / (PI_Q61 >> 21)) as $int)
+            }
+        }
+
+        impl<const FRAC: u32> ApproxEq<Fixed<$int, FRAC>> for Fixed<$int, FRAC> {
+            /// About a millionth, or sixteen steps for the formats that are less precise.
+            #[inline]
+            fn approx_epsilon() -> Self {
+                Fixed(if FRAC > 24 { 1 << (FRAC - 20) } else { 16 })
+            }
+            #[inline]
+            fn approx_eq_eps(&self, other: &Self, approx_epsilon: &Self) -> bool {
+                (self.0 as $wide - other.0 as $wide).abs() < approx_epsilon.0 as $wide
+            }
+        }
+
+        impl<const FRAC: u32> ToPrimitive for Fixed<$int, FRAC> {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.to_int().to_i64()
+            }
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.to_int().to_u64()
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0 as f64 / Self::scale())
+            }
+        }
+
+        impl<const FRAC: u32> NumCast for Fixed<$int, FRAC> {
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                let () = Self::CHECK_FRAC;
+                let v = n.to_f64()? * Self::scale();
+                let bits = FloatCore::round(v);
+                if bits >= $int::MIN as f64 && bits <= $int::MAX as f64 {
+                    Some(Fixed(bits as $int))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<const FRAC: u32> SimdScalar for Fixed<$int, FRAC> {}
+
+        impl<const FRAC: u32> Midpoint for Fixed<$int, FRAC> {
+            #[inline]
+            fn midpoint(self, other: Self) -> Self {
+                Fixed(Midpoint::midpoint(self.0, other.0))
+            }
+        }
+
+        impl<const FRAC: u32> fmt::Debug for Fixed<$int, FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&Self::to_f64(*self), f)
+            }
+        }
+
+        impl<const FRAC: u32> fmt::Display for Fixed<$int, FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&Self::to_f64(*self), f)
+            }
+        }
+    };
+}
+
+fixed!(i16, i32);
+fixed!(i32, i64);
+fixed!(i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default::{Point2D, Rect, Transform2D};
+    use crate::{point2, rect, vec2, Angle};
+
+    type F16 = Fixed<i32, 16>;
+    type F32 = Fixed<i64, 32>;
+
+    fn fx(v: f64) -> F16 {
+        F16::from_f64(v)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(fx(1.5) + fx(2.25), fx(3.75));
+        assert_eq!(fx(1.5) - fx(2.25), fx(-0.75));
+        assert_eq!(fx(1.5) * fx(-2.5), fx(-3.75));
+        assert_eq!(fx(3.0) / fx(-4.0), fx(-0.75));
+        assert_eq!(fx(7.5) % fx(2.0), fx(1.5));
+        assert_eq!(F16::from_int(3).to_bits(), 3 << 16);
+        assert_eq!(fx(-2.75).to_int(), -2);
+        assert_eq!(fx(-2.75).to_f32(), -2.75);
+        type F8 = Fixed<i16, 8>;
+        assert_eq!(F8::from_f32(1.5) * F8::from_int(3), F8::from_f32(4.5));
+
+        assert_eq!(fx(-2.5).floor(), fx(-3.0));
+        assert_eq!(fx(-2.5).ceil(), fx(-2.0));
+        assert_eq!(fx(-2.5).round(), fx(-2.0));
+        assert_eq!(fx(2.5).round(), fx(3.0));
+        assert_eq!(fx(2.25).round(), fx(2.0));
+
+        assert_eq!(<F16 as NumCast>::from(2.5f32), Some(fx(2.5)));
+        assert_eq!(<F16 as NumCast>::from(1.0e6f64), None);
+        assert_eq!(fx(2.5).to_i32(), Some(2));
+        assert_eq!(F16::default(), F16::from_int(0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn test_mul_overflow() {
+        let _ = F16::from_int(300) * F16::from_int(300);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "out of range")]
+    fn test_from_f64_overflow() {
+        let _ = fx(1.0e6);
+    }
+
+    #[test]
+    fn test_trig() {
+        for i in -40..=40 {
+            let x = i as f64 * 0.3;
+            let e16 = 4.0 / 65536.0;
+            assert!((fx(x).sin().to_f64() - x.sin()).abs() < e16, "sin({})", x);
+            assert!((fx(x).cos().to_f64() - x.cos()).abs() < e16, "cos({})", x);
+
+            let e32 = 1.0e-8;
+            let f = F32::from_f64(x);
+            assert!(
+                (f.sin().to_f64() - f.to_f64().sin()).abs() < e32,
+                "sin({})",
+                x
+            );
+            assert!(
+                (f.cos().to_f64() - f.to_f64().cos()).abs() < e32,
+                "cos({})",
+                x
+            );
+
+            let y = 2.0 - i as f64 * 0.1;
+            let a = F32::fast_atan2(F32::from_f64(y), F32::from_f64(x));
+            assert!(
+                (a.to_f64() - f64::fast_atan2(y, x)).abs() < 1.0e-8,
+                "atan2({}, {})",
+                y,
+                x
+            );
+        }
+        assert!((fx(0.5).tan().to_f64() - 0.5f64.tan()).abs() < 1.0e-4);
+        // Within 2e-5 of ±π/2, the tangent is out of range and saturates with its sign.
+        assert_eq!(F16::from_bits(102_943).tan(), F16::MAX);
+        assert_eq!(F16::from_bits(-102_943).tan(), F16::MIN);
+        assert!(F16::from_bits(-102_900).tan() < fx(-1000.0));
+        assert_eq!(F16::pi(), fx(core::f64::consts::PI));
+        assert_eq!(
+            F32::frac_pi_3(),
+            F32::from_f64(core::f64::consts::FRAC_PI_3)
+        );
+        assert_eq!(F16::degrees_to_radians(fx(180.0)), F16::pi());
+        // π/2 is rounded to 16 fractional bits, which is about 0.0004 degrees.
+        let right_angle = F16::radians_to_degrees(F16::frac_pi_2());
+        assert!(right_angle.approx_eq_eps(&fx(90.0), &fx(0.001)));
+        assert_eq!(
+            F32::radians_to_degrees(F32::frac_pi_2()).round(),
+            F32::from_int(90)
+        );
+    }
+
+    #[test]
+    fn test_geometry() {
+        let r: Rect<F16> = rect(fx(0.5), fx(0.25), fx(1.0), fx(1.5));
+        assert_eq!(r.center(), point2(fx(1.0), fx(1.0)));
+        assert_eq!(r.round_out(), rect(fx(0.0), fx(0.0), fx(2.0), fx(2.0)));
+
+        let rotation: Transform2D<F16> = Transform2D::rotation(Angle::degrees(fx(30.0)));
+        let p: Point2D<F16> = point2(fx(2.0), fx(0.0));
+        let q = rotation.transform_point(p);
+        assert!(q.approx_eq(&point2(fx(3.0f64.sqrt()), fx(1.0))));
+
+        let t: Transform2D<F16> = Transform2D::rotation(Angle::degrees(fx(45.0)))
+            .then_scale(fx(2.0), fx(0.5))
+            .then_translate(vec2(fx(3.0), fx(-1.0)));
+        let back = t.inverse().unwrap().transform_point(t.transform_point(p));
+        assert!(back.approx_eq(&p));
+        assert_eq!(p.cast::<f32>(), point2(2.0, 0.0));
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::batch::SimdScalar;
+use crate::num::{One, Zero};
+use crate::point::{point2, point3, Point2D, Point3D};
+use crate::transform3d::Transform3D;
+
+use core::fmt;
+use core::ops::{Add, BitAnd, BitOr, Div, Mul, Neg, Sub};
+
+/// A classification of a `Transform3D`, as returned by [`Transform3D::flags`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransformFlags(u8);
+
+impl TransformFlags {
+    /// The transform is the identity.
+    pub const IDENTITY: Self = TransformFlags(1);
+    /// The transform only translates, possibly along the z axis.
+    pub const TRANSLATION_ONLY: Self = TransformFlags(2);
+    /// The transform can be represented with a `Transform2D`.
+    pub const AFFINE_2D: Self = TransformFlags(4);
+    /// The last column of the transform is not `(0, 0, 0, 1)`.
+    pub const HAS_PERSPECTIVE: Self = TransformFlags(8);
+
+    /// Returns flags with no bit set, which is the case of general affine 3d transforms.
+    #[inline]
+    pub const fn empty() -> Self {
+        TransformFlags(0)
+    }
+
+    /// Returns the raw bits.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if all of the bits of `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no bit is set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for TransformFlags {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        TransformFlags(self.0 | other.0)
+    }
+}
+
+impl BitAnd for TransformFlags {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        TransformFlags(self.0 & other.0)
+    }
+}
+
+/// A `Transform3D` along with its cached [`TransformFlags`].
+///
+/// Composition, point transformation and inversion skip the full 4x4 math when
+/// the transform is the identity or a translation, which is the common case in
+/// scenes where most items are only offset from their parent.
+///
+/// ```
+/// use euclid::default::{FlaggedTransform3D, Transform3D};
+/// use euclid::{point2, TransformFlags};
+///
+/// let a = FlaggedTransform3D::new(Transform3D::translation(1.0, 2.0, 0.0));
+/// let b = FlaggedTransform3D::new(Transform3D::translation(3.0, 0.0, 0.0));
+/// let c = a.then(&b);
+/// assert!(c.flags().contains(TransformFlags::TRANSLATION_ONLY));
+/// assert_eq!(c.transform_point2d(point2(0.0, 0.0)), Some(point2(4.0, 2.0)));
+/// ```
+pub struct FlaggedTransform3D<T, Src, Dst> {
+    transform: Transform3D<T, Src, Dst>,
+    flags: TransformFlags,
+}
+
+impl<T: Zero + One + PartialEq, Src, Dst> FlaggedTransform3D<T, Src, Dst> {
+    /// Wraps a transform, computing its flags.
+    #[inline]
+    pub fn new(transform: Transform3D<T, Src, Dst>) -> Self {
+        let flags = transform.flags();
+        FlaggedTransform3D { transform, flags }
+    }
+
+    /// Creates an identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        FlaggedTransform3D {
+            transform: Transform3D::identity(),
+            flags: TransformFlags::IDENTITY
+                | TransformFlags::TRANSLATION_ONLY
+                | TransformFlags::AFFINE_2D,
+        }
+    }
+}
+
+impl<T, Src, Dst> FlaggedTransform3D<T, Src, Dst> {
+    /// Returns the wrapped transform.
+    #[inline]
+    pub fn transform(&self) -> &Transform3D<T, Src, Dst> {
+        &self.transform
+    }
+
+    /// Unwraps the transform.
+    #[inline]
+    pub fn into_inner(self) -> Transform3D<T, Src, Dst> {
+        self.transform
+    }
+
+    /// Returns the cached flags.
+    #[inline]
+    pub fn flags(&self) -> TransformFlags {
+        self.flags
+    }
+
+    #[inline]
+    fn is(&self, flags: TransformFlags) -> bool {
+        self.flags.contains(flags)
+    }
+}
+
+impl<T, Src, Dst> FlaggedTransform3D<T, Src, Dst>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One + PartialEq + SimdScalar,
+{
+    /// Returns a transform with a transformation applied after self's transformation.
+    ///
+    /// See [`Transform3D::then`].
+    pub fn then<NewDst>(
+        &self,
+        other: &FlaggedTransform3D<T, Dst, NewDst>,
+    ) -> FlaggedTransform3D<T, Src, NewDst> {
+        if self.is(TransformFlags::IDENTITY) {
+            return FlaggedTransform3D {
+                transform: other.transform.with_source(),
+                flags: other.flags,
+            };
+        }
+        if other.is(TransformFlags::IDENTITY) {
+            return FlaggedTransform3D {
+                transform: self.transform.with_destination(),
+                flags: self.flags,
+            };
+        }
+        if self.is(TransformFlags::TRANSLATION_ONLY) && other.is(TransformFlags::TRANSLATION_ONLY) {
+            let (a, b) = (&self.transform, &other.transform);
+            return FlaggedTransform3D::new(Transform3D::translation(
+                a.m41 + b.m41,
+                a.m42 + b.m42,
+                a.m43 + b.m43,
+            ));
+        }
+        FlaggedTransform3D::new(self.transform.then(&other.transform))
+    }
+
+    /// Returns the given 2d point transformed by this transform, if the transform makes sense,
+    /// or `None` otherwise.
+    ///
+    /// See [`Transform3D::transform_point2d`].
+    #[inline]
+    pub fn transform_point2d(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>>
+    where
+        T: Div<Output = T> + PartialOrd,
+    {
+        let m = &self.transform;
+        if self.is(TransformFlags::IDENTITY) {
+            return Some(p.cast_unit());
+        }
+        if self.is(TransformFlags::TRANSLATION_ONLY) {
+            return Some(point2(p.x + m.m41, p.y + m.m42));
+        }
+        if self.is(TransformFlags::AFFINE_2D) {
+            return Some(point2(
+                p.x * m.m11 + p.y * m.m21 + m.m41,
+                p.x * m.m12 + p.y * m.m22 + m.m42,
+            ));
+        }
+        m.transform_point2d(p)
+    }
+
+    /// Returns the given 3d point transformed by this transform, if the transform makes sense,
+    /// or `None` otherwise.
+    ///
+    /// See [`Transform3D::transform_point3d`].
+    #[inline]
+    pub fn transform_point3d(&self, p: Point3D<T, Src>) -> Option<Point3D<T, Dst>>
+    where
+        T: Div<Output = T> + PartialOrd,
+    {
+        let m = &self.transform;
+        if self.is(TransformFlags::IDENTITY) {
+            return Some(p.cast_unit());
+        }
+        if self.is(TransformFlags::TRANSLATION_ONLY) {
+            return Some(point3(p.x + m.m41, p.y + m.m42, p.z + m.m43));
+        }
+        m.transform_point3d(p)
+    }
+}
+
+impl<T, Src, Dst> FlaggedTransform3D<T, Src, Dst>
+where
+    T: Copy
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>
+        + Div<T, Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + One
+        + Zero,
+{
+    /// Returns the inverse transform if possible.
+    ///
+    /// See [`Transform3D::inverse`].
+    pub fn inverse(&self) -> Option<FlaggedTransform3D<T, Dst, Src>> {
+        let m = &self.transform;
+        if self.is(TransformFlags::IDENTITY) {
+            return Some(FlaggedTransform3D {
+                transform: m.with_source().with_destination(),
+                flags: self.flags,
+            });
+        }
+        if self.is(TransformFlags::TRANSLATION_ONLY) {
+            return Some(FlaggedTransform3D {
+                transform: Transform3D::translation(-m.m41, -m.m42, -m.m43),
+                flags: self.flags,
+            });
+        }
+        if self.is(TransformFlags::AFFINE_2D) {
+            return m
+                .to_2d()
+                .inverse()
+                .map(|inv| FlaggedTransform3D::new(inv.to_3d()));
+        }
+        m.inverse().map(FlaggedTransform3D::new)
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for FlaggedTransform3D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for FlaggedTransform3D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        FlaggedTransform3D {
+            transform: self.transform.clone(),
+            flags: self.flags,
+        }
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for FlaggedTransform3D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.transform == other.transform
+    }
+}
+
+impl<T: Zero + One + PartialEq, Src, Dst> Default for FlaggedTransform3D<T, Src, Dst> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T, Src, Dst> fmt::Debug for FlaggedTransform3D<T, Src, Dst>
+where
+    T: Copy + fmt::Debug + PartialEq + One + Zero,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlaggedTransform3D")
+            .field("transform", &self.transform)
+            .field("flags", &self.flags)
+            .finish()
+    }
+}
+
+impl<T: Zero + One + PartialEq, Src, Dst> From<Transform3D<T, Src, Dst>>
+    for FlaggedTransform3D<T, Src, Dst>
+{
+    fn from(transform: Transform3D<T, Src, Dst>) -> Self {
+        FlaggedTransform3D::new(transform)
+    }
+}
+
+impl<T, Src, Dst> From<FlaggedTransform3D<T, Src, Dst>> for Transform3D<T, Src, Dst> {
+    fn from(t: FlaggedTransform3D<T, Src, Dst>) -> Self {
+        t.transform
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{vec3, Angle};
+
+    type Mf = default::Transform3D<f32>;
+    type Ff = default::FlaggedTransform3D<f32>;
+
+    fn transforms() -> [Mf; 6] {
+        [
+            Mf::identity(),
+            Mf::translation(1.0, -2.0, 0.0),
+            Mf::translation(1.0, -2.0, 3.5),
+            Mf::scale(2.0, 3.0, 1.0).then_translate(vec3(1.0, 1.0, 0.0)),
+            Mf::rotation(0.5, 1.0, 2.0, Angle::radians(0.7)),
+            Mf::perspective(5.0).then_translate(vec3(0.25, 0.5, -1.0)),
+        ]
+    }
+
+    #[test]
+    fn test_flags() {
+        let [identity, translation_2d, translation_3d, affine_2d, affine_3d, perspective] =
+            transforms();
+        assert_eq!(
+            identity.flags(),
+            TransformFlags::IDENTITY | TransformFlags::TRANSLATION_ONLY | TransformFlags::AFFINE_2D
+        );
+        assert_eq!(
+            translation_2d.flags(),
+            TransformFlags::TRANSLATION_ONLY | TransformFlags::AFFINE_2D
+        );
+        assert_eq!(translation_3d.flags(), TransformFlags::TRANSLATION_ONLY);
+        assert_eq!(affine_2d.flags(), TransformFlags::AFFINE_2D);
+        assert!(affine_3d.flags().is_empty());
+        assert_eq!(perspective.flags(), TransformFlags::HAS_PERSPECTIVE);
+        assert_eq!(Ff::identity().flags(), identity.flags());
+    }
+
+    #[test]
+    fn test_shortcuts_match_full_math() {
+        let p2 = point2(3.0, -4.0);
+        let p3 = point3(3.0, -4.0, 0.5);
+        for a in &transforms() {
+            let fa = Ff::new(*a);
+            assert_eq!(fa.transform_point2d(p2), a.transform_point2d(p2));
+            assert_eq!(fa.transform_point3d(p3), a.transform_point3d(p3));
+            match (fa.inverse(), a.inverse()) {
+                (Some(fi), Some(i)) => assert!(fi.transform().approx_eq(&i)),
+                (None, None) => {}
+                _ => panic!("{:?}", a),
+            }
+            for b in &transforms() {
+                let c = fa.then(&Ff::new(*b));
+                assert_eq!(*c.transform(), a.then(b));
+                assert_eq!(c.flags(), a.then(b).flags());
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box3d::Box3D;
+use crate::circle::Sphere;
+use crate::num::*;
+use crate::plane::{Plane, PlaneSide};
+use crate::point::Point3D;
+use crate::transform3d::{DepthRange, Transform3D};
+use crate::vector::vec3;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// The volume of space visible through a projection, bounded by six planes.
+///
+/// The planes are in the order left, right, bottom, top, near, far, and face the inside
+/// of the frustum, so that a point is inside when it is in front of all of them.
+///
+/// ```
+/// use euclid::default::{Box3D, Frustum, Transform3D};
+/// use euclid::{point3, DepthRange};
+///
+/// let depth = DepthRange::ZeroToOne;
+/// let projection = Transform3D::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0, depth);
+/// let frustum = Frustum::from_transform(&projection, depth).unwrap();
+///
+/// assert!(frustum.contains_point(point3(0.0, 0.0, -5.0)));
+/// assert!(!frustum.contains_point(point3(0.0, 0.0, 5.0)));
+/// assert!(frustum.intersects_box3d(&Box3D::new(point3(-1.0, -1.0, -3.0), point3(1.0, 1.0, -2.0))));
+/// assert!(!frustum.intersects_box3d(&Box3D::new(point3(4.0, -1.0, -3.0), point3(5.0, 1.0, -2.0))));
+/// ```
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Frustum<T, U> {
+    pub planes: [Plane<T, U>; 6],
+}
+
+impl<T: Hash, U> Hash for Frustum<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.planes.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for Frustum<T, U> {}
+
+impl<T: Clone, U> Clone for Frustum<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.planes.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Frustum<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.planes.eq(&other.planes)
+    }
+}
+
+impl<T: Eq, U> Eq for Frustum<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Frustum<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Frustum").field(&self.planes).finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for Frustum<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Frustum::new([
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+        ]))
+    }
+}
+
+impl<T, U> Frustum<T, U> {
+    /// Constructor, taking the planes in the order left, right, bottom, top, near, far.
+    #[inline]
+    pub const fn new(planes: [Plane<T, U>; 6]) -> Self {
+        Frustum { planes }
+    }
+}
+
+impl<T: Copy, U> Frustum<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Frustum<T, UnknownUnit> {
+        Frustum::new(self.planes.map(|p| p.to_untyped()))
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(f: &Frustum<T, UnknownUnit>) -> Self {
+        Frustum::new(f.planes.map(|p| Plane::from_untyped(&p)))
+    }
+}
+
+impl<T: RealScalar, U> Frustum<T, U> {
+    /// Returns the frustum of the points that a projection maps inside of the clip space
+    /// cube, or `None` if the transform is degenerate.
+    ///
+    /// `depth` is the depth range of clip space that the projection was made for. The
+    /// planes are extracted from the matrix (the method of Gribb and Hartmann), so this
+    /// works with any projection, including one combined with a view transform.
+    pub fn from_transform<Dst>(m: &Transform3D<T, U, Dst>, depth: DepthRange) -> Option<Self> {
+        // The coefficients of the clip space x, y, z and w coordinates, as functions of
+        // the x, y and z coordinates and a constant.
+        let x = [m.m11, m.m21, m.m31, m.m41];
+        let y = [m.m12, m.m22, m.m32, m.m42];
+        let z = [m.m13, m.m23, m.m33, m.m43];
+        let w = [m.m14, m.m24, m.m34, m.m44];
+
+        let add = |a: [T; 4], b: [T; 4]| [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]];
+        let sub = |a: [T; 4], b: [T; 4]| [a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]];
+        let plane = |c: [T; 4]| {
+            let normal = vec3(c[0], c[1], c[2]);
+            let length = normal.length();
+            if length == T::zero() {
+                return None;
+            }
+            Some(Plane::new(normal / length, c[3] / length))
+        };
+
+        let near = match depth {
+            DepthRange::NegativeOneToOne => add(w, z),
+            DepthRange::ZeroToOne => z,
+        };
+
+        Some(Frustum::new([
+            plane(add(w, x))?,
+            plane(sub(w, x))?,
+            plane(add(w, y))?,
+            plane(sub(w, y))?,
+            plane(near)?,
+            plane(sub(w, z))?,
+        ]))
+    }
+
+    /// Returns `true` if the point is inside of the frustum or on its boundary.
+    #[inline]
+    pub fn contains_point(&self, p: Point3D<T, U>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= T::zero())
+    }
+
+    /// Returns `true` if the box may be visible.
+    ///
+    /// This is conservative: a box that is outside of the frustum, but not entirely
+    /// behind any one of its planes, such as some boxes near the edges where two planes
+    /// meet, is also reported as intersecting.
+    #[inline]
+    pub fn intersects_box3d(&self, b: &Box3D<T, U>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.classify_box(b) != PlaneSide::Back)
+    }
+
+    /// Returns `true` if the sphere may be visible.
+    ///
+    /// This is conservative in the same way as [`intersects_box3d`](Self::intersects_box3d).
+    #[inline]
+    pub fn intersects_sphere(&self, s: &Sphere<T, U>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(s.center) >= -s.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Box3D, Frustum, Sphere, Transform3D};
+    use crate::{point3, Angle, DepthRange};
+
+    #[test]
+    fn test_perspective() {
+        for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+            let projection =
+                Transform3D::<f64>::perspective_fov(Angle::degrees(90.0), 2.0, 1.0, 100.0, depth);
+            let frustum = Frustum::from_transform(&projection, depth).unwrap();
+
+            // The frustum goes from x = ±2z and y = ±z, between z = -1 and z = -100.
+            assert!(frustum.contains_point(point3(0.0, 0.0, -1.01)));
+            assert!(frustum.contains_point(point3(3.9, -1.9, -2.0)));
+            assert!(!frustum.contains_point(point3(4.1, 0.0, -2.0)));
+            assert!(!frustum.contains_point(point3(0.0, 2.1, -2.0)));
+            assert!(!frustum.contains_point(point3(0.0, 0.0, -0.9)));
+            assert!(!frustum.contains_point(point3(0.0, 0.0, -101.0)));
+            assert!(!frustum.contains_point(point3(0.0, 0.0, 5.0)));
+
+            // The clip space of each point should agree.
+            let p = point3(3.0, 1.0, -10.0);
+            let clip = projection.transform_point3d(p).unwrap();
+            assert!(clip.x.abs() <= 1.0 && clip.y.abs() <= 1.0);
+            assert!(frustum.contains_point(p));
+
+            let b = Box3D::new(point3(-1.0, -1.0, -200.0), point3(1.0, 1.0, -99.0));
+            assert!(frustum.intersects_box3d(&b));
+            let behind = Box3D::new(point3(-1.0, -1.0, 1.0), point3(1.0, 1.0, 2.0));
+            assert!(!frustum.intersects_box3d(&behind));
+            let left = Box3D::new(point3(-30.0, -1.0, -11.0), point3(-23.0, 1.0, -10.0));
+            assert!(!frustum.intersects_box3d(&left));
+
+            assert!(frustum.intersects_sphere(&Sphere::new(point3(0.0, 0.0, 0.0), 1.5)));
+            assert!(!frustum.intersects_sphere(&Sphere::new(point3(0.0, 0.0, 0.0), 0.5)));
+            assert!(!frustum.intersects_sphere(&Sphere::new(point3(0.0, 50.0, -10.0), 10.0)));
+        }
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let depth = DepthRange::NegativeOneToOne;
+        let projection = Transform3D::orthographic(0.0, 800.0, 600.0, 0.0, 1.0, 2.0, depth);
+        let view = Transform3D::translation(0.0, 0.0, -1.5);
+        let frustum = Frustum::from_transform(&view.then(&projection), depth).unwrap();
+        assert!(frustum.contains_point(point3(400.0, 300.0, 0.0)));
+        assert!(frustum.contains_point(point3(800.0, 600.0, 0.5)));
+        assert!(!frustum.contains_point(point3(400.0, 300.0, 0.6)));
+        assert!(!frustum.contains_point(point3(-1.0, 300.0, 0.0)));
+
+        let degenerate = Transform3D::scale(1.0, 0.0, 1.0);
+        assert_eq!(Frustum::from_transform(&degenerate, depth), None);
+    }
+}
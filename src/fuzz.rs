@@ -0,0 +1,518 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Constrained [`arbitrary`] input generation.
+//!
+//! The plain `Arbitrary` implementations of euclid types produce any bit pattern,
+//! including NaN and infinite components, and boxes whose `min` is greater than
+//! their `max`. Wrapping a type in [`Finite`] instead only produces finite scalars,
+//! and boxes that are well-ordered on every axis. Wrapping it in [`NonNegative`]
+//! also makes sizes, lengths, side offsets and radii non-negative.
+//!
+//! ```ignore
+//! fuzz_target!(|input: Finite<Box2D<f32, LayoutSpace>>| {
+//!     let b = input.0;
+//!     assert!(b.min.x <= b.max.x);
+//! });
+//! ```
+
+use crate::{Angle, Box2D, Box3D, Circle, DualQuaternion, Frustum, HomogeneousVector, Length};
+use crate::{LineSegment2D, Plane, Plane2D, Point2D, Point3D, Ray2D, Ray3D, Rect};
+use crate::{RigidTransform3D, Rotation2D, Rotation3D, Scale, Scale2D, SideOffsets2D, Size2D};
+use crate::{Size3D, Sphere, Transform2D, Transform3D, Translation2D, Translation3D};
+use crate::{Triangle2D, Triangle3D, Vector2D, Vector3D};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::mem::swap;
+
+/// Generates values with finite components, and well-ordered boxes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Finite<T>(pub T);
+
+impl<'a, T: ArbitraryFinite<'a>> Arbitrary<'a> for Finite<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary_finite(u).map(Finite)
+    }
+}
+
+/// Generates values with finite components, well-ordered boxes, and non-negative
+/// sizes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonNegative<T>(pub T);
+
+impl<'a, T: ArbitraryNonNegative<'a>> Arbitrary<'a> for NonNegative<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary_non_negative(u).map(NonNegative)
+    }
+}
+
+/// Types that can be generated with only finite components.
+///
+/// This is implemented for the primitive numeric types and for all euclid
+/// types. Use it through the [`Finite`] wrapper.
+pub trait ArbitraryFinite<'a>: Sized {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self>;
+}
+
+macro_rules! arbitrary_finite_float {
+    ($($ty:ty),*) => {$(
+        impl<'a> ArbitraryFinite<'a> for $ty {
+            fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+                let v = <$ty>::arbitrary(u)?;
+                Ok(if v.is_nan() {
+                    0.0
+                } else {
+                    v.max(<$ty>::MIN).min(<$ty>::MAX)
+                })
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_finite_int {
+    ($($ty:ty),*) => {$(
+        impl<'a> ArbitraryFinite<'a> for $ty {
+            fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+                <$ty>::arbitrary(u)
+            }
+        }
+    )*};
+}
+
+arbitrary_finite_float!(f32, f64);
+arbitrary_finite_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn finite<'a, T: ArbitraryFinite<'a>>(u: &mut Unstructured<'a>) -> Result<T> {
+    T::arbitrary_finite(u)
+}
+
+/// Types that can be generated with finite and non-negative sizes.
+///
+/// This is implemented for the primitive numeric types and for the euclid types
+/// that have a size or a radius. Use it through the [`NonNegative`] wrapper.
+pub trait ArbitraryNonNegative<'a>: Sized {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self>;
+}
+
+macro_rules! arbitrary_non_negative_float {
+    ($($ty:ty),*) => {$(
+        impl<'a> ArbitraryNonNegative<'a> for $ty {
+            fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+                let v = <$ty>::arbitrary_finite(u)?;
+                Ok(if v < 0.0 { -v } else { v })
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_non_negative_int {
+    ($($ty:ty),*) => {$(
+        impl<'a> ArbitraryNonNegative<'a> for $ty {
+            fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok(<$ty>::arbitrary(u)?.saturating_abs())
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_non_negative_uint {
+    ($($ty:ty),*) => {$(
+        impl<'a> ArbitraryNonNegative<'a> for $ty {
+            fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+                <$ty>::arbitrary(u)
+            }
+        }
+    )*};
+}
+
+arbitrary_non_negative_float!(f32, f64);
+arbitrary_non_negative_int!(i8, i16, i32, i64, i128, isize);
+arbitrary_non_negative_uint!(u8, u16, u32, u64, u128, usize);
+
+fn non_negative<'a, T: ArbitraryNonNegative<'a>>(u: &mut Unstructured<'a>) -> Result<T> {
+    T::arbitrary_non_negative(u)
+}
+
+fn ordered<T: PartialOrd>(min: &mut T, max: &mut T) {
+    if *min > *max {
+        swap(min, max);
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>> ArbitraryFinite<'a> for Angle<T> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Angle::radians(finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Length<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Length::new(finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Point2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Point2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Point3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Point3D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Vector2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Vector2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Vector3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Vector3D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for HomogeneousVector<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(HomogeneousVector::new(
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Size2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Size2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Size3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Size3D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Rect<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Rect::new(finite(u)?, finite(u)?))
+    }
+}
+
+/// Boxes are generated with `min <= max` on every axis.
+impl<'a, T: ArbitraryFinite<'a> + PartialOrd, U> ArbitraryFinite<'a> for Box2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut b: Self = Box2D::new(finite(u)?, finite(u)?);
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        Ok(b)
+    }
+}
+
+/// Boxes are generated with `min <= max` on every axis.
+impl<'a, T: ArbitraryFinite<'a> + PartialOrd, U> ArbitraryFinite<'a> for Box3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut b: Self = Box3D::new(finite(u)?, finite(u)?);
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        ordered(&mut b.min.z, &mut b.max.z);
+        Ok(b)
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for SideOffsets2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SideOffsets2D::new(
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Scale<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Scale::new(finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Translation2D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Translation2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Translation3D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Translation3D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Rotation2D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Rotation2D::new(finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Rotation3D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Rotation3D::quaternion(
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for RigidTransform3D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(RigidTransform3D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Transform2D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Transform2D::new(
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Transform3D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Transform3D::new(
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for Scale2D<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Scale2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, Src, Dst> ArbitraryFinite<'a> for DualQuaternion<T, Src, Dst> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(DualQuaternion::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for LineSegment2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(LineSegment2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Triangle2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Triangle2D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Triangle3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Triangle3D::new(finite(u)?, finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Ray2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Ray2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Ray3D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Ray3D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Circle<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Circle::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Sphere<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Sphere::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Plane2D<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Plane2D::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Plane<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Plane::new(finite(u)?, finite(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryFinite<'a>, U> ArbitraryFinite<'a> for Frustum<T, U> {
+    fn arbitrary_finite(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Frustum::new([
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+            finite(u)?,
+        ]))
+    }
+}
+
+impl<'a, T: ArbitraryNonNegative<'a>, U> ArbitraryNonNegative<'a> for Length<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Length::new(non_negative(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryNonNegative<'a>, U> ArbitraryNonNegative<'a> for Size2D<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Size2D::new(non_negative(u)?, non_negative(u)?))
+    }
+}
+
+impl<'a, T: ArbitraryNonNegative<'a>, U> ArbitraryNonNegative<'a> for Size3D<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Size3D::new(
+            non_negative(u)?,
+            non_negative(u)?,
+            non_negative(u)?,
+        ))
+    }
+}
+
+impl<'a, T, U> ArbitraryNonNegative<'a> for Rect<T, U>
+where
+    T: ArbitraryFinite<'a> + ArbitraryNonNegative<'a>,
+{
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Rect::new(finite(u)?, non_negative(u)?))
+    }
+}
+
+/// Boxes are generated with `min <= max` on every axis.
+impl<'a, T: ArbitraryFinite<'a> + PartialOrd, U> ArbitraryNonNegative<'a> for Box2D<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        finite(u)
+    }
+}
+
+/// Boxes are generated with `min <= max` on every axis.
+impl<'a, T: ArbitraryFinite<'a> + PartialOrd, U> ArbitraryNonNegative<'a> for Box3D<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        finite(u)
+    }
+}
+
+impl<'a, T: ArbitraryNonNegative<'a>, U> ArbitraryNonNegative<'a> for SideOffsets2D<T, U> {
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SideOffsets2D::new(
+            non_negative(u)?,
+            non_negative(u)?,
+            non_negative(u)?,
+            non_negative(u)?,
+        ))
+    }
+}
+
+impl<'a, T, U> ArbitraryNonNegative<'a> for Circle<T, U>
+where
+    T: ArbitraryFinite<'a> + ArbitraryNonNegative<'a>,
+{
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Circle::new(finite(u)?, non_negative(u)?))
+    }
+}
+
+impl<'a, T, U> ArbitraryNonNegative<'a> for Sphere<T, U>
+where
+    T: ArbitraryFinite<'a> + ArbitraryNonNegative<'a>,
+{
+    fn arbitrary_non_negative(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Sphere::new(finite(u)?, non_negative(u)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+
+    #[test]
+    fn test_finite_floats() {
+        let mut bytes = [0u8; 64];
+        bytes[..4].copy_from_slice(&f32::NAN.to_ne_bytes());
+        bytes[4..8].copy_from_slice(&f32::INFINITY.to_ne_bytes());
+        bytes[8..12].copy_from_slice(&f32::NEG_INFINITY.to_ne_bytes());
+        let mut u = Unstructured::new(&bytes);
+        let Finite(p) = Finite::<default::Point3D<f32>>::arbitrary(&mut u).unwrap();
+        assert_eq!(p.x, 0.0);
+        assert_eq!(p.y, f32::MAX);
+        assert_eq!(p.z, f32::MIN);
+    }
+
+    #[test]
+    fn test_ordered_boxes() {
+        let bytes: [u8; 8] = [10, 0, 20, 0, 5, 0, 30, 0];
+        let mut u = Unstructured::new(&bytes);
+        let Finite(b) = Finite::<default::Box2D<i16>>::arbitrary(&mut u).unwrap();
+        assert!(b.min.x <= b.max.x);
+        assert!(b.min.y <= b.max.y);
+        assert!(!b.is_negative());
+    }
+
+    #[test]
+    fn test_non_negative() {
+        let mut bytes = [0u8; 64];
+        bytes[..4].copy_from_slice(&f32::NEG_INFINITY.to_ne_bytes());
+        bytes[4..8].copy_from_slice(&(-2.5f32).to_ne_bytes());
+        let mut u = Unstructured::new(&bytes);
+        let NonNegative(s) = NonNegative::<default::Size2D<f32>>::arbitrary(&mut u).unwrap();
+        assert_eq!(s, default::Size2D::new(f32::MAX, 2.5));
+
+        let mut bytes = [0u8; 12];
+        bytes[8..].copy_from_slice(&i32::MIN.to_ne_bytes());
+        let mut u = Unstructured::new(&bytes);
+        let NonNegative(c) = NonNegative::<default::Circle<i32>>::arbitrary(&mut u).unwrap();
+        assert_eq!(c.radius, i32::MAX);
+    }
+}
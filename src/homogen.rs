@@ -19,11 +19,13 @@ use core::fmt;
 use core::hash::Hash;
 use core::marker::PhantomData;
 use core::ops::Div;
-#[cfg(feature = "serde")]
-use serde;
 
 /// Homogeneous vector in 3D space.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct HomogeneousVector<T, U> {
     pub x: T,
     pub y: T,
@@ -33,6 +35,8 @@ pub struct HomogeneousVector<T, U> {
     pub _unit: PhantomData<U>,
 }
 
+glam_vec!(HomogeneousVector[x, y, z, w] = Vec4);
+
 impl<T: Copy, U> Copy for HomogeneousVector<T, U> {}
 
 impl<T: Clone, U> Clone for HomogeneousVector<T, U> {
@@ -126,6 +130,12 @@ where
     }
 }
 
+impl<T: Default, U> Default for HomogeneousVector<T, U> {
+    fn default() -> Self {
+        HomogeneousVector::new(T::default(), T::default(), T::default(), T::default())
+    }
+}
+
 impl<T, U> HomogeneousVector<T, U> {
     /// Constructor taking scalar values directly.
     #[inline]
@@ -138,6 +148,18 @@ impl<T, U> HomogeneousVector<T, U> {
             _unit: PhantomData,
         }
     }
+
+    /// Constructor taking a tuple with x, y, z and w.
+    #[inline]
+    pub fn from_tuple((x, y, z, w): (T, T, T, T)) -> Self {
+        HomogeneousVector::new(x, y, z, w)
+    }
+
+    /// Cast into a tuple with x, y, z and w.
+    #[inline]
+    pub fn to_tuple(self) -> (T, T, T, T) {
+        (self.x, self.y, self.z, self.w)
+    }
 }
 
 impl<T: Copy + Div<T, Output = T> + Zero + PartialOrd, U> HomogeneousVector<T, U> {
@@ -147,7 +169,9 @@ impl<T: Copy + Div<T, Output = T> + Zero + PartialOrd, U> HomogeneousVector<T, U
     #[inline]
     pub fn to_point2d(self) -> Option<Point2D<T, U>> {
         if self.w > T::zero() {
-            Some(Point2D::new(self.x / self.w, self.y / self.w))
+            let p = Point2D::new(self.x / self.w, self.y / self.w);
+            debug_check_nan!("HomogeneousVector::to_point2d", p.x, p.y);
+            Some(p)
         } else {
             None
         }
@@ -159,11 +183,9 @@ impl<T: Copy + Div<T, Output = T> + Zero + PartialOrd, U> HomogeneousVector<T, U
     #[inline]
     pub fn to_point3d(self) -> Option<Point3D<T, U>> {
         if self.w > T::zero() {
-            Some(Point3D::new(
-                self.x / self.w,
-                self.y / self.w,
-                self.z / self.w,
-            ))
+            let p = Point3D::new(self.x / self.w, self.y / self.w, self.z / self.w);
+            debug_check_nan!("HomogeneousVector::to_point3d", p.x, p.y, p.z);
+            Some(p)
         } else {
             None
         }
@@ -198,6 +220,32 @@ impl<T: One, U> From<Point3D<T, U>> for HomogeneousVector<T, U> {
     }
 }
 
+impl<T, U> From<HomogeneousVector<T, U>> for [T; 4] {
+    fn from(v: HomogeneousVector<T, U>) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl<T, U> From<[T; 4]> for HomogeneousVector<T, U> {
+    fn from([x, y, z, w]: [T; 4]) -> Self {
+        HomogeneousVector::new(x, y, z, w)
+    }
+}
+
+impl<T, U> From<HomogeneousVector<T, U>> for (T, T, T, T) {
+    fn from(v: HomogeneousVector<T, U>) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<T, U> From<(T, T, T, T)> for HomogeneousVector<T, U> {
+    fn from((x, y, z, w): (T, T, T, T)) -> Self {
+        HomogeneousVector::new(x, y, z, w)
+    }
+}
+
+mint_vec!(HomogeneousVector[x, y, z, w] = Vector4);
+
 impl<T: fmt::Debug, U> fmt::Debug for HomogeneousVector<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("")
@@ -237,4 +285,13 @@ mod homogeneous {
             HomogeneousVector::<f32, ()>::new(1.0, -2.0, -3.0, -2.0).to_point3d()
         );
     }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint() {
+        let v = HomogeneousVector::<f32, ()>::new(1.0, 2.0, 3.0, 4.0);
+        let m: mint::Vector4<_> = v.into();
+        assert_eq!((m.x, m.y, m.z, m.w), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(HomogeneousVector::from(m), v);
+    }
 }
@@ -9,7 +9,7 @@
 //! A one-dimensional length, tagged with its units.
 
 use crate::approxeq::ApproxEq;
-use crate::approxord::{max, min};
+use crate::approxord::{abs_diff, max, min};
 use crate::num::Zero;
 use crate::scale::Scale;
 
@@ -39,6 +39,10 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// You can multiply a `Length` by a [`Scale`] to convert it from one unit to
 /// another. See the [`Scale`] docs for an example.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Length<T, Unit>(pub T, #[doc(hidden)] pub PhantomData<Unit>);
 
 impl<T: Clone, U> Clone for Length<T, U> {
@@ -149,6 +153,17 @@ impl<T: PartialOrd, U> Length<T, U> {
     pub fn max(self, other: Self) -> Self {
         max(self, other)
     }
+
+    /// Returns the absolute difference between this length and another length.
+    ///
+    /// Unlike `(self - other).abs()`, this doesn't underflow for unsigned integers.
+    #[inline]
+    pub fn abs_diff(self, other: Self) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        abs_diff(self, other)
+    }
 }
 
 impl<T: NumCast + Clone, U> Length<T, U> {
@@ -305,6 +320,14 @@ impl<Src, Dst, T: Mul> Mul<Scale<T, Src, Dst>> for Length<T, Src> {
     }
 }
 
+// length *= scaleFactor
+impl<T: Copy + Mul<T, Output = T>, U> MulAssign<Scale<T, U, U>> for Length<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale<T, U, U>) {
+        *self = *self * scale;
+    }
+}
+
 // length / scaleFactor
 impl<Src, Dst, T: Div> Div<Scale<T, Src, Dst>> for Length<T, Dst> {
     type Output = Length<T::Output, Src>;
@@ -315,6 +338,14 @@ impl<Src, Dst, T: Div> Div<Scale<T, Src, Dst>> for Length<T, Dst> {
     }
 }
 
+// length /= scaleFactor
+impl<T: Copy + Div<T, Output = T>, U> DivAssign<Scale<T, U, U>> for Length<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale<T, U, U>) {
+        *self = *self / scale;
+    }
+}
+
 // -length
 impl<U, T: Neg> Neg for Length<T, U> {
     type Output = Length<T::Output, U>;
@@ -37,53 +37,153 @@
 //!
 //! All euclid types are marked `#[repr(C)]` in order to facilitate exposing them to
 //! foreign function interfaces (provided the underlying scalar type is also `repr(C)`).
+//! Their fields are laid out in declaration order, which is part of the stable API: for
+//! example a `Rect` is `{ origin: { x, y }, size: { width, height } }`, and unit tags are
+//! zero-sized so they do not affect the layout. The [`ffi`] module provides monomorphic
+//! aliases that can be exported with tools such as cbindgen.
+//!
+//! The crate is `no_std`. Floating point math comes from the `std` feature, which is
+//! enabled by default, or from the `libm` feature for targets without `std`. Types that
+//! allocate, like [`BoxList2D`] and [`Polygon2D`], need the `alloc` feature, which `std` implies:
+//!
+//! ```toml
+//! euclid = { version = "0.22", default-features = false, features = ["libm", "alloc"] }
+//! ```
 //!
 #![deny(unconditional_recursion)]
 #![warn(clippy::semicolon_if_nothing_returned)]
+// The tests deliberately exercise the by-reference and `Clone` code paths, and some of
+// them predate the lints for the legacy numeric constants and redundant imports.
+#![cfg_attr(
+    test,
+    allow(
+        clippy::approx_constant,
+        clippy::clone_on_copy,
+        clippy::excessive_precision,
+        clippy::legacy_numeric_constants,
+        clippy::needless_borrows_for_generic_args,
+        clippy::op_ref,
+        clippy::single_component_path_imports
+    )
+)]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("euclid needs either the `std` or the `libm` feature for floating point math");
+
+pub use crate::aligned::{AlignedPoint3D, AlignedVector3D};
 pub use crate::angle::Angle;
-pub use crate::box2d::Box2D;
+pub use crate::batch::SimdScalar;
+pub use crate::box2d::{box2d, Box2D};
+#[cfg(feature = "alloc")]
+pub use crate::box_list::{BoxList2D, BoxMask};
+pub use crate::cast::{CastError, CastErrorKind, RoundingMode};
+pub use crate::circle::{Circle, Sphere};
+pub use crate::decomposition::{Decomposition2D, Decomposition3D};
+pub use crate::dimension::{AxisPlane, To2D, To3D};
+pub use crate::dual_quaternion::DualQuaternion;
+pub use crate::error::Error;
+pub use crate::fixed::Fixed;
+pub use crate::flagged::{FlaggedTransform3D, TransformFlags};
+pub use crate::frustum::Frustum;
 pub use crate::homogen::HomogeneousVector;
 pub use crate::length::Length;
+pub use crate::mapping::{CoordinateSpaceMapping, SpaceMapping};
+pub use crate::nan_checks::allow_nan;
+pub use crate::num::RealScalar;
+pub use crate::ordered::{Lexicographic, OrderedScalar};
+pub use crate::plane::{Plane, Plane2D, PlaneSide};
 pub use crate::point::{point2, point3, Point2D, Point3D};
-pub use crate::scale::Scale;
+#[cfg(feature = "alloc")]
+pub use crate::polygon::Polygon2D;
+pub use crate::polygon::{FillRule, PolygonRef2D, Winding};
+pub use crate::ray::{Ray2D, Ray3D};
+pub use crate::rectangle::AxisAlignedRectangle;
+#[cfg(feature = "alloc")]
+pub use crate::region::Region2D;
+pub use crate::scale::{Scale, Scale2D};
+pub use crate::segment::LineSegment2D;
 pub use crate::transform2d::Transform2D;
-pub use crate::transform3d::Transform3D;
+pub use crate::transform3d::{DepthRange, Handedness, Transform3D};
 pub use crate::vector::{bvec2, bvec3, BoolVector2D, BoolVector3D};
 pub use crate::vector::{vec2, vec3, Vector2D, Vector3D};
+pub use crate::wide::{Point2Dx4, Point2Dx8, Point3Dx4, Point3Dx8};
+pub use crate::wide::{Vector2Dx4, Vector2Dx8, Vector3Dx4, Vector3Dx8};
 
 pub use crate::box3d::{box3d, Box3D};
 pub use crate::rect::{rect, Rect};
 pub use crate::rigid::RigidTransform3D;
-pub use crate::rotation::{Rotation2D, Rotation3D};
+pub use crate::rotation::{EulerOrder, Rotation2D, Rotation3D};
 pub use crate::side_offsets::SideOffsets2D;
 pub use crate::size::{size2, size3, Size2D, Size3D};
 pub use crate::translation::{Translation2D, Translation3D};
+pub use crate::triangle::{Triangle2D, Triangle3D};
 pub use crate::trig::Trig;
+pub use crate::unit_name::{DebugWithUnitNames, UnitName, WithUnitNames};
 
 #[macro_use]
 mod macros;
 
+mod aligned;
 mod angle;
 pub mod approxeq;
 pub mod approxord;
+mod batch;
 mod box2d;
 mod box3d;
+#[cfg(feature = "alloc")]
+mod box_list;
+mod cast;
+mod circle;
+mod decomposition;
+mod dimension;
+mod dual_quaternion;
+mod error;
+mod fixed;
+mod flagged;
+mod frustum;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
 mod homogen;
 mod length;
+mod mapping;
+mod matrix;
+mod nan_checks;
 pub mod num;
+mod ordered;
+mod plane;
 mod point;
+mod polygon;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(feature = "rand")]
+pub mod random;
+mod ray;
 mod rect;
+mod rectangle;
+#[cfg(feature = "alloc")]
+mod region;
 mod rigid;
 mod rotation;
 mod scale;
+mod segment;
+#[cfg(feature = "serde")]
+mod serde_fields;
 mod side_offsets;
+#[cfg(feature = "simd")]
+mod simd;
 mod size;
+mod space;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 mod transform2d;
 mod transform3d;
 mod translation;
+mod triangle;
 mod trig;
+mod unit_name;
+pub mod validated;
 mod vector;
+mod wide;
 
 /// The default unit.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -98,19 +198,127 @@ pub mod default {
     pub type Point3D<T> = super::Point3D<T, UnknownUnit>;
     pub type Vector2D<T> = super::Vector2D<T, UnknownUnit>;
     pub type Vector3D<T> = super::Vector3D<T, UnknownUnit>;
+    pub type AlignedPoint3D<T> = super::AlignedPoint3D<T, UnknownUnit>;
+    pub type AlignedVector3D<T> = super::AlignedVector3D<T, UnknownUnit>;
     pub type HomogeneousVector<T> = super::HomogeneousVector<T, UnknownUnit>;
     pub type Size2D<T> = super::Size2D<T, UnknownUnit>;
     pub type Size3D<T> = super::Size3D<T, UnknownUnit>;
+    pub type Ray2D<T> = super::Ray2D<T, UnknownUnit>;
+    pub type Ray3D<T> = super::Ray3D<T, UnknownUnit>;
     pub type Rect<T> = super::Rect<T, UnknownUnit>;
     pub type Box2D<T> = super::Box2D<T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type BoxList2D<T> = super::BoxList2D<T, UnknownUnit>;
     pub type Box3D<T> = super::Box3D<T, UnknownUnit>;
+    pub type Circle<T> = super::Circle<T, UnknownUnit>;
+    pub type Sphere<T> = super::Sphere<T, UnknownUnit>;
+    pub type Frustum<T> = super::Frustum<T, UnknownUnit>;
+    pub type LineSegment2D<T> = super::LineSegment2D<T, UnknownUnit>;
+    pub type Plane<T> = super::Plane<T, UnknownUnit>;
+    pub type Plane2D<T> = super::Plane2D<T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type Polygon2D<T> = super::Polygon2D<T, UnknownUnit>;
+    pub type PolygonRef2D<'a, T> = super::PolygonRef2D<'a, T, UnknownUnit>;
+    #[cfg(feature = "alloc")]
+    pub type Region2D<T> = super::Region2D<T, UnknownUnit>;
     pub type SideOffsets2D<T> = super::SideOffsets2D<T, UnknownUnit>;
     pub type Transform2D<T> = super::Transform2D<T, UnknownUnit, UnknownUnit>;
     pub type Transform3D<T> = super::Transform3D<T, UnknownUnit, UnknownUnit>;
+    pub type Decomposition2D<T> = super::Decomposition2D<T, UnknownUnit, UnknownUnit>;
+    pub type Decomposition3D<T> = super::Decomposition3D<T, UnknownUnit, UnknownUnit>;
+    pub type FlaggedTransform3D<T> = super::FlaggedTransform3D<T, UnknownUnit, UnknownUnit>;
     pub type Rotation2D<T> = super::Rotation2D<T, UnknownUnit, UnknownUnit>;
     pub type Rotation3D<T> = super::Rotation3D<T, UnknownUnit, UnknownUnit>;
     pub type Translation2D<T> = super::Translation2D<T, UnknownUnit, UnknownUnit>;
     pub type Translation3D<T> = super::Translation3D<T, UnknownUnit, UnknownUnit>;
+    pub type Triangle2D<T> = super::Triangle2D<T, UnknownUnit>;
+    pub type Triangle3D<T> = super::Triangle3D<T, UnknownUnit>;
     pub type Scale<T> = super::Scale<T, UnknownUnit, UnknownUnit>;
+    pub type Scale2D<T> = super::Scale2D<T, UnknownUnit, UnknownUnit>;
     pub type RigidTransform3D<T> = super::RigidTransform3D<T, UnknownUnit, UnknownUnit>;
+    pub type DualQuaternion<T> = super::DualQuaternion<T, UnknownUnit, UnknownUnit>;
+}
+
+pub mod prelude {
+    //! The commonly used types, constructor functions and traits.
+    //!
+    //! ```
+    //! use euclid::prelude::*;
+    //!
+    //! struct World;
+    //!
+    //! let bounds: Box2D<f32, World> = box2d(0.0, 0.0, 10.0, 10.0);
+    //! let t: Transform2D<f32, World, World> =
+    //!     Transform2D::rotation(Angle::degrees(90.0)).then_translate(vec2(10.0, 0.0));
+    //! let corner = t.transform_point(point2(10.0, 0.0));
+    //! assert!(corner.approx_eq(&point2(10.0, 10.0)));
+    //! assert!(bounds.contains_inclusive(corner));
+    //! ```
+
+    pub use crate::approxeq::ApproxEq;
+    pub use crate::dimension::{To2D, To3D};
+    pub use crate::mapping::CoordinateSpaceMapping;
+    pub use crate::rectangle::AxisAlignedRectangle;
+    pub use crate::unit_name::DebugWithUnitNames;
+    pub use crate::{box2d, box3d, point2, point3, rect, size2, size3, vec2, vec3};
+    pub use crate::{Angle, Length, Scale, SideOffsets2D, UnknownUnit};
+    pub use crate::{Box2D, Box3D, Point2D, Point3D, Rect, Size2D, Size3D, Vector2D, Vector3D};
+    pub use crate::{Rotation2D, Rotation3D, Transform2D, Transform3D};
+    pub use crate::{Translation2D, Translation3D};
+}
+
+pub mod ffi {
+    //! Monomorphic aliases of the common types with the default unknown unit, for use
+    //! in `extern "C"` signatures and with binding generators.
+    //!
+    //! The layout of each type is the `#[repr(C)]` layout of its fields in declaration
+    //! order, for example `Box2DF32` is `{ min: { x, y }, max: { x, y } }` with `f32`
+    //! components, and `Transform3DF32` is sixteen `f32` in `m11, m12, ..., m44` order.
+
+    use super::default;
+
+    pub type Point2DF32 = default::Point2D<f32>;
+    pub type Point2DF64 = default::Point2D<f64>;
+    pub type Point2DI32 = default::Point2D<i32>;
+    pub type Point3DF32 = default::Point3D<f32>;
+    pub type Point3DF64 = default::Point3D<f64>;
+    pub type Point3DI32 = default::Point3D<i32>;
+    pub type Vector2DF32 = default::Vector2D<f32>;
+    pub type Vector2DF64 = default::Vector2D<f64>;
+    pub type Vector2DI32 = default::Vector2D<i32>;
+    pub type Vector3DF32 = default::Vector3D<f32>;
+    pub type Vector3DF64 = default::Vector3D<f64>;
+    pub type Vector3DI32 = default::Vector3D<i32>;
+    pub type Size2DF32 = default::Size2D<f32>;
+    pub type Size2DF64 = default::Size2D<f64>;
+    pub type Size2DI32 = default::Size2D<i32>;
+    pub type Size3DF32 = default::Size3D<f32>;
+    pub type Size3DF64 = default::Size3D<f64>;
+    pub type Size3DI32 = default::Size3D<i32>;
+    pub type RectF32 = default::Rect<f32>;
+    pub type RectF64 = default::Rect<f64>;
+    pub type RectI32 = default::Rect<i32>;
+    pub type Box2DF32 = default::Box2D<f32>;
+    pub type Box2DF64 = default::Box2D<f64>;
+    pub type Box2DI32 = default::Box2D<i32>;
+    pub type Box3DF32 = default::Box3D<f32>;
+    pub type Box3DF64 = default::Box3D<f64>;
+    pub type Box3DI32 = default::Box3D<i32>;
+    pub type SideOffsets2DF32 = default::SideOffsets2D<f32>;
+    pub type SideOffsets2DF64 = default::SideOffsets2D<f64>;
+    pub type SideOffsets2DI32 = default::SideOffsets2D<i32>;
+    pub type Translation2DF32 = default::Translation2D<f32>;
+    pub type Translation2DF64 = default::Translation2D<f64>;
+    pub type Translation2DI32 = default::Translation2D<i32>;
+    pub type Translation3DF32 = default::Translation3D<f32>;
+    pub type Translation3DF64 = default::Translation3D<f64>;
+    pub type Translation3DI32 = default::Translation3D<i32>;
+    pub type Transform2DF32 = default::Transform2D<f32>;
+    pub type Transform2DF64 = default::Transform2D<f64>;
+    pub type Transform3DF32 = default::Transform3D<f32>;
+    pub type Transform3DF64 = default::Transform3D<f64>;
+    pub type Rotation3DF32 = default::Rotation3D<f32>;
+    pub type Rotation3DF64 = default::Rotation3D<f64>;
+    pub type HomogeneousVectorF32 = default::HomogeneousVector<f32>;
+    pub type HomogeneousVectorF64 = default::HomogeneousVector<f64>;
 }
@@ -28,3 +28,93 @@ macro_rules! mint_vec {
         }
     }
 }
+
+macro_rules! nalgebra_vec {
+    ($name:ident [ $($field:ident),* ] = $na_name:ident) => {
+        #[cfg(feature = "nalgebra")]
+        impl<T: nalgebra::Scalar, U> From<nalgebra::$na_name<T>> for $name<T, U> {
+            fn from(v: nalgebra::$na_name<T>) -> Self {
+                $name {
+                    $( $field: v.$field.clone(), )*
+                    _unit: PhantomData,
+                }
+            }
+        }
+        #[cfg(feature = "nalgebra")]
+        impl<T: nalgebra::Scalar, U> From<$name<T, U>> for nalgebra::$na_name<T> {
+            fn from(v: $name<T, U>) -> Self {
+                nalgebra::$na_name::new($( v.$field ),*)
+            }
+        }
+    }
+}
+
+macro_rules! glam_vec {
+    ($name:ident [ $($field:ident),* ] = $glam_name:ident) => {
+        #[cfg(feature = "glam")]
+        impl<U> From<glam::$glam_name> for $name<f32, U> {
+            fn from(v: glam::$glam_name) -> Self {
+                $name {
+                    $( $field: v.$field, )*
+                    _unit: PhantomData,
+                }
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl<U> From<$name<f32, U>> for glam::$glam_name {
+            fn from(v: $name<f32, U>) -> Self {
+                glam::$glam_name::new($( v.$field ),*)
+            }
+        }
+    }
+}
+
+/// Panics in debug builds if any of the values is NaN, when the `nan-checks` feature is
+/// enabled. See the `nan_checks` module.
+macro_rules! debug_check_nan {
+    ($what:expr, $($value:expr),+) => {
+        #[cfg(all(feature = "nan-checks", debug_assertions))]
+        {
+            $(crate::nan_checks::check(&$value, $what);)+
+        }
+    };
+}
+
+/// Asserts an invariant in debug builds, and in all builds when the `validate` feature
+/// is enabled. See the `validated` module.
+macro_rules! check_invariant {
+    ($cond:expr, $($arg:tt)+) => {
+        if cfg!(any(feature = "validate", debug_assertions)) {
+            assert!($cond, $($arg)+);
+        }
+    };
+}
+
+/// Asserts an invariant only when the `validate` feature is enabled. This is for the
+/// checks that are too costly or too strict to be enabled in every debug build.
+macro_rules! validate_invariant {
+    ($cond:expr, $($arg:tt)+) => {
+        if cfg!(feature = "validate") {
+            assert!($cond, $($arg)+);
+        }
+    };
+}
+
+/// Invokes `$mac!(type, zero, one)` for each primitive numeric type, to implement
+/// associated constants that can't be written generically.
+macro_rules! primitive_consts {
+    ($mac:ident) => {
+        $mac!(f32, 0.0, 1.0);
+        $mac!(f64, 0.0, 1.0);
+        $mac!(i8, 0, 1);
+        $mac!(i16, 0, 1);
+        $mac!(i32, 0, 1);
+        $mac!(i64, 0, 1);
+        $mac!(isize, 0, 1);
+        $mac!(u8, 0, 1);
+        $mac!(u16, 0, 1);
+        $mac!(u32, 0, 1);
+        $mac!(u64, 0, 1);
+        $mac!(usize, 0, 1);
+    };
+}
@@ -0,0 +1,618 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A common interface for the types that map 2d geometry between coordinate spaces.
+
+use crate::batch::SimdScalar;
+use crate::num::{One, Zero};
+use crate::{Box2D, Point2D, Rect, Scale, Scale2D, Transform2D, Transform3D, Translation2D};
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A mapping of 2d geometry from the `Src` space to the `Dst` space.
+///
+/// This is implemented by [`Scale`], [`Scale2D`], [`Translation2D`], [`Transform2D`] and
+/// [`Transform3D`], so that code can be generic over the representation of a mapping.
+/// [`SpaceMapping`] stores one of them and picks the cheapest one for a given transform.
+///
+/// Rectangles and boxes are mapped to the smallest rectangle or box containing the
+/// result. The methods return `None` when the mapping is not defined for their argument,
+/// like for points behind the camera of a perspective transform or for the inverse of a
+/// mapping that can't be inverted.
+pub trait CoordinateSpaceMapping<T, Src, Dst> {
+    /// Maps a point from the source space to the destination space.
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>>;
+
+    /// Maps a rectangle from the source space to the destination space.
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>>;
+
+    /// Maps a box from the source space to the destination space.
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>>;
+
+    /// Maps a point from the destination space back to the source space.
+    ///
+    /// 3d transforms unproject the point onto the z = 0 plane of the source space.
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>>;
+
+    /// Maps a rectangle from the destination space back to the source space.
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>>;
+
+    /// Maps a box from the destination space back to the source space.
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>>;
+
+    /// Returns the mapping as a 3d transform.
+    fn to_transform(&self) -> Transform3D<T, Src, Dst>;
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for Scale<T, Src, Dst>
+where
+    T: Copy + Zero + One + PartialEq + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    #[inline]
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        Some(self.transform_point(p))
+    }
+
+    #[inline]
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        Some(self.transform_rect(r))
+    }
+
+    #[inline]
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        Some(self.transform_box2d(b))
+    }
+
+    #[inline]
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_point(p))
+    }
+
+    #[inline]
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_rect(r))
+    }
+
+    #[inline]
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_box2d(b))
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        Transform3D::scale(self.0, self.0, T::one())
+    }
+}
+
+impl<T, Src, Dst> Scale<T, Src, Dst>
+where
+    T: Copy + Zero + One + PartialEq + Div<Output = T>,
+{
+    /// Returns the inverse scale, or `None` if the scale is zero.
+    #[inline]
+    fn checked_inverse(&self) -> Option<Scale<T, Dst, Src>> {
+        if self.0 == T::zero() {
+            None
+        } else {
+            Some(self.inverse())
+        }
+    }
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for Scale2D<T, Src, Dst>
+where
+    T: Copy + Zero + One + PartialEq + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    #[inline]
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        Some(self.transform_point(p))
+    }
+
+    #[inline]
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        Some(self.transform_rect(r))
+    }
+
+    #[inline]
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        Some(self.transform_box2d(b))
+    }
+
+    #[inline]
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_point(p))
+    }
+
+    #[inline]
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_rect(r))
+    }
+
+    #[inline]
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        self.checked_inverse().map(|s| s.transform_box2d(b))
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        Transform3D::scale(self.x, self.y, T::one())
+    }
+}
+
+impl<T, Src, Dst> Scale2D<T, Src, Dst>
+where
+    T: Copy + Zero + One + PartialEq + Div<Output = T>,
+{
+    /// Returns the inverse scale, or `None` if either factor is zero.
+    #[inline]
+    fn checked_inverse(&self) -> Option<Scale2D<T, Dst, Src>> {
+        if self.x == T::zero() || self.y == T::zero() {
+            None
+        } else {
+            Some(self.inverse())
+        }
+    }
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for Translation2D<T, Src, Dst>
+where
+    T: Copy + Zero + One + Add<Output = T> + Neg<Output = T>,
+{
+    #[inline]
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        Some(self.transform_point(p))
+    }
+
+    #[inline]
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        Some(self.transform_rect(r))
+    }
+
+    #[inline]
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        Some(self.transform_box(b))
+    }
+
+    #[inline]
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        Some(self.inverse().transform_point(p))
+    }
+
+    #[inline]
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        Some(self.inverse().transform_rect(r))
+    }
+
+    #[inline]
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        Some(self.inverse().transform_box(b))
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        Transform3D::translation(self.x, self.y, T::zero())
+    }
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for Transform2D<T, Src, Dst>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + SimdScalar,
+{
+    #[inline]
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        Some(self.transform_point(p))
+    }
+
+    #[inline]
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        Some(self.outer_transformed_rect(r))
+    }
+
+    #[inline]
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        Some(self.outer_transformed_box(b))
+    }
+
+    #[inline]
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        Some(self.inverse()?.transform_point(p))
+    }
+
+    #[inline]
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        Some(self.inverse()?.outer_transformed_rect(r))
+    }
+
+    #[inline]
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        Some(self.inverse()?.outer_transformed_box(b))
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        self.to_3d()
+    }
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for Transform3D<T, Src, Dst>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + SimdScalar,
+{
+    #[inline]
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        self.transform_point2d(p)
+    }
+
+    #[inline]
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        self.outer_transformed_rect(r)
+    }
+
+    #[inline]
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        self.outer_transformed_box2d(b)
+    }
+
+    #[inline]
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        self.plane_inverse()?.transform_point2d(p)
+    }
+
+    #[inline]
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        self.plane_inverse()?.outer_transformed_rect(r)
+    }
+
+    #[inline]
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        self.plane_inverse()?.outer_transformed_box2d(b)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        *self
+    }
+}
+
+impl<T, Src, Dst> Transform3D<T, Src, Dst>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    /// Returns the transform that unprojects points of the destination plane onto the
+    /// z = 0 plane of the source space, or `None` if the z = 0 plane is mapped to a line.
+    ///
+    /// Restricted to z = 0, the transform is the projective mapping formed by its x, y
+    /// and w rows and columns. Its inverse maps every point back onto that plane, where
+    /// the full inverse of a transform with z or perspective terms would not.
+    fn plane_inverse(&self) -> Option<Transform3D<T, Dst, Src>> {
+        let (zero, one) = (T::zero(), T::one());
+        #[rustfmt::skip]
+        let flat: Transform3D<T, Src, Dst> = Transform3D::new(
+            self.m11, self.m12, zero, self.m14,
+            self.m21, self.m22, zero, self.m24,
+            zero,     zero,     one,  zero,
+            self.m41, self.m42, zero, self.m44,
+        );
+        flat.inverse()
+    }
+}
+
+/// A mapping between two coordinate spaces, stored in its cheapest representation.
+///
+/// [`SpaceMapping::from_transform`] picks the representation from the terms of a
+/// transform, so that mappings which turn out to be scales or translations are applied
+/// without matrix multiplications, and their inverse without computing a determinant.
+///
+/// ```
+/// use euclid::default::{Rect, Transform3D};
+/// use euclid::{point2, rect, CoordinateSpaceMapping, SpaceMapping};
+///
+/// let mapping = SpaceMapping::from_transform(&Transform3D::translation(10.0, 5.0, 0.0));
+/// assert!(matches!(mapping, SpaceMapping::Translation(..)));
+/// assert_eq!(mapping.map_point(point2(1.0, 1.0)), Some(point2(11.0, 6.0)));
+///
+/// let r: Rect<f32> = rect(0.0, 0.0, 2.0, 2.0);
+/// assert_eq!(mapping.inverse_map_rect(&r), Some(rect(-10.0, -5.0, 2.0, 2.0)));
+/// ```
+pub enum SpaceMapping<T, Src, Dst> {
+    Scale(Scale<T, Src, Dst>),
+    Translation(Translation2D<T, Src, Dst>),
+    Transform2D(Transform2D<T, Src, Dst>),
+    Transform3D(Transform3D<T, Src, Dst>),
+}
+
+impl<T, Src, Dst> SpaceMapping<T, Src, Dst>
+where
+    T: Copy + Zero + One + PartialEq,
+{
+    /// Returns the cheapest representation of a transform.
+    ///
+    /// The transform is stored as a uniform [`Scale`] or a [`Translation2D`] if it is
+    /// exactly one of them, as a [`Transform2D`] if it only has 2d terms, and as is
+    /// otherwise.
+    pub fn from_transform(t: &Transform3D<T, Src, Dst>) -> Self {
+        if !t.is_2d() {
+            return SpaceMapping::Transform3D(*t);
+        }
+        let (zero, one) = (T::zero(), T::one());
+        if t.m12 == zero && t.m21 == zero {
+            if t.m11 == one && t.m22 == one {
+                return SpaceMapping::Translation(Translation2D::new(t.m41, t.m42));
+            }
+            if t.m11 == t.m22 && t.m41 == zero && t.m42 == zero {
+                return SpaceMapping::Scale(Scale::new(t.m11));
+            }
+        }
+        SpaceMapping::Transform2D(t.to_2d())
+    }
+}
+
+impl<T, Src, Dst> CoordinateSpaceMapping<T, Src, Dst> for SpaceMapping<T, Src, Dst>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + SimdScalar,
+{
+    fn map_point(&self, p: Point2D<T, Src>) -> Option<Point2D<T, Dst>> {
+        match self {
+            SpaceMapping::Scale(m) => m.map_point(p),
+            SpaceMapping::Translation(m) => m.map_point(p),
+            SpaceMapping::Transform2D(m) => m.map_point(p),
+            SpaceMapping::Transform3D(m) => m.map_point(p),
+        }
+    }
+
+    fn map_rect(&self, r: &Rect<T, Src>) -> Option<Rect<T, Dst>> {
+        match self {
+            SpaceMapping::Scale(m) => m.map_rect(r),
+            SpaceMapping::Translation(m) => m.map_rect(r),
+            SpaceMapping::Transform2D(m) => m.map_rect(r),
+            SpaceMapping::Transform3D(m) => m.map_rect(r),
+        }
+    }
+
+    fn map_box(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>> {
+        match self {
+            SpaceMapping::Scale(m) => m.map_box(b),
+            SpaceMapping::Translation(m) => m.map_box(b),
+            SpaceMapping::Transform2D(m) => m.map_box(b),
+            SpaceMapping::Transform3D(m) => m.map_box(b),
+        }
+    }
+
+    fn inverse_map_point(&self, p: Point2D<T, Dst>) -> Option<Point2D<T, Src>> {
+        match self {
+            SpaceMapping::Scale(m) => m.inverse_map_point(p),
+            SpaceMapping::Translation(m) => m.inverse_map_point(p),
+            SpaceMapping::Transform2D(m) => m.inverse_map_point(p),
+            SpaceMapping::Transform3D(m) => m.inverse_map_point(p),
+        }
+    }
+
+    fn inverse_map_rect(&self, r: &Rect<T, Dst>) -> Option<Rect<T, Src>> {
+        match self {
+            SpaceMapping::Scale(m) => m.inverse_map_rect(r),
+            SpaceMapping::Translation(m) => m.inverse_map_rect(r),
+            SpaceMapping::Transform2D(m) => m.inverse_map_rect(r),
+            SpaceMapping::Transform3D(m) => m.inverse_map_rect(r),
+        }
+    }
+
+    fn inverse_map_box(&self, b: &Box2D<T, Dst>) -> Option<Box2D<T, Src>> {
+        match self {
+            SpaceMapping::Scale(m) => m.inverse_map_box(b),
+            SpaceMapping::Translation(m) => m.inverse_map_box(b),
+            SpaceMapping::Transform2D(m) => m.inverse_map_box(b),
+            SpaceMapping::Transform3D(m) => m.inverse_map_box(b),
+        }
+    }
+
+    fn to_transform(&self) -> Transform3D<T, Src, Dst> {
+        match self {
+            SpaceMapping::Scale(m) => CoordinateSpaceMapping::to_transform(m),
+            SpaceMapping::Translation(m) => CoordinateSpaceMapping::to_transform(m),
+            SpaceMapping::Transform2D(m) => CoordinateSpaceMapping::to_transform(m),
+            SpaceMapping::Transform3D(m) => *m,
+        }
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for SpaceMapping<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for SpaceMapping<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        match self {
+            SpaceMapping::Scale(m) => SpaceMapping::Scale(m.clone()),
+            SpaceMapping::Translation(m) => SpaceMapping::Translation(m.clone()),
+            SpaceMapping::Transform2D(m) => SpaceMapping::Transform2D(m.clone()),
+            SpaceMapping::Transform3D(m) => SpaceMapping::Transform3D(m.clone()),
+        }
+    }
+}
+
+impl<T: Zero, Src, Dst> Default for SpaceMapping<T, Src, Dst> {
+    /// Returns the identity mapping, as the translation `from_transform` picks for it.
+    fn default() -> Self {
+        SpaceMapping::Translation(Translation2D::identity())
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for SpaceMapping<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SpaceMapping::Scale(a), SpaceMapping::Scale(b)) => a == b,
+            (SpaceMapping::Translation(a), SpaceMapping::Translation(b)) => a == b,
+            (SpaceMapping::Transform2D(a), SpaceMapping::Transform2D(b)) => a == b,
+            (SpaceMapping::Transform3D(a), SpaceMapping::Transform3D(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T, Src, Dst> fmt::Debug for SpaceMapping<T, Src, Dst>
+where
+    T: Copy + fmt::Debug + PartialEq + One + Zero,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpaceMapping::Scale(m) => f.debug_tuple("Scale").field(m).finish(),
+            SpaceMapping::Translation(m) => f.debug_tuple("Translation").field(m).finish(),
+            SpaceMapping::Transform2D(m) => f.debug_tuple("Transform2D").field(m).finish(),
+            SpaceMapping::Transform3D(m) => f.debug_tuple("Transform3D").field(m).finish(),
+        }
+    }
+}
+
+impl<T, Src, Dst> From<Scale<T, Src, Dst>> for SpaceMapping<T, Src, Dst> {
+    fn from(m: Scale<T, Src, Dst>) -> Self {
+        SpaceMapping::Scale(m)
+    }
+}
+
+impl<T, Src, Dst> From<Translation2D<T, Src, Dst>> for SpaceMapping<T, Src, Dst> {
+    fn from(m: Translation2D<T, Src, Dst>) -> Self {
+        SpaceMapping::Translation(m)
+    }
+}
+
+impl<T, Src, Dst> From<Transform2D<T, Src, Dst>> for SpaceMapping<T, Src, Dst> {
+    fn from(m: Transform2D<T, Src, Dst>) -> Self {
+        SpaceMapping::Transform2D(m)
+    }
+}
+
+impl<T, Src, Dst> From<Transform3D<T, Src, Dst>> for SpaceMapping<T, Src, Dst> {
+    fn from(m: Transform3D<T, Src, Dst>) -> Self {
+        SpaceMapping::Transform3D(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approxeq::ApproxEq;
+    use crate::default;
+    use crate::{point2, rect, vec3, Angle};
+
+    fn transforms() -> [default::Transform3D<f64>; 5] {
+        [
+            Transform3D::identity(),
+            Transform3D::translation(3.0, -2.0, 0.0),
+            Transform3D::scale(0.5, 0.5, 1.0),
+            Transform3D::rotation(0.0, 0.0, 1.0, Angle::radians(0.3)).then_scale(2.0, 1.0, 1.0),
+            Transform3D::perspective(100.0).then_translate(vec3(1.0, 2.0, 3.0)),
+        ]
+    }
+
+    #[test]
+    fn test_from_transform() {
+        let kinds: [fn(&SpaceMapping<f64, _, _>) -> bool; 5] = [
+            |m| matches!(m, SpaceMapping::Translation(..)),
+            |m| matches!(m, SpaceMapping::Translation(..)),
+            |m| matches!(m, SpaceMapping::Scale(..)),
+            |m| matches!(m, SpaceMapping::Transform2D(..)),
+            |m| matches!(m, SpaceMapping::Transform3D(..)),
+        ];
+        for (t, kind) in transforms().iter().zip(kinds.iter()) {
+            let m = SpaceMapping::from_transform(t);
+            assert!(kind(&m), "{:?}", m);
+            assert_eq!(m.to_transform(), *t);
+        }
+    }
+
+    #[test]
+    fn test_mapping() {
+        let r: default::Rect<f64> = rect(-1.0, 2.0, 3.0, 4.0);
+        let p = point2(1.5, -0.5);
+        for t in &transforms() {
+            let m = SpaceMapping::from_transform(t);
+            assert!(m.map_point(p).unwrap().approx_eq(&t.map_point(p).unwrap()));
+            let mapped = m.map_rect(&r).unwrap();
+            let expected = t.outer_transformed_rect(&r).unwrap();
+            assert!(mapped.origin.approx_eq(&expected.origin));
+            assert!(mapped.max().approx_eq(&expected.max()));
+            assert!(m
+                .inverse_map_point(m.map_point(p).unwrap())
+                .unwrap()
+                .approx_eq(&p));
+            let b = m.inverse_map_box(&m.map_box(&r.to_box2d()).unwrap());
+            assert!(b.unwrap().contains_box(&r.to_box2d().inflate(-1e-9, -1e-9)));
+        }
+    }
+
+    #[test]
+    fn test_inverse_perspective() {
+        // Tilting the plane away from the viewer makes points further down look smaller.
+        let tilt: default::Transform3D<f64> =
+            Transform3D::rotation(1.0, 0.0, 0.0, Angle::degrees(60.0));
+        let t = tilt
+            .then_translate(vec3(0.0, 0.0, -50.0))
+            .then(&Transform3D::perspective(100.0));
+        for p in &[point2(5.0, 5.0), point2(10.0, 20.0), point2(-30.0, -5.0)] {
+            let mapped = t.map_point(*p).unwrap();
+            assert!(!mapped.approx_eq(p));
+            assert!(t.inverse_map_point(mapped).unwrap().approx_eq(p));
+        }
+
+        let b = Box2D::new(point2(-10.0, -10.0), point2(10.0, 10.0));
+        let unprojected = t.inverse_map_box(&t.map_box(&b).unwrap()).unwrap();
+        assert!(unprojected.contains_box(&b.inflate(-1e-9, -1e-9)));
+
+        // The plane seen edge-on covers a single line of the screen.
+        #[rustfmt::skip]
+        let edge_on: default::Transform3D<f64> = Transform3D::new(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, -1.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        );
+        assert_eq!(edge_on.inverse_map_point(point2(1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_not_invertible() {
+        let s: Scale<f32, (), ()> = Scale::new(0.0);
+        assert_eq!(s.inverse_map_point(point2(1.0, 1.0)), None);
+        let t: default::Transform2D<f32> = Transform2D::scale(0.0, 1.0);
+        assert_eq!(t.inverse_map_rect(&rect(0.0, 0.0, 1.0, 1.0)), None);
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The matrix operations shared by `Transform2D` and `Transform3D`.
+//!
+//! Both transforms are `#[repr(C)]` structs whose terms are laid out in row-major
+//! order, so they can be viewed as a [`Matrix`] without copying. The product loops
+//! have constant bounds and are fully unrolled by the compiler, and they evaluate
+//! sums in the same order as the hand-written expressions they replace.
+//!
+//! This covers element access, rows and columns, the products, the determinant and
+//! the inverse. The inverse is computed from the cofactors rather than with pivoting,
+//! so that it only requires the arithmetic operators of `T`, which is practical for
+//! the 3x3 and 4x4 matrices of the transforms only. The pseudo-inverse is only defined
+//! for floating point scalars.
+
+use crate::num::{One, RealScalar, Zero};
+use core::mem::size_of;
+use core::ops::{Add, Div, Mul, Sub};
+#[cfg(feature = "fma")]
+use num_traits::MulAdd;
+
+/// A row-major matrix with `R` rows and `C` columns.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Matrix<T, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Views the terms of a transform as a matrix.
+    ///
+    /// # Safety
+    ///
+    /// `S` must be `#[repr(C)]` and made of exactly `R * C` fields of type `T` in
+    /// row-major order, plus zero-sized fields.
+    #[inline]
+    pub(crate) unsafe fn view<S>(s: &S) -> &Self {
+        debug_assert_eq!(size_of::<S>(), size_of::<Self>());
+        &*(s as *const S as *const Self)
+    }
+
+    /// Mutable version of [`Matrix::view`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Matrix::view`].
+    #[inline]
+    pub(crate) unsafe fn view_mut<S>(s: &mut S) -> &mut Self {
+        debug_assert_eq!(size_of::<S>(), size_of::<Self>());
+        &mut *(s as *mut S as *mut Self)
+    }
+
+    /// Returns a reference to a term, panicking with a message naming the
+    /// transform type if it is out of bounds.
+    #[inline]
+    pub(crate) fn term(&self, name: &str, row: usize, column: usize) -> &T {
+        assert!(
+            row < R && column < C,
+            "{} index out of bounds: ({}, {})",
+            name,
+            row,
+            column
+        );
+        &self.0[row][column]
+    }
+
+    /// Mutable version of [`Matrix::term`].
+    #[inline]
+    pub(crate) fn term_mut(&mut self, name: &str, row: usize, column: usize) -> &mut T {
+        assert!(
+            row < R && column < C,
+            "{} index out of bounds: ({}, {})",
+            name,
+            row,
+            column
+        );
+        &mut self.0[row][column]
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns a row, panicking with a message naming the transform type if it is
+    /// out of bounds.
+    #[inline]
+    pub(crate) fn row(&self, name: &str, row: usize) -> [T; C] {
+        let _ = self.term(name, row, 0);
+        self.0[row]
+    }
+
+    /// Returns a column, panicking with a message naming the transform type if it
+    /// is out of bounds.
+    #[inline]
+    pub(crate) fn column(&self, name: &str, column: usize) -> [T; R] {
+        let mut c = [*self.term(name, 0, column); R];
+        for (i, term) in c.iter_mut().enumerate() {
+            *term = self.0[i][column];
+        }
+        c
+    }
+
+    /// Returns the transposed matrix.
+    #[inline]
+    pub(crate) fn transpose(&self) -> Matrix<T, C, R> {
+        let mut m = Matrix([[self.0[0][0]; R]; C]);
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, term) in row.iter().enumerate() {
+                m.0[j][i] = *term;
+            }
+        }
+        m
+    }
+
+    /// Computes `self * other`.
+    #[inline]
+    pub(crate) fn mul<const K: usize>(&self, other: &Matrix<T, C, K>) -> Matrix<T, R, K>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        let mut m = Matrix([[self.0[0][0]; K]; R]);
+        for (i, row) in m.0.iter_mut().enumerate() {
+            *row = vec_mul(self.0[i], other);
+        }
+        m
+    }
+
+    /// Computes `self * other` with fused multiply-add operations.
+    #[cfg(feature = "fma")]
+    #[inline]
+    pub(crate) fn mul_fused<const K: usize>(&self, other: &Matrix<T, C, K>) -> Matrix<T, R, K>
+    where
+        T: Mul<Output = T> + MulAdd<Output = T>,
+    {
+        let mut m = Matrix([[self.0[0][0]; K]; R]);
+        for (i, row) in m.0.iter_mut().enumerate() {
+            *row = vec_mul_fused(self.0[i], other);
+        }
+        m
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero + One,
+{
+    /// Computes the determinant, with a cofactor expansion along the first row.
+    #[inline]
+    pub(crate) fn determinant(&self) -> T {
+        minor(&self.0, &rows_without::<N>(N), all_columns::<N>())
+    }
+
+    /// Computes the inverse, or returns `None` if the determinant is zero.
+    ///
+    /// Each term is the cofactor of the transposed term, divided by the determinant.
+    pub(crate) fn inverse(&self) -> Option<Self>
+    where
+        T: Div<Output = T> + PartialEq,
+    {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+        let mut m = *self;
+        for (i, row) in m.0.iter_mut().enumerate() {
+            for (j, term) in row.iter_mut().enumerate() {
+                let rows = rows_without::<N>(j);
+                let minor = minor(&self.0, &rows[..N - 1], all_columns::<N>() & !(1 << i));
+                let cofactor = if (i + j) % 2 == 0 {
+                    minor
+                } else {
+                    T::zero() - minor
+                };
+                *term = inv_det * cofactor;
+            }
+        }
+        Some(m)
+    }
+}
+
+/// Returns the mask of the columns of an `N` by `N` matrix.
+#[inline]
+fn all_columns<const N: usize>() -> u8 {
+    debug_assert!(
+        N <= 4,
+        "the cofactor expansion is only meant for small matrices"
+    );
+    (1 << N) - 1
+}
+
+/// Returns the row indices of an `N` by `N` matrix without `skip`, followed by zeros.
+#[inline]
+fn rows_without<const N: usize>(skip: usize) -> [usize; N] {
+    let mut rows = [0; N];
+    for (r, i) in rows.iter_mut().zip((0..N).filter(|&i| i != skip)) {
+        *r = i;
+    }
+    rows
+}
+
+/// Computes the determinant of the square submatrix of `m` made of `rows` and of the
+/// columns whose bit is set in `columns`, with a cofactor expansion along its first row.
+fn minor<T, const N: usize>(m: &[[T; N]; N], rows: &[usize], columns: u8) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + One,
+{
+    let (&row, rows) = match rows.split_first() {
+        Some(split) => split,
+        None => return T::one(),
+    };
+    let mut det = None;
+    for (k, column) in (0..N).filter(|c| columns & (1 << c) != 0).enumerate() {
+        let term = m[row][column] * minor(m, rows, columns & !(1 << column));
+        det = Some(match det {
+            None => term,
+            Some(det) if k % 2 == 0 => det + term,
+            Some(det) => det - term,
+        });
+    }
+    det.unwrap()
+}
+
+/// Computes the product of the row vector `v` and the matrix `m`.
+#[inline]
+pub(crate) fn vec_mul<T, const N: usize, const K: usize>(v: [T; N], m: &Matrix<T, N, K>) -> [T; K]
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    let mut r = [v[0]; K];
+    for (j, term) in r.iter_mut().enumerate() {
+        let mut sum = v[0] * m.0[0][j];
+        for (k, v_k) in v.iter().enumerate().skip(1) {
+            sum = sum + *v_k * m.0[k][j];
+        }
+        *term = sum;
+    }
+    r
+}
+
+/// Computes the product of the row vector `v` and the matrix `m` with fused
+/// multiply-add operations.
+#[cfg(feature = "fma")]
+#[inline]
+pub(crate) fn vec_mul_fused<T, const N: usize, const K: usize>(
+    v: [T; N],
+    m: &Matrix<T, N, K>,
+) -> [T; K]
+where
+    T: Copy + Mul<Output = T> + MulAdd<Output = T>,
+{
+    let mut r = [v[0]; K];
+    for (j, term) in r.iter_mut().enumerate() {
+        let mut sum = v[0] * m.0[0][j];
+        for (k, v_k) in v.iter().enumerate().skip(1) {
+            sum = MulAdd::mul_add(*v_k, m.0[k][j], sum);
+        }
+        *term = sum;
+    }
+    r
+}
+
+/// Computes `acc + v * m` with fused multiply-add operations.
+#[cfg(feature = "fma")]
+#[inline]
+pub(crate) fn vec_mul_add<T, const N: usize, const K: usize>(
+    v: [T; N],
+    m: &Matrix<T, N, K>,
+    acc: [T; K],
+) -> [T; K]
+where
+    T: Copy + MulAdd<Output = T>,
+{
+    let mut r = acc;
+    for (j, term) in r.iter_mut().enumerate() {
+        for (k, v_k) in v.iter().enumerate() {
+            *term = MulAdd::mul_add(*v_k, m.0[k][j], *term);
+        }
+    }
+    r
+}
+
+/// Composes the affine transforms `x * a + a_t` and `x * b + b_t`, in that order.
+#[inline]
+pub(crate) fn affine_then<T, const N: usize>(
+    a: &Matrix<T, N, N>,
+    a_t: [T; N],
+    b: &Matrix<T, N, N>,
+    b_t: [T; N],
+) -> (Matrix<T, N, N>, [T; N])
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    let mut t = vec_mul(a_t, b);
+    for (term, b_term) in t.iter_mut().zip(b_t.iter()) {
+        *term = *term + *b_term;
+    }
+    (a.mul(b), t)
+}
+
+/// Computes the Moore-Penrose pseudo-inverse of `m`.
+///
+/// This goes through the singular value decomposition `U * S * V^T` of `m`, computed
+/// with the one-sided Jacobi method. It works on `m` directly rather than on `m^T * m`,
+/// which would square the condition number. The singular values that are negligible
+/// compared to the largest one are treated as zero.
+pub(crate) fn pseudo_inverse<T: RealScalar, const N: usize>(
+    m: &Matrix<T, N, N>,
+) -> Matrix<T, N, N> {
+    let (zero, one) = (T::zero(), T::one());
+    let mut v = Matrix([[zero; N]; N]);
+    for (i, row) in v.0.iter_mut().enumerate() {
+        row[i] = one;
+    }
+
+    // Rotate pairs of columns until they are all orthogonal. The columns of `a` then
+    // converge to those of `U * S`, and `v` accumulates the rotations. The method
+    // converges quadratically, so a few sweeps are enough in practice.
+    let mut a = *m;
+    for _ in 0..16 {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in p + 1..N {
+                let (mut alpha, mut beta, mut gamma) = (zero, zero, zero);
+                for row in a.0.iter() {
+                    alpha = alpha + row[p] * row[p];
+                    beta = beta + row[q] * row[q];
+                    gamma = gamma + row[p] * row[q];
+                }
+                if gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+                // Rotate the p and q columns by the angle that makes them orthogonal.
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = one.copysign(zeta) / (zeta.abs() + (zeta * zeta + one).sqrt());
+                let c = one / (t * t + one).sqrt();
+                let s = t * c;
+                for row in a.0.iter_mut().chain(v.0.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    // The singular values are the lengths of the columns of `a`.
+    let mut squared = [zero; N];
+    for (i, sq) in squared.iter_mut().enumerate() {
+        *sq = a.0.iter().fold(zero, |sum, row| sum + row[i] * row[i]);
+    }
+    let max = squared.iter().fold(zero, |max, sq| max.max(*sq)).sqrt();
+    let n = T::from(N).unwrap();
+    let tolerance = max * n * T::epsilon();
+
+    // V * S^-1 * U^T = V * S^-2 * (U * S)^T, skipping the directions in which `m` is
+    // degenerate.
+    let mut vd = v;
+    for row in vd.0.iter_mut() {
+        for (term, sq) in row.iter_mut().zip(squared.iter()) {
+            *term = if sq.sqrt() > tolerance {
+                *term / *sq
+            } else {
+                zero
+            };
+        }
+    }
+    vd.mul(&a.transpose())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul() {
+        let a = Matrix([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix([[1, 0], [0, 1], [2, -1]]);
+        assert_eq!(a.mul(&b), Matrix([[7, -1], [16, -1]]));
+        assert_eq!(a.column("Matrix", 2), [3, 6]);
+        assert_eq!(b.row("Matrix", 2), [2, -1]);
+        assert_eq!(a.transpose(), Matrix([[1, 4], [2, 5], [3, 6]]));
+    }
+
+    #[test]
+    fn test_affine_then() {
+        let a = Matrix([[2, 0], [0, 3]]);
+        let b = Matrix([[0, 1], [1, 0]]);
+        let (m, t) = affine_then(&a, [1, 2], &b, [10, 20]);
+        assert_eq!(m, Matrix([[0, 2], [3, 0]]));
+        assert_eq!(t, [12, 21]);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix([[2, 0, 0], [0, 4, 0], [6, 8, 1]]);
+        assert_eq!(m.determinant(), 8);
+        let m: Matrix<f64, 3, 3> = Matrix([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [6.0, 8.0, 1.0]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(
+            inv,
+            Matrix([[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [-3.0, -2.0, 1.0]])
+        );
+
+        let m: Matrix<f64, 4, 4> = Matrix([
+            [1.0, 2.0, 0.0, 1.0],
+            [0.0, 1.0, 3.0, 0.0],
+            [2.0, 0.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0, 2.0],
+        ]);
+        let p = m.mul(&m.inverse().unwrap());
+        for (i, row) in p.0.iter().enumerate() {
+            for (j, term) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((term - expected).abs() < 1e-12, "{:?}", p);
+            }
+        }
+
+        assert_eq!(Matrix([[1.0]]).inverse(), Some(Matrix([[1.0]])));
+        assert_eq!(Matrix([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        // A rank 2 matrix, whose pseudo-inverse satisfies the Penrose conditions.
+        let m: Matrix<f64, 3, 3> = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let p = pseudo_inverse(&m);
+        let close = |a: Matrix<f64, 3, 3>, b: Matrix<f64, 3, 3>| {
+            a.0.iter()
+                .flatten()
+                .zip(b.0.iter().flatten())
+                .all(|(a, b)| (a - b).abs() < 1e-9)
+        };
+        assert!(close(m.mul(&p).mul(&m), m));
+        assert!(close(p.mul(&m).mul(&p), p));
+        assert!(close(m.mul(&p).transpose(), m.mul(&p)));
+        assert!(close(p.mul(&m).transpose(), p.mul(&m)));
+
+        let zero = Matrix([[0.0f32; 2]; 2]);
+        assert_eq!(pseudo_inverse(&zero), zero);
+
+        // A well-conditioned anisotropic f32 matrix keeps all of its directions.
+        let m: Matrix<f32, 4, 4> = Matrix([
+            [1000.0, 0.0, 0.0, 0.0],
+            [0.0, 1000.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [5.0, 6.0, 7.0, 1.0],
+        ]);
+        let p = vec_mul(vec_mul([1.0, 1.0, 1.0, 1.0], &m), &pseudo_inverse(&m));
+        for (a, b) in p.iter().zip([1.0, 1.0, 1.0, 1.0].iter()) {
+            assert!((a - b).abs() < 1e-4, "{:?}", p);
+        }
+    }
+
+    #[cfg(feature = "fma")]
+    #[test]
+    fn test_fused() {
+        let a = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix([[1.0, 0.0], [0.0, 1.0], [2.0, -1.0]]);
+        assert_eq!(a.mul_fused(&b), a.mul(&b));
+        assert_eq!(vec_mul_add([1.0, 2.0, 3.0], &b, [0.5, 0.5]), [7.5, -0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix index out of bounds: (0, 3)")]
+    fn test_out_of_bounds() {
+        Matrix([[1, 2, 3]]).column("Matrix", 3);
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Debug assertions against NaN results.
+//!
+//! With the `nan-checks` feature, the operations that can turn finite inputs into NaN
+//! panic in debug builds when they produce one, naming the operation. This points at the
+//! origin of a NaN instead of wherever it is eventually noticed. Release builds are not
+//! affected.
+//!
+//! The checked operations are:
+//!
+//! - `Vector2D::normalize`, `Vector3D::normalize` and `Rotation3D::normalize`, and the
+//!   methods built on them, like `with_length`,
+//! - `Vector2D::with_min_length` and `Vector3D::with_min_length`,
+//! - `Rotation3D::slerp`,
+//! - `Transform2D::inverse` and `Transform3D::inverse`,
+//! - the projective divisions of `HomogeneousVector::to_point2d`,
+//!   `HomogeneousVector::to_point3d` and `Transform3D::transform_point2d`, which back
+//!   the point transforms of `Transform3D`.
+//!
+//! Constructors and arithmetic operators are not checked: the constructors are `const`,
+//! and the operators would need a `PartialEq` bound they don't have. Their results are
+//! NaN only if an operand already is, or after an infinite intermediate value.
+//!
+//! The checks compare values with themselves, so they work for any scalar implementing
+//! `PartialEq`. Infinite values are not reported, since they are legitimate results of
+//! some operations, like the projection of points far behind the camera.
+//!
+//! Code that expects NaN values can opt out of the checks with [`allow_nan`].
+
+#[cfg(feature = "nan-checks")]
+extern crate std;
+
+#[cfg(feature = "nan-checks")]
+use core::cell::Cell;
+
+#[cfg(feature = "nan-checks")]
+std::thread_local! {
+    static ALLOWED: Cell<u32> = Cell::new(0);
+}
+
+/// Runs `f` with the checks of the `nan-checks` feature disabled on the current thread.
+///
+/// This does nothing special without the feature, so it can be called unconditionally.
+///
+/// ```
+/// use euclid::default::Vector2D;
+///
+/// let v: Vector2D<f32> = euclid::allow_nan(|| Vector2D::zero().normalize());
+/// assert!(v.x.is_nan());
+/// ```
+pub fn allow_nan<R, F: FnOnce() -> R>(f: F) -> R {
+    #[cfg(feature = "nan-checks")]
+    {
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                ALLOWED.with(|a| a.set(a.get() - 1));
+            }
+        }
+        ALLOWED.with(|a| a.set(a.get() + 1));
+        let _guard = Guard;
+        f()
+    }
+    #[cfg(not(feature = "nan-checks"))]
+    f()
+}
+
+/// Panics if `value` is NaN, unless called from within [`allow_nan`].
+#[cfg(feature = "nan-checks")]
+#[allow(clippy::eq_op)]
+#[track_caller]
+pub(crate) fn check<T: PartialEq>(value: &T, what: &str) {
+    if value != value && ALLOWED.with(|a| a.get() == 0) {
+        panic!("{} produced a NaN", what);
+    }
+}
+
+#[cfg(all(test, feature = "nan-checks", debug_assertions))]
+mod tests {
+    use super::*;
+    use crate::default::{Transform3D, Vector3D};
+    use crate::point3;
+
+    #[test]
+    #[should_panic(expected = "Vector3D::normalize produced a NaN")]
+    fn test_normalize() {
+        let _ = Vector3D::<f32>::zero().normalize();
+    }
+
+    #[test]
+    #[should_panic(expected = "HomogeneousVector::to_point3d produced a NaN")]
+    fn test_transform() {
+        let m = Transform3D::<f64>::scale(f64::INFINITY, 1.0, 1.0);
+        let _ = m.transform_point3d(point3(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector3D::with_min_length produced a NaN")]
+    fn test_with_min_length() {
+        let _ = Vector3D::<f32>::zero().with_min_length(1.0);
+    }
+
+    #[test]
+    fn test_allow_nan() {
+        let v = allow_nan(|| Vector3D::<f32>::zero().normalize());
+        assert!(v.x.is_nan());
+        assert!(std::panic::catch_unwind(|| Vector3D::<f32>::zero().normalize()).is_err());
+    }
+}
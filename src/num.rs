@@ -8,6 +8,10 @@
 // except according to those terms.
 //! A one-dimensional length, tagged with its units.
 
+use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
+use crate::trig::Trig;
+use core::ops::{Add, Div};
 use num_traits;
 
 // Euclid has its own Zero and One traits instead of of using the num_traits equivalents.
@@ -45,6 +49,70 @@ impl<T: num_traits::One> One for T {
     }
 }
 
+/// The zero and one of the primitive types, as constants.
+///
+/// This backs the generic `IDENTITY` constants, and lives in a private module so that it
+/// can't be implemented outside of euclid.
+pub trait ConstZeroOne: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! const_zero_one {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl ConstZeroOne for $ty {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        }
+    };
+}
+
+primitive_consts!(const_zero_one);
+
+/// The multiples of π used by [`Angle`](crate::Angle).
+///
+/// Like `Zero` and `One`, this is automatically implemented for all types that implement
+/// the `num_traits::FloatConst` counterpart. Scalar types that can't provide every
+/// floating point constant, such as fixed-point numbers, can implement it directly to
+/// construct and normalize angles.
+pub trait Pi {
+    fn pi() -> Self;
+    fn frac_pi_2() -> Self;
+    fn frac_pi_3() -> Self;
+    fn frac_pi_4() -> Self;
+}
+
+impl<T: num_traits::FloatConst> Pi for T {
+    fn pi() -> T {
+        T::PI()
+    }
+    fn frac_pi_2() -> T {
+        T::FRAC_PI_2()
+    }
+    fn frac_pi_3() -> T {
+        T::FRAC_PI_3()
+    }
+    fn frac_pi_4() -> T {
+        T::FRAC_PI_4()
+    }
+}
+
+/// Halving, used to compute centers.
+///
+/// This is automatically implemented for types that implement `num_traits::One`, `Add`
+/// and `Div` by dividing by `one() + one()`. Scalar types without a division, or with a
+/// cheaper or exact way to halve a value, such as a shift for fixed-point numbers, can
+/// implement it directly.
+pub trait Half {
+    fn half(self) -> Self;
+}
+
+impl<T: num_traits::One + Add<Output = T> + Div<Output = T>> Half for T {
+    fn half(self) -> T {
+        self / (T::one() + T::one())
+    }
+}
+
 /// Defines the nearest integer value to the original value.
 pub trait Round: Copy {
     /// Rounds to the nearest integer value.
@@ -70,6 +138,56 @@ pub trait Ceil: Copy {
     fn ceil(self) -> Self;
 }
 
+/// The value halfway between two values, used to compute centers.
+///
+/// For integers, this is rounded towards negative infinity. Unlike `(a + b) / 2`, this
+/// doesn't overflow for integers close to the limits of their type, nor for floats
+/// close to `MAX`.
+pub trait Midpoint {
+    fn midpoint(self, other: Self) -> Self;
+}
+
+macro_rules! int_midpoint {
+    ($($ty:ty),*) => {$(
+        impl Midpoint for $ty {
+            /// The common bits plus half of the differing ones.
+            #[inline]
+            fn midpoint(self, other: $ty) -> $ty {
+                (self & other) + ((self ^ other) >> 1)
+            }
+        }
+    )*};
+}
+
+int_midpoint!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! float_midpoint {
+    ($($ty:ident),*) => {$(
+        impl Midpoint for $ty {
+            /// Halves the terms first when their sum could overflow.
+            #[inline]
+            fn midpoint(self, other: $ty) -> $ty {
+                const HI: $ty = $ty::MAX / 2.0;
+                if num_traits::Float::abs(self) <= HI && num_traits::Float::abs(other) <= HI {
+                    (self + other) / 2.0
+                } else {
+                    self / 2.0 + other / 2.0
+                }
+            }
+        }
+    )*};
+}
+
+float_midpoint!(f32, f64);
+
+#[cfg(feature = "half")]
+impl Midpoint for half::f16 {
+    #[inline]
+    fn midpoint(self, other: half::f16) -> half::f16 {
+        half::f16::from_f32((self.to_f32() + other.to_f32()) / 2.0)
+    }
+}
+
 macro_rules! num_int {
     ($ty:ty) => {
         impl Round for $ty {
@@ -126,3 +244,247 @@ num_int!(isize);
 num_int!(usize);
 num_float!(f32);
 num_float!(f64);
+
+/// The bounds needed by most of the floating point operations of euclid.
+///
+/// This is automatically implemented for `f32`, `f64` and any other type that satisfies
+/// the bounds, so that generic code can be written as `fn foo<T: euclid::RealScalar, U>(..)`
+/// instead of repeating them.
+///
+/// ```
+/// use euclid::default::{Point2D, Vector2D};
+/// use euclid::RealScalar;
+///
+/// fn direction<T: RealScalar>(from: Point2D<T>, to: Point2D<T>) -> Vector2D<T> {
+///     (to - from).normalize()
+/// }
+///
+/// assert_eq!(direction(Point2D::new(1.0, 1.0), Point2D::new(1.0, 3.0)), Vector2D::new(0.0, 1.0));
+/// ```
+pub trait RealScalar:
+    num_traits::Float
+    + num_traits::FloatConst
+    + Trig
+    + ApproxEq<Self>
+    + Round
+    + Floor
+    + Ceil
+    + Default
+    + core::fmt::Debug
+    + SimdScalar
+    + Midpoint
+{
+}
+
+impl<T> RealScalar for T where
+    T: num_traits::Float
+        + num_traits::FloatConst
+        + Trig
+        + ApproxEq<T>
+        + Round
+        + Floor
+        + Ceil
+        + Default
+        + core::fmt::Debug
+        + SimdScalar
+        + Midpoint
+{
+}
+
+#[cfg(feature = "half")]
+impl Round for half::f16 {
+    #[inline]
+    fn round(self) -> Self {
+        // Add in `f32`, since the `f16` sum rounds once `|self| >= 1024`.
+        half::f16::from_f32(num_traits::Float::floor(self.to_f32() + 0.5))
+    }
+}
+#[cfg(feature = "half")]
+impl Floor for half::f16 {
+    #[inline]
+    fn floor(self) -> Self {
+        num_traits::Float::floor(self)
+    }
+}
+#[cfg(feature = "half")]
+impl Ceil for half::f16 {
+    #[inline]
+    fn ceil(self) -> Self {
+        num_traits::Float::ceil(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default::{Point2D, Rect};
+    use crate::{point2, rect, Angle, Trig};
+    use core::ops::{Add, Mul, Rem, Sub};
+
+    /// A 16.16 fixed-point number, which doesn't implement `num_traits::Float`.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Fixed(i32);
+
+    const FRAC: f64 = 65536.0;
+
+    impl Fixed {
+        fn from_f64(v: f64) -> Self {
+            Fixed((v * FRAC).round() as i32)
+        }
+        fn to_f64(self) -> f64 {
+            self.0 as f64 / FRAC
+        }
+    }
+
+    impl Add for Fixed {
+        type Output = Fixed;
+        fn add(self, other: Fixed) -> Fixed {
+            Fixed(self.0 + other.0)
+        }
+    }
+    impl Sub for Fixed {
+        type Output = Fixed;
+        fn sub(self, other: Fixed) -> Fixed {
+            Fixed(self.0 - other.0)
+        }
+    }
+    impl Mul for Fixed {
+        type Output = Fixed;
+        fn mul(self, other: Fixed) -> Fixed {
+            Fixed(((self.0 as i64 * other.0 as i64) >> 16) as i32)
+        }
+    }
+    impl Rem for Fixed {
+        type Output = Fixed;
+        fn rem(self, other: Fixed) -> Fixed {
+            Fixed(self.0 % other.0)
+        }
+    }
+    impl num_traits::Zero for Fixed {
+        fn zero() -> Fixed {
+            Fixed(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+    impl num_traits::One for Fixed {
+        fn one() -> Fixed {
+            Fixed(1 << 16)
+        }
+    }
+    impl Round for Fixed {
+        fn round(self) -> Fixed {
+            Fixed((self.0 + (1 << 15)) & !0xffff)
+        }
+    }
+    impl Floor for Fixed {
+        fn floor(self) -> Fixed {
+            Fixed(self.0 & !0xffff)
+        }
+    }
+    impl Ceil for Fixed {
+        fn ceil(self) -> Fixed {
+            Fixed((self.0 + 0xffff) & !0xffff)
+        }
+    }
+    impl Pi for Fixed {
+        fn pi() -> Fixed {
+            Fixed(205887)
+        }
+        fn frac_pi_2() -> Fixed {
+            Fixed(102944)
+        }
+        fn frac_pi_3() -> Fixed {
+            Fixed(68629)
+        }
+        fn frac_pi_4() -> Fixed {
+            Fixed(51472)
+        }
+    }
+    impl Half for Fixed {
+        fn half(self) -> Fixed {
+            Fixed(self.0 >> 1)
+        }
+    }
+    impl Midpoint for Fixed {
+        fn midpoint(self, other: Fixed) -> Fixed {
+            Fixed(Midpoint::midpoint(self.0, other.0))
+        }
+    }
+    impl Trig for Fixed {
+        fn sin(self) -> Fixed {
+            Fixed::from_f64(self.to_f64().sin())
+        }
+        fn cos(self) -> Fixed {
+            Fixed::from_f64(self.to_f64().cos())
+        }
+        fn tan(self) -> Fixed {
+            Fixed::from_f64(self.to_f64().tan())
+        }
+        fn fast_atan2(y: Fixed, x: Fixed) -> Fixed {
+            Fixed::from_f64(y.to_f64().atan2(x.to_f64()))
+        }
+        fn degrees_to_radians(deg: Fixed) -> Fixed {
+            Fixed::from_f64(deg.to_f64().to_radians())
+        }
+        fn radians_to_degrees(rad: Fixed) -> Fixed {
+            Fixed::from_f64(rad.to_f64().to_degrees())
+        }
+    }
+
+    fn fx(v: f64) -> Fixed {
+        Fixed::from_f64(v)
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let p: Point2D<Fixed> = point2(fx(1.25), fx(-2.5));
+        assert_eq!(p.round(), point2(fx(1.0), fx(-2.0)));
+        assert_eq!(p.floor(), point2(fx(1.0), fx(-3.0)));
+        assert_eq!(p.ceil(), point2(fx(2.0), fx(-2.0)));
+
+        let r: Rect<Fixed> = rect(fx(0.5), fx(0.25), fx(1.0), fx(1.5));
+        assert_eq!(r.round_out(), rect(fx(0.0), fx(0.0), fx(2.0), fx(2.0)));
+        assert_eq!(r.center(), point2(fx(1.0), fx(1.0)));
+        assert_eq!(r.to_box2d().center(), r.center());
+
+        assert_eq!(Angle::<Fixed>::frac_pi_2().get(), fx(90.0_f64.to_radians()));
+        assert_eq!(Angle::degrees(fx(90.0)).get(), Fixed::frac_pi_2());
+        let a = Angle::radians(Fixed(-Fixed::frac_pi_2().0));
+        assert_eq!(a.positive(), Angle::two_pi() - Angle::frac_pi_2());
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_f16() {
+        use crate::approxeq::ApproxEq;
+        use half::f16;
+
+        let p = point2::<_, crate::UnknownUnit>(1.5f32, -2.25).to_f16();
+        assert_eq!(p, point2(f16::from_f32(1.5), f16::from_f32(-2.25)));
+        assert_eq!(p.round().to_f32(), point2(2.0, -2.0));
+        assert_eq!(p.floor().to_f32(), point2(1.0, -3.0));
+        assert_eq!(Round::round(f16::from_f32(1025.0)), f16::from_f32(1025.0));
+        assert_eq!(Round::round(f16::from_f32(-1025.0)), f16::from_f32(-1025.0));
+
+        let a = Angle::degrees(f16::from_f32(90.0));
+        assert!(a
+            .get()
+            .approx_eq(&f16::from_f32(core::f32::consts::FRAC_PI_2)));
+        assert!(Angle::<f16>::frac_pi_2().approx_eq(&a));
+    }
+
+    fn rotate_and_snap<T: super::RealScalar, U>(v: crate::Vector2D<T, U>) -> crate::Vector2D<T, U> {
+        let rotated = crate::Rotation2D::<T, U, U>::new(Angle::frac_pi_2()).transform_vector(v);
+        assert!(rotated.length().approx_eq(&v.length()));
+        rotated.round()
+    }
+
+    #[test]
+    fn test_real() {
+        let v: crate::default::Vector2D<f32> = crate::vec2(2.0, 0.0);
+        assert_eq!(rotate_and_snap(v), crate::vec2(0.0, 2.0));
+        assert_eq!(rotate_and_snap(v.to_f64()), crate::vec2(0.0, 2.0));
+    }
+}
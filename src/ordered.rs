@@ -0,0 +1,209 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A total order on the euclid types, for use as map keys.
+
+use crate::{Box2D, Box3D, Point2D, Point3D, Rect, Size2D, Size3D, Vector2D, Vector3D};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A scalar with a total order and a hash consistent with it.
+///
+/// For floating point numbers, `-0.0` and `0.0` are equal, and all NaNs are equal to
+/// each other and greater than any other value, including infinity.
+pub trait OrderedScalar {
+    /// Compares two values in the total order.
+    fn cmp_ordered(&self, other: &Self) -> Ordering;
+
+    /// Feeds the value into a hasher, such that equal values hash the same.
+    fn hash_ordered<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! ordered_float {
+    ($($ty:ty),+) => {$(
+        impl OrderedScalar for $ty {
+            #[inline]
+            fn cmp_ordered(&self, other: &Self) -> Ordering {
+                match self.partial_cmp(other) {
+                    Some(ordering) => ordering,
+                    None => self.is_nan().cmp(&other.is_nan()),
+                }
+            }
+
+            #[inline]
+            fn hash_ordered<H: Hasher>(&self, state: &mut H) {
+                let canonical = if self.is_nan() {
+                    <$ty>::NAN
+                } else if *self == 0.0 {
+                    0.0
+                } else {
+                    *self
+                };
+                canonical.to_bits().hash(state);
+            }
+        }
+    )+};
+}
+
+ordered_float!(f32, f64);
+
+macro_rules! ordered_int {
+    ($($ty:ty),+) => {$(
+        impl OrderedScalar for $ty {
+            #[inline]
+            fn cmp_ordered(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+
+            #[inline]
+            fn hash_ordered<H: Hasher>(&self, state: &mut H) {
+                self.hash(state);
+            }
+        }
+    )+};
+}
+
+ordered_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Orders a value lexicographically by its components, so that it can be used as a
+/// key of a `BTreeMap` or `HashMap`, or sorted and deduplicated.
+///
+/// The components are compared in declaration order, for example `x` then `y` for a
+/// point, or `origin` then `size` for a rectangle. Floating point components follow
+/// the policy of [`OrderedScalar`].
+///
+/// ```
+/// use euclid::default::Point2D;
+/// use euclid::{point2, Lexicographic};
+/// use std::collections::BTreeSet;
+///
+/// let points: Vec<Point2D<f32>> = vec![
+///     point2(1.0, 2.0),
+///     point2(0.0, f32::NAN),
+///     point2(-0.0, 3.0),
+///     point2(1.0, 2.0),
+///     point2(0.0, f32::NAN),
+/// ];
+/// let unique: BTreeSet<_> = points.into_iter().map(Lexicographic).collect();
+/// assert_eq!(unique.len(), 3);
+/// assert_eq!(unique.iter().next().unwrap().0, point2(0.0, 3.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lexicographic<V>(pub V);
+
+macro_rules! lexicographic {
+    ($($ty:ident [$($($field:ident).+),+]),+) => {$(
+        impl<T: OrderedScalar, U> PartialEq for Lexicographic<$ty<T, U>> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl<T: OrderedScalar, U> Eq for Lexicographic<$ty<T, U>> {}
+
+        impl<T: OrderedScalar, U> PartialOrd for Lexicographic<$ty<T, U>> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: OrderedScalar, U> Ord for Lexicographic<$ty<T, U>> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                Ordering::Equal
+                    $(.then_with(|| self.0.$($field).+.cmp_ordered(&other.0.$($field).+)))+
+            }
+        }
+
+        impl<T: OrderedScalar, U> Hash for Lexicographic<$ty<T, U>> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $(self.0.$($field).+.hash_ordered(state);)+
+            }
+        }
+    )+};
+}
+
+lexicographic!(
+    Point2D[x, y],
+    Point3D[x, y, z],
+    Vector2D[x, y],
+    Vector3D[x, y, z],
+    Size2D[width, height],
+    Size3D[width, height, depth],
+    Rect[origin.x, origin.y, size.width, size.height],
+    Box2D[min.x, min.y, max.x, max.y],
+    Box3D[min.x, min.y, min.z, max.x, max.y, max.z]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{point2, rect, size2};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeMap, HashSet};
+
+    fn hash<V: Hash>(v: &V) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_scalar_order() {
+        let values = [
+            f32::NEG_INFINITY,
+            -1.0,
+            -0.0,
+            0.0,
+            1.0,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                let expected = match (i, j) {
+                    (2, 3) | (3, 2) => Ordering::Equal,
+                    _ => i.cmp(&j),
+                };
+                assert_eq!(a.cmp_ordered(b), expected, "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lexicographic() {
+        let a: default::Point2D<f64> = point2(1.0, f64::NAN);
+        let b = point2(1.0, -f64::NAN);
+        let c = point2(1.0, 5.0);
+        assert_eq!(Lexicographic(a), Lexicographic(b));
+        assert_eq!(hash(&Lexicographic(a)), hash(&Lexicographic(b)));
+        assert!(Lexicographic(c) < Lexicographic(a));
+        assert_eq!(
+            hash(&Lexicographic(point2::<f64, ()>(0.0, 0.0))),
+            hash(&Lexicographic(point2::<f64, ()>(-0.0, 0.0)))
+        );
+
+        let set: HashSet<_> = [a, b, c].iter().copied().map(Lexicographic).collect();
+        assert_eq!(set.len(), 2);
+
+        let mut sizes: BTreeMap<Lexicographic<default::Size2D<i32>>, &str> = BTreeMap::new();
+        sizes.insert(Lexicographic(size2(2, 1)), "wide");
+        sizes.insert(Lexicographic(size2(1, 2)), "tall");
+        assert_eq!(
+            sizes.values().copied().collect::<Vec<_>>(),
+            ["tall", "wide"]
+        );
+
+        let r: default::Rect<f32> = rect(0.0, 0.0, 1.0, 2.0);
+        assert!(Lexicographic(r) < Lexicographic(rect(0.0, 0.0, 2.0, 1.0)));
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::vector::{vec2, vec3, Vector2D, Vector3D};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Neg};
+
+/// A plane in 3d space, defined by a unit normal and an offset.
+///
+/// The points of the plane are the points `p` for which `normal.dot(p) + offset` is zero.
+/// That expression is the signed distance from the plane, positive on the side the
+/// normal points towards, which is called the front of the plane.
+///
+/// The normal is expected to be normalized. Constructing a plane with [`Plane::new`]
+/// doesn't check it, and distances are scaled by the length of the normal otherwise.
+///
+/// ```
+/// use euclid::default::{Box3D, Plane};
+/// use euclid::{point3, PlaneSide};
+///
+/// let plane = Plane::from_points(
+///     point3(0.0, 0.0, 1.0),
+///     point3(1.0, 0.0, 1.0),
+///     point3(0.0, 1.0, 1.0),
+/// ).unwrap();
+/// assert_eq!(plane.signed_distance(point3(5.0, 3.0, 4.0)), 3.0);
+///
+/// let b = Box3D::new(point3(0.0, 0.0, 2.0), point3(1.0, 1.0, 3.0));
+/// assert_eq!(plane.classify_box(&b), PlaneSide::Front);
+/// ```
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Plane<T, U> {
+    pub normal: Vector3D<T, U>,
+    pub offset: T,
+}
+
+/// A line in 2d space, splitting it into two half-planes.
+///
+/// This is the 2d version of [`Plane`], with the same conventions: the line is where
+/// `normal.dot(p) + offset` is zero, and its front is the half-plane the normal points
+/// towards.
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Plane2D<T, U> {
+    pub normal: Vector2D<T, U>,
+    pub offset: T,
+}
+
+/// Where a shape is relative to a [`Plane`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlaneSide {
+    /// Entirely on the side the normal points towards.
+    Front,
+    /// Entirely on the other side.
+    Back,
+    /// Crossing or touching the plane.
+    Intersecting,
+}
+
+macro_rules! plane_impls {
+    ($name:ident, $point:ident, $vector:ident) => {
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.normal.hash(h);
+                self.offset.hash(h);
+            }
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                Self::new(self.normal.clone(), self.offset.clone())
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.normal.eq(&other.normal) && self.offset.eq(&other.offset)
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.normal)
+                    .field(&self.offset)
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a, T, U> arbitrary::Arbitrary<'a> for $name<T, U>
+        where
+            T: arbitrary::Arbitrary<'a>,
+        {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok($name::new(
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                ))
+            }
+        }
+
+        impl<T, U> $name<T, U> {
+            /// Constructor, taking a unit normal.
+            #[inline]
+            pub const fn new(normal: $vector<T, U>, offset: T) -> Self {
+                $name { normal, offset }
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// Returns the signed distance from the plane to the point, which is positive in
+            /// front of the plane.
+            #[inline]
+            pub fn signed_distance(&self, p: $point<T, U>) -> T
+            where
+                T: Add<Output = T> + Mul<Output = T>,
+            {
+                self.normal.dot(p.to_vector()) + self.offset
+            }
+
+            /// Returns the same plane, facing the other way.
+            #[inline]
+            pub fn flip(&self) -> Self
+            where
+                T: Neg<Output = T>,
+            {
+                $name::new(-self.normal, -self.offset)
+            }
+
+            /// Drop the units, preserving only the numeric value.
+            #[inline]
+            pub fn to_untyped(&self) -> $name<T, UnknownUnit> {
+                $name::new(self.normal.to_untyped(), self.offset)
+            }
+
+            /// Tag a unitless value with units.
+            #[inline]
+            pub fn from_untyped(p: &$name<T, UnknownUnit>) -> Self {
+                $name::new($vector::from_untyped(p.normal), p.offset)
+            }
+        }
+
+        impl<T: RealScalar, U> $name<T, U> {
+            /// Returns the plane going through `point` and perpendicular to `normal`, or
+            /// `None` if the normal is zero.
+            ///
+            /// The normal doesn't need to be normalized.
+            #[inline]
+            pub fn from_point_and_normal(
+                point: $point<T, U>,
+                normal: $vector<T, U>,
+            ) -> Option<Self> {
+                let normal = normal.try_normalize()?;
+                Some($name::new(normal, -normal.dot(point.to_vector())))
+            }
+
+            /// Returns the point of the plane closest to `p`.
+            #[inline]
+            pub fn project_point(&self, p: $point<T, U>) -> $point<T, U> {
+                p - self.normal * self.signed_distance(p)
+            }
+        }
+    };
+}
+
+plane_impls!(Plane, Point3D, Vector3D);
+plane_impls!(Plane2D, Point2D, Vector2D);
+
+impl<T: RealScalar, U> Plane<T, U> {
+    /// Returns the plane going through the three points, or `None` if they are on a line.
+    ///
+    /// The front of the plane is the side from which the points go counter-clockwise in
+    /// a right-handed coordinate system, as with [`Triangle3D::normal`].
+    ///
+    /// [`Triangle3D::normal`]: crate::Triangle3D::normal
+    #[inline]
+    pub fn from_points(a: Point3D<T, U>, b: Point3D<T, U>, c: Point3D<T, U>) -> Option<Self> {
+        Self::from_point_and_normal(a, (b - a).cross(c - a))
+    }
+
+    /// Returns which side of the plane the box is on.
+    ///
+    /// A box touching the plane counts as intersecting it, and a negative box is
+    /// classified by its center.
+    pub fn classify_box(&self, b: &Box3D<T, U>) -> PlaneSide {
+        let two = T::one() + T::one();
+        let half_extents = ((b.max - b.min) / two).max(Vector3D::zero());
+        // The largest distance from the center of the box to a corner, along the normal.
+        let n = self.normal;
+        let radius = half_extents.dot(vec3(n.x.abs(), n.y.abs(), n.z.abs()));
+        let distance = self.signed_distance(b.center());
+        if distance > radius {
+            PlaneSide::Front
+        } else if distance < -radius {
+            PlaneSide::Back
+        } else {
+            PlaneSide::Intersecting
+        }
+    }
+}
+
+impl<T: RealScalar, U> Plane2D<T, U> {
+    /// Returns the line going through `a` and `b`, or `None` if they are the same point.
+    ///
+    /// The front of the line is on the left when going from `a` to `b` with the y axis
+    /// pointing up, which is the inside of a
+    /// [`CounterClockwise`](crate::Winding::CounterClockwise) polygon.
+    #[inline]
+    pub fn from_points(a: Point2D<T, U>, b: Point2D<T, U>) -> Option<Self> {
+        let d = b - a;
+        Self::from_point_and_normal(a, vec2(-d.y, d.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlaneSide;
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box3D, Plane, Plane2D};
+    use crate::{point2, point3, vec2, vec3};
+
+    #[test]
+    fn test_plane() {
+        let plane = Plane::from_point_and_normal(point3(0.0, 2.0, 0.0), vec3(0.0, 3.0, 0.0));
+        let plane = plane.unwrap();
+        assert_eq!(plane, Plane::new(vec3(0.0, 1.0, 0.0), -2.0));
+        assert_eq!(plane.signed_distance(point3(7.0, 5.0, -1.0)), 3.0);
+        assert_eq!(plane.signed_distance(point3(7.0, -1.0, -1.0)), -3.0);
+        assert_eq!(plane.flip().signed_distance(point3(7.0, -1.0, -1.0)), 3.0);
+        assert_eq!(
+            plane.project_point(point3(7.0, 5.0, -1.0)),
+            point3(7.0, 2.0, -1.0)
+        );
+        assert_eq!(
+            Plane::from_point_and_normal(point3(1.0, 1.0, 1.0), vec3(0.0, 0.0, 0.0)),
+            None
+        );
+
+        let a = point3(1.0, 0.0, 0.0);
+        let b = point3(0.0, 1.0, 0.0);
+        let c = point3(0.0, 0.0, 1.0);
+        let plane = Plane::from_points(a, b, c).unwrap();
+        for p in [a, b, c] {
+            assert!(plane.signed_distance(p).approx_eq(&0.0));
+        }
+        assert!(plane.signed_distance(point3(0.0, 0.0, 0.0)) < 0.0);
+        assert!(plane.normal.length().approx_eq(&1.0));
+        let reversed = Plane::from_points(a, c, b).unwrap();
+        assert!(reversed.normal.approx_eq(&-plane.normal));
+        assert_eq!(Plane::from_points(a, a * 2.0, a * 3.0), None);
+    }
+
+    #[test]
+    fn test_classify_box() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 2.0, 2.0));
+        let plane = Plane::new(vec3(1.0, 0.0, 0.0), -1.0);
+        assert_eq!(plane.classify_box(&b), PlaneSide::Intersecting);
+        assert_eq!(
+            Plane::new(vec3(1.0, 0.0, 0.0), 1.0).classify_box(&b),
+            PlaneSide::Front
+        );
+        assert_eq!(
+            Plane::new(vec3(-1.0, 0.0, 0.0), 3.0).classify_box(&b),
+            PlaneSide::Front
+        );
+        assert_eq!(
+            Plane::new(vec3(1.0, 0.0, 0.0), -3.0).classify_box(&b),
+            PlaneSide::Back
+        );
+        // Touching.
+        assert_eq!(
+            Plane::new(vec3(1.0, 0.0, 0.0), -2.0).classify_box(&b),
+            PlaneSide::Intersecting
+        );
+
+        // Diagonal plane going through (3, 0, 0), (0, 3, 0) and (0, 0, 3). Only the
+        // (2, 2, 2) corner is in front of it.
+        let diagonal = Plane::from_points(
+            point3(3.0, 0.0, 0.0),
+            point3(0.0, 3.0, 0.0),
+            point3(0.0, 0.0, 3.0),
+        )
+        .unwrap();
+        assert_eq!(diagonal.classify_box(&b), PlaneSide::Intersecting);
+        let near = Box3D::new(point3(0.0, 0.0, 0.0), point3(0.9, 0.9, 0.9));
+        assert_eq!(diagonal.classify_box(&near), PlaneSide::Back);
+        assert_eq!(diagonal.flip().classify_box(&near), PlaneSide::Front);
+        let negative = Box3D::new(point3(2.5, 2.5, 2.5), point3(-1.0, -1.0, -1.0));
+        assert_eq!(plane.classify_box(&negative), PlaneSide::Back);
+    }
+
+    #[test]
+    fn test_plane2d() {
+        let line = Plane2D::from_points(point2(0.0, 1.0), point2(4.0, 1.0)).unwrap();
+        assert_eq!(line, Plane2D::new(vec2(0.0, 1.0), -1.0));
+        assert_eq!(line.signed_distance(point2(3.0, 4.0)), 3.0);
+        assert_eq!(line.flip().signed_distance(point2(3.0, 4.0)), -3.0);
+        assert_eq!(line.project_point(point2(3.0, 4.0)), point2(3.0, 1.0));
+        assert_eq!(
+            Plane2D::from_points(point2(1.0, 1.0), point2(1.0, 1.0)),
+            None
+        );
+
+        let diagonal = Plane2D::from_point_and_normal(point2(1.0, 1.0), vec2(1.0, 1.0));
+        let d = diagonal.unwrap().signed_distance(point2(2.0, 2.0));
+        assert!(d.approx_eq(&2.0f64.sqrt()));
+    }
+}
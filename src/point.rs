@@ -9,29 +9,34 @@
 
 use super::UnknownUnit;
 use crate::approxeq::ApproxEq;
-use crate::approxord::{max, min};
+use crate::approxord::{abs_diff, max, min};
+use crate::batch::SimdScalar;
+use crate::cast::{cast_component, round_component, saturate_component, CastError, RoundingMode};
 use crate::length::Length;
 use crate::num::*;
-use crate::scale::Scale;
+use crate::scale::{Scale, Scale2D};
 use crate::size::{Size2D, Size3D};
 use crate::vector::{vec2, vec3, Vector2D, Vector3D};
 use core::cmp::{Eq, PartialEq};
 use core::fmt;
 use core::hash::Hash;
 use core::marker::PhantomData;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-#[cfg(feature = "mint")]
-use mint;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
 use num_traits::real::Real;
-use num_traits::{Euclid, Float, NumCast};
-#[cfg(feature = "serde")]
-use serde;
+use num_traits::{Bounded, Euclid, Float, NumCast, WrappingAdd, WrappingMul, WrappingSub};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
 /// A 2d Point tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Point2D<T, U> {
     pub x: T,
     pub y: T,
@@ -60,7 +65,9 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (x, y) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None];
+        crate::serde_fields::deserialize(deserializer, "Point2D", &["x", "y"], &mut values)?;
+        let [x, y] = values.map(Option::unwrap);
         Ok(Point2D {
             x,
             y,
@@ -78,7 +85,7 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.x, &self.y).serialize(serializer)
+        crate::serde_fields::serialize(serializer, "Point2D", &["x", "y"], &[&self.x, &self.y])
     }
 }
 
@@ -125,6 +132,8 @@ where
 }
 
 mint_vec!(Point2D[x, y] = Point2);
+nalgebra_vec!(Point2D[x, y] = Point2);
+glam_vec!(Point2D[x, y] = Vec2);
 
 impl<T: fmt::Debug, U> fmt::Debug for Point2D<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -138,6 +147,22 @@ impl<T: Default, U> Default for Point2D<T, U> {
     }
 }
 
+macro_rules! point_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<U> Point2D<$ty, U> {
+            /// The origin, with all coordinates set to zero.
+            pub const ORIGIN: Self = point2($zero, $zero);
+        }
+
+        impl<U> Point3D<$ty, U> {
+            /// The origin, with all coordinates set to zero.
+            pub const ORIGIN: Self = point3($zero, $zero, $zero);
+        }
+    };
+}
+
+primitive_consts!(point_consts);
+
 impl<T, U> Point2D<T, U> {
     /// Constructor, setting all components to zero.
     #[inline]
@@ -167,6 +192,12 @@ impl<T, U> Point2D<T, U> {
         }
     }
 
+    /// Constructor taking a tuple with x and y.
+    #[inline]
+    pub fn from_tuple((x, y): (T, T)) -> Self {
+        Point2D::new(x, y)
+    }
+
     /// Constructor taking properly Lengths instead of scalar values.
     #[inline]
     pub fn from_lengths(x: Length<T, U>, y: Length<T, U>) -> Self {
@@ -226,9 +257,21 @@ impl<T, U> Point2D<T, U> {
     pub fn zip<V, F: FnMut(T, T) -> V>(self, rhs: Self, mut f: F) -> Vector2D<V, U> {
         vec2(f(self.x, rhs.x), f(self.y, rhs.y))
     }
+
+    /// Returns a reference to the components as an array of x and y.
+    ///
+    /// `Point2D` is `#[repr(C)]` and its unit is zero-sized, so its memory layout is
+    /// guaranteed to be the same as `[T; 2]`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Self` is `#[repr(C)]` with 2 fields of type `T`, which have the size
+        // and alignment of `T` so there is no padding between them, and a zero-sized
+        // unit, so it has the layout of `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
 }
 
-impl<T: Copy, U> Point2D<T, U> {
+impl<T: Clone, U> Point2D<T, U> {
     /// Create a 3d point from this one, using the specified z value.
     #[inline]
     pub fn extend(self, z: T) -> Point3D<T, U> {
@@ -421,10 +464,12 @@ impl<T: Copy, U> Point2D<T, U> {
     #[inline]
     pub fn lerp(self, other: Self, t: T) -> Self
     where
-        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T> + SimdScalar,
     {
-        let one_t = T::one() - t;
-        point2(one_t * self.x + t * other.x, one_t * self.y + t * other.y)
+        point2(
+            T::lerp(self.x, other.x, t.clone()),
+            T::lerp(self.y, other.y, t),
+        )
     }
 }
 
@@ -439,6 +484,17 @@ impl<T: PartialOrd, U> Point2D<T, U> {
         point2(max(self.x, other.x), max(self.y, other.y))
     }
 
+    /// Returns the absolute difference of each component, as a vector.
+    ///
+    /// Unlike `(self - other).abs()`, this doesn't underflow for unsigned integers.
+    #[inline]
+    pub fn abs_diff(self, other: Self) -> Vector2D<T, U>
+    where
+        T: Sub<Output = T>,
+    {
+        vec2(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+
     /// Returns the point each component of which clamped by corresponding
     /// components of `start` and `end`.
     ///
@@ -475,6 +531,48 @@ impl<T: NumCast + Copy, U> Point2D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Point2D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Point2D<NewT, U>, CastError> {
+        Ok(Point2D::new(
+            cast_component(self.x, "x")?,
+            cast_component(self.y, "y")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Point2D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Point2D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Point2D<NewT, U>> {
+        Some(Point2D::new(
+            round_component(self.x, mode)?,
+            round_component(self.y, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Point2D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Point2D<NewT, U> {
+        Point2D::new(saturate_component(self.x), saturate_component(self.y))
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` point.
@@ -489,6 +587,13 @@ impl<T: NumCast + Copy, U> Point2D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` point.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Point2D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` point, truncating decimals if any.
     ///
     /// When casting from floating point points, it is worth considering whether
@@ -528,6 +633,46 @@ impl<T: NumCast + Copy, U> Point2D<T, U> {
     pub fn to_i64(self) -> Point2D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Point2D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Point2D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(self) -> Point2D<u64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(self) -> Point2D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Point2D<T, U> {
@@ -538,10 +683,10 @@ impl<T: Float, U> Point2D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> Point2D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> Point2D<T, U> {
     #[inline]
     pub fn add_size(self, other: &Size2D<T, U>) -> Self {
-        point2(self.x + other.width, self.y + other.height)
+        point2(self.x + other.width.clone(), self.y + other.height.clone())
     }
 }
 
@@ -587,10 +732,10 @@ impl<T: Add, U> Add<Vector2D<T, U>> for Point2D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> AddAssign<Vector2D<T, U>> for Point2D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> AddAssign<Vector2D<T, U>> for Point2D<T, U> {
     #[inline]
     fn add_assign(&mut self, other: Vector2D<T, U>) {
-        *self = *self + other;
+        *self = self.clone() + other;
     }
 }
 
@@ -629,79 +774,113 @@ impl<T: Sub, U> Sub<Vector2D<T, U>> for Point2D<T, U> {
     }
 }
 
-impl<T: Copy + Sub<T, Output = T>, U> SubAssign<Vector2D<T, U>> for Point2D<T, U> {
+impl<T: Clone + Sub<T, Output = T>, U> SubAssign<Vector2D<T, U>> for Point2D<T, U> {
     #[inline]
     fn sub_assign(&mut self, other: Vector2D<T, U>) {
-        *self = *self - other;
+        *self = self.clone() - other;
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Point2D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Point2D<T, U> {
     type Output = Point2D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        point2(self.x * scale, self.y * scale)
+        point2(self.x * scale.clone(), self.y * scale)
     }
 }
 
-impl<T: Copy + Mul<T, Output = T>, U> MulAssign<T> for Point2D<T, U> {
+impl<T: Clone + Mul<T, Output = T>, U> MulAssign<T> for Point2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
-        *self = *self * scale;
+        *self = self.clone() * scale;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Point2D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Point2D<T, U1> {
     type Output = Point2D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        point2(self.x * scale.0, self.y * scale.0)
+        point2(self.x * scale.0.clone(), self.y * scale.0)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Point2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Point2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x *= scale.0;
+        self.x *= scale.0.clone();
         self.y *= scale.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Point2D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Point2D<T, U> {
     type Output = Point2D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        point2(self.x / scale, self.y / scale)
+        point2(self.x / scale.clone(), self.y / scale)
     }
 }
 
-impl<T: Copy + Div<T, Output = T>, U> DivAssign<T> for Point2D<T, U> {
+impl<T: Clone + Div<T, Output = T>, U> DivAssign<T> for Point2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
-        *self = *self / scale;
+        *self = self.clone() / scale;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Point2D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Point2D<T, U2> {
     type Output = Point2D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        point2(self.x / scale.0, self.y / scale.0)
+        point2(self.x / scale.0.clone(), self.y / scale.0)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Point2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Point2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x /= scale.0;
+        self.x /= scale.0.clone();
         self.y /= scale.0;
     }
 }
 
+impl<T: Mul, U1, U2> Mul<Scale2D<T, U1, U2>> for Point2D<T, U1> {
+    type Output = Point2D<T::Output, U2>;
+
+    #[inline]
+    fn mul(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        point2(self.x * scale.x, self.y * scale.y)
+    }
+}
+
+impl<T: MulAssign, U> MulAssign<Scale2D<T, U, U>> for Point2D<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.x *= scale.x;
+        self.y *= scale.y;
+    }
+}
+
+impl<T: Div, U1, U2> Div<Scale2D<T, U1, U2>> for Point2D<T, U2> {
+    type Output = Point2D<T::Output, U1>;
+
+    #[inline]
+    fn div(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        point2(self.x / scale.x, self.y / scale.y)
+    }
+}
+
+impl<T: DivAssign, U> DivAssign<Scale2D<T, U, U>> for Point2D<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.x /= scale.x;
+        self.y /= scale.y;
+    }
+}
+
 impl<T: Zero, U> Zero for Point2D<T, U> {
     #[inline]
     fn zero() -> Self {
@@ -745,6 +924,114 @@ impl<T: ApproxEq<T>, U> ApproxEq<Point2D<T, U>> for Point2D<T, U> {
     }
 }
 
+impl<T: Copy, U> Point2D<T, U> {
+    /// Adds a vector, wrapping around at the boundary of the integer type.
+    ///
+    /// This is useful for coordinate spaces that deliberately wrap around, such as
+    /// tiled infinite canvases, where the regular operators panic on overflow in
+    /// debug builds.
+    #[inline]
+    pub fn wrapping_add(self, other: Vector2D<T, U>) -> Self
+    where
+        T: WrappingAdd,
+    {
+        point2(self.x.wrapping_add(&other.x), self.y.wrapping_add(&other.y))
+    }
+
+    /// Subtracts a vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_sub(self, other: Vector2D<T, U>) -> Self
+    where
+        T: WrappingSub,
+    {
+        point2(self.x.wrapping_sub(&other.x), self.y.wrapping_sub(&other.y))
+    }
+
+    /// Multiplies each component by a scalar, wrapping around at the boundary of the
+    /// integer type.
+    #[inline]
+    pub fn wrapping_mul(self, scale: T) -> Self
+    where
+        T: WrappingMul,
+    {
+        point2(self.x.wrapping_mul(&scale), self.y.wrapping_mul(&scale))
+    }
+
+    /// Adds a vector, returning the wrapped result along with whether any component
+    /// overflowed.
+    #[inline]
+    pub fn overflowing_add(self, other: Vector2D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingAdd,
+    {
+        let (x, x_overflow) = self.x.overflowing_add(&other.x);
+        let (y, y_overflow) = self.y.overflowing_add(&other.y);
+        (point2(x, y), x_overflow || y_overflow)
+    }
+
+    /// Subtracts a vector, returning the wrapped result along with whether any
+    /// component overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: Vector2D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingSub,
+    {
+        let (x, x_overflow) = self.x.overflowing_sub(&other.x);
+        let (y, y_overflow) = self.y.overflowing_sub(&other.y);
+        (point2(x, y), x_overflow || y_overflow)
+    }
+
+    /// Multiplies each component by a scalar, returning the wrapped result along with
+    /// whether any component overflowed.
+    #[inline]
+    pub fn overflowing_mul(self, scale: T) -> (Self, bool)
+    where
+        T: OverflowingMul,
+    {
+        let (x, x_overflow) = self.x.overflowing_mul(&scale);
+        let (y, y_overflow) = self.y.overflowing_mul(&scale);
+        (point2(x, y), x_overflow || y_overflow)
+    }
+}
+
+macro_rules! int_midpoint {
+    ($($ty:ty),+) => {$(
+        impl<U> Point2D<$ty, U> {
+            /// Returns the point halfway between `self` and `other`, rounded towards
+            /// negative infinity.
+            ///
+            /// Unlike `(self + other.to_vector()) / 2`, this never overflows, even for
+            /// coordinates close to the limits of the integer type.
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                point2(
+                    int_midpoint!(@mid self.x, other.x),
+                    int_midpoint!(@mid self.y, other.y),
+                )
+            }
+        }
+
+        impl<U> Point3D<$ty, U> {
+            /// Returns the point halfway between `self` and `other`, rounded towards
+            /// negative infinity.
+            ///
+            /// See [`Point2D::midpoint`].
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                point3(
+                    int_midpoint!(@mid self.x, other.x),
+                    int_midpoint!(@mid self.y, other.y),
+                    int_midpoint!(@mid self.z, other.z),
+                )
+            }
+        }
+    )+};
+    // The common bits plus half of the differing ones.
+    (@mid $a:expr, $b:expr) => { Midpoint::midpoint($a, $b) };
+}
+
+int_midpoint!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
 impl<T: Euclid, U> Point2D<T, U> {
     /// Calculates the least nonnegative remainder of `self (mod other)`.
     ///
@@ -817,8 +1104,44 @@ impl<T, U> From<(T, T)> for Point2D<T, U> {
     }
 }
 
+impl<T, U> AsRef<[T; 2]> for Point2D<T, U> {
+    fn as_ref(&self) -> &[T; 2] {
+        self.as_array()
+    }
+}
+
+/// Indexes the x and y components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Point2D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Point2D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Point2D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Point2D index out of bounds: {}", index),
+        }
+    }
+}
+
 /// A 3d Point tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Point3D<T, U> {
     pub x: T,
     pub y: T,
@@ -828,6 +1151,8 @@ pub struct Point3D<T, U> {
 }
 
 mint_vec!(Point3D[x, y, z] = Point3);
+nalgebra_vec!(Point3D[x, y, z] = Point3);
+glam_vec!(Point3D[x, y, z] = Vec3);
 
 impl<T: Copy, U> Copy for Point3D<T, U> {}
 
@@ -851,7 +1176,9 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (x, y, z) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None, None];
+        crate::serde_fields::deserialize(deserializer, "Point3D", &["x", "y", "z"], &mut values)?;
+        let [x, y, z] = values.map(Option::unwrap);
         Ok(Point3D {
             x,
             y,
@@ -870,7 +1197,12 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.x, &self.y, &self.z).serialize(serializer)
+        crate::serde_fields::serialize(
+            serializer,
+            "Point3D",
+            &["x", "y", "z"],
+            &[&self.x, &self.y, &self.z],
+        )
     }
 }
 
@@ -964,6 +1296,12 @@ impl<T, U> Point3D<T, U> {
         }
     }
 
+    /// Constructor taking a tuple with x, y and z.
+    #[inline]
+    pub fn from_tuple((x, y, z): (T, T, T)) -> Self {
+        Point3D::new(x, y, z)
+    }
+
     /// Constructor taking properly Lengths instead of scalar values.
     #[inline]
     pub fn from_lengths(x: Length<T, U>, y: Length<T, U>, z: Length<T, U>) -> Self {
@@ -1024,9 +1362,21 @@ impl<T, U> Point3D<T, U> {
     pub fn zip<V, F: FnMut(T, T) -> V>(self, rhs: Self, mut f: F) -> Vector3D<V, U> {
         vec3(f(self.x, rhs.x), f(self.y, rhs.y), f(self.z, rhs.z))
     }
+
+    /// Returns a reference to the components as an array of x, y and z.
+    ///
+    /// `Point3D` is `#[repr(C)]` and its unit is zero-sized, so its memory layout is
+    /// guaranteed to be the same as `[T; 3]`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 3] {
+        // SAFETY: `Self` is `#[repr(C)]` with 3 fields of type `T`, which have the size
+        // and alignment of `T` so there is no padding between them, and a zero-sized
+        // unit, so it has the layout of `[T; 3]`.
+        unsafe { &*(self as *const Self as *const [T; 3]) }
+    }
 }
 
-impl<T: Copy, U> Point3D<T, U> {
+impl<T: Clone, U> Point3D<T, U> {
     /// Cast this point into a vector.
     ///
     /// Equivalent to subtracting the origin to this point.
@@ -1052,7 +1402,7 @@ impl<T: Copy, U> Point3D<T, U> {
         point2(self.x, self.z)
     }
 
-    /// Returns a 2d point using this point's x and z coordinates
+    /// Returns a 2d point using this point's y and z coordinates
     #[inline]
     pub fn yz(self) -> Point2D<T, U> {
         point2(self.y, self.z)
@@ -1230,13 +1580,12 @@ impl<T: Copy, U> Point3D<T, U> {
     #[inline]
     pub fn lerp(self, other: Self, t: T) -> Self
     where
-        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T> + SimdScalar,
     {
-        let one_t = T::one() - t;
         point3(
-            one_t * self.x + t * other.x,
-            one_t * self.y + t * other.y,
-            one_t * self.z + t * other.z,
+            T::lerp(self.x, other.x, t.clone()),
+            T::lerp(self.y, other.y, t.clone()),
+            T::lerp(self.z, other.z, t),
         )
     }
 }
@@ -1260,6 +1609,21 @@ impl<T: PartialOrd, U> Point3D<T, U> {
         )
     }
 
+    /// Returns the absolute difference of each component, as a vector.
+    ///
+    /// Unlike `(self - other).abs()`, this doesn't underflow for unsigned integers.
+    #[inline]
+    pub fn abs_diff(self, other: Self) -> Vector3D<T, U>
+    where
+        T: Sub<Output = T>,
+    {
+        vec3(
+            abs_diff(self.x, other.x),
+            abs_diff(self.y, other.y),
+            abs_diff(self.z, other.z),
+        )
+    }
+
     /// Returns the point each component of which clamped by corresponding
     /// components of `start` and `end`.
     ///
@@ -1300,6 +1664,54 @@ impl<T: NumCast + Copy, U> Point3D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Point3D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Point3D<NewT, U>, CastError> {
+        Ok(Point3D::new(
+            cast_component(self.x, "x")?,
+            cast_component(self.y, "y")?,
+            cast_component(self.z, "z")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Point3D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Point3D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Point3D<NewT, U>> {
+        Some(Point3D::new(
+            round_component(self.x, mode)?,
+            round_component(self.y, mode)?,
+            round_component(self.z, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Point3D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Point3D<NewT, U> {
+        Point3D::new(
+            saturate_component(self.x),
+            saturate_component(self.y),
+            saturate_component(self.z),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` point.
@@ -1314,6 +1726,13 @@ impl<T: NumCast + Copy, U> Point3D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` point.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Point3D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` point, truncating decimals if any.
     ///
     /// When casting from floating point points, it is worth considering whether
@@ -1353,6 +1772,46 @@ impl<T: NumCast + Copy, U> Point3D<T, U> {
     pub fn to_i64(self) -> Point3D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Point3D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Point3D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(self) -> Point3D<u64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` point, truncating decimals if any.
+    ///
+    /// When casting from floating point points, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(self) -> Point3D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Point3D<T, U> {
@@ -1363,7 +1822,7 @@ impl<T: Float, U> Point3D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> Point3D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> Point3D<T, U> {
     #[inline]
     pub fn add_size(self, other: Size3D<T, U>) -> Self {
         point3(
@@ -1421,10 +1880,10 @@ impl<T: Add, U> Add<Vector3D<T, U>> for Point3D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> AddAssign<Vector3D<T, U>> for Point3D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> AddAssign<Vector3D<T, U>> for Point3D<T, U> {
     #[inline]
     fn add_assign(&mut self, other: Vector3D<T, U>) {
-        *self = *self + other;
+        *self = self.clone() + other;
     }
 }
 
@@ -1468,75 +1927,91 @@ impl<T: Sub, U> Sub<Vector3D<T, U>> for Point3D<T, U> {
     }
 }
 
-impl<T: Copy + Sub<T, Output = T>, U> SubAssign<Vector3D<T, U>> for Point3D<T, U> {
+impl<T: Clone + Sub<T, Output = T>, U> SubAssign<Vector3D<T, U>> for Point3D<T, U> {
     #[inline]
     fn sub_assign(&mut self, other: Vector3D<T, U>) {
-        *self = *self - other;
+        *self = self.clone() - other;
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Point3D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Point3D<T, U> {
     type Output = Point3D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        point3(self.x * scale, self.y * scale, self.z * scale)
+        point3(
+            self.x * scale.clone(),
+            self.y * scale.clone(),
+            self.z * scale,
+        )
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Point3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Point3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
-        self.x *= scale;
-        self.y *= scale;
+        self.x *= scale.clone();
+        self.y *= scale.clone();
         self.z *= scale;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Point3D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Point3D<T, U1> {
     type Output = Point3D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        point3(self.x * scale.0, self.y * scale.0, self.z * scale.0)
+        point3(
+            self.x * scale.0.clone(),
+            self.y * scale.0.clone(),
+            self.z * scale.0,
+        )
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Point3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Point3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
         *self *= scale.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Point3D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Point3D<T, U> {
     type Output = Point3D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        point3(self.x / scale, self.y / scale, self.z / scale)
+        point3(
+            self.x / scale.clone(),
+            self.y / scale.clone(),
+            self.z / scale,
+        )
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Point3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Point3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
-        self.x /= scale;
-        self.y /= scale;
+        self.x /= scale.clone();
+        self.y /= scale.clone();
         self.z /= scale;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Point3D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Point3D<T, U2> {
     type Output = Point3D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        point3(self.x / scale.0, self.y / scale.0, self.z / scale.0)
+        point3(
+            self.x / scale.0.clone(),
+            self.y / scale.0.clone(),
+            self.z / scale.0,
+        )
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Point3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Point3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
         *self /= scale.0;
@@ -1592,6 +2067,87 @@ impl<T: ApproxEq<T>, U> ApproxEq<Point3D<T, U>> for Point3D<T, U> {
     }
 }
 
+impl<T: Copy, U> Point3D<T, U> {
+    /// Adds a vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_add(self, other: Vector3D<T, U>) -> Self
+    where
+        T: WrappingAdd,
+    {
+        point3(
+            self.x.wrapping_add(&other.x),
+            self.y.wrapping_add(&other.y),
+            self.z.wrapping_add(&other.z),
+        )
+    }
+
+    /// Subtracts a vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_sub(self, other: Vector3D<T, U>) -> Self
+    where
+        T: WrappingSub,
+    {
+        point3(
+            self.x.wrapping_sub(&other.x),
+            self.y.wrapping_sub(&other.y),
+            self.z.wrapping_sub(&other.z),
+        )
+    }
+
+    /// Multiplies each component by a scalar, wrapping around at the boundary of the
+    /// integer type.
+    #[inline]
+    pub fn wrapping_mul(self, scale: T) -> Self
+    where
+        T: WrappingMul,
+    {
+        point3(
+            self.x.wrapping_mul(&scale),
+            self.y.wrapping_mul(&scale),
+            self.z.wrapping_mul(&scale),
+        )
+    }
+
+    /// Adds a vector, returning the wrapped result along with whether any component
+    /// overflowed.
+    #[inline]
+    pub fn overflowing_add(self, other: Vector3D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingAdd,
+    {
+        let (x, x_overflow) = self.x.overflowing_add(&other.x);
+        let (y, y_overflow) = self.y.overflowing_add(&other.y);
+        let (z, z_overflow) = self.z.overflowing_add(&other.z);
+        (point3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+
+    /// Subtracts a vector, returning the wrapped result along with whether any
+    /// component overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: Vector3D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingSub,
+    {
+        let (x, x_overflow) = self.x.overflowing_sub(&other.x);
+        let (y, y_overflow) = self.y.overflowing_sub(&other.y);
+        let (z, z_overflow) = self.z.overflowing_sub(&other.z);
+        (point3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+
+    /// Multiplies each component by a scalar, returning the wrapped result along with
+    /// whether any component overflowed.
+    #[inline]
+    pub fn overflowing_mul(self, scale: T) -> (Self, bool)
+    where
+        T: OverflowingMul,
+    {
+        let (x, x_overflow) = self.x.overflowing_mul(&scale);
+        let (y, y_overflow) = self.y.overflowing_mul(&scale);
+        let (z, z_overflow) = self.z.overflowing_mul(&scale);
+        (point3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+}
+
 impl<T: Euclid, U> Point3D<T, U> {
     /// Calculates the least nonnegative remainder of `self (mod other)`.
     ///
@@ -1666,6 +2222,40 @@ impl<T, U> From<(T, T, T)> for Point3D<T, U> {
     }
 }
 
+impl<T, U> AsRef<[T; 3]> for Point3D<T, U> {
+    fn as_ref(&self) -> &[T; 3] {
+        self.as_array()
+    }
+}
+
+/// Indexes the x, y and z components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Point3D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Point3D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Point3D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Point3D index out of bounds: {}", index),
+        }
+    }
+}
+
 /// Shorthand for `Point2D::new(x, y)`.
 #[inline]
 pub const fn point2<T, U>(x: T, y: T) -> Point2D<T, U> {
@@ -1690,7 +2280,7 @@ pub const fn point3<T, U>(x: T, y: T, z: T) -> Point3D<T, U> {
 #[cfg(test)]
 mod point2d {
     use crate::default::Point2D;
-    use crate::point2;
+    use crate::{point2, point3};
 
     #[cfg(feature = "mint")]
     use mint;
@@ -1715,6 +2305,17 @@ mod point2d {
         assert_eq!(result, Point2D::new(2.0, 3.0));
     }
 
+    #[test]
+    pub fn test_clamp() {
+        let p1 = Point2D::new(1.0, -1.0);
+        let p2 = Point2D::new(2.0, 5.0);
+        let p3 = Point2D::new(-1.0, 2.0);
+
+        let result = p3.clamp(p1, p2);
+
+        assert_eq!(result, Point2D::new(1.0, 2.0));
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -1725,6 +2326,66 @@ mod point2d {
         assert_eq!(p1, p2);
     }
 
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    pub fn test_nalgebra() {
+        let p1 = Point2D::new(1.0, 3.0);
+        let pn: nalgebra::Point2<_> = p1.into();
+        let p2 = Point2D::from(pn);
+
+        assert_eq!(p1, p2);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    pub fn test_glam() {
+        let p1 = Point2D::new(1.0, 3.0);
+        let pg: glam::Vec2 = p1.into();
+        let p2 = Point2D::from(pg);
+
+        assert_eq!(p1, p2);
+    }
+
+    #[test]
+    pub fn test_small_int_casts() {
+        let p = Point2D::new(1.5, 65535.0);
+        assert_eq!(p.to_u16(), Point2D::new(1, 65535));
+        assert_eq!(p.to_u64(), Point2D::new(1, 65535));
+        assert_eq!(Point2D::new(-1.5, 300.9).to_i16(), Point2D::new(-1, 300));
+        assert_eq!(Point2D::new(-1.5, 300.9).to_isize(), Point2D::new(-1, 300));
+    }
+
+    #[test]
+    pub fn test_midpoint_abs_diff() {
+        let a: Point2D<i32> = Point2D::new(i32::MAX, -3);
+        let b: Point2D<i32> = Point2D::new(i32::MAX - 2, i32::MIN);
+        assert_eq!(a.midpoint(b), Point2D::new(i32::MAX - 1, i32::MIN / 2 - 2));
+        assert_eq!(
+            Point2D::<i8>::new(-3, 0).midpoint(Point2D::new(0, 1)),
+            point2(-2, 0)
+        );
+
+        let p: Point2D<u32> = Point2D::new(3, 10);
+        assert_eq!(p.abs_diff(Point2D::new(5, 4)), crate::vec2(2, 6));
+    }
+
+    #[test]
+    pub fn test_wrapping() {
+        let p: Point2D<u8> = Point2D::new(250, 3);
+        assert_eq!(p.wrapping_add(crate::vec2(10, 1)), Point2D::new(4, 4));
+        assert_eq!(p.wrapping_sub(crate::vec2(1, 5)), Point2D::new(249, 254));
+        assert_eq!(p.wrapping_mul(2), Point2D::new(244, 6));
+        assert_eq!(
+            p.overflowing_add(crate::vec2(10, 1)),
+            (Point2D::new(4, 4), true)
+        );
+        assert_eq!(
+            p.overflowing_sub(crate::vec2(1, 1)),
+            (Point2D::new(249, 2), false)
+        );
+        assert_eq!(p.overflowing_mul(1), (p, false));
+    }
+
     #[test]
     pub fn test_conv_vector() {
         for i in 0..100 {
@@ -1740,6 +2401,29 @@ mod point2d {
     pub fn test_swizzling() {
         let p: Point2D<i32> = point2(1, 2);
         assert_eq!(p.yx(), point2(2, 1));
+        assert_eq!(p.extend(3), point3(1, 2, 3));
+    }
+
+    #[test]
+    pub fn test_as_array() {
+        let p: Point2D<i32> = point2(1, 2);
+        assert_eq!(p.as_array(), &[1, 2]);
+        assert_eq!(AsRef::<[i32; 2]>::as_ref(&p), &p.to_array());
+    }
+
+    #[test]
+    pub fn test_index() {
+        let mut p: Point2D<i32> = point2(1, 2);
+        assert_eq!((p[0], p[1]), (1, 2));
+        p[1] = 5;
+        assert_eq!(p, point2(1, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_index_out_of_bounds() {
+        let p: Point2D<i32> = point2(1, 2);
+        let _ = p[2];
     }
 
     #[test]
@@ -1773,6 +2457,101 @@ mod point2d {
             assert_eq!(-Point2D::new(-1.0, -2.0), Point2D::new(1.0, 2.0));
         }
 
+        /// A scalar that is `Clone` but not `Copy`, like an arbitrary-precision number.
+        #[derive(Clone, Debug, PartialEq, PartialOrd)]
+        struct Boxed(Box<i32>);
+
+        macro_rules! boxed_op {
+            ($op:ident, $method:ident) => {
+                impl core::ops::$op for Boxed {
+                    type Output = Boxed;
+                    fn $method(self, other: Boxed) -> Boxed {
+                        Boxed(Box::new(core::ops::$op::$method(*self.0, *other.0)))
+                    }
+                }
+            };
+        }
+        boxed_op!(Add, add);
+        boxed_op!(Sub, sub);
+        boxed_op!(Mul, mul);
+        boxed_op!(Div, div);
+
+        impl num_traits::Zero for Boxed {
+            fn zero() -> Boxed {
+                b(0)
+            }
+            fn is_zero(&self) -> bool {
+                *self.0 == 0
+            }
+        }
+
+        fn b(v: i32) -> Boxed {
+            Boxed(Box::new(v))
+        }
+
+        #[test]
+        pub fn test_clone_scalar() {
+            let mut p = Point2D::new(b(1), b(2));
+            p += vec2(b(3), b(4));
+            p *= b(6);
+            p /= b(2);
+            assert_eq!(p, Point2D::new(b(12), b(18)));
+            assert_eq!(
+                p.clone().to_vector() * Scale::<_, _, Mm>::new(b(2)),
+                Vector2D::new(b(24), b(36))
+            );
+
+            let r = crate::default::Box2D::new(Point2D::new(b(0), b(0)), p) * b(2);
+            assert_eq!(r.max, Point2D::new(b(24), b(36)));
+
+            let other =
+                crate::default::Box2D::new(Point2D::new(b(10), b(-5)), Point2D::new(b(30), b(20)));
+            assert_eq!(
+                r.intersection(&other),
+                Some(crate::default::Box2D::new(
+                    Point2D::new(b(10), b(0)),
+                    Point2D::new(b(24), b(20))
+                ))
+            );
+            assert_eq!(
+                r.union(&other),
+                crate::default::Box2D::new(Point2D::new(b(0), b(-5)), Point2D::new(b(30), b(36)))
+            );
+            assert_eq!(r.size(), crate::size2(b(24), b(36)));
+            assert!(r.contains(Point2D::new(b(5), b(5))));
+
+            let rect = r.to_rect();
+            let other = other.to_rect();
+            assert_eq!(rect.max(), Point2D::new(b(24), b(36)));
+            assert_eq!(
+                rect.intersection(&other),
+                Some(crate::default::Rect::new(
+                    Point2D::new(b(10), b(0)),
+                    crate::size2(b(14), b(20))
+                ))
+            );
+            assert_eq!(
+                rect.union(&other),
+                crate::default::Rect::new(Point2D::new(b(0), b(-5)), crate::size2(b(30), b(41)))
+            );
+            assert!(rect.contains(Point2D::new(b(5), b(5))));
+            assert!(!rect.contains(Point2D::new(b(25), b(5))));
+
+            let p3 = |x, y, z| crate::point3(b(x), b(y), b(z));
+            let c = crate::default::Box3D::new(p3(0, 0, 0), p3(4, 4, 4));
+            let d = crate::default::Box3D::new(p3(2, -2, 2), p3(6, 2, 6));
+            assert_eq!(
+                c.intersection(&d),
+                Some(crate::default::Box3D::new(p3(2, 0, 2), p3(4, 2, 4)))
+            );
+            assert_eq!(
+                c.union(&d),
+                crate::default::Box3D::new(p3(0, -2, 0), p3(6, 4, 6))
+            );
+            assert_eq!(c.size(), crate::size3(b(4), b(4), b(4)));
+            assert!(c.contains(p3(1, 1, 1)));
+        }
+
         #[test]
         pub fn test_add_size() {
             let p1 = Point2DMm::new(1.0, 2.0);
@@ -2001,6 +2780,17 @@ mod point3d {
         assert_eq!(result, Point3D::new(2.0, 3.0, 5.0));
     }
 
+    #[test]
+    pub fn test_clamp() {
+        let p1 = Point3D::new(1.0, -1.0, 5.0);
+        let p2 = Point3D::new(2.0, 5.0, 10.0);
+        let p3 = Point3D::new(-1.0, 2.0, 20.0);
+
+        let result = p3.clamp(p1, p2);
+
+        assert_eq!(result, Point3D::new(1.0, 2.0, 10.0));
+    }
+
     #[test]
     pub fn test_conv_vector() {
         use crate::point3;
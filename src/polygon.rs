@@ -0,0 +1,640 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Simple polygons, as an owned list of vertices or a borrowed slice of them.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::box2d::Box2D;
+use crate::num::*;
+#[cfg(feature = "alloc")]
+use crate::plane::Plane2D;
+use crate::point::Point2D;
+use crate::segment::LineSegment2D;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The direction in which the vertices of a polygon go around it.
+///
+/// The directions are for a y axis pointing up. With the y axis pointing down, as on
+/// screen, a `CounterClockwise` polygon appears to go clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// The vertices go from the x axis towards the y axis, and the signed area is
+    /// positive.
+    CounterClockwise,
+    /// The vertices go from the y axis towards the x axis, and the signed area is
+    /// negative.
+    Clockwise,
+}
+
+/// The rule deciding which points are inside of a self-intersecting polygon, as in SVG
+/// and CSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Points around which the polygon winds an odd number of times are inside.
+    EvenOdd,
+    /// Points around which the polygon winds at least once are inside.
+    NonZero,
+}
+
+/// A polygon borrowing its vertices from a slice.
+///
+/// The polygon is closed: the last vertex is connected back to the first one. See
+/// [`Polygon2D`] for the owned version.
+///
+/// ```
+/// use euclid::default::{Point2D, PolygonRef2D};
+/// use euclid::{point2, FillRule, Winding};
+///
+/// let points: [Point2D<i32>; 4] = [point2(0, 0), point2(4, 0), point2(4, 4), point2(0, 4)];
+/// let square = PolygonRef2D::new(&points);
+/// assert_eq!(square.signed_area(), 16);
+/// assert_eq!(square.winding(), Some(Winding::CounterClockwise));
+/// assert!(square.is_convex());
+/// assert!(square.contains(point2(1, 3), FillRule::NonZero));
+/// ```
+pub struct PolygonRef2D<'a, T, U> {
+    pub points: &'a [Point2D<T, U>],
+}
+
+/// A polygon owning its vertices.
+///
+/// The polygon is closed: the last vertex is connected back to the first one. This needs
+/// the `alloc` feature; [`PolygonRef2D`] provides the same queries on a borrowed slice
+/// of vertices.
+///
+/// ```
+/// use euclid::default::Polygon2D;
+/// use euclid::{point2, FillRule, Winding};
+///
+/// // A star, crossing itself.
+/// let mut star: Polygon2D<f32> = vec![
+///     point2(0.0, 3.0),
+///     point2(2.0, -3.0),
+///     point2(-3.0, 1.0),
+///     point2(3.0, 1.0),
+///     point2(-2.0, -3.0),
+/// ]
+/// .into();
+/// assert!(!star.is_convex());
+/// assert!(star.contains(point2(0.0, 0.0), FillRule::NonZero));
+/// assert!(!star.contains(point2(0.0, 0.0), FillRule::EvenOdd));
+///
+/// let winding = star.winding();
+/// star.reverse();
+/// assert_ne!(star.winding(), winding);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Polygon2D<T, U> {
+    pub points: Vec<Point2D<T, U>>,
+}
+
+/// Counts the changes of sign in a closed sequence, ignoring zeros.
+struct SignChanges {
+    first: i8,
+    last: i8,
+    count: u32,
+}
+
+impl SignChanges {
+    fn new() -> Self {
+        SignChanges {
+            first: 0,
+            last: 0,
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, sign: i8) {
+        if sign == 0 {
+            return;
+        }
+        if self.first == 0 {
+            self.first = sign;
+        } else if sign != self.last {
+            self.count += 1;
+        }
+        self.last = sign;
+    }
+
+    /// The number of changes, including the one from the last sign back to the first.
+    fn total(&self) -> u32 {
+        self.count + (self.last != self.first) as u32
+    }
+}
+
+/// Clips the closed polygon to the points with a positive distance, keeping the ones on
+/// the boundary.
+#[cfg(feature = "alloc")]
+fn clip<T: RealScalar, U>(
+    points: &[Point2D<T, U>],
+    distance: impl Fn(Point2D<T, U>) -> T,
+) -> Vec<Point2D<T, U>> {
+    let zero = T::zero();
+    let mut result = Vec::with_capacity(points.len() + 1);
+    let mut next = points.iter().cycle().skip(1);
+    for &from in points {
+        let to = *next.next().unwrap();
+        let (d_from, d_to) = (distance(from), distance(to));
+        if d_from >= zero {
+            result.push(from);
+        }
+        if (d_from > zero && d_to < zero) || (d_from < zero && d_to > zero) {
+            result.push(from.lerp(to, d_from / (d_from - d_to)));
+        }
+    }
+
+    result
+}
+
+macro_rules! polygon_impls {
+    ($name:ident $(<$lt:lifetime>)?) => {
+        impl<$($lt,)? T: Hash, U> Hash for $name<$($lt,)? T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.points.hash(h);
+            }
+        }
+
+        impl<$($lt,)? T: PartialEq, U> PartialEq for $name<$($lt,)? T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.points[..].eq(&other.points[..])
+            }
+        }
+
+        impl<$($lt,)? T: Eq, U> Eq for $name<$($lt,)? T, U> {}
+
+        impl<$($lt,)? T: fmt::Debug, U> fmt::Debug for $name<$($lt,)? T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.points).finish()
+            }
+        }
+
+        impl<$($lt,)? T: Copy, U> $name<$($lt,)? T, U> {
+            /// Returns the edges of the polygon, including the one from the last vertex back
+            /// to the first.
+            pub fn edges(&self) -> impl Iterator<Item = LineSegment2D<T, U>> + '_ {
+                let points = &self.points[..];
+                let next = points.iter().cycle().skip(1);
+                points
+                    .iter()
+                    .zip(next)
+                    .map(|(from, to)| LineSegment2D::new(*from, *to))
+            }
+
+            /// Returns the smallest box containing the polygon.
+            #[inline]
+            pub fn bounding_box(&self) -> Box2D<T, U>
+            where
+                T: Zero + PartialOrd,
+            {
+                Box2D::from_points(self.points.iter())
+            }
+
+            /// Returns twice the signed area of the polygon.
+            ///
+            /// This avoids the division, so that it is exact for integer coordinates. For a
+            /// self-intersecting polygon, the areas of the parts going in opposite
+            /// directions cancel out.
+            pub fn double_signed_area(&self) -> T
+            where
+                T: Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+            {
+                self.edges().fold(T::zero(), |sum, edge| {
+                    sum + edge.from.to_vector().cross(edge.to.to_vector())
+                })
+            }
+
+            /// Returns the signed area of the polygon, which is positive for polygons going
+            /// [`CounterClockwise`](Winding::CounterClockwise).
+            #[inline]
+            pub fn signed_area(&self) -> T
+            where
+                T: Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+            {
+                self.double_signed_area() / (T::one() + T::one())
+            }
+
+            /// Returns the winding order of the polygon, or `None` if its area is zero.
+            pub fn winding(&self) -> Option<Winding>
+            where
+                T: Zero + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+            {
+                let area = self.double_signed_area();
+                if area > T::zero() {
+                    Some(Winding::CounterClockwise)
+                } else if area < T::zero() {
+                    Some(Winding::Clockwise)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `true` if the polygon is convex.
+            ///
+            /// Consecutive vertices on a line are allowed, but polygons with fewer than
+            /// three vertices or an area of zero aren't convex.
+            pub fn is_convex(&self) -> bool
+            where
+                T: Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+            {
+                let points = &self.points[..];
+                let n = points.len();
+                if n < 3 {
+                    return false;
+                }
+
+                let zero = T::zero();
+                let sign = |v: T| {
+                    if v > zero {
+                        1
+                    } else if v < zero {
+                        -1
+                    } else {
+                        0
+                    }
+                };
+                let edge = |i: usize| points[(i + 1) % n] - points[i % n];
+
+                let mut turns = SignChanges::new();
+                // A polygon turning the same way at every vertex can still go around
+                // more than once, like a star. The direction of the edges along each
+                // axis changes exactly twice in a convex polygon.
+                let mut x_directions = SignChanges::new();
+                let mut y_directions = SignChanges::new();
+                for i in 0..n {
+                    let e = edge(i);
+                    turns.push(sign(e.cross(edge(i + 1))));
+                    x_directions.push(sign(e.x));
+                    y_directions.push(sign(e.y));
+                }
+
+                turns.first != 0
+                    && turns.count == 0
+                    && x_directions.total() <= 2
+                    && y_directions.total() <= 2
+            }
+
+            /// Returns the number of times the polygon goes around the point, counting
+            /// [`CounterClockwise`](Winding::CounterClockwise) turns as positive.
+            ///
+            /// Points exactly on an edge may be counted as inside or outside of it.
+            pub fn winding_number(&self, p: Point2D<T, U>) -> i32
+            where
+                T: Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+            {
+                let zero = T::zero();
+                let mut winding = 0;
+                for edge in self.edges() {
+                    let side = (edge.to - edge.from).cross(p - edge.from);
+                    if edge.from.y <= p.y {
+                        if edge.to.y > p.y && side > zero {
+                            winding += 1;
+                        }
+                    } else if edge.to.y <= p.y && side < zero {
+                        winding -= 1;
+                    }
+                }
+
+                winding
+            }
+
+            /// Returns the part of the polygon in front of the half-plane, using the
+            /// Sutherland-Hodgman algorithm.
+            ///
+            /// The result is exact for convex polygons, which stay convex. A concave
+            /// polygon split into several parts gives a single polygon connecting them
+            /// with edges along the boundary of the half-plane. The result is empty if the
+            /// whole polygon is behind the half-plane.
+            ///
+            /// ```
+            /// use euclid::default::{Plane2D, Polygon2D};
+            /// use euclid::{point2, vec2};
+            ///
+            /// let triangle: Polygon2D<f32> =
+            ///     vec![point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 4.0)].into();
+            /// let left = Plane2D::new(vec2(-1.0, 0.0), 2.0);
+            /// let clipped = triangle.clip_to_half_plane(&left);
+            /// assert_eq!(
+            ///     clipped.points,
+            ///     vec![point2(0.0, 0.0), point2(2.0, 0.0), point2(2.0, 2.0), point2(0.0, 4.0)],
+            /// );
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn clip_to_half_plane(&self, plane: &Plane2D<T, U>) -> Polygon2D<T, U>
+            where
+                T: RealScalar,
+            {
+                Polygon2D::new(clip(&self.points, |p| plane.signed_distance(p)))
+            }
+
+            /// Returns the part of the polygon inside of the box, using the
+            /// Sutherland-Hodgman algorithm.
+            ///
+            /// See [`clip_to_half_plane`](Self::clip_to_half_plane). The result is empty if
+            /// the polygon is outside of the box, or the box is negative.
+            #[cfg(feature = "alloc")]
+            pub fn clip_to_box(&self, b: &Box2D<T, U>) -> Polygon2D<T, U>
+            where
+                T: RealScalar,
+            {
+                if b.is_negative() {
+                    return Polygon2D::default();
+                }
+
+                let points = clip(&self.points, |p| p.x - b.min.x);
+                let points = clip(&points, |p| b.max.x - p.x);
+                let points = clip(&points, |p| p.y - b.min.y);
+                Polygon2D::new(clip(&points, |p| b.max.y - p.y))
+            }
+
+            /// Returns `true` if the point is inside of the polygon, according to the fill
+            /// rule.
+            ///
+            /// Points exactly on an edge may be counted as inside or outside of it.
+            #[inline]
+            pub fn contains(&self, p: Point2D<T, U>, fill_rule: FillRule) -> bool
+            where
+                T: Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+            {
+                let winding = self.winding_number(p);
+                match fill_rule {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                }
+            }
+        }
+    };
+}
+
+polygon_impls!(PolygonRef2D<'a>);
+#[cfg(feature = "alloc")]
+polygon_impls!(Polygon2D);
+
+impl<'a, T, U> PolygonRef2D<'a, T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(points: &'a [Point2D<T, U>]) -> Self {
+        PolygonRef2D { points }
+    }
+
+    /// Copies the vertices into an owned polygon.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_polygon(&self) -> Polygon2D<T, U>
+    where
+        T: Clone,
+    {
+        Polygon2D::new(self.points.to_vec())
+    }
+}
+
+impl<'a, T, U> Copy for PolygonRef2D<'a, T, U> {}
+
+impl<'a, T, U> Clone for PolygonRef2D<'a, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, U> From<&'a [Point2D<T, U>]> for PolygonRef2D<'a, T, U> {
+    fn from(points: &'a [Point2D<T, U>]) -> Self {
+        PolygonRef2D::new(points)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> Polygon2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub fn new(points: Vec<Point2D<T, U>>) -> Self {
+        Polygon2D { points }
+    }
+
+    /// Borrows the vertices.
+    #[inline]
+    pub fn as_polygon_ref(&self) -> PolygonRef2D<'_, T, U> {
+        PolygonRef2D::new(&self.points)
+    }
+
+    /// Reverses the winding order of the polygon.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, U> Clone for Polygon2D<T, U> {
+    fn clone(&self) -> Self {
+        Polygon2D::new(self.points.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> Default for Polygon2D<T, U> {
+    fn default() -> Self {
+        Polygon2D::new(Vec::new())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> From<Vec<Point2D<T, U>>> for Polygon2D<T, U> {
+    fn from(points: Vec<Point2D<T, U>>) -> Self {
+        Polygon2D::new(points)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> FromIterator<Point2D<T, U>> for Polygon2D<T, U> {
+    fn from_iter<I: IntoIterator<Item = Point2D<T, U>>>(iter: I) -> Self {
+        Polygon2D::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FillRule, Winding};
+    use crate::default::{Box2D, Plane2D, Polygon2D, PolygonRef2D};
+    use crate::point2;
+
+    #[test]
+    fn test_area_and_winding() {
+        let mut p: Polygon2D<i32> = vec![
+            point2(0, 0),
+            point2(6, 0),
+            point2(6, 2),
+            point2(2, 2),
+            point2(2, 6),
+            point2(0, 6),
+        ]
+        .into();
+        assert_eq!(p.double_signed_area(), 40);
+        assert_eq!(p.signed_area(), 20);
+        assert_eq!(p.winding(), Some(Winding::CounterClockwise));
+        assert_eq!(p.bounding_box(), Box2D::new(point2(0, 0), point2(6, 6)));
+        assert_eq!(p.edges().count(), 6);
+        assert_eq!(p.edges().last().unwrap().to, point2(0, 0));
+
+        p.reverse();
+        assert_eq!(p.signed_area(), -20);
+        assert_eq!(p.winding(), Some(Winding::Clockwise));
+        assert_eq!(p.as_polygon_ref().to_polygon(), p);
+
+        let flat = [point2(0, 0), point2(1, 1), point2(2, 2)];
+        assert_eq!(PolygonRef2D::new(&flat).winding(), None);
+        assert_eq!(PolygonRef2D::<i32>::new(&[]).signed_area(), 0);
+    }
+
+    #[test]
+    fn test_is_convex() {
+        let square = [point2(0, 0), point2(4, 0), point2(4, 4), point2(0, 4)];
+        assert!(PolygonRef2D::new(&square).is_convex());
+        let mut reversed = square;
+        reversed.reverse();
+        assert!(PolygonRef2D::new(&reversed).is_convex());
+
+        // Collinear and repeated vertices are fine.
+        let padded = [
+            point2(0, 0),
+            point2(2, 0),
+            point2(4, 0),
+            point2(4, 4),
+            point2(4, 4),
+            point2(0, 4),
+        ];
+        assert!(PolygonRef2D::new(&padded).is_convex());
+
+        let notch = [
+            point2(0, 0),
+            point2(4, 0),
+            point2(2, 1),
+            point2(4, 4),
+            point2(0, 4),
+        ];
+        assert!(!PolygonRef2D::new(&notch).is_convex());
+
+        // Turns the same way at every vertex, but goes around twice.
+        let star = [
+            point2(0, 3),
+            point2(2, -3),
+            point2(-3, 1),
+            point2(3, 1),
+            point2(-2, -3),
+        ];
+        assert!(!PolygonRef2D::new(&star).is_convex());
+
+        assert!(!PolygonRef2D::new(&square[..2]).is_convex());
+        let flat = [point2(0, 0), point2(1, 1), point2(2, 2)];
+        assert!(!PolygonRef2D::new(&flat).is_convex());
+    }
+
+    #[test]
+    fn test_contains() {
+        let l = [
+            point2(0.0, 0.0),
+            point2(6.0, 0.0),
+            point2(6.0, 2.0),
+            point2(2.0, 2.0),
+            point2(2.0, 6.0),
+            point2(0.0, 6.0),
+        ];
+        let p = PolygonRef2D::new(&l);
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            assert!(p.contains(point2(1.0, 1.0), rule));
+            assert!(p.contains(point2(5.0, 1.0), rule));
+            assert!(p.contains(point2(1.0, 5.0), rule));
+            assert!(!p.contains(point2(4.0, 4.0), rule));
+            assert!(!p.contains(point2(7.0, 1.0), rule));
+            assert!(!p.contains(point2(-1.0, 1.0), rule));
+        }
+        assert_eq!(p.winding_number(point2(1.0, 1.0)), 1);
+
+        let mut reversed = l;
+        reversed.reverse();
+        assert_eq!(
+            PolygonRef2D::new(&reversed).winding_number(point2(1.0, 1.0)),
+            -1
+        );
+
+        // A square going around twice.
+        let twice: Polygon2D<f64> = [
+            point2(0.0, 0.0),
+            point2(4.0, 0.0),
+            point2(4.0, 4.0),
+            point2(0.0, 4.0),
+        ]
+        .iter()
+        .cycle()
+        .take(8)
+        .copied()
+        .collect();
+        assert_eq!(twice.winding_number(point2(2.0, 2.0)), 2);
+        assert!(twice.contains(point2(2.0, 2.0), FillRule::NonZero));
+        assert!(!twice.contains(point2(2.0, 2.0), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_clip() {
+        let diamond = [
+            point2(2.0, 0.0),
+            point2(4.0, 2.0),
+            point2(2.0, 4.0),
+            point2(0.0, 2.0),
+        ];
+        let diamond = PolygonRef2D::new(&diamond);
+
+        let b = Box2D::new(point2(1.0, 1.0), point2(5.0, 5.0));
+        let clipped = diamond.clip_to_box(&b);
+        assert_eq!(
+            clipped.points,
+            vec![
+                point2(3.0, 1.0),
+                point2(4.0, 2.0),
+                point2(2.0, 4.0),
+                point2(1.0, 3.0),
+                point2(1.0, 1.0),
+            ]
+        );
+        assert!(clipped.is_convex());
+        assert_eq!(clipped.signed_area(), 6.0);
+
+        let inside = Box2D::new(point2(-1.0, -1.0), point2(5.0, 5.0));
+        assert_eq!(diamond.clip_to_box(&inside), diamond.to_polygon());
+        let outside = Box2D::new(point2(5.0, 5.0), point2(6.0, 6.0));
+        assert!(diamond.clip_to_box(&outside).points.is_empty());
+        let negative = Box2D::new(point2(3.0, 3.0), point2(1.0, 1.0));
+        assert!(diamond.clip_to_box(&negative).points.is_empty());
+
+        // Intersecting two convex polygons by clipping one to the edges of the other.
+        let triangle = [point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 4.0)];
+        let triangle = PolygonRef2D::new(&triangle);
+        let mut intersection = diamond.to_polygon();
+        for edge in triangle.edges() {
+            let plane = Plane2D::from_points(edge.from, edge.to).unwrap();
+            intersection = intersection.clip_to_half_plane(&plane);
+        }
+        assert_eq!(
+            intersection.points,
+            vec![
+                point2(2.0, 0.0),
+                point2(3.0, 1.0),
+                point2(1.0, 3.0),
+                point2(0.0, 2.0),
+            ]
+        );
+    }
+}
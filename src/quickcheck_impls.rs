@@ -0,0 +1,344 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `quickcheck::Arbitrary` implementations.
+//!
+//! Components shrink towards zero, transforms and rotations shrink towards the
+//! identity first, and boxes are generated and shrunk with `min <= max` on every axis.
+
+extern crate alloc;
+
+use crate::{Angle, BoolVector2D, BoolVector3D, Box2D, Box3D, HomogeneousVector, Length};
+use crate::{Point2D, Point3D, Rect, RigidTransform3D, Rotation2D, Rotation3D, Scale};
+use crate::{SideOffsets2D, Size2D, Size3D, Transform2D, Transform3D, Translation2D};
+use crate::{Translation3D, Vector2D, Vector3D};
+
+use alloc::boxed::Box;
+use core::mem::swap;
+use num_traits::{One, Zero};
+use quickcheck::{Arbitrary, Gen};
+
+/// Shrinks one term at a time, keeping the others unchanged.
+fn shrink_terms<T: Arbitrary, const N: usize>(terms: [T; N]) -> impl Iterator<Item = [T; N]> {
+    (0..N).flat_map(move |i| {
+        let terms = terms.clone();
+        terms[i].shrink().map(move |t| {
+            let mut shrunk = terms.clone();
+            shrunk[i] = t;
+            shrunk
+        })
+    })
+}
+
+fn ordered<T: PartialOrd>(min: &mut T, max: &mut T) {
+    if *min > *max {
+        swap(min, max);
+    }
+}
+
+macro_rules! arbitrary_components {
+    ($name:ident [ $($field:ident),* ] ($($ty:ty),*), $ctor:expr) => {
+        impl<T: Arbitrary, U: 'static> Arbitrary for $name<T, U> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                $ctor($( <$ty>::arbitrary(g) ),*)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(
+                    ($( self.$field.clone(), )*)
+                        .shrink()
+                        .map(|($( $field, )*)| $ctor($( $field ),*)),
+                )
+            }
+        }
+    };
+}
+
+arbitrary_components!(Point2D[x, y](T, T), Point2D::new);
+arbitrary_components!(Point3D[x, y, z](T, T, T), Point3D::new);
+arbitrary_components!(Vector2D[x, y](T, T), Vector2D::new);
+arbitrary_components!(Vector3D[x, y, z](T, T, T), Vector3D::new);
+arbitrary_components!(Size2D[width, height](T, T), Size2D::new);
+arbitrary_components!(Size3D[width, height, depth](T, T, T), Size3D::new);
+arbitrary_components!(HomogeneousVector[x, y, z, w](T, T, T, T), HomogeneousVector::new);
+arbitrary_components!(Rect[origin, size](Point2D<T, U>, Size2D<T, U>), Rect::new);
+arbitrary_components!(
+    SideOffsets2D[top, right, bottom, left](T, T, T, T),
+    SideOffsets2D::new
+);
+
+impl<T: Arbitrary> Arbitrary for Angle<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Angle::radians(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.radians.shrink().map(Angle::radians))
+    }
+}
+
+impl<T: Arbitrary, U: 'static> Arbitrary for Length<T, U> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Length::new(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Length::new))
+    }
+}
+
+impl<T: Arbitrary + PartialOrd, U: 'static> Arbitrary for Box2D<T, U> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut b = Box2D::new(Point2D::arbitrary(g), Point2D::arbitrary(g));
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        b
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.min.clone(), self.max.clone())
+                .shrink()
+                .map(|(min, max)| Box2D::new(min, max))
+                .filter(|b| b.min.x <= b.max.x && b.min.y <= b.max.y),
+        )
+    }
+}
+
+impl<T: Arbitrary + PartialOrd, U: 'static> Arbitrary for Box3D<T, U> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut b = Box3D::new(Point3D::arbitrary(g), Point3D::arbitrary(g));
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        ordered(&mut b.min.z, &mut b.max.z);
+        b
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.min.clone(), self.max.clone())
+                .shrink()
+                .map(|(min, max)| Box3D::new(min, max))
+                .filter(|b| b.min.x <= b.max.x && b.min.y <= b.max.y && b.min.z <= b.max.z),
+        )
+    }
+}
+
+impl<T: Arbitrary, Src: 'static, Dst: 'static> Arbitrary for Scale<T, Src, Dst> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Scale::new(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Scale::new))
+    }
+}
+
+impl<T: Arbitrary, Src: 'static, Dst: 'static> Arbitrary for Translation2D<T, Src, Dst> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Translation2D::new(T::arbitrary(g), T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.x.clone(), self.y.clone())
+                .shrink()
+                .map(|(x, y)| Translation2D::new(x, y)),
+        )
+    }
+}
+
+impl<T: Arbitrary, Src: 'static, Dst: 'static> Arbitrary for Translation3D<T, Src, Dst> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Translation3D::new(T::arbitrary(g), T::arbitrary(g), T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.x.clone(), self.y.clone(), self.z.clone())
+                .shrink()
+                .map(|(x, y, z)| Translation3D::new(x, y, z)),
+        )
+    }
+}
+
+impl<T: Arbitrary, Src: 'static, Dst: 'static> Arbitrary for Rotation2D<T, Src, Dst> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Rotation2D::new(Angle::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.angle.shrink().map(Rotation2D::radians))
+    }
+}
+
+/// The generated quaternions are not normalized.
+impl<T, Src: 'static, Dst: 'static> Arbitrary for Rotation3D<T, Src, Dst>
+where
+    T: Arbitrary + Zero + One + PartialEq,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Rotation3D::quaternion(
+            T::arbitrary(g),
+            T::arbitrary(g),
+            T::arbitrary(g),
+            T::arbitrary(g),
+        )
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let identity = Rotation3D::identity();
+        let first = if *self == identity {
+            None
+        } else {
+            Some(identity)
+        };
+        let terms = [
+            self.i.clone(),
+            self.j.clone(),
+            self.k.clone(),
+            self.r.clone(),
+        ];
+        Box::new(
+            first
+                .into_iter()
+                .chain(shrink_terms(terms).map(|[i, j, k, r]| Rotation3D::quaternion(i, j, k, r))),
+        )
+    }
+}
+
+impl<T, Src: 'static, Dst: 'static> Arbitrary for RigidTransform3D<T, Src, Dst>
+where
+    T: Arbitrary + Zero + One + PartialEq,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        RigidTransform3D::new(Rotation3D::arbitrary(g), Vector3D::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.rotation.clone(), self.translation.clone())
+                .shrink()
+                .map(|(rotation, translation)| RigidTransform3D::new(rotation, translation)),
+        )
+    }
+}
+
+impl<T, Src: 'static, Dst: 'static> Arbitrary for Transform2D<T, Src, Dst>
+where
+    T: Arbitrary + Copy + Zero + One + PartialEq,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut terms = [T::zero(); 6];
+        for term in &mut terms {
+            *term = T::arbitrary(g);
+        }
+        Transform2D::from_array(terms)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let identity = Transform2D::identity();
+        let first = if *self == identity {
+            None
+        } else {
+            Some(identity)
+        };
+        Box::new(
+            first
+                .into_iter()
+                .chain(shrink_terms(self.to_array()).map(Transform2D::from_array)),
+        )
+    }
+}
+
+impl<T, Src: 'static, Dst: 'static> Arbitrary for Transform3D<T, Src, Dst>
+where
+    T: Arbitrary + Copy + Zero + One + PartialEq,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut terms = [T::zero(); 16];
+        for term in &mut terms {
+            *term = T::arbitrary(g);
+        }
+        Transform3D::from_array(terms)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let identity = Transform3D::identity();
+        let first = if *self == identity {
+            None
+        } else {
+            Some(identity)
+        };
+        Box::new(
+            first
+                .into_iter()
+                .chain(shrink_terms(self.to_array()).map(Transform3D::from_array)),
+        )
+    }
+}
+
+impl Arbitrary for BoolVector2D {
+    fn arbitrary(g: &mut Gen) -> Self {
+        crate::bvec2(bool::arbitrary(g), bool::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((self.x, self.y).shrink().map(|(x, y)| crate::bvec2(x, y)))
+    }
+}
+
+impl Arbitrary for BoolVector3D {
+    fn arbitrary(g: &mut Gen) -> Self {
+        crate::bvec3(bool::arbitrary(g), bool::arbitrary(g), bool::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.x, self.y, self.z)
+                .shrink()
+                .map(|(x, y, z)| crate::bvec3(x, y, z)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::point2;
+    use quickcheck::quickcheck;
+
+    quickcheck! {
+        fn union_is_commutative(a: default::Box2D<i32>, b: default::Box2D<i32>) -> bool {
+            a.union(&b) == b.union(&a)
+        }
+
+        fn boxes_are_ordered(b: default::Box3D<i64>) -> bool {
+            !b.is_negative()
+        }
+    }
+
+    #[test]
+    fn test_shrink_box() {
+        let b: default::Box2D<i32> = Box2D::new(point2(-5, -3), point2(7, 9));
+        for shrunk in b.shrink() {
+            assert!(shrunk.min.x <= shrunk.max.x && shrunk.min.y <= shrunk.max.y);
+        }
+    }
+
+    #[test]
+    fn test_shrink_transform() {
+        let m = default::Transform3D::translation(1.0, 2.0, 3.0);
+        assert_eq!(m.shrink().next(), Some(default::Transform3D::identity()));
+        assert!(default::Transform3D::<f32>::identity()
+            .shrink()
+            .all(|t| t != default::Transform3D::identity()));
+    }
+}
@@ -0,0 +1,326 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Random sampling of points, directions and rotations with [`rand`].
+//!
+//! Rectangles, boxes, circles and spheres are distributions of the points they
+//! contain, uniformly by area or volume:
+//!
+//! ```
+//! use euclid::default::{Box2D, Point2D};
+//! use euclid::point2;
+//! use rand::distributions::Distribution;
+//! # let mut rng = rand::rngs::mock::StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+//!
+//! let b: Box2D<f32> = Box2D::new(point2(0.0, 0.0), point2(10.0, 5.0));
+//! let p: Point2D<f32> = b.sample(&mut rng);
+//! assert!(b.contains(p));
+//! ```
+//!
+//! [`OnBoundary`] samples the circumference of a circle or the surface of a sphere
+//! instead, and [`UnitCircle`], [`UnitDisc`], [`UnitSphere`] and [`UnitBall`] sample
+//! vectors. [`UniformRotation`] samples rotations uniformly.
+
+use crate::{Box2D, Box3D, Circle, Point2D, Point3D, Rect, Rotation2D, Rotation3D, Sphere};
+use crate::{Vector2D, Vector3D};
+
+use num_traits::{Float, FloatConst};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+/// Returns a random angle in `[0, 2π)`.
+fn turn<T, R>(rng: &mut R) -> T
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+    R: Rng + ?Sized,
+{
+    T::TAU() * rng.gen::<T>()
+}
+
+/// Samples vectors of length one, uniformly distributed in direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitCircle;
+
+/// Samples vectors uniformly distributed inside the disc of radius one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitDisc;
+
+/// Samples vectors of length one, uniformly distributed on the sphere.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitSphere;
+
+/// Samples vectors uniformly distributed inside the ball of radius one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitBall;
+
+/// Samples rotations uniformly, so that every orientation is equally likely.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UniformRotation;
+
+/// Samples points on the boundary of a [`Circle`] or a [`Sphere`], uniformly by
+/// length or area.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OnBoundary<S>(pub S);
+
+impl<T, U> Distribution<Vector2D<T, U>> for UnitCircle
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2D<T, U> {
+        let (sin, cos) = turn::<T, R>(rng).sin_cos();
+        Vector2D::new(cos, sin)
+    }
+}
+
+impl<T, U> Distribution<Vector2D<T, U>> for UnitDisc
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2D<T, U> {
+        let direction: Vector2D<T, U> = UnitCircle.sample(rng);
+        direction * rng.gen::<T>().sqrt()
+    }
+}
+
+impl<T, U> Distribution<Vector3D<T, U>> for UnitSphere
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3D<T, U> {
+        // The height is uniform in [-1, 1], by Archimedes' hat-box theorem.
+        let u = rng.gen::<T>();
+        let z = T::one() - (u + u);
+        let r = (T::one() - z * z).max(T::zero()).sqrt();
+        let (sin, cos) = turn::<T, R>(rng).sin_cos();
+        Vector3D::new(r * cos, r * sin, z)
+    }
+}
+
+impl<T, U> Distribution<Vector3D<T, U>> for UnitBall
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3D<T, U> {
+        let direction: Vector3D<T, U> = UnitSphere.sample(rng);
+        direction * rng.gen::<T>().cbrt()
+    }
+}
+
+impl<T, Src, Dst> Distribution<Rotation2D<T, Src, Dst>> for UniformRotation
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotation2D<T, Src, Dst> {
+        Rotation2D::radians(turn::<T, R>(rng) - T::PI())
+    }
+}
+
+/// Uses the method from Ken Shoemake's "Uniform random rotations", in Graphics
+/// Gems III.
+impl<T, Src, Dst> Distribution<Rotation3D<T, Src, Dst>> for UniformRotation
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotation3D<T, Src, Dst> {
+        let u = rng.gen::<T>();
+        let (a, b) = ((T::one() - u).sqrt(), u.sqrt());
+        let (sin1, cos1) = turn::<T, R>(rng).sin_cos();
+        let (sin2, cos2) = turn::<T, R>(rng).sin_cos();
+        Rotation3D::quaternion(a * sin1, a * cos1, b * sin2, b * cos2)
+    }
+}
+
+impl<T, U> Distribution<Point2D<T, U>> for Rect<T, U>
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2D<T, U> {
+        Point2D::new(
+            self.origin.x + self.size.width * rng.gen::<T>(),
+            self.origin.y + self.size.height * rng.gen::<T>(),
+        )
+    }
+}
+
+impl<T, U> Distribution<Point2D<T, U>> for Box2D<T, U>
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2D<T, U> {
+        self.min + (self.max - self.min).component_mul(Vector2D::new(rng.gen(), rng.gen()))
+    }
+}
+
+impl<T, U> Distribution<Point3D<T, U>> for Box3D<T, U>
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3D<T, U> {
+        self.min
+            + (self.max - self.min).component_mul(Vector3D::new(rng.gen(), rng.gen(), rng.gen()))
+    }
+}
+
+impl<T, U> Distribution<Point2D<T, U>> for Circle<T, U>
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2D<T, U> {
+        let offset: Vector2D<T, U> = UnitDisc.sample(rng);
+        self.center + offset * self.radius
+    }
+}
+
+impl<T, U> Distribution<Point3D<T, U>> for Sphere<T, U>
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3D<T, U> {
+        let offset: Vector3D<T, U> = UnitBall.sample(rng);
+        self.center + offset * self.radius
+    }
+}
+
+impl<T, U> Distribution<Point2D<T, U>> for OnBoundary<Circle<T, U>>
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2D<T, U> {
+        let offset: Vector2D<T, U> = UnitCircle.sample(rng);
+        self.0.center + offset * self.0.radius
+    }
+}
+
+impl<T, U> Distribution<Point3D<T, U>> for OnBoundary<Sphere<T, U>>
+where
+    T: Float + FloatConst,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3D<T, U> {
+        let offset: Vector3D<T, U> = UnitSphere.sample(rng);
+        self.0.center + offset * self.0.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approxeq::ApproxEq;
+    use crate::default;
+    use crate::{point2, point3, vec3};
+    use rand::RngCore;
+
+    /// A xorshift64* generator, to keep the tests deterministic.
+    struct XorShift(u64);
+
+    impl RngCore for XorShift {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn rng() -> XorShift {
+        XorShift(0x9e37_79b9_7f4a_7c15)
+    }
+
+    #[test]
+    fn test_rect_and_boxes() {
+        let mut rng = rng();
+        let r: default::Rect<f32> = Rect::new(point2(-1.0, 2.0), crate::size2(3.0, 0.5));
+        let b: default::Box2D<f64> = Box2D::new(point2(-1.0, -2.0), point2(1.0, 0.0));
+        let b3: default::Box3D<f64> = Box3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 2.0, 3.0));
+        for _ in 0..100 {
+            assert!(r.contains(r.sample(&mut rng)));
+            assert!(b.contains(b.sample(&mut rng)));
+            assert!(b3.contains(b3.sample(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_circles_and_spheres() {
+        let mut rng = rng();
+        let c: default::Circle<f64> = Circle::new(point2(1.0, 2.0), 3.0);
+        let s: default::Sphere<f64> = Sphere::new(point3(1.0, 2.0, 3.0), 0.5);
+        let mut quadrants = [0; 4];
+        for _ in 0..100 {
+            let p = c.sample(&mut rng);
+            assert!((p - c.center).length() <= c.radius);
+            quadrants[(p.x > c.center.x) as usize * 2 + (p.y > c.center.y) as usize] += 1;
+            assert!((s.sample(&mut rng) - s.center).length() <= s.radius);
+
+            let p = OnBoundary(c).sample(&mut rng);
+            assert!((p - c.center).length().approx_eq(&c.radius));
+            let p = OnBoundary(s).sample(&mut rng);
+            assert!((p - s.center).length().approx_eq(&s.radius));
+        }
+        assert!(quadrants.iter().all(|&n| n > 10));
+    }
+
+    #[test]
+    fn test_unit_vectors() {
+        let mut rng = rng();
+        let mut sum = vec3(0.0, 0.0, 0.0);
+        for _ in 0..1000 {
+            let v: default::Vector2D<f32> = UnitCircle.sample(&mut rng);
+            assert!(v.length().approx_eq(&1.0));
+            let v: default::Vector2D<f32> = UnitDisc.sample(&mut rng);
+            assert!(v.length() <= 1.0);
+            let v: default::Vector3D<f64> = UnitBall.sample(&mut rng);
+            assert!(v.length() <= 1.0);
+            let v: default::Vector3D<f64> = UnitSphere.sample(&mut rng);
+            assert!(v.length().approx_eq(&1.0));
+            sum += v;
+        }
+        // The directions are spread out evenly.
+        assert!(sum.length() < 100.0);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut rng = rng();
+        for _ in 0..100 {
+            let r: default::Rotation3D<f64> = UniformRotation.sample(&mut rng);
+            assert!(r.is_normalized());
+            let r: default::Rotation2D<f32> = UniformRotation.sample(&mut rng);
+            assert!(r.angle >= -core::f32::consts::PI && r.angle <= core::f32::consts::PI);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::vector::{Vector2D, Vector3D};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul};
+
+/// A 2d half-line, starting at `origin` and going along `direction`.
+///
+/// The points of the ray are `origin + direction * t` for `t >= 0`. The direction
+/// doesn't need to be normalized, in which case the parameters `t` are in multiples of
+/// its length rather than distances.
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Ray2D<T, U> {
+    pub origin: Point2D<T, U>,
+    pub direction: Vector2D<T, U>,
+}
+
+/// A 3d half-line, starting at `origin` and going along `direction`.
+///
+/// See [`Ray2D`].
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Ray3D<T, U> {
+    pub origin: Point3D<T, U>,
+    pub direction: Vector3D<T, U>,
+}
+
+macro_rules! ray_impls {
+    ($name:ident, $point:ident, $vector:ident) => {
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.origin.hash(h);
+                self.direction.hash(h);
+            }
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                Self::new(self.origin.clone(), self.direction.clone())
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.origin.eq(&other.origin) && self.direction.eq(&other.direction)
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.origin)
+                    .field(&self.direction)
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a, T, U> arbitrary::Arbitrary<'a> for $name<T, U>
+        where
+            T: arbitrary::Arbitrary<'a>,
+        {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok($name::new(
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                ))
+            }
+        }
+
+        impl<T, U> $name<T, U> {
+            /// Constructor.
+            #[inline]
+            pub const fn new(origin: $point<T, U>, direction: $vector<T, U>) -> Self {
+                $name { origin, direction }
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// Returns the point at `t` along the ray, `origin + direction * t`.
+            #[inline]
+            pub fn point_at(&self, t: T) -> $point<T, U>
+            where
+                T: Add<Output = T> + Mul<Output = T>,
+            {
+                self.origin + self.direction * t
+            }
+
+            /// Drop the units, preserving only the numeric value.
+            #[inline]
+            pub fn to_untyped(&self) -> $name<T, UnknownUnit> {
+                $name::new(self.origin.to_untyped(), self.direction.to_untyped())
+            }
+
+            /// Tag a unitless value with units.
+            #[inline]
+            pub fn from_untyped(r: &$name<T, UnknownUnit>) -> Self {
+                $name::new(
+                    $point::from_untyped(r.origin),
+                    $vector::from_untyped(r.direction),
+                )
+            }
+        }
+    };
+}
+
+ray_impls!(Ray2D, Point2D, Vector2D);
+ray_impls!(Ray3D, Point3D, Vector3D);
+
+/// The slab test: returns the range of `t >= 0` for which the ray is between `min` and
+/// `max` along every axis.
+fn slabs<T: RealScalar, const N: usize>(
+    origin: [T; N],
+    direction: [T; N],
+    min: [T; N],
+    max: [T; N],
+) -> Option<(T, T)> {
+    let mut t_min = T::zero();
+    let mut t_max = T::infinity();
+    for i in 0..N {
+        if direction[i] == T::zero() {
+            // Parallel to the slab, so either always or never within it.
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return None;
+            }
+            continue;
+        }
+
+        let t0 = (min[i] - origin[i]) / direction[i];
+        let t1 = (max[i] - origin[i]) / direction[i];
+        let (near, far) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+        t_min = t_min.max(near);
+        t_max = t_max.min(far);
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    Some((t_min, t_max))
+}
+
+impl<T: RealScalar, U> Ray2D<T, U> {
+    /// Returns the smallest `t` at which the ray is inside of the box, or `None` if it
+    /// misses the box.
+    ///
+    /// The boundary of the box is considered inside, and a ray starting inside of the
+    /// box gives `0`.
+    ///
+    /// ```
+    /// use euclid::default::{Box2D, Ray2D};
+    /// use euclid::{point2, vec2};
+    ///
+    /// let b = Box2D::new(point2(2.0, -1.0), point2(4.0, 1.0));
+    /// let ray = Ray2D::new(point2(0.0, 0.0), vec2(1.0, 0.0));
+    /// assert_eq!(ray.intersection_t(&b), Some(2.0));
+    /// assert_eq!(ray.point_at(2.0), point2(2.0, 0.0));
+    /// assert!(!Ray2D::new(point2(0.0, 0.0), vec2(-1.0, 0.0)).intersects_box(&b));
+    /// ```
+    #[inline]
+    pub fn intersection_t(&self, b: &Box2D<T, U>) -> Option<T> {
+        self.intersection_range(b).map(|(t, _)| t)
+    }
+
+    /// Returns the range of `t` over which the ray is inside of the box, or `None` if it
+    /// misses the box.
+    #[inline]
+    pub fn intersection_range(&self, b: &Box2D<T, U>) -> Option<(T, T)> {
+        slabs(
+            self.origin.to_array(),
+            self.direction.to_array(),
+            b.min.to_array(),
+            b.max.to_array(),
+        )
+    }
+
+    /// Returns `true` if the ray goes through the box.
+    #[inline]
+    pub fn intersects_box(&self, b: &Box2D<T, U>) -> bool {
+        self.intersection_range(b).is_some()
+    }
+}
+
+impl<T: RealScalar, U> Ray3D<T, U> {
+    /// Returns the smallest `t` at which the ray is inside of the box, or `None` if it
+    /// misses the box.
+    ///
+    /// The boundary of the box is considered inside, and a ray starting inside of the
+    /// box gives `0`.
+    #[inline]
+    pub fn intersection_t(&self, b: &Box3D<T, U>) -> Option<T> {
+        self.intersection_range(b).map(|(t, _)| t)
+    }
+
+    /// Returns the range of `t` over which the ray is inside of the box, or `None` if it
+    /// misses the box.
+    #[inline]
+    pub fn intersection_range(&self, b: &Box3D<T, U>) -> Option<(T, T)> {
+        slabs(
+            self.origin.to_array(),
+            self.direction.to_array(),
+            b.min.to_array(),
+            b.max.to_array(),
+        )
+    }
+
+    /// Returns `true` if the ray goes through the box.
+    #[inline]
+    pub fn intersects_box(&self, b: &Box3D<T, U>) -> bool {
+        self.intersection_range(b).is_some()
+    }
+
+    /// Returns the `t` at which the ray crosses the plane going through `point` and
+    /// perpendicular to `normal`, or `None` if the ray is parallel to the plane or
+    /// points away from it.
+    ///
+    /// ```
+    /// use euclid::default::Ray3D;
+    /// use euclid::{point3, vec3};
+    ///
+    /// let ray = Ray3D::new(point3(1.0, 2.0, 10.0), vec3(0.0, 0.0, -2.0));
+    /// let t = ray.plane_intersection_t(point3(0.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(t, Some(5.0));
+    /// assert_eq!(ray.point_at(5.0), point3(1.0, 2.0, 0.0));
+    /// ```
+    pub fn plane_intersection_t(&self, point: Point3D<T, U>, normal: Vector3D<T, U>) -> Option<T> {
+        let denom = self.direction.dot(normal);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let t = (point - self.origin).dot(normal) / denom;
+        if t < T::zero() {
+            return None;
+        }
+
+        Some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Box2D, Box3D, Ray2D, Ray3D};
+    use crate::{point2, point3, vec2, vec3};
+
+    #[test]
+    fn test_ray2d_box() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+        let hit = Ray2D::new(point2(-5.0, 5.0), vec2(1.0, 0.0));
+        let diagonal = Ray2D::new(point2(-2.0, -4.0), vec2(1.0, 1.0));
+        let inside = Ray2D::new(point2(5.0, 5.0), vec2(0.0, -2.0));
+        let behind = Ray2D::new(point2(15.0, 5.0), vec2(1.0, 0.0));
+        let parallel = Ray2D::new(point2(-5.0, 11.0), vec2(1.0, 0.0));
+        let edge = Ray2D::new(point2(-5.0, 10.0), vec2(1.0, 0.0));
+        let miss = Ray2D::new(point2(-5.0, 5.0), vec2(1.0, 2.0));
+
+        assert_eq!(hit.intersection_range(&b), Some((5.0, 15.0)));
+        assert_eq!(diagonal.intersection_t(&b), Some(4.0));
+        assert_eq!(diagonal.point_at(4.0), point2(2.0, 0.0));
+        assert_eq!(inside.intersection_range(&b), Some((0.0, 2.5)));
+        assert_eq!(behind.intersection_t(&b), None);
+        assert_eq!(parallel.intersection_t(&b), None);
+        assert_eq!(edge.intersection_t(&b), Some(5.0));
+        assert!(!miss.intersects_box(&b));
+        assert!(hit.intersects_box(&b));
+    }
+
+    #[test]
+    fn test_ray3d() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 1.0, 1.0));
+        let ray = Ray3D::new(point3(0.5, 0.5, -2.0), vec3(0.0, 0.0, 0.5));
+        assert_eq!(ray.intersection_range(&b), Some((4.0, 6.0)));
+        assert!(!Ray3D::new(point3(2.0, 0.5, -2.0), vec3(0.0, 0.0, 1.0)).intersects_box(&b));
+
+        let origin = point3(0.0, 0.0, 1.0);
+        let normal = vec3(0.0, 0.0, 1.0);
+        assert_eq!(ray.plane_intersection_t(origin, normal), Some(6.0));
+        assert_eq!(ray.plane_intersection_t(origin, -normal), Some(6.0));
+        assert_eq!(
+            ray.plane_intersection_t(point3(0.0, 0.0, -3.0), normal),
+            None
+        );
+        let sideways = Ray3D::new(point3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0));
+        assert_eq!(sideways.plane_intersection_t(origin, normal), None);
+    }
+}
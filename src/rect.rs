@@ -8,17 +8,20 @@
 // except according to those terms.
 
 use super::UnknownUnit;
+use crate::batch::SimdScalar;
 use crate::box2d::Box2D;
+use crate::cast::{cast_component, CastError, RoundingMode};
 use crate::num::*;
-use crate::point::Point2D;
-use crate::scale::Scale;
+use crate::point::{point2, Point2D};
+use crate::scale::{Scale, Scale2D};
 use crate::side_offsets::SideOffsets2D;
 use crate::size::Size2D;
 use crate::vector::Vector2D;
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-use num_traits::{Float, NumCast};
+use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, Float, NumCast};
+use num_traits::{SaturatingAdd, SaturatingSub};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +48,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 ///
 /// [`is_empty`]: Self::is_empty
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -111,12 +118,36 @@ impl<T: Default, U> Default for Rect<T, U> {
     }
 }
 
+impl<T, U> AsRef<[T; 4]> for Rect<T, U> {
+    fn as_ref(&self) -> &[T; 4] {
+        self.as_array()
+    }
+}
+
 impl<T, U> Rect<T, U> {
     /// Constructor.
     #[inline]
     pub const fn new(origin: Point2D<T, U>, size: Size2D<T, U>) -> Self {
         Rect { origin, size }
     }
+
+    /// Constructor taking a tuple with the origin `(x, y)` and the size `(width, height)`.
+    #[inline]
+    pub fn from_tuple(((x, y), (width, height)): ((T, T), (T, T))) -> Self {
+        Rect::new(Point2D::new(x, y), Size2D::new(width, height))
+    }
+
+    /// Returns a reference to the components as an array of x, y, width and height.
+    ///
+    /// `Rect` is `#[repr(C)]` with a `#[repr(C)]` origin and size, so its memory layout
+    /// is guaranteed to be the same as `[x, y, width, height]`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 4] {
+        // SAFETY: `Rect` is `#[repr(C)]` with two fields, `Point2D` and `Size2D`, which
+        // both have the layout of `[T; 2]`, so there is no padding and it has the layout
+        // of `[T; 4]`.
+        unsafe { &*(self as *const Self as *const [T; 4]) }
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -141,46 +172,46 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + Add<T, Output = T>,
+    T: Clone + Add<T, Output = T>,
 {
     #[inline]
     pub fn min(&self) -> Point2D<T, U> {
-        self.origin
+        self.origin.clone()
     }
 
     #[inline]
     pub fn max(&self) -> Point2D<T, U> {
-        self.origin + self.size
+        self.origin.clone() + self.size.clone()
     }
 
     #[inline]
     pub fn max_x(&self) -> T {
-        self.origin.x + self.size.width
+        self.origin.x.clone() + self.size.width.clone()
     }
 
     #[inline]
     pub fn min_x(&self) -> T {
-        self.origin.x
+        self.origin.x.clone()
     }
 
     #[inline]
     pub fn max_y(&self) -> T {
-        self.origin.y + self.size.height
+        self.origin.y.clone() + self.size.height.clone()
     }
 
     #[inline]
     pub fn min_y(&self) -> T {
-        self.origin.y
+        self.origin.y.clone()
     }
 
     #[inline]
     pub fn width(&self) -> T {
-        self.size.width
+        self.size.width.clone()
     }
 
     #[inline]
     pub fn height(&self) -> T {
-        self.size.height
+        self.size.height.clone()
     }
 
     #[inline]
@@ -193,11 +224,49 @@ where
         self.min_y()..self.max_y()
     }
 
+    /// Returns the origin of the rectangle.
+    ///
+    /// The names of the corners assume that the y axis points down.
+    #[inline]
+    pub fn top_left(&self) -> Point2D<T, U> {
+        self.origin.clone()
+    }
+
+    /// Returns the corner with the largest x and the smallest y coordinate.
+    #[inline]
+    pub fn top_right(&self) -> Point2D<T, U> {
+        point2(self.max_x(), self.origin.y.clone())
+    }
+
+    /// Returns the corner with the smallest x and the largest y coordinate.
+    #[inline]
+    pub fn bottom_left(&self) -> Point2D<T, U> {
+        point2(self.origin.x.clone(), self.max_y())
+    }
+
+    /// Returns the corner opposite to the origin.
+    #[inline]
+    pub fn bottom_right(&self) -> Point2D<T, U> {
+        self.max()
+    }
+
+    /// Returns the four corners, clockwise from the top left one when the y axis points
+    /// down. This is the same order as [`Box2D::corners`].
+    #[inline]
+    pub fn corners(&self) -> [Point2D<T, U>; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
     /// Returns the same rectangle, translated by a vector.
     #[inline]
     #[must_use]
     pub fn translate(&self, by: Vector2D<T, U>) -> Self {
-        Self::new(self.origin + by, self.size)
+        Self::new(self.origin.clone() + by, self.size.clone())
     }
 
     #[inline]
@@ -211,7 +280,7 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + PartialOrd + Add<T, Output = T>,
+    T: Clone + PartialOrd + Add<T, Output = T>,
 {
     /// Returns `true` if this rectangle contains the point. Points are considered
     /// in the rectangle if they are on the left or top edge, but outside if they
@@ -229,7 +298,7 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
+    T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
 {
     #[inline]
     pub fn intersection(&self, other: &Self) -> Option<Self> {
@@ -245,16 +314,19 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + Add<T, Output = T> + Sub<T, Output = T>,
+    T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
 {
     #[inline]
     #[must_use]
     pub fn inflate(&self, width: T, height: T) -> Self {
         Rect::new(
-            Point2D::new(self.origin.x - width, self.origin.y - height),
+            Point2D::new(
+                self.origin.x.clone() - width.clone(),
+                self.origin.y.clone() - height.clone(),
+            ),
             Size2D::new(
-                self.size.width + width + width,
-                self.size.height + height + height,
+                self.size.width.clone() + width.clone() + width,
+                self.size.height.clone() + height.clone() + height,
             ),
         )
     }
@@ -262,7 +334,7 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + Zero + PartialOrd + Add<T, Output = T>,
+    T: Clone + Zero + PartialOrd + Add<T, Output = T>,
 {
     /// Returns `true` if this rectangle contains the interior of `rect`. Always
     /// returns `true` if `rect` is empty, and always returns `false` if `rect` is
@@ -294,8 +366,10 @@ where
                 self.size.height - offsets.vertical(),
             ),
         );
-        debug_assert!(rect.size.width >= Zero::zero());
-        debug_assert!(rect.size.height >= Zero::zero());
+        check_invariant!(
+            rect.size.width >= Zero::zero() && rect.size.height >= Zero::zero(),
+            "Rect::inner_rect: the offsets are larger than the rectangle"
+        );
         rect
     }
 }
@@ -345,7 +419,7 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + SimdScalar,
 {
     /// Linearly interpolate between this rectangle and another rectangle.
     #[inline]
@@ -359,22 +433,35 @@ where
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + One + Add<Output = T> + Div<Output = T>,
+    T: Copy + Add<Output = T> + Half,
 {
     pub fn center(&self) -> Point2D<T, U> {
-        let two = T::one() + T::one();
-        self.origin + self.size.to_vector() / two
+        point2(
+            self.origin.x + self.size.width.half(),
+            self.origin.y + self.size.height.half(),
+        )
     }
 }
 
 impl<T, U> Rect<T, U>
 where
-    T: Copy + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Zero,
+    T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Zero,
 {
     #[inline]
     pub fn union(&self, other: &Self) -> Self {
         self.to_box2d().union(&other.to_box2d()).to_rect()
     }
+
+    /// Returns the parts of the rectangle that `other` doesn't cover, as up to four
+    /// non-overlapping rectangles.
+    ///
+    /// See [`Box2D::difference`].
+    #[inline]
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        self.to_box2d()
+            .difference(&other.to_box2d())
+            .map(|b| b.to_rect())
+    }
 }
 
 impl<T, U> Rect<T, U> {
@@ -390,48 +477,193 @@ impl<T, U> Rect<T, U> {
     }
 }
 
-impl<T: Copy + Mul<T, Output = T>, U> Rect<T, U> {
+impl<T: Clone + Mul<T, Output = T>, U> Rect<T, U> {
     #[inline]
     pub fn area(&self) -> T {
-        self.size.area()
+        self.size.clone().area()
     }
 }
 
-impl<T: Copy + Zero + PartialOrd, U> Rect<T, U> {
+/// Overflow-checked operations, for integer coordinates.
+///
+/// These return `None` instead of wrapping around or panicking when a coordinate, the
+/// size or the maximum corner `origin + size` doesn't fit in `T`.
+impl<T, U> Rect<T, U>
+where
+    T: Copy + CheckedAdd + CheckedSub,
+{
+    /// Same as [`max`](Self::max), returning `None` on overflow.
+    #[inline]
+    pub fn checked_max(&self) -> Option<Point2D<T, U>> {
+        Some(point2(
+            self.origin.x.checked_add(&self.size.width)?,
+            self.origin.y.checked_add(&self.size.height)?,
+        ))
+    }
+
+    /// Same as [`to_box2d`](Self::to_box2d), returning `None` on overflow.
+    #[inline]
+    pub fn checked_to_box2d(&self) -> Option<Box2D<T, U>> {
+        Some(Box2D::new(self.origin, self.checked_max()?))
+    }
+
+    /// Same as [`translate`](Self::translate), returning `None` on overflow.
+    #[inline]
+    pub fn checked_translate(&self, by: Vector2D<T, U>) -> Option<Self> {
+        let origin = point2(
+            self.origin.x.checked_add(&by.x)?,
+            self.origin.y.checked_add(&by.y)?,
+        );
+        Rect::new(origin, self.size).checked_valid()
+    }
+
+    /// Same as [`inflate`](Self::inflate), returning `None` on overflow.
+    #[inline]
+    pub fn checked_inflate(&self, width: T, height: T) -> Option<Self> {
+        let origin = point2(
+            self.origin.x.checked_sub(&width)?,
+            self.origin.y.checked_sub(&height)?,
+        );
+        let size = Size2D::new(
+            self.size.width.checked_add(&width)?.checked_add(&width)?,
+            self.size
+                .height
+                .checked_add(&height)?
+                .checked_add(&height)?,
+        );
+        Rect::new(origin, size).checked_valid()
+    }
+
+    /// Same as [`union`](Self::union), returning `None` on overflow.
+    ///
+    /// ```
+    /// use euclid::default::Rect;
+    /// use euclid::rect;
+    ///
+    /// let a: Rect<i32> = rect(-10, 0, 10, 10);
+    /// assert_eq!(a.checked_union(&rect(20, 0, 10, 10)), Some(rect(-10, 0, 40, 10)));
+    /// assert_eq!(a.checked_union(&rect(i32::MAX - 10, 0, 10, 10)), None);
+    /// ```
+    #[inline]
+    pub fn checked_union(&self, other: &Self) -> Option<Self>
+    where
+        T: PartialOrd,
+    {
+        let b = self.checked_to_box2d()?.union(&other.checked_to_box2d()?);
+        b.checked_to_rect()
+    }
+
+    /// Same as [`area`](Self::area), returning `None` on overflow.
+    #[inline]
+    pub fn checked_area(&self) -> Option<T>
+    where
+        T: CheckedMul,
+    {
+        self.size.width.checked_mul(&self.size.height)
+    }
+
+    /// Returns the rectangle if its maximum corner doesn't overflow.
+    #[inline]
+    fn checked_valid(self) -> Option<Self> {
+        self.checked_max().map(|_| self)
+    }
+}
+
+/// Saturating operations, for integer coordinates.
+///
+/// These clamp the coordinates to the range of `T` instead of wrapping around or
+/// panicking. The maximum corner `origin + size` is clamped as well, so the size shrinks
+/// when the rectangle reaches the largest value of `T`, and it is clamped too when it
+/// doesn't fit in `T`. Use the `checked_*` methods to detect these cases instead.
+impl<T, U> Rect<T, U>
+where
+    T: Copy + SaturatingAdd + SaturatingSub,
+{
+    /// Same as [`to_box2d`](Self::to_box2d), clamping the maximum corner.
+    #[inline]
+    pub fn saturating_to_box2d(&self) -> Box2D<T, U> {
+        Box2D::new(
+            self.origin,
+            point2(
+                self.origin.x.saturating_add(&self.size.width),
+                self.origin.y.saturating_add(&self.size.height),
+            ),
+        )
+    }
+
+    /// Same as [`translate`](Self::translate), clamping the coordinates.
+    #[inline]
+    pub fn saturating_translate(&self, by: Vector2D<T, U>) -> Self {
+        self.saturating_to_box2d()
+            .saturating_translate(by)
+            .saturating_to_rect()
+    }
+
+    /// Same as [`inflate`](Self::inflate), clamping the coordinates and the size.
+    #[inline]
+    pub fn saturating_inflate(&self, width: T, height: T) -> Self {
+        self.saturating_to_box2d()
+            .saturating_inflate(width, height)
+            .saturating_to_rect()
+    }
+
+    /// Same as [`union`](Self::union), clamping the coordinates and the size.
+    ///
+    /// ```
+    /// use euclid::default::Rect;
+    /// use euclid::rect;
+    ///
+    /// let a: Rect<i32> = rect(0, 0, 10, 10);
+    /// let b: Rect<i32> = rect(i32::MAX - 5, 0, 10, 10);
+    /// assert_eq!(a.saturating_union(&b), rect(0, 0, i32::MAX, 10));
+    /// assert_eq!(a.saturating_union(&rect(i32::MIN, 0, 10, 10)), rect(i32::MIN, 0, i32::MAX, 10));
+    /// ```
+    #[inline]
+    pub fn saturating_union(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.saturating_to_box2d()
+            .saturating_union(&other.saturating_to_box2d())
+            .saturating_to_rect()
+    }
+}
+
+impl<T: Clone + Zero + PartialOrd, U> Rect<T, U> {
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.size.is_empty()
+        self.size.clone().is_empty()
     }
 }
 
-impl<T: Copy + Zero + PartialOrd, U> Rect<T, U> {
+impl<T: Clone + Zero + PartialOrd, U> Rect<T, U> {
     #[inline]
     pub fn to_non_empty(&self) -> Option<Self> {
         if self.is_empty() {
             return None;
         }
 
-        Some(*self)
+        Some(self.clone())
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Rect<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Rect<T, U> {
     type Output = Rect<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        Rect::new(self.origin * scale, self.size * scale)
+        Rect::new(self.origin * scale.clone(), self.size * scale)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Rect<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Rect<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
         *self *= Scale::new(scale);
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Rect<T, U> {
+impl<T: Clone + Div, U> Div<T> for Rect<T, U> {
     type Output = Rect<T::Output, U>;
 
     #[inline]
@@ -440,14 +672,14 @@ impl<T: Copy + Div, U> Div<T> for Rect<T, U> {
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Rect<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Rect<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
         *self /= Scale::new(scale);
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Rect<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Rect<T, U1> {
     type Output = Rect<T::Output, U2>;
 
     #[inline]
@@ -456,7 +688,7 @@ impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Rect<T, U1> {
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Rect<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Rect<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
         self.origin *= scale.clone();
@@ -464,7 +696,7 @@ impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Rect<T, U> {
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Rect<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Rect<T, U2> {
     type Output = Rect<T::Output, U1>;
 
     #[inline]
@@ -473,7 +705,7 @@ impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Rect<T, U2> {
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Rect<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Rect<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
         self.origin /= scale.clone();
@@ -481,7 +713,68 @@ impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Rect<T, U> {
     }
 }
 
+impl<T: Clone + Mul, U1, U2> Mul<Scale2D<T, U1, U2>> for Rect<T, U1> {
+    type Output = Rect<T::Output, U2>;
+
+    #[inline]
+    fn mul(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        Rect::new(self.origin * scale.clone(), self.size * scale)
+    }
+}
+
+impl<T: Clone + MulAssign, U> MulAssign<Scale2D<T, U, U>> for Rect<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.origin *= scale.clone();
+        self.size *= scale;
+    }
+}
+
+impl<T: Clone + Div, U1, U2> Div<Scale2D<T, U1, U2>> for Rect<T, U2> {
+    type Output = Rect<T::Output, U1>;
+
+    #[inline]
+    fn div(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        Rect::new(self.origin / scale.clone(), self.size / scale)
+    }
+}
+
+impl<T: Clone + DivAssign, U> DivAssign<Scale2D<T, U, U>> for Rect<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.origin /= scale.clone();
+        self.size /= scale;
+    }
+}
+
 impl<T: Copy, U> Rect<T, U> {
+    /// Cast into an array with x, y, width and height.
+    #[inline]
+    pub fn to_array(&self) -> [T; 4] {
+        [
+            self.origin.x,
+            self.origin.y,
+            self.size.width,
+            self.size.height,
+        ]
+    }
+
+    /// Cast into a tuple with the origin `(x, y)` and the size `(width, height)`.
+    ///
+    /// ```
+    /// use euclid::default::Rect;
+    /// use euclid::rect;
+    ///
+    /// let r: Rect<i32> = rect(1, 2, 30, 40);
+    /// let ((x, y), (w, h)) = r.to_tuple();
+    /// assert_eq!((x, y, w, h), (1, 2, 30, 40));
+    /// assert_eq!(Rect::from_tuple(r.to_tuple()), r);
+    /// ```
+    #[inline]
+    pub fn to_tuple(&self) -> ((T, T), (T, T)) {
+        (self.origin.to_tuple(), self.size.to_tuple())
+    }
+
     /// Drop the units, preserving only the numeric value.
     #[inline]
     pub fn to_untyped(&self) -> Rect<T, UnknownUnit> {
@@ -535,6 +828,74 @@ impl<T: NumCast + Copy, U> Rect<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Rect::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(&self) -> Result<Rect<NewT, U>, CastError> {
+        Ok(Rect::new(
+            Point2D::new(
+                cast_component(self.origin.x, "origin.x")?,
+                cast_component(self.origin.y, "origin.y")?,
+            ),
+            Size2D::new(
+                cast_component(self.size.width, "size.width")?,
+                cast_component(self.size.height, "size.height")?,
+            ),
+        ))
+    }
+
+    /// Rounds the edges with the given mode and casts the result to another numeric
+    /// representation, preserving the units.
+    ///
+    /// The size is computed from the rounded edges, so that rectangles sharing an edge
+    /// still share it after the cast. See [`Rect::round_out`] and [`Rect::round_in`] to
+    /// round outward or inward instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded edge can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT>(&self, mode: RoundingMode) -> Rect<NewT, U>
+    where
+        T: Add<Output = T>,
+        NewT: NumCast + Copy + Sub<Output = NewT>,
+    {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Rect::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT>(&self, mode: RoundingMode) -> Option<Rect<NewT, U>>
+    where
+        T: Add<Output = T>,
+        NewT: NumCast + Copy + Sub<Output = NewT>,
+    {
+        self.to_box2d()
+            .try_cast_with_rounding(mode)
+            .map(|b| b.to_rect())
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each edge to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Rect::cast`]. The size
+    /// is computed from the clamped edges, so that `max_x()` and `max_y()` don't overflow.
+    pub fn cast_saturating<NewT>(&self) -> Rect<NewT, U>
+    where
+        T: Add<Output = T>,
+        NewT: NumCast + Bounded + Copy + SaturatingSub,
+    {
+        let b = self.to_box2d().cast_saturating::<NewT>();
+        Rect::new(
+            b.min,
+            Size2D::new(
+                b.max.x.saturating_sub(&b.min.x),
+                b.max.y.saturating_sub(&b.min.y),
+            ),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` rectangle.
@@ -549,6 +910,13 @@ impl<T: NumCast + Copy, U> Rect<T, U> {
         self.cast()
     }
 
+    /// Cast angle `f16`.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(&self) -> Rect<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` rectangle, truncating decimals if any.
     ///
     /// When casting from floating point rectangles, it is worth considering whether
@@ -598,6 +966,36 @@ impl<T: NumCast + Copy, U> Rect<T, U> {
     pub fn to_i64(&self) -> Rect<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` rectangle, truncating decimals if any.
+    ///
+    /// When casting from floating point rectangles, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(&self) -> Rect<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` rectangle, truncating decimals if any.
+    ///
+    /// When casting from floating point rectangles, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(&self) -> Rect<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` rectangle, truncating decimals if any.
+    ///
+    /// When casting from floating point rectangles, it is worth considering whether
+    /// to `round()`, `round_in()` or `round_out()` before the cast in order to
+    /// obtain the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(&self) -> Rect<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Rect<T, U> {
@@ -667,9 +1065,38 @@ pub const fn rect<T, U>(x: T, y: T, w: T, h: T) -> Rect<T, U> {
     Rect::new(Point2D::new(x, y), Size2D::new(w, h))
 }
 
+impl<T, U> From<(Point2D<T, U>, Size2D<T, U>)> for Rect<T, U> {
+    fn from((origin, size): (Point2D<T, U>, Size2D<T, U>)) -> Self {
+        Rect::new(origin, size)
+    }
+}
+
+impl<T, U> From<Rect<T, U>> for (Point2D<T, U>, Size2D<T, U>) {
+    fn from(r: Rect<T, U>) -> Self {
+        (r.origin, r.size)
+    }
+}
+
+macro_rules! int_clip {
+    ($($ty:ty),+) => {$(
+        impl<U> Rect<$ty, U> {
+            /// Returns the part of this rectangle that is inside `clip`, or an empty
+            /// rectangle if they don't overlap.
+            ///
+            /// See [`Box2D::clip`] for how this differs from [`Rect::intersection`].
+            #[inline]
+            pub fn clip(&self, clip: &Self) -> Self {
+                self.to_box2d().clip(&clip.to_box2d()).to_rect()
+            }
+        }
+    )+};
+}
+
+int_clip!(i32, u32);
+
 #[cfg(test)]
 mod tests {
-    use crate::default::{Point2D, Rect, Size2D};
+    use crate::default::{Box2D, Point2D, Rect, Size2D};
     use crate::side_offsets::SideOffsets2D;
     use crate::{point2, rect, size2, vec2};
 
@@ -815,6 +1242,93 @@ mod tests {
         assert!(rr.origin.y == -100);
     }
 
+    #[test]
+    fn test_checked() {
+        let r: Rect<i32> = rect(10, 20, 100, 200);
+        assert_eq!(
+            r.checked_translate(vec2(5, -5)),
+            Some(r.translate(vec2(5, -5)))
+        );
+        assert_eq!(r.checked_inflate(3, 4), Some(r.inflate(3, 4)));
+        assert_eq!(r.checked_area(), Some(20_000));
+        assert_eq!(r.checked_to_box2d(), Some(r.to_box2d()));
+
+        // The origin fits, but not the maximum corner.
+        assert_eq!(r.checked_translate(vec2(i32::MAX - 50, 0)), None);
+        let low: Rect<i32> = rect(0, i32::MIN + 5, 10, 10);
+        assert_eq!(low.checked_translate(vec2(0, -10)), None);
+        assert_eq!(r.checked_inflate(i32::MAX / 2, 0), None);
+        assert_eq!(rect::<_, ()>(i32::MAX, 0, 1, 1).checked_max(), None);
+        assert_eq!(rect::<i32, ()>(0, 0, 65_536, 65_536).checked_area(), None);
+
+        let far = rect(i32::MIN, 0, 10, 10);
+        assert_eq!(r.checked_union(&far), None);
+        assert_eq!(r.checked_union(&rect(0, 0, 0, 0)), Some(r));
+        assert_eq!(
+            r.checked_union(&rect(0, 0, 5, 5)),
+            Some(r.union(&rect(0, 0, 5, 5)))
+        );
+    }
+
+    #[test]
+    fn test_difference() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 10.0);
+        let parts: Vec<_> = r.difference(&rect(5.0, -5.0, 10.0, 10.0)).collect();
+        assert_eq!(
+            parts,
+            vec![rect(0.0, 0.0, 5.0, 5.0), rect(0.0, 5.0, 10.0, 5.0)]
+        );
+        assert_eq!(r.difference(&r).count(), 0);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let r: Rect<i32> = rect(10, 20, 100, 200);
+        assert_eq!(
+            r.saturating_translate(vec2(5, -5)),
+            r.translate(vec2(5, -5))
+        );
+        assert_eq!(r.saturating_inflate(3, 4), r.inflate(3, 4));
+        assert_eq!(r.saturating_to_box2d(), r.to_box2d());
+        assert_eq!(
+            r.saturating_union(&rect(0, 0, 5, 5)),
+            r.union(&rect(0, 0, 5, 5))
+        );
+
+        // The maximum corner is clamped, so the size shrinks.
+        assert_eq!(
+            r.saturating_translate(vec2(i32::MAX - 50, 0)),
+            rect(i32::MAX - 40, 20, 40, 200)
+        );
+        let low: Rect<i32> = rect(0, i32::MIN + 5, 10, 10);
+        assert_eq!(
+            low.saturating_translate(vec2(0, -10)),
+            rect(0, i32::MIN, 10, 5)
+        );
+        let edge: Rect<i32> = rect(i32::MAX, 0, 1, 1);
+        assert_eq!(
+            edge.saturating_to_box2d(),
+            Box2D::new(point2(i32::MAX, 0), point2(i32::MAX, 1))
+        );
+
+        // Clamped coordinates still contain the original rectangle.
+        let inflated = low.saturating_inflate(0, 10);
+        assert_eq!(inflated, rect(0, i32::MIN, 10, 25));
+        assert!(inflated.contains_rect(&low));
+        let far = rect(i32::MAX - 5, 0, 10, 10);
+        assert_eq!(r.saturating_union(&far), rect(10, 0, i32::MAX - 10, 220));
+
+        // The size is clamped when it doesn't fit.
+        assert_eq!(
+            r.saturating_inflate(0, i32::MAX),
+            rect(10, i32::MIN + 21, 100, i32::MAX)
+        );
+        assert_eq!(
+            r.saturating_union(&rect(i32::MIN, 0, 10, 10)),
+            rect(i32::MIN, 0, i32::MAX, 220)
+        );
+    }
+
     #[test]
     fn test_inflate() {
         let p = Rect::new(Point2D::new(0, 0), Size2D::new(10, 10));
@@ -911,6 +1425,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_with_rounding() {
+        use crate::RoundingMode;
+
+        // Rounding the size on its own would give a 0x1 rectangle.
+        let r: Rect<f32> = rect(0.5, 0.0, 0.5, 1.0);
+        assert_eq!(
+            r.cast_with_rounding::<i32>(RoundingMode::Floor),
+            rect(0, 0, 1, 1)
+        );
+
+        // Adjacent rectangles still share their edge.
+        let a: Rect<f32> = rect(0.3, 0.0, 1.4, 1.0);
+        let b: Rect<f32> = rect(1.7, 0.0, 1.4, 1.0);
+        let (a, b) = (
+            a.cast_with_rounding::<i32>(RoundingMode::Round),
+            b.cast_with_rounding::<i32>(RoundingMode::Round),
+        );
+        assert_eq!(a.max_x(), b.min_x());
+    }
+
     #[test]
     fn test_center() {
         let r: Rect<i32> = rect(-2, 5, 4, 10);
@@ -920,6 +1455,13 @@ mod tests {
         assert_eq!(r.center(), point2(2.5, 4.0));
     }
 
+    #[test]
+    fn test_as_array() {
+        let r: Rect<i32> = rect(-2, 5, 4, 10);
+        assert_eq!(r.to_array(), [-2, 5, 4, 10]);
+        assert_eq!(r.as_array(), &r.to_array());
+    }
+
     #[test]
     fn test_nan() {
         let r1: Rect<f32> = rect(-2.0, 5.0, 4.0, std::f32::NAN);
@@ -927,4 +1469,45 @@ mod tests {
 
         assert_eq!(r1.intersection(&r2), None);
     }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        use rkyv::ser::serializers::BufferSerializer;
+        use rkyv::ser::Serializer;
+        use rkyv::{AlignedBytes, Deserialize, Infallible};
+
+        let r: Rect<f32> = rect(1.0, 2.0, 3.0, 4.0);
+
+        let mut serializer = BufferSerializer::new(AlignedBytes([0u8; 64]));
+        let pos = serializer.serialize_value(&r).unwrap();
+        let bytes = serializer.into_inner();
+
+        let archived = unsafe { rkyv::archived_value::<Rect<f32>>(bytes.as_ref(), pos) };
+        assert_eq!(archived.origin.x, 1.0);
+        assert_eq!(archived.size.height, 4.0);
+
+        let deserialized: Rect<f32> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, r);
+    }
+
+    #[test]
+    fn test_clip() {
+        let clip: Rect<u32> = rect(0, 0, 100, 50);
+        let r: Rect<u32> = rect(10, 20, 200, 10);
+        assert_eq!(r.clip(&clip), rect(10, 20, 90, 10));
+        let r: Rect<u32> = rect(150, 20, 10, 10);
+        assert!(r.clip(&clip).is_empty());
+        let r: Rect<i32> = rect(-5, -5, 10, 10);
+        assert_eq!(r.clip(&rect(0, 0, 4, 4)), rect(0, 0, 4, 4));
+    }
+
+    #[test]
+    fn test_small_int_casts() {
+        let r: Rect<f32> = rect(1.5, 2.7, 300.0, 30000.0);
+        assert_eq!(r.to_u16(), rect(1, 2, 300, 30000));
+        assert_eq!(r.to_i16(), rect(1, 2, 300, 30000));
+        assert_eq!(r.to_isize(), rect(1, 2, 300, 30000));
+        assert_eq!(r.to_u64(), rect(1, 2, 300, 30000));
+    }
 }
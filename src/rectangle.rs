@@ -0,0 +1,236 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A common interface for the two representations of axis-aligned rectangles.
+
+use crate::num::Zero;
+use crate::{Box2D, Point2D, Rect, Size2D, Vector2D};
+
+use core::ops::{Add, Sub};
+
+/// An axis-aligned rectangle, represented either by its origin and size ([`Rect`]) or
+/// by its minimum and maximum corners ([`Box2D`]).
+///
+/// Code that only needs the rectangle operations can be generic over this trait, so
+/// that callers don't have to convert between the representations.
+///
+/// ```
+/// use euclid::default::{Box2D, Point2D, Rect};
+/// use euclid::{point2, rect, AxisAlignedRectangle};
+///
+/// fn count_inside<R: AxisAlignedRectangle<f32, euclid::UnknownUnit>>(
+///     area: &R,
+///     points: &[Point2D<f32>],
+/// ) -> usize {
+///     points.iter().filter(|p| area.contains(**p)).count()
+/// }
+///
+/// let points = [point2(1.0, 1.0), point2(5.0, 1.0)];
+/// let r: Rect<f32> = rect(0.0, 0.0, 2.0, 2.0);
+/// assert_eq!(count_inside(&r, &points), 1);
+/// assert_eq!(count_inside(&r.to_box2d(), &points), 1);
+/// ```
+pub trait AxisAlignedRectangle<T, U>: Sized {
+    /// Creates a rectangle from its minimum and maximum corners.
+    fn from_min_max(min: Point2D<T, U>, max: Point2D<T, U>) -> Self;
+
+    /// Returns the corner with the smallest coordinates.
+    fn min(&self) -> Point2D<T, U>;
+
+    /// Returns the corner with the largest coordinates.
+    fn max(&self) -> Point2D<T, U>;
+
+    /// Returns the size of the rectangle.
+    fn size(&self) -> Size2D<T, U>;
+
+    /// Returns `true` if the size is zero, negative or NaN.
+    fn is_empty(&self) -> bool;
+
+    /// Returns `true` if the point is inside the rectangle, excluding its maximum edges.
+    fn contains(&self, p: Point2D<T, U>) -> bool;
+
+    /// Computes the intersection of two rectangles, returning `None` if they do not
+    /// intersect.
+    fn intersection(&self, other: &Self) -> Option<Self>;
+
+    /// Computes the smallest rectangle containing both rectangles.
+    ///
+    /// If either of the rectangles is empty, the other one is returned.
+    fn union(&self, other: &Self) -> Self;
+
+    /// Returns the same rectangle, translated by a vector.
+    fn translate(&self, by: Vector2D<T, U>) -> Self;
+
+    /// Converts into a [`Rect`].
+    fn to_rect(&self) -> Rect<T, U>;
+
+    /// Converts into a [`Box2D`].
+    fn to_box2d(&self) -> Box2D<T, U>;
+}
+
+impl<T, U> AxisAlignedRectangle<T, U> for Rect<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    #[inline]
+    fn from_min_max(min: Point2D<T, U>, max: Point2D<T, U>) -> Self {
+        Box2D::new(min, max).to_rect()
+    }
+
+    #[inline]
+    fn min(&self) -> Point2D<T, U> {
+        Rect::min(self)
+    }
+
+    #[inline]
+    fn max(&self) -> Point2D<T, U> {
+        Rect::max(self)
+    }
+
+    #[inline]
+    fn size(&self) -> Size2D<T, U> {
+        self.size
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Rect::is_empty(self)
+    }
+
+    #[inline]
+    fn contains(&self, p: Point2D<T, U>) -> bool {
+        Rect::contains(self, p)
+    }
+
+    #[inline]
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        Rect::intersection(self, other)
+    }
+
+    #[inline]
+    fn union(&self, other: &Self) -> Self {
+        Rect::union(self, other)
+    }
+
+    #[inline]
+    fn translate(&self, by: Vector2D<T, U>) -> Self {
+        Rect::translate(self, by)
+    }
+
+    #[inline]
+    fn to_rect(&self) -> Rect<T, U> {
+        *self
+    }
+
+    #[inline]
+    fn to_box2d(&self) -> Box2D<T, U> {
+        Rect::to_box2d(self)
+    }
+}
+
+impl<T, U> AxisAlignedRectangle<T, U> for Box2D<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    #[inline]
+    fn from_min_max(min: Point2D<T, U>, max: Point2D<T, U>) -> Self {
+        Box2D::new(min, max)
+    }
+
+    #[inline]
+    fn min(&self) -> Point2D<T, U> {
+        self.min
+    }
+
+    #[inline]
+    fn max(&self) -> Point2D<T, U> {
+        self.max
+    }
+
+    #[inline]
+    fn size(&self) -> Size2D<T, U> {
+        Box2D::size(self)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Box2D::is_empty(self)
+    }
+
+    #[inline]
+    fn contains(&self, p: Point2D<T, U>) -> bool {
+        Box2D::contains(self, p)
+    }
+
+    #[inline]
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        Box2D::intersection(self, other)
+    }
+
+    #[inline]
+    fn union(&self, other: &Self) -> Self {
+        Box2D::union(self, other)
+    }
+
+    #[inline]
+    fn translate(&self, by: Vector2D<T, U>) -> Self {
+        Box2D::translate(self, by)
+    }
+
+    #[inline]
+    fn to_rect(&self) -> Rect<T, U> {
+        Box2D::to_rect(self)
+    }
+
+    #[inline]
+    fn to_box2d(&self) -> Box2D<T, U> {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{point2, rect, size2, vec2};
+
+    // Sums the areas of the intersections of `first` with each of `others`.
+    fn covered<R: AxisAlignedRectangle<i32, crate::UnknownUnit>>(first: &R, others: &[R]) -> i32 {
+        others
+            .iter()
+            .filter_map(|r| first.intersection(r))
+            .map(|r| r.size().area())
+            .sum()
+    }
+
+    #[test]
+    fn test_both_representations() {
+        let rects: [default::Rect<i32>; 3] =
+            [rect(0, 0, 10, 10), rect(-5, -5, 10, 10), rect(8, 0, 10, 1)];
+        let boxes = rects.map(|r| r.to_box2d());
+        assert_eq!(covered(&rects[0], &rects[1..]), 27);
+        assert_eq!(covered(&boxes[0], &boxes[1..]), 27);
+
+        for (r, b) in rects.iter().zip(&boxes) {
+            assert_eq!(AxisAlignedRectangle::min(r), AxisAlignedRectangle::min(b));
+            assert_eq!(AxisAlignedRectangle::max(r), AxisAlignedRectangle::max(b));
+            assert_eq!(AxisAlignedRectangle::size(r), AxisAlignedRectangle::size(b));
+            assert_eq!(AxisAlignedRectangle::to_box2d(r), *b);
+            assert_eq!(AxisAlignedRectangle::to_rect(b), *r);
+            assert_eq!(<default::Rect<i32>>::from_min_max(b.min, b.max), *r);
+        }
+
+        let u = AxisAlignedRectangle::union(&boxes[0], &boxes[1]);
+        assert_eq!(AxisAlignedRectangle::to_rect(&u), rects[0].union(&rects[1]));
+        let t = AxisAlignedRectangle::translate(&rects[2], vec2(1, 1));
+        assert_eq!(t.origin, point2(9, 1));
+        assert_eq!(AxisAlignedRectangle::size(&t), size2(10, 1));
+        assert!(AxisAlignedRectangle::is_empty(&rect::<i32, ()>(0, 0, 0, 1)));
+    }
+}
@@ -0,0 +1,520 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A set of disjoint boxes, with boolean operations.
+
+extern crate alloc;
+
+use crate::num::Zero;
+use crate::{point2, Box2D, Point2D, Vector2D};
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub};
+
+/// A horizontal band of the region, covering `min_y..max_y` with the spans
+/// `spans[start..end]`.
+#[derive(Clone, Copy, PartialEq)]
+struct Band<T> {
+    min_y: T,
+    max_y: T,
+    start: usize,
+    end: usize,
+}
+
+/// An area made of any number of boxes, stored as disjoint boxes.
+///
+/// The region is split into horizontal bands, each with a list of the horizontal spans
+/// it covers. The bands and spans are sorted, and merged whenever they touch and can be
+/// merged, so that two regions covering the same area are equal.
+///
+/// ```
+/// use euclid::default::{Box2D, Region2D};
+/// use euclid::point2;
+///
+/// let a = Region2D::from(Box2D::new(point2(0, 0), point2(10, 10)));
+/// let b = Region2D::from(Box2D::new(point2(5, 5), point2(15, 15)));
+///
+/// let union = a.union(&b);
+/// assert_eq!(union.area(), 175);
+/// assert!(union.contains(point2(12, 12)));
+/// assert!(!union.contains(point2(12, 2)));
+/// assert_eq!(union.iter().count(), 3);
+///
+/// assert_eq!(a.intersection(&b), Region2D::from(Box2D::new(point2(5, 5), point2(10, 10))));
+/// assert_eq!(a.difference(&b).area(), 75);
+/// ```
+pub struct Region2D<T, U> {
+    bands: Vec<Band<T>>,
+    spans: Vec<(T, T)>,
+    _unit: PhantomData<U>,
+}
+
+impl<T, U> Region2D<T, U> {
+    /// Creates an empty region.
+    #[inline]
+    pub const fn new() -> Self {
+        Region2D {
+            bands: Vec::new(),
+            spans: Vec::new(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the region covers no area.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bands.is_empty()
+    }
+
+    /// Removes everything from the region.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bands.clear();
+        self.spans.clear();
+    }
+}
+
+impl<T: Copy + PartialOrd, U> Region2D<T, U> {
+    /// Returns the disjoint boxes making up the region, band by band from the smallest y
+    /// coordinate, and by increasing x coordinate within each band.
+    pub fn iter(&self) -> impl Iterator<Item = Box2D<T, U>> + '_ {
+        self.bands.iter().flat_map(move |band| {
+            self.spans[band.start..band.end]
+                .iter()
+                .map(move |&(min_x, max_x)| {
+                    Box2D::new(point2(min_x, band.min_y), point2(max_x, band.max_y))
+                })
+        })
+    }
+
+    /// Returns the smallest box containing the region, or `None` if it is empty.
+    pub fn bounding_box(&self) -> Option<Box2D<T, U>> {
+        let first = self.bands.first()?;
+        let last = self.bands.last()?;
+        let mut min_x = self.spans[first.start].0;
+        let mut max_x = self.spans[first.end - 1].1;
+        for band in &self.bands[1..] {
+            if self.spans[band.start].0 < min_x {
+                min_x = self.spans[band.start].0;
+            }
+            if self.spans[band.end - 1].1 > max_x {
+                max_x = self.spans[band.end - 1].1;
+            }
+        }
+
+        Some(Box2D::new(
+            point2(min_x, first.min_y),
+            point2(max_x, last.max_y),
+        ))
+    }
+
+    /// Returns `true` if the point is inside of the region, excluding the maximum edges
+    /// of its boxes as in [`Box2D::contains`].
+    pub fn contains(&self, p: Point2D<T, U>) -> bool {
+        let i = self.bands.partition_point(|band| band.max_y <= p.y);
+        match self.bands.get(i) {
+            Some(band) if band.min_y <= p.y => self.spans[band.start..band.end]
+                .iter()
+                .any(|&(min_x, max_x)| min_x <= p.x && p.x < max_x),
+            _ => false,
+        }
+    }
+
+    /// Returns the area of the region.
+    pub fn area(&self) -> T
+    where
+        T: Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let mut area = T::zero();
+        for band in &self.bands {
+            let mut width = T::zero();
+            for &(min_x, max_x) in &self.spans[band.start..band.end] {
+                width = width + (max_x - min_x);
+            }
+            area = area + width * (band.max_y - band.min_y);
+        }
+
+        area
+    }
+
+    /// Returns the region moved by a vector.
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Region2D {
+            bands: self
+                .bands
+                .iter()
+                .map(|band| Band {
+                    min_y: band.min_y + by.y,
+                    max_y: band.max_y + by.y,
+                    ..*band
+                })
+                .collect(),
+            spans: self
+                .spans
+                .iter()
+                .map(|&(min_x, max_x)| (min_x + by.x, max_x + by.x))
+                .collect(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the area covered by either region.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns the area covered by both regions.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns the area covered by `self` but not by `other`.
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Returns the area covered by exactly one of the regions.
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Adds a box to the region.
+    ///
+    /// This rebuilds the region, so adding many boxes is faster with [`Extend`].
+    #[inline]
+    pub fn union_box(&mut self, b: &Box2D<T, U>) {
+        *self = self.union(&Region2D::from(*b));
+    }
+
+    /// Returns the spans of the band at index `i` if it covers `y`.
+    fn spans_at(&self, i: usize, y: T) -> &[(T, T)] {
+        match self.bands.get(i) {
+            Some(band) if band.min_y <= y => &self.spans[band.start..band.end],
+            _ => &[],
+        }
+    }
+
+    /// Appends a band below the existing ones, extending the last band instead if it has
+    /// the same spans and touches the new one.
+    fn push_band(&mut self, min_y: T, max_y: T, spans: &[(T, T)]) {
+        if spans.is_empty() {
+            return;
+        }
+        if let Some(last) = self.bands.last_mut() {
+            if last.max_y == min_y && self.spans[last.start..last.end] == *spans {
+                last.max_y = max_y;
+                return;
+            }
+        }
+
+        let start = self.spans.len();
+        self.spans.extend_from_slice(spans);
+        self.bands.push(Band {
+            min_y,
+            max_y,
+            start,
+            end: self.spans.len(),
+        });
+    }
+
+    /// Builds the region covering `boxes` in a single sweep from top to bottom.
+    fn from_boxes(mut boxes: Vec<Box2D<T, U>>) -> Self {
+        boxes.retain(|b| !b.is_empty());
+        boxes.sort_unstable_by(|a, b| a.min.y.partial_cmp(&b.min.y).unwrap_or(Ordering::Equal));
+
+        let mut ys: Vec<T> = boxes.iter().flat_map(|b| [b.min.y, b.max.y]).collect();
+        sort_and_dedup(&mut ys);
+
+        let mut result = Region2D::new();
+        let mut active: Vec<Box2D<T, U>> = Vec::new();
+        let mut covering: Vec<(T, T)> = Vec::new();
+        let mut spans: Vec<(T, T)> = Vec::new();
+        let mut next = 0;
+        for y in ys.windows(2) {
+            active.retain(|b| b.max.y > y[0]);
+            while next < boxes.len() && boxes[next].min.y <= y[0] {
+                active.push(boxes[next]);
+                next += 1;
+            }
+
+            // Merge the overlapping and touching spans of the boxes covering the band.
+            covering.clear();
+            covering.extend(active.iter().map(|b| (b.min.x, b.max.x)));
+            covering.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            spans.clear();
+            for &(min_x, max_x) in &covering {
+                match spans.last_mut() {
+                    Some(last) if min_x <= last.1 => {
+                        if max_x > last.1 {
+                            last.1 = max_x;
+                        }
+                    }
+                    _ => spans.push((min_x, max_x)),
+                }
+            }
+            result.push_band(y[0], y[1], &spans);
+        }
+
+        result
+    }
+
+    /// Sweeps the two regions from top to bottom, keeping the areas for which `op`
+    /// returns `true` given whether each region covers them.
+    fn combine(&self, other: &Self, op: fn(bool, bool) -> bool) -> Self {
+        let mut ys: Vec<T> = self
+            .bands
+            .iter()
+            .chain(&other.bands)
+            .flat_map(|band| [band.min_y, band.max_y])
+            .collect();
+        sort_and_dedup(&mut ys);
+
+        let mut result = Region2D::new();
+        let mut spans = Vec::new();
+        let mut xs = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        for y in ys.windows(2) {
+            while i < self.bands.len() && self.bands[i].max_y <= y[0] {
+                i += 1;
+            }
+            while j < other.bands.len() && other.bands[j].max_y <= y[0] {
+                j += 1;
+            }
+
+            spans.clear();
+            combine_spans(
+                self.spans_at(i, y[0]),
+                other.spans_at(j, y[0]),
+                op,
+                &mut xs,
+                &mut spans,
+            );
+            result.push_band(y[0], y[1], &spans);
+        }
+
+        result
+    }
+}
+
+fn sort_and_dedup<T: Copy + PartialOrd>(values: &mut Vec<T>) {
+    values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    values.dedup_by(|a, b| a == b);
+}
+
+/// Sweeps two sorted lists of spans from left to right, appending the spans for which
+/// `op` returns `true` to `out`. `xs` is scratch space.
+fn combine_spans<T: Copy + PartialOrd>(
+    a: &[(T, T)],
+    b: &[(T, T)],
+    op: fn(bool, bool) -> bool,
+    xs: &mut Vec<T>,
+    out: &mut Vec<(T, T)>,
+) {
+    xs.clear();
+    xs.extend(a.iter().chain(b).flat_map(|&(min, max)| [min, max]));
+    sort_and_dedup(xs);
+
+    let (mut i, mut j) = (0, 0);
+    for x in xs.windows(2) {
+        while i < a.len() && a[i].1 <= x[0] {
+            i += 1;
+        }
+        while j < b.len() && b[j].1 <= x[0] {
+            j += 1;
+        }
+        let in_a = i < a.len() && a[i].0 <= x[0];
+        let in_b = j < b.len() && b[j].0 <= x[0];
+        if op(in_a, in_b) {
+            match out.last_mut() {
+                Some(last) if last.1 == x[0] => last.1 = x[1],
+                _ => out.push((x[0], x[1])),
+            }
+        }
+    }
+}
+
+impl<T: Clone, U> Clone for Region2D<T, U> {
+    fn clone(&self) -> Self {
+        Region2D {
+            bands: self.bands.clone(),
+            spans: self.spans.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Region2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bands == other.bands && self.spans == other.spans
+    }
+}
+
+impl<T: Eq, U> Eq for Region2D<T, U> {}
+
+impl<T, U> Default for Region2D<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + PartialOrd + fmt::Debug, U> fmt::Debug for Region2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Copy + PartialOrd, U> From<Box2D<T, U>> for Region2D<T, U> {
+    fn from(b: Box2D<T, U>) -> Self {
+        let mut region = Region2D::new();
+        if !b.is_empty() {
+            region.push_band(b.min.y, b.max.y, &[(b.min.x, b.max.x)]);
+        }
+        region
+    }
+}
+
+impl<T: Copy + PartialOrd, U> Extend<Box2D<T, U>> for Region2D<T, U> {
+    /// Adds the boxes to the region, which is rebuilt once for all of them.
+    fn extend<I: IntoIterator<Item = Box2D<T, U>>>(&mut self, iter: I) {
+        let boxes = self.iter().chain(iter).collect();
+        *self = Region2D::from_boxes(boxes);
+    }
+}
+
+impl<T: Copy + PartialOrd, U> FromIterator<Box2D<T, U>> for Region2D<T, U> {
+    fn from_iter<I: IntoIterator<Item = Box2D<T, U>>>(iter: I) -> Self {
+        Region2D::from_boxes(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::{Box2D, Region2D};
+    use crate::{point2, vec2};
+
+    fn region(boxes: &[(i32, i32, i32, i32)]) -> Region2D<i32> {
+        boxes
+            .iter()
+            .map(|&(x0, y0, x1, y1)| Box2D::new(point2(x0, y0), point2(x1, y1)))
+            .collect()
+    }
+
+    #[test]
+    fn test_normalized() {
+        // The same area, built in different ways.
+        let whole = region(&[(0, 0, 10, 10)]);
+        let halves = region(&[(0, 0, 10, 5), (0, 5, 10, 10)]);
+        let columns = region(&[(5, 0, 10, 10), (0, 0, 5, 10)]);
+        let overlapping = region(&[(0, 0, 8, 8), (2, 2, 10, 10), (0, 2, 10, 10), (0, 0, 10, 3)]);
+        assert_eq!(halves, whole);
+        assert_eq!(columns, whole);
+        assert_eq!(overlapping, whole);
+        assert_eq!(
+            whole.iter().collect::<Vec<_>>(),
+            vec![Box2D::new(point2(0, 0), point2(10, 10))]
+        );
+
+        assert!(region(&[]).is_empty());
+        assert!(region(&[(0, 0, 0, 10), (5, 5, 4, 6)]).is_empty());
+        assert_eq!(region(&[]), Region2D::default());
+        assert_eq!(region(&[]).bounding_box(), None);
+
+        // Collecting gives the same region as adding the boxes one by one.
+        let boxes = [
+            (0, 0, 4, 4),
+            (6, 1, 9, 3),
+            (2, 2, 7, 8),
+            (4, 0, 6, 1),
+            (1, 9, 3, 12),
+        ];
+        let mut one_by_one = Region2D::new();
+        for &(x0, y0, x1, y1) in &boxes {
+            one_by_one.union_box(&Box2D::new(point2(x0, y0), point2(x1, y1)));
+        }
+        assert_eq!(region(&boxes), one_by_one);
+        let mut extended = region(&boxes[..2]);
+        extended.extend(region(&boxes[2..]).iter());
+        assert_eq!(extended, one_by_one);
+    }
+
+    #[test]
+    fn test_boolean_operations() {
+        let a = region(&[(0, 0, 10, 10)]);
+        let b = region(&[(5, 5, 15, 15)]);
+
+        let union = a.union(&b);
+        assert_eq!(
+            union.iter().collect::<Vec<_>>(),
+            vec![
+                Box2D::new(point2(0, 0), point2(10, 5)),
+                Box2D::new(point2(0, 5), point2(15, 10)),
+                Box2D::new(point2(5, 10), point2(15, 15)),
+            ]
+        );
+        assert_eq!(union.area(), 175);
+        assert_eq!(
+            union.bounding_box(),
+            Some(Box2D::new(point2(0, 0), point2(15, 15)))
+        );
+
+        assert_eq!(a.intersection(&b), region(&[(5, 5, 10, 10)]));
+        assert_eq!(a.difference(&b), region(&[(0, 0, 10, 5), (0, 5, 5, 10)]));
+        assert_eq!(a.difference(&b).area(), 75);
+        assert_eq!(a.symmetric_difference(&b).area(), 150);
+        assert_eq!(
+            a.symmetric_difference(&b),
+            union.difference(&a.intersection(&b))
+        );
+
+        // A hole in the middle splits the band in two spans.
+        let frame = a.difference(&region(&[(3, 3, 7, 7)]));
+        assert_eq!(frame.iter().count(), 4);
+        assert_eq!(frame.area(), 84);
+        assert!(frame.contains(point2(1, 5)));
+        assert!(frame.contains(point2(8, 5)));
+        assert!(!frame.contains(point2(5, 5)));
+        assert!(!frame.contains(point2(10, 5)));
+        assert!(frame.contains(point2(0, 0)));
+        assert!(!frame.contains(point2(0, 10)));
+        assert_eq!(frame.union(&a), a);
+        assert!(frame.intersection(&region(&[(4, 4, 6, 6)])).is_empty());
+
+        let far = region(&[(20, 20, 30, 30)]);
+        assert_eq!(a.difference(&far), a);
+        assert!(a.intersection(&far).is_empty());
+        assert_eq!(a.union(&far).iter().count(), 2);
+    }
+
+    #[test]
+    fn test_translate() {
+        let r: Region2D<f32> = [
+            Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0)),
+            Box2D::new(point2(2.0, 0.5), point2(3.0, 2.0)),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let moved = r.translate(vec2(10.0, -1.0));
+        assert_eq!(moved.area(), r.area());
+        assert!(moved.contains(point2(12.5, 0.5)));
+        assert!(!moved.contains(point2(2.5, 1.5)));
+        assert_eq!(moved.translate(vec2(-10.0, 1.0)), r);
+    }
+}
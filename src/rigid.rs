@@ -3,8 +3,9 @@
 //! before `T2` you use `T1 * T2`
 
 use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
 use crate::trig::Trig;
-use crate::{Rotation3D, Transform3D, UnknownUnit, Vector3D};
+use crate::{Point3D, Rotation3D, Transform3D, UnknownUnit, Vector3D};
 
 use core::{fmt, hash};
 
@@ -24,6 +25,10 @@ use serde::{Deserialize, Serialize};
 /// have to deal with the decomposed quantities often.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct RigidTransform3D<T, Src, Dst> {
     pub rotation: Rotation3D<T, Src, Dst>,
     pub translation: Vector3D<T, Dst>,
@@ -121,7 +126,10 @@ impl<T: Real + ApproxEq<T>, Src, Dst> RigidTransform3D<T, Src, Dst> {
     pub fn then<Dst2>(
         &self,
         other: &RigidTransform3D<T, Dst, Dst2>,
-    ) -> RigidTransform3D<T, Src, Dst2> {
+    ) -> RigidTransform3D<T, Src, Dst2>
+    where
+        T: SimdScalar,
+    {
         // self = R1 * T1
         // other = R2 * T2
         // result = R1 * T1 * R2 * T2
@@ -163,13 +171,50 @@ impl<T: Real + ApproxEq<T>, Src, Dst> RigidTransform3D<T, Src, Dst> {
 
     pub fn to_transform(&self) -> Transform3D<T, Src, Dst>
     where
-        T: Trig,
+        T: Trig + SimdScalar,
     {
         self.rotation
             .to_transform()
             .then(&self.translation.to_transform())
     }
 
+    /// Extracts the rotation and the translation of a transform, returning `None` if
+    /// the transform is not rigid, for example if it scales, skews or mirrors, or has
+    /// a perspective component.
+    ///
+    /// The transform is only required to be rigid up to the default epsilon of
+    /// [`ApproxEq`], and the rotation is normalized.
+    pub fn from_transform(transform: &Transform3D<T, Src, Dst>) -> Option<Self>
+    where
+        T: Trig + SimdScalar,
+    {
+        let rotation = Rotation3D::from_rotation_matrix(transform).normalize();
+        if !rotation.is_normalized() {
+            // The matrix has NaNs or is too far from a rotation for the quaternion to
+            // be normalized.
+            return None;
+        }
+        let translation = Vector3D::new(transform.m41, transform.m42, transform.m43);
+        let rigid = RigidTransform3D::new(rotation, translation);
+        if !rigid.to_transform().approx_eq(transform) {
+            return None;
+        }
+
+        Some(rigid)
+    }
+
+    /// Returns the given point transformed by this transform.
+    #[inline]
+    pub fn transform_point3d(&self, point: Point3D<T, Src>) -> Point3D<T, Dst> {
+        self.rotation.transform_point3d(point) + self.translation
+    }
+
+    /// Returns the given vector transformed by this transform, which only rotates it.
+    #[inline]
+    pub fn transform_vector3d(&self, vector: Vector3D<T, Src>) -> Vector3D<T, Dst> {
+        self.rotation.transform_vector3d(vector)
+    }
+
     /// Drop the units, preserving only the numeric value.
     #[inline]
     pub fn to_untyped(&self) -> RigidTransform3D<T, UnknownUnit, UnknownUnit> {
@@ -212,6 +257,13 @@ impl<T: hash::Hash, Src, Dst> hash::Hash for RigidTransform3D<T, Src, Dst> {
     }
 }
 
+impl<T: Real + ApproxEq<T>, Src, Dst> Default for RigidTransform3D<T, Src, Dst> {
+    /// Returns the [identity transform](RigidTransform3D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 impl<T: Copy, Src, Dst> Copy for RigidTransform3D<T, Src, Dst> {}
 
 impl<T: Clone, Src, Dst> Clone for RigidTransform3D<T, Src, Dst> {
@@ -259,7 +311,9 @@ impl<T: Real + ApproxEq<T>, Src, Dst> From<Vector3D<T, Dst>> for RigidTransform3
 #[cfg(test)]
 mod test {
     use super::RigidTransform3D;
+    use crate::approxeq::ApproxEq;
     use crate::default::{Rotation3D, Transform3D, Vector3D};
+    use crate::{point3, vec3, Angle};
 
     #[test]
     fn test_rigid_construction() {
@@ -305,6 +359,44 @@ mod test {
             .approx_eq(&rigid.to_transform().inverse().unwrap()));
     }
 
+    #[test]
+    fn test_rigid_from_transform() {
+        let translation = Vector3D::new(12.1, 17.8, -5.5);
+        let rotations = [
+            Rotation3D::unit_quaternion(0.5, -7.8, 2.2, 4.3),
+            Rotation3D::around_x(Angle::degrees(179.0)),
+            Rotation3D::around_y(Angle::degrees(-179.0)),
+            Rotation3D::around_z(Angle::degrees(180.0)),
+        ];
+        for rotation in rotations {
+            let rigid = RigidTransform3D::new(rotation, translation);
+            let extracted = RigidTransform3D::from_transform(&rigid.to_transform()).unwrap();
+            assert!(extracted.to_transform().approx_eq(&rigid.to_transform()));
+            assert!(extracted.rotation.is_normalized());
+
+            let p = point3(1.0, -2.0, 3.0);
+            assert!(rigid
+                .transform_point3d(p)
+                .approx_eq(&rigid.to_transform().transform_point3d(p).unwrap()));
+            let v = vec3(1.0, -2.0, 3.0);
+            assert!(rigid
+                .transform_vector3d(v)
+                .approx_eq(&rigid.to_transform().transform_vector3d(v)));
+        }
+
+        let rigid = RigidTransform3D::new(rotations[0], translation).to_transform();
+        let non_rigid = [
+            rigid.then_scale(2.0, 2.0, 2.0),
+            rigid.then_scale(-1.0, 1.0, 1.0),
+            rigid.then(&Transform3D::skew(Angle::degrees(10.0), Angle::zero())),
+            rigid.then(&Transform3D::perspective(100.0)),
+            Transform3D::scale(0.0, 0.0, 0.0),
+        ];
+        for t in &non_rigid {
+            assert_eq!(RigidTransform3D::from_transform(t), None);
+        }
+    }
+
     #[test]
     fn test_rigid_multiply() {
         let translation = Vector3D::new(12.1, 17.8, -5.5);
@@ -8,9 +8,10 @@
 // except according to those terms.
 
 use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
 use crate::trig::Trig;
-use crate::{point2, point3, vec3, Angle, Point2D, Point3D, Vector2D, Vector3D};
-use crate::{Transform2D, Transform3D, UnknownUnit};
+use crate::{point2, point3, vec3, Angle, Box2D, Point2D, Point3D, Rect, Vector2D, Vector3D};
+use crate::{Handedness, Transform2D, Transform3D, UnknownUnit};
 
 use core::cmp::{Eq, PartialEq};
 use core::fmt;
@@ -27,6 +28,10 @@ use serde::{Deserialize, Serialize};
 
 /// A transform that can represent rotations in 2d, represented as an angle in radians.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -73,6 +78,16 @@ where
     }
 }
 
+impl<T, Src, Dst> Default for Rotation2D<T, Src, Dst>
+where
+    T: Zero,
+{
+    /// Returns the [identity rotation](Rotation2D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, T, Src, Dst> arbitrary::Arbitrary<'a> for Rotation2D<T, Src, Dst>
 where
@@ -100,8 +115,11 @@ impl<T, Src, Dst> Rotation2D<T, Src, Dst> {
     }
 
     /// Creates a rotation from an angle in radians.
-    pub fn radians(angle: T) -> Self {
-        Self::new(Angle::radians(angle))
+    pub const fn radians(angle: T) -> Self {
+        Rotation2D {
+            angle,
+            _unit: PhantomData,
+        }
     }
 
     /// Creates the identity rotation.
@@ -222,6 +240,74 @@ impl<T: Real, Src, Dst> Rotation2D<T, Src, Dst> {
     pub fn transform_vector(&self, vector: Vector2D<T, Src>) -> Vector2D<T, Dst> {
         self.transform_point(vector.to_point()).to_vector()
     }
+
+    /// Returns the four corners of the given box rotated by this rotation, in the
+    /// order of [`Box2D::corners`].
+    ///
+    /// Unlike [`transform_box`](Self::transform_box), this describes the rotated shape
+    /// exactly.
+    #[inline]
+    pub fn transform_corners(&self, b: &Box2D<T, Src>) -> [Point2D<T, Dst>; 4] {
+        let (sin, cos) = Real::sin_cos(self.angle);
+        b.corners()
+            .map(|p| point2(p.x * cos - p.y * sin, p.y * cos + p.x * sin))
+    }
+
+    /// Returns the smallest box containing the given box rotated by this rotation.
+    ///
+    /// ```
+    /// use euclid::default::{Box2D, Rotation2D};
+    /// use euclid::{point2, Angle};
+    /// use euclid::approxeq::ApproxEq;
+    ///
+    /// let r = Rotation2D::new(Angle::degrees(90.0));
+    /// let b = Box2D::new(point2(0.0, 0.0), point2(2.0, 1.0));
+    /// let expected = Box2D::new(point2(-1.0, 0.0), point2(0.0, 2.0));
+    /// assert!(r.transform_box(&b).min.approx_eq(&expected.min));
+    /// assert!(r.transform_box(&b).max.approx_eq(&expected.max));
+    /// ```
+    #[inline]
+    pub fn transform_box(&self, b: &Box2D<T, Src>) -> Box2D<T, Dst> {
+        let [a, b, c, d] = self.transform_corners(b);
+        Box2D::from_four_points(a, b, c, d)
+    }
+
+    /// Returns the smallest rectangle containing the given rectangle rotated by this
+    /// rotation.
+    #[inline]
+    pub fn transform_rect(&self, r: &Rect<T, Src>) -> Rect<T, Dst> {
+        self.transform_box(&r.to_box2d()).to_rect()
+    }
+}
+
+impl<T: Real, U> Rotation2D<T, U, U> {
+    /// Rotates each point of the slice in place.
+    ///
+    /// Equivalent to calling `transform_point` on each element, but the sine and
+    /// cosine of the angle are only computed once.
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point2D<T, U>]) {
+        let (sin, cos) = Real::sin_cos(self.angle);
+        for p in points {
+            let (x, y) = (p.x, p.y);
+            p.x = x * cos - y * sin;
+            p.y = y * cos + x * sin;
+        }
+    }
+
+    /// Rotates each vector of the slice in place.
+    ///
+    /// Equivalent to calling `transform_vector` on each element, but the sine and
+    /// cosine of the angle are only computed once.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [Vector2D<T, U>]) {
+        let (sin, cos) = Real::sin_cos(self.angle);
+        for v in vectors {
+            let (x, y) = (v.x, v.y);
+            v.x = x * cos - y * sin;
+            v.y = y * cos + x * sin;
+        }
+    }
 }
 
 impl<T, Src, Dst> Rotation2D<T, Src, Dst>
@@ -254,6 +340,50 @@ where
     }
 }
 
+/// The order in which the rotations around each axis are applied by
+/// [`Rotation3D::from_euler`] and undone by [`Rotation3D::to_euler`].
+///
+/// The rotations are around the fixed axes of the source space, so with `XYZ` the
+/// rotation around x is applied first, then the one around y and finally the one
+/// around z. This is the same as rotating around the rotated axes in the reverse
+/// order: `XYZ` is the z-y'-x'' convention commonly used for yaw, pitch and roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// Around x, then y, then z.
+    XYZ,
+    /// Around x, then z, then y.
+    XZY,
+    /// Around y, then x, then z.
+    YXZ,
+    /// Around y, then z, then x.
+    YZX,
+    /// Around z, then x, then y.
+    ZXY,
+    /// Around z, then y, then x.
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Returns the indices of the axes in the order the rotations are applied.
+    #[inline]
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// Returns `true` if the axes are not in cyclic order.
+    #[inline]
+    fn is_odd(self) -> bool {
+        matches!(self, EulerOrder::XZY | EulerOrder::YXZ | EulerOrder::ZYX)
+    }
+}
+
 /// A transform that can represent rotations in 3d, represented as a quaternion.
 ///
 /// Most methods expect the quaternion to be normalized.
@@ -267,6 +397,10 @@ where
 /// [`quaternion`]: Self::quaternion
 /// [`unit_quaternion`]: Self::unit_quaternion
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -325,6 +459,16 @@ where
     }
 }
 
+impl<T, Src, Dst> Default for Rotation3D<T, Src, Dst>
+where
+    T: Zero + One,
+{
+    /// Returns the [identity rotation](Rotation3D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 /// Note: the quaternions produced by this implementation are not normalized
 /// (nor even necessarily finite). That is, this is not appropriate to use to
 /// choose an actual “arbitrary rotation”, at least not without postprocessing.
@@ -345,6 +489,55 @@ unsafe impl<T: Zeroable, Src, Dst> Zeroable for Rotation3D<T, Src, Dst> {}
 #[cfg(feature = "bytemuck")]
 unsafe impl<T: Pod, Src: 'static, Dst: 'static> Pod for Rotation3D<T, Src, Dst> {}
 
+/// The quaternion is assumed to be normalized.
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar, Src, Dst> From<Rotation3D<T, Src, Dst>> for nalgebra::UnitQuaternion<T> {
+    fn from(r: Rotation3D<T, Src, Dst>) -> Self {
+        nalgebra::Unit::new_unchecked(nalgebra::Quaternion::new(r.r, r.i, r.j, r.k))
+    }
+}
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar, Src, Dst> From<nalgebra::UnitQuaternion<T>> for Rotation3D<T, Src, Dst> {
+    fn from(q: nalgebra::UnitQuaternion<T>) -> Self {
+        let [i, j, k, r]: [T; 4] = q.into_inner().coords.into();
+        Rotation3D::quaternion(i, j, k, r)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T, Src, Dst> From<Rotation3D<T, Src, Dst>> for mint::Quaternion<T> {
+    fn from(r: Rotation3D<T, Src, Dst>) -> Self {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: r.i,
+                y: r.j,
+                z: r.k,
+            },
+            s: r.r,
+        }
+    }
+}
+#[cfg(feature = "mint")]
+impl<T, Src, Dst> From<mint::Quaternion<T>> for Rotation3D<T, Src, Dst> {
+    fn from(q: mint::Quaternion<T>) -> Self {
+        Rotation3D::quaternion(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+/// The quaternion is assumed to be normalized.
+#[cfg(feature = "glam")]
+impl<Src, Dst> From<Rotation3D<f32, Src, Dst>> for glam::Quat {
+    fn from(r: Rotation3D<f32, Src, Dst>) -> Self {
+        glam::Quat::from_xyzw(r.i, r.j, r.k, r.r)
+    }
+}
+#[cfg(feature = "glam")]
+impl<Src, Dst> From<glam::Quat> for Rotation3D<f32, Src, Dst> {
+    fn from(q: glam::Quat) -> Self {
+        Rotation3D::quaternion(q.x, q.y, q.z, q.w)
+    }
+}
+
 impl<T, Src, Dst> Rotation3D<T, Src, Dst> {
     /// Creates a rotation around from a quaternion representation.
     ///
@@ -356,7 +549,7 @@ impl<T, Src, Dst> Rotation3D<T, Src, Dst> {
     ///
     /// [`unit_quaternion`]: Self::unit_quaternion
     #[inline]
-    pub fn quaternion(a: T, b: T, c: T, r: T) -> Self {
+    pub const fn quaternion(a: T, b: T, c: T, r: T) -> Self {
         Rotation3D {
             i: a,
             j: b,
@@ -482,6 +675,28 @@ where
         Self::quaternion(axis.x * sin, axis.y * sin, axis.z * sin, cos)
     }
 
+    /// Returns the normalized axis and the angle of this rotation, which is between
+    /// 0 and PI.
+    ///
+    /// This is the reverse of [`around_axis`](Self::around_axis). The identity rotation
+    /// has no defined axis, in which case the x axis is returned with a zero angle.
+    pub fn to_axis_angle(&self) -> (Vector3D<T, Src>, Angle<T>) {
+        let q = if self.r < T::zero() {
+            self.mul(-T::one())
+        } else {
+            *self
+        };
+        let axis = vec3(q.i, q.j, q.k);
+        let sin = axis.length();
+        let two = T::one() + T::one();
+        let angle = Angle::radians(sin.atan2(q.r) * two);
+        if sin > T::zero() {
+            (axis / sin, angle)
+        } else {
+            (vec3(T::one(), T::zero(), T::zero()), angle)
+        }
+    }
+
     /// Creates a rotation around the x axis.
     pub fn around_x(angle: Angle<T>) -> Self {
         let zero = Zero::zero();
@@ -506,9 +721,102 @@ where
         Self::quaternion(zero, zero, sin, cos)
     }
 
+    /// Creates the rotation whose matrix is the upper 3x3 part of `m`, which must be
+    /// orthonormal with a positive determinant. The result is not normalized.
+    ///
+    /// This is the reverse of [`to_transform`](Self::to_transform).
+    pub(crate) fn from_rotation_matrix(m: &Transform3D<T, Src, Dst>) -> Self {
+        // The matrices are in row-vector notation, so `m.m12` is the element at the
+        // second row and the first column of the usual column-vector matrix.
+        let one = T::one();
+        let two = one + one;
+        let trace = m.m11 + m.m22 + m.m33;
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::quaternion(
+                (m.m23 - m.m32) / s,
+                (m.m31 - m.m13) / s,
+                (m.m12 - m.m21) / s,
+                s / (two * two),
+            )
+        } else if m.m11 > m.m22 && m.m11 > m.m33 {
+            let s = (one + m.m11 - m.m22 - m.m33).sqrt() * two;
+            Self::quaternion(
+                s / (two * two),
+                (m.m21 + m.m12) / s,
+                (m.m31 + m.m13) / s,
+                (m.m23 - m.m32) / s,
+            )
+        } else if m.m22 > m.m33 {
+            let s = (one + m.m22 - m.m11 - m.m33).sqrt() * two;
+            Self::quaternion(
+                (m.m21 + m.m12) / s,
+                s / (two * two),
+                (m.m32 + m.m23) / s,
+                (m.m31 - m.m13) / s,
+            )
+        } else {
+            let s = (one + m.m33 - m.m11 - m.m22).sqrt() * two;
+            Self::quaternion(
+                (m.m31 + m.m13) / s,
+                (m.m32 + m.m23) / s,
+                s / (two * two),
+                (m.m12 - m.m21) / s,
+            )
+        }
+    }
+
+    /// Creates the rotation of a view looking in `direction`, with `up` pointing towards
+    /// the top of the view.
+    ///
+    /// `direction` is rotated to negative z with [`Handedness::RightHanded`], or positive
+    /// z with [`Handedness::LeftHanded`]. `up` does not need to be perpendicular to
+    /// `direction`, but it must not be parallel to it.
+    ///
+    /// This is the rotation part of [`Transform3D::look_to`].
+    #[rustfmt::skip]
+    pub fn look_to(direction: Vector3D<T, Src>, up: Vector3D<T, Src>, handedness: Handedness) -> Self {
+        let forward = direction.normalize();
+        let z = match handedness {
+            Handedness::RightHanded => -forward,
+            Handedness::LeftHanded => forward,
+        };
+        let x = up.cross(z).normalize();
+        let y = z.cross(x);
+        let (zero, one) = (T::zero(), T::one());
+        let m = Transform3D::new(
+            x.x, y.x, z.x, zero,
+            x.y, y.y, z.y, zero,
+            x.z, y.z, z.z, zero,
+            zero, zero, zero, one,
+        );
+        Self::from_rotation_matrix(&m).normalize()
+    }
+
+    /// Extracts the rotation of a transform, returning `None` if the transform is not a
+    /// rotation, for example if it translates, scales, skews or mirrors, or has a
+    /// perspective component.
+    ///
+    /// The transform is only required to be a rotation up to the default epsilon of
+    /// [`ApproxEq`], and the result is normalized.
+    ///
+    /// This is the reverse of [`to_transform`](Self::to_transform).
+    pub fn from_transform(transform: &Transform3D<T, Src, Dst>) -> Option<Self>
+    where
+        T: ApproxEq<T>,
+    {
+        let rotation = Self::from_rotation_matrix(transform).normalize();
+        if !rotation.is_normalized() || !rotation.to_transform().approx_eq(transform) {
+            return None;
+        }
+
+        Some(rotation)
+    }
+
     /// Creates a rotation from Euler angles.
     ///
-    /// The rotations are applied in roll then pitch then yaw order.
+    /// The rotations are applied in roll then pitch then yaw order, which is the same
+    /// as [`from_euler`](Self::from_euler) with [`EulerOrder::XYZ`].
     ///
     ///  - Roll (also called bank) is a rotation around the x axis.
     ///  - Pitch (also called bearing) is a rotation around the y axis.
@@ -528,6 +836,67 @@ where
         )
     }
 
+    /// Creates a rotation from Euler angles applied in the given order.
+    ///
+    /// `roll`, `pitch` and `yaw` are the rotations around the x, y and z axes.
+    pub fn from_euler(roll: Angle<T>, pitch: Angle<T>, yaw: Angle<T>, order: EulerOrder) -> Self
+    where
+        T: ApproxEq<T> + SimdScalar,
+    {
+        let rotations: [Rotation3D<T, Src, Src>; 3] = [
+            Rotation3D::around_x(roll),
+            Rotation3D::around_y(pitch),
+            Rotation3D::around_z(yaw),
+        ];
+        let [a, b, c] = order.axes();
+        rotations[a]
+            .then(&rotations[b])
+            .then(&rotations[c])
+            .cast_unit()
+    }
+
+    /// Returns the roll, pitch and yaw angles that [`from_euler`](Self::from_euler)
+    /// turns back into this rotation when applied in the given order.
+    ///
+    /// The angle of the second rotation is between -PI/2 and PI/2, and the two others
+    /// between -PI and PI. When the second angle is close to -PI/2 or PI/2, only the sum
+    /// or the difference of the two others matters and the last one is set to zero.
+    #[rustfmt::skip]
+    pub fn to_euler(&self, order: EulerOrder) -> (Angle<T>, Angle<T>, Angle<T>) {
+        let (i, j, k, r) = (self.i, self.j, self.k, self.r);
+        let one = T::one();
+        let two = one + one;
+        // The rotation matrix in column-vector notation, indexed by row then column.
+        let m = [
+            [one - two * (j * j + k * k), two * (i * j - k * r), two * (i * k + j * r)],
+            [two * (i * j + k * r), one - two * (i * i + k * k), two * (j * k - i * r)],
+            [two * (i * k - j * r), two * (j * k + i * r), one - two * (i * i + j * j)],
+        ];
+
+        let [a, b, c] = order.axes();
+        let cos_b = m[a][a].hypot(m[b][a]);
+        let mut angles = [T::zero(); 3];
+        angles[b] = (-m[c][a]).atan2(cos_b);
+        if cos_b > T::epsilon() * two * two {
+            angles[a] = m[c][b].atan2(m[c][c]);
+            angles[c] = m[b][a].atan2(m[a][a]);
+        } else {
+            angles[a] = (-m[b][c]).atan2(m[b][b]);
+        }
+
+        if order.is_odd() {
+            for angle in &mut angles {
+                *angle = -*angle;
+            }
+        }
+
+        (
+            Angle::radians(angles[0]),
+            Angle::radians(angles[1]),
+            Angle::radians(angles[2]),
+        )
+    }
+
     /// Returns the inverse of this rotation.
     #[inline]
     pub fn inverse(&self) -> Rotation3D<T, Dst, Src> {
@@ -551,7 +920,9 @@ where
     /// [unit quaternion]: https://en.wikipedia.org/wiki/Quaternion#Unit_quaternion
     #[inline]
     pub fn normalize(&self) -> Self {
-        self.mul(T::one() / self.norm())
+        let r = self.mul(T::one() / self.norm());
+        debug_check_nan!("Rotation3D::normalize", r.i, r.j, r.k, r.r);
+        r
     }
 
     /// Returns `true` if [norm] of this quaternion is (approximately) one.
@@ -566,55 +937,140 @@ where
         self.square_norm().approx_eq_eps(&T::one(), &eps)
     }
 
+    /// Returns the dot product of the two quaternions.
+    ///
+    /// For normalized quaternions, this is the cosine of half the angle between the
+    /// rotations, and it is negative when they are on opposite sides of the 4d sphere.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.i * other.i + self.j * other.j + self.k * other.k + self.r * other.r
+    }
+
     /// Spherical linear interpolation between this rotation and another rotation.
     ///
-    /// `t` is expected to be between zero and one.
+    /// `t` is expected to be between zero and one. The interpolation takes the shortest
+    /// arc between the two rotations, see [`slerp_longest`] for the other way around.
+    ///
+    /// [`slerp_longest`]: Self::slerp_longest
+    #[inline]
     pub fn slerp(&self, other: &Self, t: T) -> Self
     where
         T: ApproxEq<T>,
     {
-        debug_assert!(self.is_normalized());
-        debug_assert!(other.is_normalized());
+        self.slerp_path(other, t, false)
+    }
+
+    /// Spherical linear interpolation between this rotation and another rotation, along
+    /// the longest arc between them.
+    ///
+    /// With the shortest arc going from 0° to 90° around an axis, this goes from 0° to
+    /// -270° instead. When the two rotations are the same, it makes a full turn around an
+    /// arbitrary axis.
+    ///
+    /// ```
+    /// use euclid::default::Rotation3D;
+    /// use euclid::{vec3, Angle};
+    ///
+    /// let from = Rotation3D::<f64>::identity();
+    /// let to = Rotation3D::around_z(Angle::degrees(90.0));
+    /// let half_way = Rotation3D::around_z(Angle::degrees(-135.0));
+    /// let v = vec3(1.0, 0.0, 0.0);
+    /// let r = from.slerp_longest(&to, 0.5);
+    /// assert!((r.transform_vector3d(v) - half_way.transform_vector3d(v)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn slerp_longest(&self, other: &Self, t: T) -> Self
+    where
+        T: ApproxEq<T>,
+    {
+        self.slerp_path(other, t, true)
+    }
+
+    fn slerp_path(&self, other: &Self, t: T, longest: bool) -> Self
+    where
+        T: ApproxEq<T>,
+    {
+        check_invariant!(
+            self.is_normalized(),
+            "Rotation3D::slerp: the quaternion is not normalized"
+        );
+        check_invariant!(
+            other.is_normalized(),
+            "Rotation3D::slerp: the quaternion is not normalized"
+        );
 
         let r1 = *self;
         let mut r2 = *other;
 
-        let mut dot = r1.i * r2.i + r1.j * r2.j + r1.k * r2.k + r1.r * r2.r;
+        let mut dot = r1.dot(&r2);
 
         let one = T::one();
+        let zero = T::zero();
 
-        if dot.approx_eq(&T::one()) {
-            // If the inputs are too close, linearly interpolate to avoid precision issues.
-            return r1.lerp(&r2, t);
+        // q and -q represent the same rotation, from opposite sides of the 4d sphere.
+        // Reversing one quaternion picks which of the two arcs the interpolation follows:
+        // the shortest one when the dot product is positive, the longest one otherwise.
+        if (dot < zero && !longest) || (dot > zero && longest) {
+            r2 = r2.mul(-one);
+            dot = -dot;
         }
 
-        // If the dot product is negative, the quaternions
-        // have opposite handed-ness and slerp won't take
-        // the shorter path. Fix by reversing one quaternion.
-        if dot < T::zero() {
-            r2 = r2.mul(-T::one());
-            dot = -dot;
+        if dot.approx_eq(&one) {
+            // If the inputs are too close, linearly interpolate to avoid precision issues.
+            return r1.lerp(&r2, t);
         }
 
         // For robustness, stay within the domain of acos.
-        dot = Real::min(dot, one);
+        dot = Real::max(Real::min(dot, one), -one);
+
+        // r1 and r3 form an orthonormal basis.
+        let r3 = if dot.approx_eq(&-one) {
+            // Any quaternion orthogonal to r1 works, which gives a full turn.
+            Rotation3D::quaternion(-r1.j, r1.i, -r1.r, r1.k)
+        } else {
+            r2.sub(r1.mul(dot)).normalize()
+        };
 
         // Angle between r1 and the result.
         let theta = Real::acos(dot) * t;
 
-        // r1 and r3 form an orthonormal basis.
-        let r3 = r2.sub(r1.mul(dot)).normalize();
         let (sin, cos) = Real::sin_cos(theta);
-        r1.mul(cos).add(r3.mul(sin))
+        let r = r1.mul(cos).add(r3.mul(sin));
+        debug_check_nan!("Rotation3D::slerp", r.i, r.j, r.k, r.r);
+        r
     }
 
     /// Basic Linear interpolation between this rotation and another rotation.
+    ///
+    /// The result is normalized, but the quaternions are interpolated as they are, which
+    /// can follow the longest arc between the rotations. [`nlerp`] follows the shortest
+    /// one.
+    ///
+    /// [`nlerp`]: Self::nlerp
     #[inline]
     pub fn lerp(&self, other: &Self, t: T) -> Self {
         let one_t = T::one() - t;
         self.mul(one_t).add(other.mul(t)).normalize()
     }
 
+    /// Normalized linear interpolation between this rotation and another rotation, along
+    /// the shortest arc between them.
+    ///
+    /// This is cheaper than [`slerp`], but the rotation doesn't progress at a constant
+    /// speed: it is faster in the middle of the interpolation. It is a good fit for
+    /// blending rotations that are close to each other.
+    ///
+    /// [`slerp`]: Self::slerp
+    #[inline]
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            other.mul(-T::one())
+        } else {
+            *other
+        };
+        self.lerp(&other, t)
+    }
+
     /// Returns the given 3d point transformed by this rotation.
     ///
     /// The input point must be use the unit Src, and the returned point has the unit Dst.
@@ -622,7 +1078,10 @@ where
     where
         T: ApproxEq<T>,
     {
-        debug_assert!(self.is_normalized());
+        check_invariant!(
+            self.is_normalized(),
+            "Rotation3D::transform_point3d: the quaternion is not normalized"
+        );
 
         let two = T::one() + T::one();
         let cross = self.vector_part().cross(point.to_vector().to_untyped()) * two;
@@ -674,7 +1133,10 @@ where
     where
         T: ApproxEq<T>,
     {
-        debug_assert!(self.is_normalized());
+        check_invariant!(
+            self.is_normalized(),
+            "Rotation3D::to_transform: the quaternion is not normalized"
+        );
 
         let i2 = self.i + self.i;
         let j2 = self.j + self.j;
@@ -716,15 +1178,17 @@ where
     #[inline]
     pub fn then<NewDst>(&self, other: &Rotation3D<T, Dst, NewDst>) -> Rotation3D<T, Src, NewDst>
     where
-        T: ApproxEq<T>,
+        T: ApproxEq<T> + SimdScalar,
     {
-        debug_assert!(self.is_normalized());
-        Rotation3D::quaternion(
-            other.i * self.r + other.r * self.i + other.j * self.k - other.k * self.j,
-            other.j * self.r + other.r * self.j + other.k * self.i - other.i * self.k,
-            other.k * self.r + other.r * self.k + other.i * self.j - other.j * self.i,
-            other.r * self.r - other.i * self.i - other.j * self.j - other.k * self.k,
-        )
+        check_invariant!(
+            self.is_normalized(),
+            "Rotation3D::then: the quaternion is not normalized"
+        );
+        let [i, j, k, r] = T::quat_mul(
+            [other.i, other.j, other.k, other.r],
+            [self.i, self.j, self.k, self.r],
+        );
+        Rotation3D::quaternion(i, j, k, r)
     }
 
     // add, sub and mul are used internally for intermediate computation but aren't public
@@ -761,6 +1225,21 @@ where
     }
 }
 
+impl<T: Real + ApproxEq<T> + SimdScalar, U> Rotation3D<T, U, U> {
+    /// Rotates each vector of the slice in place.
+    ///
+    /// Equivalent to calling `transform_vector3d` on each element, with the two cross
+    /// products formulation. With the `simd` feature, `f32` rotations process four vectors
+    /// at a time with SSE2 or NEON instructions.
+    pub fn rotate_vectors(&self, vectors: &mut [Vector3D<T, U>]) {
+        check_invariant!(
+            self.is_normalized(),
+            "Rotation3D::rotate_vectors: the quaternion is not normalized"
+        );
+        T::rotate_vectors([self.i, self.j, self.k, self.r], vectors);
+    }
+}
+
 impl<T: fmt::Debug, Src, Dst> fmt::Debug for Rotation3D<T, Src, Dst> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -821,6 +1300,60 @@ fn simple_rotation_2d() {
         .approx_eq(&r90.transform_point(point2(1.0, 2.0))));
 }
 
+#[test]
+fn rotation_2d_slices() {
+    use crate::default::Rotation2D;
+    use crate::vec2;
+
+    let r = Rotation2D::radians(0.7);
+    let mut points = [point2(1.0, 2.0), point2(-3.0, 0.5)];
+    let expected = points.map(|p| r.transform_point(p));
+    r.transform_points(&mut points);
+    assert_eq!(points, expected);
+
+    let mut vectors = [vec2(1.0, 2.0), vec2(-3.0, 0.5)];
+    let expected = vectors.map(|v| r.transform_vector(v));
+    r.transform_vectors(&mut vectors);
+    assert_eq!(vectors, expected);
+}
+
+#[test]
+fn rotation_2d_boxes() {
+    use crate::default::{Box2D, Rect, Rotation2D};
+    use crate::{rect, Angle};
+
+    let b = Box2D::new(point2(1.0, 0.0), point2(3.0, 1.0));
+    let r = Rotation2D::new(Angle::degrees(90.0));
+    let corners = r.transform_corners(&b);
+    let expected = [
+        point2(0.0, 1.0),
+        point2(0.0, 3.0),
+        point2(-1.0, 3.0),
+        point2(-1.0, 1.0),
+    ];
+    for (c, e) in corners.iter().zip(expected.iter()) {
+        assert!(c.approx_eq(e));
+    }
+    let rotated = r.transform_box(&b);
+    assert!(rotated.min.approx_eq(&point2(-1.0, 1.0)));
+    assert!(rotated.max.approx_eq(&point2(0.0, 3.0)));
+
+    // A square rotated by 45 degrees grows by a factor of sqrt(2).
+    let r = Rotation2D::new(Angle::degrees(45.0));
+    let square: Rect<f64> = rect(-1.0, -1.0, 2.0, 2.0);
+    let rotated = r.transform_rect(&square);
+    let s = 2.0f64.sqrt();
+    assert!(rotated.min().approx_eq(&point2(-s, -s)));
+    assert!(rotated.max().approx_eq(&point2(s, s)));
+
+    // Agrees with the matrix representation.
+    let r = Rotation2D::new(Angle::radians(0.3));
+    assert!(r
+        .transform_box(&b)
+        .min
+        .approx_eq(&r.to_transform().outer_transformed_box(&b).min));
+}
+
 #[test]
 fn simple_rotation_3d_in_2d() {
     use crate::default::Rotation3D;
@@ -982,6 +1515,43 @@ fn slerp() {
     assert!(q1.slerp(&q3, 1.0).approx_eq(&q3));
 }
 
+#[test]
+fn slerp_paths() {
+    use crate::default::Rotation3D;
+    use core::f64::consts::FRAC_PI_2;
+
+    let v = vec3(1.0, 2.0, 3.0);
+    let same = |a: Rotation3D<f64>, b: Rotation3D<f64>| {
+        a.transform_vector3d(v).approx_eq(&b.transform_vector3d(v))
+    };
+
+    let q1 = Rotation3D::around_y(Angle::radians(0.0));
+    let q2 = Rotation3D::around_y(Angle::radians(FRAC_PI_2));
+    // The same rotation, from the other side of the sphere.
+    let q2_neg = q2.mul(-1.0);
+    assert!(q1.dot(&q2) > 0.0 && q1.dot(&q2_neg) < 0.0);
+
+    for t in [0.0, 0.25, 0.5, 1.0] {
+        let short = Rotation3D::around_y(Angle::radians(FRAC_PI_2 * t));
+        let long = Rotation3D::around_y(Angle::radians(-3.0 * FRAC_PI_2 * t));
+        assert!(same(q1.slerp(&q2_neg, t), short));
+        assert!(same(q1.slerp_longest(&q2, t), long));
+        assert!(same(q1.slerp_longest(&q2_neg, t), long));
+        assert!(same(q1.nlerp(&q2_neg, t), q1.nlerp(&q2, t)));
+        assert!(q1.nlerp(&q2, t).is_normalized());
+    }
+
+    // nlerp follows the same arc as slerp, without the constant speed.
+    assert!(same(q1.nlerp(&q2_neg, 0.5), q1.slerp(&q2, 0.5)));
+    assert!(!same(q1.nlerp(&q2, 0.25), q1.slerp(&q2, 0.25)));
+
+    // The longest path between a rotation and itself is a full turn.
+    let turn = q2.slerp_longest(&q2, 0.5);
+    assert!(turn.is_normalized());
+    assert!(!same(turn, q2));
+    assert!(same(q2.slerp_longest(&q2, 1.0), q2));
+}
+
 #[test]
 fn around_axis() {
     use crate::default::Rotation3D;
@@ -1004,6 +1574,83 @@ fn around_axis() {
         .approx_eq(&point3(-0.58071821, 0.81401868, -0.01182979)));
 }
 
+#[test]
+fn axis_angle() {
+    use crate::default::Rotation3D;
+    use core::f32::consts::PI;
+
+    let axis = vec3(0.5, 1.0, 2.0);
+    let r = Rotation3D::around_axis(axis, Angle::radians(2.291288));
+    let (a, angle) = r.to_axis_angle();
+    assert!(a.approx_eq(&axis.normalize()));
+    assert!(angle.approx_eq(&Angle::radians(2.291288)));
+
+    // The angle is kept between 0 and PI by flipping the axis.
+    let r = Rotation3D::around_axis(axis, Angle::radians(-1.0));
+    let (a, angle) = r.to_axis_angle();
+    assert!(a.approx_eq(&-axis.normalize()));
+    assert!(angle.approx_eq(&Angle::radians(1.0)));
+
+    // A half turn can be around either direction of the axis.
+    let r = Rotation3D::around_z(Angle::radians(PI));
+    let (a, angle) = r.to_axis_angle();
+    assert!(a.approx_eq(&vec3(0.0, 0.0, 1.0)) || a.approx_eq(&vec3(0.0, 0.0, -1.0)));
+    assert!(angle.approx_eq(&Angle::radians(PI)));
+
+    let (a, angle) = Rotation3D::<f32>::identity().to_axis_angle();
+    assert_eq!(a, vec3(1.0, 0.0, 0.0));
+    assert_eq!(angle, Angle::zero());
+}
+
+#[test]
+fn look_to() {
+    use crate::default::{Rotation3D, Transform3D};
+    use crate::Handedness::{LeftHanded, RightHanded};
+
+    let direction = vec3(1.0, -2.0, 0.5);
+    let up = vec3(0.0, 1.0, 0.0);
+    let rh = Rotation3D::look_to(direction, up, RightHanded);
+    let lh = Rotation3D::look_to(direction, up, LeftHanded);
+    assert!(rh.is_normalized());
+    assert!(rh
+        .transform_vector3d(direction.normalize())
+        .approx_eq(&vec3(0.0, 0.0, -1.0)));
+    assert!(lh
+        .transform_vector3d(direction.normalize())
+        .approx_eq(&vec3(0.0, 0.0, 1.0)));
+
+    let eye = point3(1.0, 2.0, 3.0);
+    let view = Transform3D::look_to(eye, direction, up, RightHanded);
+    assert!(view.approx_eq(&Transform3D::translation(-1.0, -2.0, -3.0).then(&rh.to_transform())));
+}
+
+#[test]
+fn from_transform() {
+    use crate::default::{Rotation3D, Transform3D};
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.291288));
+    let t = r.to_transform();
+    assert!(Rotation3D::from_transform(&t).unwrap().approx_eq(&r));
+
+    let r = Rotation3D::euler(
+        Angle::radians(3.0),
+        Angle::radians(-1.2),
+        Angle::radians(0.4),
+    );
+    let t = r.to_transform();
+    let r2 = Rotation3D::from_transform(&t).unwrap();
+    assert!(r2.to_transform().approx_eq(&t));
+
+    assert_eq!(
+        Rotation3D::from_transform(&Transform3D::<f32>::identity()),
+        Some(Rotation3D::identity())
+    );
+    assert!(Rotation3D::from_transform(&t.then_translate(vec3(1.0, 0.0, 0.0))).is_none());
+    assert!(Rotation3D::from_transform(&t.then_scale(2.0, 2.0, 2.0)).is_none());
+    assert!(Rotation3D::from_transform(&t.then_scale(-1.0, 1.0, 1.0)).is_none());
+    assert!(Rotation3D::from_transform(&Transform3D::perspective(100.0)).is_none());
+}
+
 #[test]
 fn from_euler() {
     use crate::default::Rotation3D;
@@ -1049,3 +1696,111 @@ fn from_euler() {
 
     assert!(ypr_pe.approx_eq(&ypr_pq));
 }
+
+#[test]
+fn euler_orders() {
+    use crate::default::Rotation3D;
+    use core::f32::consts::FRAC_PI_2;
+
+    let orders = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+    let (roll, pitch, yaw) = (
+        Angle::radians(0.3),
+        Angle::radians(-0.7),
+        Angle::radians(1.2),
+    );
+    let rotations = [
+        Rotation3D::around_x(roll),
+        Rotation3D::around_y(pitch),
+        Rotation3D::around_z(yaw),
+    ];
+    let p = point3(1.0, 2.0, 3.0);
+
+    for &order in &orders {
+        let [a, b, c] = order.axes();
+        let expected = rotations[a].then(&rotations[b]).then(&rotations[c]);
+        let r = Rotation3D::from_euler(roll, pitch, yaw, order);
+        assert!(r
+            .transform_point3d(p)
+            .approx_eq(&expected.transform_point3d(p)));
+
+        let (x, y, z) = r.to_euler(order);
+        assert!(x.approx_eq(&roll));
+        assert!(y.approx_eq(&pitch));
+        assert!(z.approx_eq(&yaw));
+
+        // In gimbal lock, the angles are not unique but still give back the rotation.
+        let mut angles = [roll, pitch, yaw];
+        angles[b] = Angle::radians(FRAC_PI_2);
+        let locked = Rotation3D::from_euler(angles[0], angles[1], angles[2], order);
+        let (x, y, z) = locked.to_euler(order);
+        assert!([x, y, z][b].approx_eq_eps(&angles[b], &1e-3));
+        assert!(Rotation3D::from_euler(x, y, z, order)
+            .transform_point3d(p)
+            .approx_eq_eps(&locked.transform_point3d(p), &point3(1e-3, 1e-3, 1e-3)));
+    }
+
+    let e = Rotation3D::euler(roll, pitch, yaw);
+    let xyz = Rotation3D::from_euler(roll, pitch, yaw, EulerOrder::XYZ);
+    assert!(e.transform_point3d(p).approx_eq(&xyz.transform_point3d(p)));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra() {
+    use crate::default::Rotation3D;
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.291288));
+    let q: nalgebra::UnitQuaternion<f32> = r.into();
+    let p = q * nalgebra::Point3::new(1.0, 0.0, 0.0);
+    assert!(r
+        .transform_point3d(point3(1.0, 0.0, 0.0))
+        .approx_eq(&point3(p.x, p.y, p.z)));
+    assert_eq!(Rotation3D::from(q), r);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint() {
+    use crate::default::Rotation3D;
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.291288));
+    let q: mint::Quaternion<f32> = r.into();
+    assert_eq!((q.v.x, q.v.y, q.v.z, q.s), (r.i, r.j, r.k, r.r));
+    assert_eq!(Rotation3D::from(q), r);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam() {
+    use crate::default::Rotation3D;
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.291288));
+    let q: glam::Quat = r.into();
+    let p = q * glam::Vec3::new(1.0, 0.0, 0.0);
+    assert!(r
+        .transform_point3d(point3(1.0, 0.0, 0.0))
+        .approx_eq(&point3(p.x, p.y, p.z)));
+    assert_eq!(Rotation3D::from(q), r);
+}
+
+#[test]
+fn rotate_vectors() {
+    use crate::default::Rotation3D;
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.3));
+    let mut vectors = [vec3(0.0, 0.0, 0.0); 7];
+    for (n, v) in vectors.iter_mut().enumerate() {
+        let f = n as f32;
+        *v = vec3(f, 1.0 - f, 0.5 * f);
+    }
+    let expected = vectors.map(|v| r.transform_vector3d(v));
+    r.rotate_vectors(&mut vectors);
+    assert_eq!(vectors, expected);
+}
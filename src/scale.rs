@@ -6,18 +6,18 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-//! A type-checked scaling factor between units.
+//! Type-checked scaling factors between units.
 
-use crate::num::One;
+use crate::num::{One, Zero};
 
 use crate::approxord::{max, min};
-use crate::{Box2D, Box3D, Point2D, Point3D, Rect, Size2D, Vector2D};
+use crate::{Box2D, Box3D, Point2D, Point3D, Rect, Size2D, Transform2D, UnknownUnit, Vector2D};
 
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
@@ -45,6 +45,10 @@ use serde::{Deserialize, Serialize};
 /// let one_foot_in_mm: Length<f32, Mm> = one_foot * mm_per_inch;
 /// ```
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -55,6 +59,17 @@ use serde::{Deserialize, Serialize};
 )]
 pub struct Scale<T, Src, Dst>(pub T, #[doc(hidden)] pub PhantomData<(Src, Dst)>);
 
+macro_rules! scale_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<Src, Dst> Scale<$ty, Src, Dst> {
+            /// The identity scale, see [`Scale::identity`].
+            pub const IDENTITY: Self = Scale::new($one);
+        }
+    };
+}
+
+primitive_consts!(scale_consts);
+
 impl<T, Src, Dst> Scale<T, Src, Dst> {
     #[inline]
     pub const fn new(x: T) -> Self {
@@ -351,6 +366,15 @@ impl<T: Mul, A, B, C> Mul<Scale<T, B, C>> for Scale<T, A, B> {
     }
 }
 
+// scale0 *= scale1
+// (A,B) * (B,B) = (A,B)
+impl<T: MulAssign, A, B> MulAssign<Scale<T, B, B>> for Scale<T, A, B> {
+    #[inline]
+    fn mul_assign(&mut self, other: Scale<T, B, B>) {
+        self.0 *= other.0;
+    }
+}
+
 // scale0 + scale1
 impl<T: Add, Src, Dst> Add for Scale<T, Src, Dst> {
     type Output = Scale<T::Output, Src, Dst>;
@@ -361,6 +385,14 @@ impl<T: Add, Src, Dst> Add for Scale<T, Src, Dst> {
     }
 }
 
+// scale0 += scale1
+impl<T: AddAssign, Src, Dst> AddAssign for Scale<T, Src, Dst> {
+    #[inline]
+    fn add_assign(&mut self, other: Scale<T, Src, Dst>) {
+        self.0 += other.0;
+    }
+}
+
 // scale0 - scale1
 impl<T: Sub, Src, Dst> Sub for Scale<T, Src, Dst> {
     type Output = Scale<T::Output, Src, Dst>;
@@ -371,6 +403,14 @@ impl<T: Sub, Src, Dst> Sub for Scale<T, Src, Dst> {
     }
 }
 
+// scale0 -= scale1
+impl<T: SubAssign, Src, Dst> SubAssign for Scale<T, Src, Dst> {
+    #[inline]
+    fn sub_assign(&mut self, other: Scale<T, Src, Dst>) {
+        self.0 -= other.0;
+    }
+}
+
 // FIXME: Switch to `derive(PartialEq, Clone)` after this Rust issue is fixed:
 // https://github.com/rust-lang/rust/issues/26925
 
@@ -408,9 +448,10 @@ impl<T: fmt::Debug, Src, Dst> fmt::Debug for Scale<T, Src, Dst> {
     }
 }
 
-impl<T: Default, Src, Dst> Default for Scale<T, Src, Dst> {
+impl<T: One, Src, Dst> Default for Scale<T, Src, Dst> {
+    /// Returns the [identity scale](Scale::identity), like the other transformations.
     fn default() -> Self {
-        Self::new(T::default())
+        Self::identity()
     }
 }
 
@@ -427,9 +468,334 @@ impl<T: One, Src, Dst> One for Scale<T, Src, Dst> {
     }
 }
 
+/// A scaling factor between two units that can differ along the x and y axes.
+///
+/// This is the non-uniform counterpart of [`Scale`], for conversions like device pixel
+/// ratios that aren't the same in both directions.
+///
+/// ```rust
+/// use euclid::{point2, size2, Scale2D};
+/// enum Css {};
+/// enum Device {};
+///
+/// let to_device: Scale2D<f32, Css, Device> = Scale2D::new(2.0, 1.5);
+///
+/// assert_eq!(point2(10.0, 10.0) * to_device, point2(20.0, 15.0));
+/// assert_eq!(size2(20.0, 15.0) / to_device, size2(10.0, 10.0));
+/// ```
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Scale2D<T, Src, Dst> {
+    pub x: T,
+    pub y: T,
+    #[doc(hidden)]
+    pub _unit: PhantomData<(Src, Dst)>,
+}
+
+macro_rules! scale2d_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<Src, Dst> Scale2D<$ty, Src, Dst> {
+            /// The identity scale, see [`Scale2D::identity`].
+            pub const IDENTITY: Self = Scale2D::new($one, $one);
+        }
+    };
+}
+
+primitive_consts!(scale2d_consts);
+
+impl<T, Src, Dst> Scale2D<T, Src, Dst> {
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        Scale2D {
+            x,
+            y,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Creates a scale with the same factor along both axes.
+    #[inline]
+    pub fn uniform(s: T) -> Self
+    where
+        T: Clone,
+    {
+        Scale2D::new(s.clone(), s)
+    }
+
+    /// Creates a scale from a vector of the x and y factors.
+    #[inline]
+    pub fn from_vector(v: Vector2D<T, UnknownUnit>) -> Self {
+        Scale2D::new(v.x, v.y)
+    }
+
+    /// Creates an identity scale (1.0, 1.0).
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: One,
+    {
+        Scale2D::new(T::one(), T::one())
+    }
+
+    /// Returns the given point transformed by this scale.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::{Scale2D, point2};
+    /// enum Mm {};
+    /// enum Cm {};
+    ///
+    /// let to_mm: Scale2D<i32, Cm, Mm> = Scale2D::new(10, 5);
+    ///
+    /// assert_eq!(to_mm.transform_point(point2(42, -42)), point2(420, -210));
+    /// ```
+    #[inline]
+    pub fn transform_point(self, point: Point2D<T, Src>) -> Point2D<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Point2D::new(point.x * self.x, point.y * self.y)
+    }
+
+    /// Returns the given vector transformed by this scale.
+    #[inline]
+    pub fn transform_vector(self, vec: Vector2D<T, Src>) -> Vector2D<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Vector2D::new(vec.x * self.x, vec.y * self.y)
+    }
+
+    /// Returns the given size transformed by this scale.
+    #[inline]
+    pub fn transform_size(self, size: Size2D<T, Src>) -> Size2D<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Size2D::new(size.width * self.x, size.height * self.y)
+    }
+
+    /// Returns the given rect transformed by this scale.
+    #[inline]
+    pub fn transform_rect(self, rect: &Rect<T, Src>) -> Rect<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Rect::new(
+            self.transform_point(rect.origin),
+            self.transform_size(rect.size),
+        )
+    }
+
+    /// Returns the given box transformed by this scale.
+    #[inline]
+    pub fn transform_box2d(self, b: &Box2D<T, Src>) -> Box2D<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Box2D {
+            min: self.transform_point(b.min),
+            max: self.transform_point(b.max),
+        }
+    }
+
+    /// Returns `true` if this scale has no effect.
+    #[inline]
+    pub fn is_identity(&self) -> bool
+    where
+        T: PartialEq + One,
+    {
+        self.x == T::one() && self.y == T::one()
+    }
+
+    /// Returns `true` if the x and y factors are equal.
+    #[inline]
+    pub fn is_uniform(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.x == self.y
+    }
+
+    /// The inverse scale (1.0 / self.x, 1.0 / self.y).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::Scale2D;
+    /// enum Mm {};
+    /// enum Cm {};
+    ///
+    /// let cm_per_mm: Scale2D<f32, Cm, Mm> = Scale2D::new(0.5, 0.25);
+    ///
+    /// assert_eq!(cm_per_mm.inverse(), Scale2D::new(2.0, 4.0));
+    /// ```
+    pub fn inverse(self) -> Scale2D<T::Output, Dst, Src>
+    where
+        T: One + Div,
+    {
+        Scale2D::new(T::one() / self.x, T::one() / self.y)
+    }
+}
+
+impl<T: Copy, Src, Dst> Scale2D<T, Src, Dst> {
+    /// Returns the x and y factors as a vector.
+    #[inline]
+    pub fn to_vector(&self) -> Vector2D<T, UnknownUnit> {
+        Vector2D::new(self.x, self.y)
+    }
+
+    /// Returns the matrix representation of this scale.
+    #[inline]
+    pub fn to_transform(&self) -> Transform2D<T, Src, Dst>
+    where
+        T: Zero,
+    {
+        Transform2D::scale(self.x, self.y)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Scale2D<T, UnknownUnit, UnknownUnit> {
+        Scale2D::new(self.x, self.y)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &Scale2D<T, UnknownUnit, UnknownUnit>) -> Self {
+        Scale2D::new(s.x, s.y)
+    }
+}
+
+impl<T: NumCast, Src, Dst> Scale2D<T, Src, Dst> {
+    /// Cast from one numeric representation to another, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// If a factor cannot be represented by the target type `NewT`. Use `try_cast` if
+    /// that must be handled.
+    #[inline]
+    pub fn cast<NewT: NumCast>(self) -> Scale2D<NewT, Src, Dst> {
+        self.try_cast().unwrap()
+    }
+
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    /// If a factor cannot be represented by the target type `NewT`, then `None` is
+    /// returned.
+    pub fn try_cast<NewT: NumCast>(self) -> Option<Scale2D<NewT, Src, Dst>> {
+        match (NumCast::from(self.x), NumCast::from(self.y)) {
+            (Some(x), Some(y)) => Some(Scale2D::new(x, y)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, Src, Dst> arbitrary::Arbitrary<'a> for Scale2D<T, Src, Dst>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (x, y) = arbitrary::Arbitrary::arbitrary(u)?;
+        Ok(Scale2D::new(x, y))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Zeroable, Src, Dst> Zeroable for Scale2D<T, Src, Dst> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod, Src: 'static, Dst: 'static> Pod for Scale2D<T, Src, Dst> {}
+
+// scale0 * scale1
+// (A,B) * (B,C) = (A,C)
+impl<T: Mul, A, B, C> Mul<Scale2D<T, B, C>> for Scale2D<T, A, B> {
+    type Output = Scale2D<T::Output, A, C>;
+
+    #[inline]
+    fn mul(self, other: Scale2D<T, B, C>) -> Self::Output {
+        Scale2D::new(self.x * other.x, self.y * other.y)
+    }
+}
+
+// scale0 *= scale1
+// (A,B) * (B,B) = (A,B)
+impl<T: MulAssign, A, B> MulAssign<Scale2D<T, B, B>> for Scale2D<T, A, B> {
+    #[inline]
+    fn mul_assign(&mut self, other: Scale2D<T, B, B>) {
+        self.x *= other.x;
+        self.y *= other.y;
+    }
+}
+
+impl<T: Clone, Src, Dst> From<Scale<T, Src, Dst>> for Scale2D<T, Src, Dst> {
+    fn from(s: Scale<T, Src, Dst>) -> Self {
+        Scale2D::uniform(s.0)
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Scale2D<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<T: Eq, Src, Dst> Eq for Scale2D<T, Src, Dst> {}
+
+impl<T: Clone, Src, Dst> Clone for Scale2D<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Scale2D::new(self.x.clone(), self.y.clone())
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for Scale2D<T, Src, Dst> {}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for Scale2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Scale2D")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+
+impl<T: One, Src, Dst> Default for Scale2D<T, Src, Dst> {
+    /// Returns the [identity scale](Scale2D::identity).
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Hash, Src, Dst> Hash for Scale2D<T, Src, Dst> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
+impl<T: One, Src, Dst> One for Scale2D<T, Src, Dst> {
+    #[inline]
+    fn one() -> Self {
+        Scale2D::identity()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Scale;
+    use super::{Scale, Scale2D};
+    use crate::{box2d, point2, rect, size2, vec2};
 
     enum Inch {}
     enum Cm {}
@@ -470,4 +836,79 @@ mod tests {
         let c = Scale::<f32, Inch, Inch>::new(2.5);
         assert_eq!(c.clamp(a, b), c);
     }
+
+    #[test]
+    fn test_scale2d() {
+        let to_mm: Scale2D<f32, Inch, Mm> = Scale2D::new(25.4, 12.7);
+        let to_cm: Scale2D<f32, Mm, Cm> = Scale2D::uniform(0.1);
+        assert!(to_cm.is_uniform());
+        assert!(!to_mm.is_identity());
+        assert!(Scale2D::<f32, Mm, Mm>::identity().is_identity());
+        assert_eq!(Scale2D::from(Scale::<f32, Mm, Cm>::new(0.1)), to_cm);
+
+        let inch_to_cm: Scale2D<f32, Inch, Cm> = to_mm * to_cm;
+        assert_eq!(inch_to_cm, Scale2D::new(2.54, 1.27));
+        assert_eq!(inch_to_cm.inverse(), Scale2D::new(1.0 / 2.54, 1.0 / 1.27));
+
+        let s: Scale2D<i32, Inch, Mm> = Scale2D::new(2, 3);
+        assert_eq!(point2(1, 2) * s, point2(2, 6));
+        assert_eq!(vec2(1, 2) * s, vec2(2, 6));
+        assert_eq!(size2(1, 2) * s, size2(2, 6));
+        assert_eq!(rect(1, 1, 2, 2) * s, rect(2, 3, 4, 6));
+        assert_eq!(box2d(1, 1, 2, 2) * s, box2d(2, 3, 4, 6));
+        assert_eq!(point2(2, 6) / s, point2(1, 2));
+        assert_eq!(vec2(2, 6) / s, vec2(1, 2));
+        assert_eq!(size2(2, 6) / s, size2(1, 2));
+        assert_eq!(rect(2, 3, 4, 6) / s, rect(1, 1, 2, 2));
+        assert_eq!(box2d(2, 3, 4, 6) / s, box2d(1, 1, 2, 2));
+        assert_eq!(s.transform_rect(&rect(1, 1, 2, 2)), rect(2, 3, 4, 6));
+
+        let mut p = point2::<i32, Mm>(1, 2);
+        p *= Scale2D::new(2, 3);
+        assert_eq!(p, point2(2, 6));
+        p /= Scale2D::new(2, 2);
+        assert_eq!(p, point2(1, 3));
+
+        let t = s.to_transform();
+        assert_eq!(t.transform_point(point2(1, 2)), point2(2, 6));
+        assert_eq!(s.cast::<f32>(), Scale2D::new(2.0, 3.0));
+        assert_eq!(
+            Scale2D::<f32, Mm, Mm>::new(1e20, 1.0).try_cast::<i32>(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut s: Scale<f32, Inch, Cm> = Scale::new(2.54);
+        s *= Scale::<f32, Cm, Cm>::new(2.0);
+        assert_eq!(s, Scale::new(5.08));
+        s += Scale::new(1.0);
+        assert_eq!(s, Scale::new(6.08));
+        s -= Scale::new(6.0);
+        assert!((s.get() - 0.08).abs() < 1e-6);
+
+        let mut l: crate::Length<f32, Mm> = crate::Length::new(3.0);
+        l *= Scale::new(4.0);
+        assert_eq!(l.get(), 12.0);
+        l /= Scale::new(2.0);
+        assert_eq!(l.get(), 6.0);
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Camera {
+            zoom: Scale<f32, Inch, Cm>,
+            rotation: crate::Rotation3D<f32, Cm, Cm>,
+            transform: crate::Transform2D<f32, Cm, Mm>,
+            offset: crate::Vector2D<f32, Mm>,
+        }
+        let camera = Camera::default();
+        assert!(camera.zoom.is_identity());
+        assert_eq!(camera.rotation, crate::Rotation3D::identity());
+        assert_eq!(camera.transform, crate::Transform2D::identity());
+        assert_eq!(camera.offset, crate::vec2(0.0, 0.0));
+        assert_eq!(crate::Rotation2D::<f32, Cm, Cm>::default().angle, 0.0);
+    }
 }
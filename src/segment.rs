@@ -0,0 +1,422 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::approxord::{max, min};
+use crate::batch::SimdScalar;
+use crate::box2d::Box2D;
+use crate::num::*;
+use crate::point::Point2D;
+use crate::rectangle::AxisAlignedRectangle;
+use crate::vector::Vector2D;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+/// A 2d line segment between two points.
+///
+/// The segment includes both of its endpoints. The intersection queries work with
+/// integer coordinates as well as floating point ones, but the queries that produce
+/// new points, like [`intersection_point`] and [`clip`], need floating point
+/// coordinates.
+///
+/// [`intersection_point`]: Self::intersection_point
+/// [`clip`]: Self::clip
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct LineSegment2D<T, U> {
+    pub from: Point2D<T, U>,
+    pub to: Point2D<T, U>,
+}
+
+impl<T: Hash, U> Hash for LineSegment2D<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.from.hash(h);
+        self.to.hash(h);
+    }
+}
+
+impl<T: Copy, U> Copy for LineSegment2D<T, U> {}
+
+impl<T: Clone, U> Clone for LineSegment2D<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.from.clone(), self.to.clone())
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for LineSegment2D<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.from.eq(&other.from) && self.to.eq(&other.to)
+    }
+}
+
+impl<T: Eq, U> Eq for LineSegment2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for LineSegment2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("LineSegment2D")
+            .field(&self.from)
+            .field(&self.to)
+            .finish()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, U> arbitrary::Arbitrary<'a> for LineSegment2D<T, U>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(LineSegment2D::new(
+            arbitrary::Arbitrary::arbitrary(u)?,
+            arbitrary::Arbitrary::arbitrary(u)?,
+        ))
+    }
+}
+
+impl<T, U> LineSegment2D<T, U> {
+    /// Constructor.
+    #[inline]
+    pub const fn new(from: Point2D<T, U>, to: Point2D<T, U>) -> Self {
+        LineSegment2D { from, to }
+    }
+
+    /// Returns the same segment, going in the opposite direction.
+    #[inline]
+    pub fn flip(self) -> Self {
+        LineSegment2D::new(self.to, self.from)
+    }
+}
+
+impl<T: Copy, U> LineSegment2D<T, U> {
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> LineSegment2D<T, UnknownUnit> {
+        LineSegment2D::new(self.from.to_untyped(), self.to.to_untyped())
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &LineSegment2D<T, UnknownUnit>) -> Self {
+        LineSegment2D::new(Point2D::from_untyped(s.from), Point2D::from_untyped(s.to))
+    }
+
+    /// Returns the vector going from `from` to `to`.
+    #[inline]
+    pub fn to_vector(&self) -> Vector2D<T, U>
+    where
+        T: Sub<Output = T>,
+    {
+        self.to - self.from
+    }
+
+    /// Returns the smallest box containing the segment.
+    #[inline]
+    pub fn bounding_box(&self) -> Box2D<T, U>
+    where
+        T: PartialOrd,
+    {
+        Box2D::new(self.from.min(self.to), self.from.max(self.to))
+    }
+
+    /// Returns the same segment, translated by a vector.
+    #[inline]
+    pub fn translate(&self, by: Vector2D<T, U>) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        LineSegment2D::new(self.from + by, self.to + by)
+    }
+
+    /// Returns the squared length of the segment, avoiding the square root.
+    #[inline]
+    pub fn square_length(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.to_vector().square_length()
+    }
+
+    /// Returns the point at `t` along the segment, `from` for `t = 0` and `to` for `t = 1`.
+    ///
+    /// Values of `t` outside of `[0, 1]` give points on the line beyond the endpoints.
+    #[inline]
+    pub fn lerp(&self, t: T) -> Point2D<T, U>
+    where
+        T: One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + SimdScalar,
+    {
+        self.from.lerp(self.to, t)
+    }
+
+    /// Returns the point halfway between the two endpoints.
+    ///
+    /// Like [`Box2D::center`], this is computed as `from + (to - from) / 2`.
+    #[inline]
+    pub fn midpoint(&self) -> Point2D<T, U>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Half,
+    {
+        Point2D::new(
+            self.from.x + (self.to.x - self.from.x).half(),
+            self.from.y + (self.to.y - self.from.y).half(),
+        )
+    }
+
+    /// Returns `true` if the segments have at least one point in common, including when
+    /// they only touch at an endpoint or overlap along a common line.
+    ///
+    /// ```
+    /// use euclid::default::LineSegment2D;
+    /// use euclid::point2;
+    ///
+    /// let a = LineSegment2D::new(point2(0, 0), point2(4, 4));
+    /// assert!(a.intersects(&LineSegment2D::new(point2(0, 4), point2(4, 0))));
+    /// assert!(a.intersects(&LineSegment2D::new(point2(4, 4), point2(6, 0))));
+    /// assert!(!a.intersects(&LineSegment2D::new(point2(1, 0), point2(5, 4))));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool
+    where
+        T: Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+    {
+        let (a, b) = (self.from, self.to);
+        let (c, d) = (other.from, other.to);
+        let d1 = side(c, d, a);
+        let d2 = side(c, d, b);
+        let d3 = side(a, b, c);
+        let d4 = side(a, b, d);
+
+        if d1 * d2 < 0 && d3 * d4 < 0 {
+            return true;
+        }
+
+        // The remaining cases are an endpoint lying on the other segment.
+        (d1 == 0 && other.bounding_box().contains_inclusive(a))
+            || (d2 == 0 && other.bounding_box().contains_inclusive(b))
+            || (d3 == 0 && self.bounding_box().contains_inclusive(c))
+            || (d4 == 0 && self.bounding_box().contains_inclusive(d))
+    }
+}
+
+/// Returns the side of the line going through `a` and `b` that `p` is on, as the sign
+/// of `-1`, `0` or `1`.
+#[inline]
+fn side<T, U>(a: Point2D<T, U>, b: Point2D<T, U>, p: Point2D<T, U>) -> i8
+where
+    T: Copy + Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+{
+    let c = (b - a).cross(p - a);
+    if c > T::zero() {
+        1
+    } else if c < T::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+impl<T: RealScalar, U> LineSegment2D<T, U> {
+    /// Returns the length of the segment.
+    #[inline]
+    pub fn length(&self) -> T {
+        self.to_vector().length()
+    }
+
+    /// Returns the point where the two segments cross, or `None` if they don't.
+    ///
+    /// Parallel segments return `None`, even when they overlap, since there is no single
+    /// intersection point. [`intersects`] handles that case.
+    ///
+    /// ```
+    /// use euclid::default::LineSegment2D;
+    /// use euclid::point2;
+    ///
+    /// let a = LineSegment2D::new(point2(0.0, 0.0), point2(4.0, 4.0));
+    /// let b = LineSegment2D::new(point2(0.0, 4.0), point2(4.0, 0.0));
+    /// assert_eq!(a.intersection_point(&b), Some(point2(2.0, 2.0)));
+    /// ```
+    ///
+    /// [`intersects`]: Self::intersects
+    pub fn intersection_point(&self, other: &Self) -> Option<Point2D<T, U>> {
+        let v1 = self.to_vector();
+        let v2 = other.to_vector();
+        let denom = v1.cross(v2);
+        if denom == T::zero() {
+            return None;
+        }
+
+        let offset = other.from - self.from;
+        let t = offset.cross(v2) / denom;
+        let u = offset.cross(v1) / denom;
+        let range = T::zero()..=T::one();
+        if !range.contains(&t) || !range.contains(&u) {
+            return None;
+        }
+
+        Some(self.lerp(t))
+    }
+
+    /// Returns the part of the segment inside of a [`Box2D`] or [`Rect`], or `None` if the
+    /// segment is entirely outside of it.
+    ///
+    /// The boundary of the rectangle is considered inside, and the returned segment
+    /// keeps the direction of this one.
+    ///
+    /// ```
+    /// use euclid::default::{Box2D, LineSegment2D};
+    /// use euclid::{point2, rect};
+    ///
+    /// let s = LineSegment2D::new(point2(-5.0, 5.0), point2(15.0, 5.0));
+    /// let clipped = LineSegment2D::new(point2(0.0, 5.0), point2(10.0, 5.0));
+    /// assert_eq!(s.clip(&Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0))), Some(clipped));
+    /// assert_eq!(s.clip(&rect(0.0, 0.0, 10.0, 10.0)), Some(clipped));
+    /// assert_eq!(s.clip(&rect(0.0, 6.0, 10.0, 10.0)), None);
+    /// ```
+    ///
+    /// [`Rect`]: crate::Rect
+    pub fn clip<R: AxisAlignedRectangle<T, U>>(&self, area: &R) -> Option<Self> {
+        // Liang-Barsky: narrow down the range of `t` against each of the four edges.
+        let (lo, hi) = (area.min(), area.max());
+        let v = self.to_vector();
+        let mut t0 = T::zero();
+        let mut t1 = T::one();
+        for (p, q) in [
+            (-v.x, self.from.x - lo.x),
+            (v.x, hi.x - self.from.x),
+            (-v.y, self.from.y - lo.y),
+            (v.y, hi.y - self.from.y),
+        ] {
+            if p == T::zero() {
+                // Parallel to this edge, so either entirely inside or outside of it.
+                if q < T::zero() {
+                    return None;
+                }
+                continue;
+            }
+
+            let t = q / p;
+            if p < T::zero() {
+                t0 = max(t0, t);
+            } else {
+                t1 = min(t1, t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+
+        Some(LineSegment2D::new(self.lerp(t0), self.lerp(t1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, LineSegment2D};
+    use crate::{point2, rect, vec2};
+
+    #[test]
+    fn test_measurements() {
+        let s = LineSegment2D::new(point2(1.0, 1.0), point2(4.0, 5.0));
+        assert_eq!(s.length(), 5.0);
+        assert_eq!(s.square_length(), 25.0);
+        assert_eq!(s.midpoint(), point2(2.5, 3.0));
+        assert_eq!(s.lerp(0.0), s.from);
+        assert_eq!(s.lerp(1.0), s.to);
+        assert_eq!(s.to_vector(), vec2(3.0, 4.0));
+        assert_eq!(s.flip().bounding_box(), s.bounding_box());
+        assert_eq!(s.translate(vec2(1.0, -1.0)).from, point2(2.0, 0.0));
+        assert_eq!(
+            LineSegment2D::new(point2(0, 0), point2(4, 2)).midpoint(),
+            point2(2, 1)
+        );
+        assert_eq!(
+            LineSegment2D::new(point2(i32::MAX - 4, 0), point2(i32::MAX, 2)).midpoint(),
+            point2(i32::MAX - 2, 1)
+        );
+    }
+
+    #[test]
+    fn test_intersects() {
+        let a = LineSegment2D::new(point2(0, 0), point2(10, 0));
+        let crossing = LineSegment2D::new(point2(5, -5), point2(5, 5));
+        let touching = LineSegment2D::new(point2(10, 0), point2(10, 5));
+        let overlapping = LineSegment2D::new(point2(5, 0), point2(15, 0));
+        let collinear = LineSegment2D::new(point2(11, 0), point2(15, 0));
+        let parallel = LineSegment2D::new(point2(0, 1), point2(10, 1));
+        let short = LineSegment2D::new(point2(5, 1), point2(5, 5));
+
+        assert!(a.intersects(&crossing));
+        assert!(a.intersects(&touching));
+        assert!(a.intersects(&overlapping));
+        assert!(!a.intersects(&collinear));
+        assert!(!a.intersects(&parallel));
+        assert!(!a.intersects(&short));
+        for s in [crossing, touching, overlapping, collinear, parallel, short] {
+            assert_eq!(a.intersects(&s), s.intersects(&a));
+            assert_eq!(a.intersects(&s), a.flip().intersects(&s));
+        }
+    }
+
+    #[test]
+    fn test_intersection_point() {
+        let a = LineSegment2D::new(point2(0.0, 0.0), point2(10.0, 0.0));
+        let crossing = LineSegment2D::new(point2(3.0, -1.0), point2(3.0, 3.0));
+        let touching = LineSegment2D::new(point2(10.0, 0.0), point2(12.0, 5.0));
+        let missing = LineSegment2D::new(point2(3.0, 1.0), point2(3.0, 3.0));
+        let overlapping = LineSegment2D::new(point2(5.0, 0.0), point2(15.0, 0.0));
+
+        assert_eq!(a.intersection_point(&crossing), Some(point2(3.0, 0.0)));
+        assert_eq!(crossing.intersection_point(&a), Some(point2(3.0, 0.0)));
+        assert_eq!(a.intersection_point(&touching), Some(point2(10.0, 0.0)));
+        assert_eq!(a.intersection_point(&missing), None);
+        assert_eq!(a.intersection_point(&overlapping), None);
+
+        let b = LineSegment2D::new(point2(1.0, 2.0), point2(7.0, -4.0));
+        let c = LineSegment2D::new(point2(0.0, -3.0), point2(6.0, 3.0));
+        let p = b.intersection_point(&c).unwrap();
+        assert!(p.approx_eq(&point2(3.0, 0.0)));
+    }
+
+    #[test]
+    fn test_clip() {
+        let area = Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+        let inside = LineSegment2D::new(point2(1.0, 1.0), point2(9.0, 2.0));
+        let diagonal = LineSegment2D::new(point2(-5.0, -5.0), point2(15.0, 15.0));
+        let outside = LineSegment2D::new(point2(-5.0, 1.0), point2(-1.0, 9.0));
+        let corner = LineSegment2D::new(point2(-5.0, 5.0), point2(5.0, 15.0));
+        let vertical = LineSegment2D::new(point2(5.0, 20.0), point2(5.0, -20.0));
+
+        assert_eq!(inside.clip(&area), Some(inside));
+        assert_eq!(
+            diagonal.clip(&area),
+            Some(LineSegment2D::new(point2(0.0, 0.0), point2(10.0, 10.0)))
+        );
+        assert_eq!(outside.clip(&area), None);
+        assert_eq!(
+            corner.clip(&area),
+            Some(LineSegment2D::new(point2(0.0, 10.0), point2(0.0, 10.0)))
+        );
+        assert_eq!(
+            vertical.clip(&rect(0.0, 0.0, 10.0, 10.0)),
+            Some(LineSegment2D::new(point2(5.0, 10.0), point2(5.0, 0.0)))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serialization of the component types, like points and sizes.
+//!
+//! Human-readable formats, like JSON, get a struct with named fields such as
+//! `{"x": 1.0, "y": 2.0}`. Other formats keep the compact tuple encoding `(1.0, 2.0)`.
+//! Deserializing from a human-readable format accepts both forms, so that data written
+//! before the named fields were introduced can still be read.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// Serializes `values` as the fields named `fields` of the struct `name`, or as a tuple
+/// for formats that are not human-readable.
+pub(crate) fn serialize<S, T>(
+    serializer: S,
+    name: &'static str,
+    fields: &'static [&'static str],
+    values: &[&T],
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    debug_assert_eq!(fields.len(), values.len());
+    if serializer.is_human_readable() {
+        let mut state = serializer.serialize_struct(name, fields.len())?;
+        for (field, value) in fields.iter().zip(values) {
+            state.serialize_field(field, value)?;
+        }
+        state.end()
+    } else {
+        let mut state = serializer.serialize_tuple(values.len())?;
+        for value in values {
+            state.serialize_element(value)?;
+        }
+        state.end()
+    }
+}
+
+/// Deserializes the fields serialized by [`serialize`] into `values`, which must have
+/// the same length as `fields`.
+///
+/// On success, every element of `values` is `Some`.
+pub(crate) fn deserialize<'de, D, T>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str],
+    values: &mut [Option<T>],
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    debug_assert_eq!(fields.len(), values.len());
+    let visitor = FieldsVisitor {
+        name,
+        fields,
+        values,
+        _marker: PhantomData,
+    };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_tuple(fields.len(), visitor)
+    }
+}
+
+struct FieldsVisitor<'a, 'de, T> {
+    name: &'static str,
+    fields: &'static [&'static str],
+    values: &'a mut [Option<T>],
+    _marker: PhantomData<&'de ()>,
+}
+
+impl<'a, 'de, T: Deserialize<'de>> Visitor<'de> for FieldsVisitor<'a, 'de, T> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a {} as a tuple or a struct with the fields {:?}",
+            self.name, self.fields
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for i in 0..self.values.len() {
+            match seq.next_element()? {
+                Some(value) => self.values[i] = Some(value),
+                None => return Err(de::Error::invalid_length(i, &self)),
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(i) = map.next_key_seed(FieldIndex(self.fields))? {
+            if self.values[i].is_some() {
+                return Err(de::Error::duplicate_field(self.fields[i]));
+            }
+            self.values[i] = Some(map.next_value()?);
+        }
+        for (field, value) in self.fields.iter().zip(self.values.iter()) {
+            if value.is_none() {
+                return Err(de::Error::missing_field(field));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes a field name into its index in the list of fields.
+struct FieldIndex(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIndex {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "one of the fields {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|field| *field == value)
+            .ok_or_else(|| de::Error::unknown_field(value, self.0))
+    }
+}
@@ -29,6 +29,10 @@ use serde::{Deserialize, Serialize};
 /// A group of 2D side offsets, which correspond to top/right/bottom/left for borders, padding,
 /// and margins in CSS, optionally tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -143,6 +147,18 @@ impl<T, U> SideOffsets2D<T, U> {
         }
     }
 
+    /// Constructor taking a tuple with the top, right, bottom and left sides.
+    #[inline]
+    pub fn from_tuple((top, right, bottom, left): (T, T, T, T)) -> Self {
+        SideOffsets2D::new(top, right, bottom, left)
+    }
+
+    /// Cast into a tuple with the top, right, bottom and left sides.
+    #[inline]
+    pub fn to_tuple(self) -> (T, T, T, T) {
+        (self.top, self.right, self.bottom, self.left)
+    }
+
     /// Constructor taking a typed Length for each side.
     ///
     /// Sides are specified in top-right-bottom-left order following
@@ -437,6 +453,34 @@ fn test_is_zero() {
     assert!(!s2.is_zero());
 }
 
+/// The components are in `top`, `right`, `bottom`, `left` order, as in
+/// `SideOffsets2D::new`.
+impl<T, U> From<[T; 4]> for SideOffsets2D<T, U> {
+    fn from([top, right, bottom, left]: [T; 4]) -> Self {
+        SideOffsets2D::new(top, right, bottom, left)
+    }
+}
+
+impl<T, U> From<SideOffsets2D<T, U>> for [T; 4] {
+    fn from(s: SideOffsets2D<T, U>) -> Self {
+        [s.top, s.right, s.bottom, s.left]
+    }
+}
+
+/// The components are in `top`, `right`, `bottom`, `left` order, as in
+/// `SideOffsets2D::new`.
+impl<T, U> From<(T, T, T, T)> for SideOffsets2D<T, U> {
+    fn from((top, right, bottom, left): (T, T, T, T)) -> Self {
+        SideOffsets2D::new(top, right, bottom, left)
+    }
+}
+
+impl<T, U> From<SideOffsets2D<T, U>> for (T, T, T, T) {
+    fn from(s: SideOffsets2D<T, U>) -> Self {
+        (s.top, s.right, s.bottom, s.left)
+    }
+}
+
 #[cfg(test)]
 mod ops {
     use crate::Scale;
@@ -0,0 +1,297 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Explicit SIMD kernels for `f32` transforms.
+//!
+//! The kernels are written once against a small four-lane vector type which uses SSE2
+//! on x86 and x86_64, NEON on aarch64 and plain arrays elsewhere. They back the products,
+//! the point transforms and the slice transforms of `Transform3D<f32, ..>` and
+//! `Rotation3D<f32, ..>`, through `SimdScalar`.
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(all(target_arch = "x86", target_feature = "sse2"))]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+))]
+#[derive(Copy, Clone)]
+struct F32x4(__m128);
+
+#[cfg(target_arch = "aarch64")]
+#[derive(Copy, Clone)]
+struct F32x4(float32x4_t);
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    target_arch = "aarch64"
+)))]
+#[derive(Copy, Clone)]
+struct F32x4([f32; 4]);
+
+// The intrinsics used below are part of the baseline instruction set of the targets
+// they are compiled for, so calling them is always sound.
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+))]
+impl F32x4 {
+    #[inline]
+    fn load(a: &[f32; 4]) -> Self {
+        F32x4(unsafe { _mm_loadu_ps(a.as_ptr()) })
+    }
+    #[inline]
+    fn splat(v: f32) -> Self {
+        F32x4(unsafe { _mm_set1_ps(v) })
+    }
+    #[inline]
+    fn add(self, o: Self) -> Self {
+        F32x4(unsafe { _mm_add_ps(self.0, o.0) })
+    }
+    #[inline]
+    fn sub(self, o: Self) -> Self {
+        F32x4(unsafe { _mm_sub_ps(self.0, o.0) })
+    }
+    #[inline]
+    fn mul(self, o: Self) -> Self {
+        F32x4(unsafe { _mm_mul_ps(self.0, o.0) })
+    }
+    #[inline]
+    fn store(self) -> [f32; 4] {
+        let mut a = [0.0; 4];
+        unsafe { _mm_storeu_ps(a.as_mut_ptr(), self.0) };
+        a
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl F32x4 {
+    #[inline]
+    fn load(a: &[f32; 4]) -> Self {
+        F32x4(unsafe { vld1q_f32(a.as_ptr()) })
+    }
+    #[inline]
+    fn splat(v: f32) -> Self {
+        F32x4(unsafe { vdupq_n_f32(v) })
+    }
+    #[inline]
+    fn add(self, o: Self) -> Self {
+        F32x4(unsafe { vaddq_f32(self.0, o.0) })
+    }
+    #[inline]
+    fn sub(self, o: Self) -> Self {
+        F32x4(unsafe { vsubq_f32(self.0, o.0) })
+    }
+    #[inline]
+    fn mul(self, o: Self) -> Self {
+        F32x4(unsafe { vmulq_f32(self.0, o.0) })
+    }
+    #[inline]
+    fn store(self) -> [f32; 4] {
+        let mut a = [0.0; 4];
+        unsafe { vst1q_f32(a.as_mut_ptr(), self.0) };
+        a
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    target_arch = "aarch64"
+)))]
+impl F32x4 {
+    #[inline]
+    fn load(a: &[f32; 4]) -> Self {
+        F32x4(*a)
+    }
+    #[inline]
+    fn splat(v: f32) -> Self {
+        F32x4([v; 4])
+    }
+    #[inline]
+    fn add(self, o: Self) -> Self {
+        F32x4([
+            self.0[0] + o.0[0],
+            self.0[1] + o.0[1],
+            self.0[2] + o.0[2],
+            self.0[3] + o.0[3],
+        ])
+    }
+    #[inline]
+    fn sub(self, o: Self) -> Self {
+        F32x4([
+            self.0[0] - o.0[0],
+            self.0[1] - o.0[1],
+            self.0[2] - o.0[2],
+            self.0[3] - o.0[3],
+        ])
+    }
+    #[inline]
+    fn mul(self, o: Self) -> Self {
+        F32x4([
+            self.0[0] * o.0[0],
+            self.0[1] * o.0[1],
+            self.0[2] * o.0[2],
+            self.0[3] * o.0[3],
+        ])
+    }
+    #[inline]
+    fn store(self) -> [f32; 4] {
+        self.0
+    }
+}
+
+/// A row-major matrix loaded in vector registers, to multiply many vectors by it.
+pub(crate) struct Mat4([F32x4; 4]);
+
+impl Mat4 {
+    #[inline]
+    pub(crate) fn load(m: &[[f32; 4]; 4]) -> Self {
+        Mat4([
+            F32x4::load(&m[0]),
+            F32x4::load(&m[1]),
+            F32x4::load(&m[2]),
+            F32x4::load(&m[3]),
+        ])
+    }
+
+    /// Multiplies the row vector `[v[0], .., 0]` by the matrix, skipping the rows past
+    /// the coordinates of `v`.
+    #[inline]
+    pub(crate) fn mul_vec<const N: usize>(&self, v: [f32; N]) -> [f32; 4] {
+        self.mul_rows(v).store()
+    }
+
+    /// Multiplies the row vector `[p[0], .., 1]` by the matrix, skipping the rows
+    /// between the coordinates of the point `p` and the last row.
+    #[inline]
+    pub(crate) fn mul_point<const N: usize>(&self, p: [f32; N]) -> [f32; 4] {
+        self.mul_rows(p).add(self.0[3]).store()
+    }
+
+    /// Computes the sum of the first `N` rows of the matrix weighted by `v`.
+    #[inline]
+    fn mul_rows<const N: usize>(&self, v: [f32; N]) -> F32x4 {
+        let m = &self.0;
+        let mut acc = F32x4::splat(v[0]).mul(m[0]);
+        for r in 1..N {
+            acc = acc.add(F32x4::splat(v[r]).mul(m[r]));
+        }
+        acc
+    }
+}
+
+/// Computes the product of the row-major matrices `a` and `b`, one row at a time.
+#[inline]
+pub(crate) fn mat4_mul(a: &[[f32; 4]; 4], b: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let b = Mat4::load(b);
+    [
+        b.mul_vec(a[0]),
+        b.mul_vec(a[1]),
+        b.mul_vec(a[2]),
+        b.mul_vec(a[3]),
+    ]
+}
+
+/// Computes the Hamilton product `a * b` of the `[i, j, k, r]` quaternions `a` and `b`.
+///
+/// The four components are computed at once, and the negated terms of the real part
+/// are multiplied by `-1`, which is exact.
+#[inline]
+pub(crate) fn quat_mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let [ai, aj, ak, ar] = a;
+    let [bi, bj, bk, br] = b;
+    let sign = F32x4::load(&[1.0, 1.0, 1.0, -1.0]);
+    let t0 = F32x4::load(&a).mul(F32x4::splat(br));
+    let t1 = F32x4::load(&[ar, ar, ar, ai]).mul(F32x4::load(&[bi, bj, bk, bi]));
+    let t2 = F32x4::load(&[aj, ak, ai, aj]).mul(F32x4::load(&[bk, bi, bj, bj]));
+    let t3 = F32x4::load(&[ak, ai, aj, ak]).mul(F32x4::load(&[bj, bk, bi, bk]));
+    t0.add(t1.mul(sign)).add(t2.mul(sign)).sub(t3).store()
+}
+
+/// Rotates four vectors, given as one array per axis, by the `[i, j, k, r]` quaternion
+/// `q`, with the two cross products formulation.
+#[inline]
+pub(crate) fn quat_rotate_x4(q: [f32; 4], v: [[f32; 4]; 3]) -> [[f32; 4]; 3] {
+    let [i, j, k, r] = q.map(F32x4::splat);
+    let two = F32x4::splat(2.0);
+    let [x, y, z] = [F32x4::load(&v[0]), F32x4::load(&v[1]), F32x4::load(&v[2])];
+    let cx = j.mul(z).sub(k.mul(y)).mul(two);
+    let cy = k.mul(x).sub(i.mul(z)).mul(two);
+    let cz = i.mul(y).sub(j.mul(x)).mul(two);
+    [
+        x.add(r.mul(cx)).add(j.mul(cz)).sub(k.mul(cy)).store(),
+        y.add(r.mul(cy)).add(k.mul(cx)).sub(i.mul(cz)).store(),
+        z.add(r.mul(cz)).add(i.mul(cy)).sub(j.mul(cx)).store(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Rotation3D, Transform3D};
+    use crate::{point3, vec3, Angle};
+
+    fn transforms() -> [Transform3D<f32>; 4] {
+        [
+            Transform3D::identity(),
+            Transform3D::translation(1.0, -2.0, 3.5),
+            Transform3D::rotation(0.5, 1.0, 2.0, Angle::radians(0.7)).then_scale(2.0, 3.0, 0.5),
+            Transform3D::perspective(5.0).then_translate(vec3(0.25, 0.5, -1.0)),
+        ]
+    }
+
+    #[test]
+    fn test_transform_slices() {
+        let points = [
+            point3(1.5, -2.0, 0.25),
+            point3(0.0, 0.0, 0.0),
+            point3(-3.0, 4.0, 10.0),
+            point3(0.0, 0.0, 5.0),
+        ];
+        let vectors = points.map(|p| p.to_vector());
+        for m in &transforms() {
+            // The batch transforms of `f32` go through the SIMD kernels.
+            let mut out = [None; 4];
+            m.transform_points3d(&points, &mut out);
+            for (p, o) in points.iter().zip(&out) {
+                match (m.transform_point3d(*p), o) {
+                    (Some(a), Some(b)) => assert!(a.approx_eq(b)),
+                    (a, b) => assert_eq!(a.is_none(), b.is_none()),
+                }
+            }
+
+            let mut out = vectors;
+            m.transform_vectors3d(&vectors, &mut out);
+            for (v, o) in vectors.iter().zip(&out) {
+                assert!(m.transform_vector3d(*v).approx_eq(o));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_vectors() {
+        let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.3));
+        let mut vectors = [vec3(0.0, 0.0, 0.0); 7];
+        for (n, v) in vectors.iter_mut().enumerate() {
+            let f = n as f32;
+            *v = vec3(f, 1.0 - f, 0.5 * f);
+        }
+        let expected = vectors.map(|v| r.transform_vector3d(v));
+        r.rotate_vectors(&mut vectors);
+        for (a, b) in vectors.iter().zip(&expected) {
+            assert!(a.approx_eq(b));
+        }
+    }
+}
@@ -9,9 +9,10 @@
 
 use super::UnknownUnit;
 use crate::approxord::{max, min};
+use crate::cast::{cast_component, round_component, saturate_component, CastError, RoundingMode};
 use crate::length::Length;
 use crate::num::*;
-use crate::scale::Scale;
+use crate::scale::{Scale, Scale2D};
 use crate::vector::{vec2, BoolVector2D, Vector2D};
 use crate::vector::{vec3, BoolVector3D, Vector3D};
 
@@ -20,18 +21,20 @@ use core::fmt;
 use core::hash::Hash;
 use core::iter::Sum;
 use core::marker::PhantomData;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-#[cfg(feature = "mint")]
-use mint;
-use num_traits::{Float, NumCast, Signed};
-#[cfg(feature = "serde")]
-use serde;
+use num_traits::{Bounded, Float, NumCast, Signed};
 
 /// A 2d size tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Size2D<T, U> {
     /// The extent of the element in the `U` units along the `x` axis (usually horizontal).
     pub width: T,
@@ -63,7 +66,14 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (width, height) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None];
+        crate::serde_fields::deserialize(
+            deserializer,
+            "Size2D",
+            &["width", "height"],
+            &mut values,
+        )?;
+        let [width, height] = values.map(Option::unwrap);
         Ok(Size2D {
             width,
             height,
@@ -82,7 +92,12 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.width, &self.height).serialize(serializer)
+        crate::serde_fields::serialize(
+            serializer,
+            "Size2D",
+            &["width", "height"],
+            &[&self.width, &self.height],
+        )
     }
 }
 
@@ -142,6 +157,22 @@ impl<T: Default, U> Default for Size2D<T, U> {
     }
 }
 
+macro_rules! size_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<U> Size2D<$ty, U> {
+            /// The size with all dimensions set to zero.
+            pub const ZERO: Self = size2($zero, $zero);
+        }
+
+        impl<U> Size3D<$ty, U> {
+            /// The size with all dimensions set to zero.
+            pub const ZERO: Self = size3($zero, $zero, $zero);
+        }
+    };
+}
+
+primitive_consts!(size_consts);
+
 impl<T, U> Size2D<T, U> {
     /// The same as [`Zero::zero`] but available without importing trait.
     ///
@@ -163,6 +194,12 @@ impl<T, U> Size2D<T, U> {
             _unit: PhantomData,
         }
     }
+
+    /// Constructor taking a tuple with width and height.
+    #[inline]
+    pub fn from_tuple((width, height): (T, T)) -> Self {
+        Size2D::new(width, height)
+    }
     /// Constructor taking scalar strongly typed lengths.
     #[inline]
     pub fn from_lengths(width: Length<T, U>, height: Length<T, U>) -> Self {
@@ -189,7 +226,7 @@ impl<T, U> Size2D<T, U> {
     }
 }
 
-impl<T: Copy, U> Size2D<T, U> {
+impl<T: Clone, U> Size2D<T, U> {
     /// Return this size as an array of two elements (width, then height).
     #[inline]
     pub fn to_array(self) -> [T; 2] {
@@ -307,7 +344,7 @@ impl<T: Copy, U> Size2D<T, U> {
     where
         T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
     {
-        let one_t = T::one() - t;
+        let one_t = T::one() - t.clone();
         self * one_t + other * t
     }
 }
@@ -335,6 +372,51 @@ impl<T: NumCast + Copy, U> Size2D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Size2D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Size2D<NewT, U>, CastError> {
+        Ok(Size2D::new(
+            cast_component(self.width, "width")?,
+            cast_component(self.height, "height")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Size2D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Size2D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Size2D<NewT, U>> {
+        Some(Size2D::new(
+            round_component(self.width, mode)?,
+            round_component(self.height, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Size2D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Size2D<NewT, U> {
+        Size2D::new(
+            saturate_component(self.width),
+            saturate_component(self.height),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` size.
@@ -349,6 +431,13 @@ impl<T: NumCast + Copy, U> Size2D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` size.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Size2D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `uint` size, truncating decimals if any.
     ///
     /// When casting from floating point sizes, it is worth considering whether
@@ -398,6 +487,36 @@ impl<T: NumCast + Copy, U> Size2D<T, U> {
     pub fn to_i64(self) -> Size2D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Size2D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Size2D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(self) -> Size2D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Size2D<T, U> {
@@ -406,6 +525,14 @@ impl<T: Float, U> Size2D<T, U> {
     pub fn is_finite(self) -> bool {
         self.width.is_finite() && self.height.is_finite()
     }
+
+    /// Computes the reciprocal of each component.
+    ///
+    /// A zero component becomes infinite.
+    #[inline]
+    pub fn recip(self) -> Self {
+        size2(self.width.recip(), self.height.recip())
+    }
 }
 
 impl<T: Signed, U> Size2D<T, U> {
@@ -418,6 +545,14 @@ impl<T: Signed, U> Size2D<T, U> {
         size2(self.width.abs(), self.height.abs())
     }
 
+    /// Computes the sign of each component.
+    ///
+    /// The behavior for each component follows the scalar type's implementation of
+    /// `num_traits::Signed::signum`.
+    pub fn signum(self) -> Self {
+        size2(self.width.signum(), self.height.signum())
+    }
+
     /// Returns `true` if both components is positive and `false` any component is zero or negative.
     pub fn is_positive(self) -> bool {
         self.width.is_positive() && self.height.is_positive()
@@ -550,10 +685,13 @@ impl<T: Add, U> Add for Size2D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> Add<&Self> for Size2D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> Add<&Self> for Size2D<T, U> {
     type Output = Self;
     fn add(self, other: &Self) -> Self {
-        Size2D::new(self.width + other.width, self.height + other.height)
+        Size2D::new(
+            self.width + other.width.clone(),
+            self.height + other.height.clone(),
+        )
     }
 }
 
@@ -563,7 +701,7 @@ impl<T: Add<Output = T> + Zero, U> Sum for Size2D<T, U> {
     }
 }
 
-impl<'a, T: 'a + Add<Output = T> + Copy + Zero, U: 'a> Sum<&'a Self> for Size2D<T, U> {
+impl<'a, T: 'a + Add<Output = T> + Clone + Zero, U: 'a> Sum<&'a Self> for Size2D<T, U> {
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
         iter.fold(Self::zero(), Add::add)
     }
@@ -594,72 +732,106 @@ impl<T: SubAssign, U> SubAssign for Size2D<T, U> {
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Size2D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Size2D<T, U> {
     type Output = Size2D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        Size2D::new(self.width * scale, self.height * scale)
+        Size2D::new(self.width * scale.clone(), self.height * scale)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Size2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Size2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, other: T) {
-        self.width *= other;
+        self.width *= other.clone();
         self.height *= other;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Size2D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Size2D<T, U1> {
     type Output = Size2D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        Size2D::new(self.width * scale.0, self.height * scale.0)
+        Size2D::new(self.width * scale.0.clone(), self.height * scale.0)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Size2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Size2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, other: Scale<T, U, U>) {
         *self *= other.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Size2D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Size2D<T, U> {
     type Output = Size2D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        Size2D::new(self.width / scale, self.height / scale)
+        Size2D::new(self.width / scale.clone(), self.height / scale)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Size2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Size2D<T, U> {
     #[inline]
     fn div_assign(&mut self, other: T) {
-        self.width /= other;
+        self.width /= other.clone();
         self.height /= other;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Size2D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Size2D<T, U2> {
     type Output = Size2D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        Size2D::new(self.width / scale.0, self.height / scale.0)
+        Size2D::new(self.width / scale.0.clone(), self.height / scale.0)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Size2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Size2D<T, U> {
     #[inline]
     fn div_assign(&mut self, other: Scale<T, U, U>) {
         *self /= other.0;
     }
 }
 
+impl<T: Mul, U1, U2> Mul<Scale2D<T, U1, U2>> for Size2D<T, U1> {
+    type Output = Size2D<T::Output, U2>;
+
+    #[inline]
+    fn mul(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        size2(self.width * scale.x, self.height * scale.y)
+    }
+}
+
+impl<T: MulAssign, U> MulAssign<Scale2D<T, U, U>> for Size2D<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.width *= scale.x;
+        self.height *= scale.y;
+    }
+}
+
+impl<T: Div, U1, U2> Div<Scale2D<T, U1, U2>> for Size2D<T, U2> {
+    type Output = Size2D<T::Output, U1>;
+
+    #[inline]
+    fn div(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        size2(self.width / scale.x, self.height / scale.y)
+    }
+}
+
+impl<T: DivAssign, U> DivAssign<Scale2D<T, U, U>> for Size2D<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.width /= scale.x;
+        self.height /= scale.y;
+    }
+}
+
 /// Shorthand for `Size2D::new(w, h)`.
 #[inline]
 pub const fn size2<T, U>(w: T, h: T) -> Size2D<T, U> {
@@ -723,6 +895,32 @@ impl<T, U> From<(T, T)> for Size2D<T, U> {
     }
 }
 
+/// Indexes the width and height components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Size2D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.width,
+            1 => &self.height,
+            _ => panic!("Size2D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Size2D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.width,
+            1 => &mut self.height,
+            _ => panic!("Size2D index out of bounds: {}", index),
+        }
+    }
+}
+
 #[cfg(test)]
 mod size2d {
     use crate::default::Size2D;
@@ -735,6 +933,72 @@ mod size2d {
         assert_eq!(p.area(), 3.0);
     }
 
+    #[test]
+    pub fn test_min_max_clamp() {
+        let s1 = Size2D::new(1.0, 3.0);
+        let s2 = Size2D::new(2.0, 2.0);
+
+        assert_eq!(s1.min(s2), Size2D::new(1.0, 2.0));
+        assert_eq!(s1.max(s2), Size2D::new(2.0, 3.0));
+        assert_eq!(
+            Size2D::new(0.5, 4.0).clamp(s1.min(s2), s1.max(s2)),
+            Size2D::new(1.0, 3.0)
+        );
+    }
+
+    #[test]
+    pub fn test_signum_recip() {
+        let s = Size2D::new(-2.0, 4.0);
+
+        assert_eq!(s.signum(), Size2D::new(-1.0, 1.0));
+        assert_eq!(s.recip(), Size2D::new(-0.5, 0.25));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, Readable, Token};
+        use serde_test::{assert_tokens, Configure};
+
+        let s: Size2D<f32> = Size2D::new(1.0, 2.0);
+        let named = |width: &'static str, height: &'static str| {
+            [
+                Token::Struct {
+                    name: "Size2D",
+                    len: 2,
+                },
+                Token::Str(width),
+                Token::F32(1.0),
+                Token::Str(height),
+                Token::F32(2.0),
+                Token::StructEnd,
+            ]
+        };
+        assert_tokens(&s.readable(), &named("width", "height"));
+        assert_tokens(
+            &s.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::TupleEnd,
+            ],
+        );
+
+        let mut reordered = named("height", "width");
+        reordered[2] = Token::F32(2.0);
+        reordered[4] = Token::F32(1.0);
+        assert_de_tokens(&s.readable(), &reordered);
+        assert_de_tokens_error::<Readable<Size2D<f32>>>(
+            &named("width", "depth")[..4],
+            "unknown field `depth`, expected `width` or `height`",
+        );
+        assert_de_tokens_error::<Readable<Size2D<f32>>>(
+            &named("width", "width")[..4],
+            "duplicate field `width`",
+        );
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -941,6 +1205,10 @@ mod size2d {
 
 /// A 3d size tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Size3D<T, U> {
     /// The extent of the element in the `U` units along the `x` axis.
     pub width: T,
@@ -974,7 +1242,14 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (width, height, depth) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None, None];
+        crate::serde_fields::deserialize(
+            deserializer,
+            "Size3D",
+            &["width", "height", "depth"],
+            &mut values,
+        )?;
+        let [width, height, depth] = values.map(Option::unwrap);
         Ok(Size3D {
             width,
             height,
@@ -993,7 +1268,12 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.width, &self.height, &self.depth).serialize(serializer)
+        crate::serde_fields::serialize(
+            serializer,
+            "Size3D",
+            &["width", "height", "depth"],
+            &[&self.width, &self.height, &self.depth],
+        )
     }
 }
 
@@ -1078,6 +1358,12 @@ impl<T, U> Size3D<T, U> {
             _unit: PhantomData,
         }
     }
+
+    /// Constructor taking a tuple with width, height and depth.
+    #[inline]
+    pub fn from_tuple((width, height, depth): (T, T, T)) -> Self {
+        Size3D::new(width, height, depth)
+    }
     /// Constructor taking scalar strongly typed lengths.
     #[inline]
     pub fn from_lengths(width: Length<T, U>, height: Length<T, U>, depth: Length<T, U>) -> Self {
@@ -1105,7 +1391,7 @@ impl<T, U> Size3D<T, U> {
     }
 }
 
-impl<T: Copy, U> Size3D<T, U> {
+impl<T: Clone, U> Size3D<T, U> {
     /// Return this size as an array of three elements (width, then height, then depth).
     #[inline]
     pub fn to_array(self) -> [T; 3] {
@@ -1223,7 +1509,7 @@ impl<T: Copy, U> Size3D<T, U> {
     where
         T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
     {
-        let one_t = T::one() - t;
+        let one_t = T::one() - t.clone();
         self * one_t + other * t
     }
 }
@@ -1255,6 +1541,54 @@ impl<T: NumCast + Copy, U> Size3D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Size3D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Size3D<NewT, U>, CastError> {
+        Ok(Size3D::new(
+            cast_component(self.width, "width")?,
+            cast_component(self.height, "height")?,
+            cast_component(self.depth, "depth")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Size3D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Size3D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Size3D<NewT, U>> {
+        Some(Size3D::new(
+            round_component(self.width, mode)?,
+            round_component(self.height, mode)?,
+            round_component(self.depth, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Size3D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Size3D<NewT, U> {
+        Size3D::new(
+            saturate_component(self.width),
+            saturate_component(self.height),
+            saturate_component(self.depth),
+        )
+    }
+
     // Convenience functions for common casts
 
     /// Cast into an `f32` size.
@@ -1269,6 +1603,13 @@ impl<T: NumCast + Copy, U> Size3D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` size.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Size3D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `uint` size, truncating decimals if any.
     ///
     /// When casting from floating point sizes, it is worth considering whether
@@ -1308,6 +1649,46 @@ impl<T: NumCast + Copy, U> Size3D<T, U> {
     pub fn to_i64(self) -> Size3D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Size3D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Size3D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(self) -> Size3D<u64, U> {
+        self.cast()
+    }
+
+    /// Cast into an `isize` size, truncating decimals if any.
+    ///
+    /// When casting from floating point sizes, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_isize(self) -> Size3D<isize, U> {
+        self.cast()
+    }
 }
 
 impl<T: Float, U> Size3D<T, U> {
@@ -1316,6 +1697,14 @@ impl<T: Float, U> Size3D<T, U> {
     pub fn is_finite(self) -> bool {
         self.width.is_finite() && self.height.is_finite() && self.depth.is_finite()
     }
+
+    /// Computes the reciprocal of each component.
+    ///
+    /// A zero component becomes infinite.
+    #[inline]
+    pub fn recip(self) -> Self {
+        size3(self.width.recip(), self.height.recip(), self.depth.recip())
+    }
 }
 
 impl<T: Signed, U> Size3D<T, U> {
@@ -1328,6 +1717,18 @@ impl<T: Signed, U> Size3D<T, U> {
         size3(self.width.abs(), self.height.abs(), self.depth.abs())
     }
 
+    /// Computes the sign of each component.
+    ///
+    /// The behavior for each component follows the scalar type's implementation of
+    /// `num_traits::Signed::signum`.
+    pub fn signum(self) -> Self {
+        size3(
+            self.width.signum(),
+            self.height.signum(),
+            self.depth.signum(),
+        )
+    }
+
     /// Returns `true` if all components is positive and `false` any component is zero or negative.
     pub fn is_positive(self) -> bool {
         self.width.is_positive() && self.height.is_positive() && self.depth.is_positive()
@@ -1474,13 +1875,13 @@ impl<T: Add, U> Add for Size3D<T, U> {
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> Add<&Self> for Size3D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> Add<&Self> for Size3D<T, U> {
     type Output = Self;
     fn add(self, other: &Self) -> Self {
         Size3D::new(
-            self.width + other.width,
-            self.height + other.height,
-            self.depth + other.depth,
+            self.width + other.width.clone(),
+            self.height + other.height.clone(),
+            self.depth + other.depth.clone(),
         )
     }
 }
@@ -1491,7 +1892,7 @@ impl<T: Add<Output = T> + Zero, U> Sum for Size3D<T, U> {
     }
 }
 
-impl<'a, T: 'a + Add<Output = T> + Copy + Zero, U: 'a> Sum<&'a Self> for Size3D<T, U> {
+impl<'a, T: 'a + Add<Output = T> + Clone + Zero, U: 'a> Sum<&'a Self> for Size3D<T, U> {
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
         iter.fold(Self::zero(), Add::add)
     }
@@ -1528,86 +1929,86 @@ impl<T: SubAssign, U> SubAssign for Size3D<T, U> {
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Size3D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Size3D<T, U> {
     type Output = Size3D<T::Output, U>;
 
     #[inline]
     #[rustfmt::skip]
     fn mul(self, scale: T) -> Self::Output {
         Size3D::new(
-            self.width * scale,
-            self.height * scale,
+            self.width * scale.clone(),
+            self.height * scale.clone(),
             self.depth * scale,
         )
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<T> for Size3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<T> for Size3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, other: T) {
-        self.width *= other;
-        self.height *= other;
+        self.width *= other.clone();
+        self.height *= other.clone();
         self.depth *= other;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Size3D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Size3D<T, U1> {
     type Output = Size3D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
         Size3D::new(
-            self.width * scale.0,
-            self.height * scale.0,
+            self.width * scale.0.clone(),
+            self.height * scale.0.clone(),
             self.depth * scale.0,
         )
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Size3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Size3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, other: Scale<T, U, U>) {
         *self *= other.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Size3D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Size3D<T, U> {
     type Output = Size3D<T::Output, U>;
 
     #[inline]
     #[rustfmt::skip]
     fn div(self, scale: T) -> Self::Output {
         Size3D::new(
-            self.width / scale,
-            self.height / scale,
+            self.width / scale.clone(),
+            self.height / scale.clone(),
             self.depth / scale,
         )
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<T> for Size3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<T> for Size3D<T, U> {
     #[inline]
     fn div_assign(&mut self, other: T) {
-        self.width /= other;
-        self.height /= other;
+        self.width /= other.clone();
+        self.height /= other.clone();
         self.depth /= other;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Size3D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Size3D<T, U2> {
     type Output = Size3D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
         Size3D::new(
-            self.width / scale.0,
-            self.height / scale.0,
+            self.width / scale.0.clone(),
+            self.height / scale.0.clone(),
             self.depth / scale.0,
         )
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Size3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Size3D<T, U> {
     #[inline]
     fn div_assign(&mut self, other: Scale<T, U, U>) {
         *self /= other.0;
@@ -1668,6 +2069,34 @@ impl<T, U> From<(T, T, T)> for Size3D<T, U> {
     }
 }
 
+/// Indexes the width, height and depth components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Size3D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.width,
+            1 => &self.height,
+            2 => &self.depth,
+            _ => panic!("Size3D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Size3D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.width,
+            1 => &mut self.height,
+            2 => &mut self.depth,
+            _ => panic!("Size3D index out of bounds: {}", index),
+        }
+    }
+}
+
 /// Shorthand for `Size3D::new(w, h, d)`.
 #[inline]
 pub const fn size3<T, U>(w: T, h: T, d: T) -> Size3D<T, U> {
@@ -0,0 +1,139 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Declaration of coordinate spaces.
+
+/// Declares a unit marker type for a coordinate space, along with a module of type
+/// aliases for the euclid types in that space.
+///
+/// Codebases with many coordinate spaces otherwise write the same dozen aliases for
+/// each of them. Since `macro_rules` can't build identifiers such as `LayoutRect`, the
+/// aliases are grouped in a module named after the space instead, so that they read as
+/// `layout::Rect`. The aliases of the types that map between two spaces, like
+/// `layout::ScaleTo<DevicePixel>`, take the destination space as a parameter.
+///
+/// ```
+/// use euclid::{declare_coordinate_space, point2, size2};
+///
+/// declare_coordinate_space! {
+///     /// CSS pixels, before the device pixel ratio is applied.
+///     pub struct LayoutPixel;
+///     /// Geometry in layout space.
+///     pub mod layout(f32);
+/// }
+///
+/// declare_coordinate_space! {
+///     pub struct DevicePixel;
+///     pub mod device(f32);
+/// }
+///
+/// # fn main() {
+/// let bounds = layout::Rect::new(point2(10.0, 10.0), size2(100.0, 50.0));
+/// let ratio = layout::ScaleTo::<DevicePixel>::new(2.0);
+/// let device_bounds: device::Rect = bounds * ratio;
+/// assert_eq!(device_bounds.size, size2(200.0, 100.0));
+/// # }
+/// ```
+///
+/// The marker type implements [`UnitName`](crate::UnitName), so that the values in
+/// the space can be debugged with their units.
+///
+/// The macro must be invoked at module level, since the generated module refers to the
+/// marker type and the scalar type through `super`.
+///
+/// The module contains the aliases `Length`, `Point`, `Point3D`, `Vector`, `Vector3D`,
+/// `Size`, `Size3D`, `Rect`, `Box2D`, `Box3D`, `SideOffsets`, `Translation`,
+/// `Translation3D` and `HomogeneousVector` for values in the space, and `ScaleTo<Dst>`,
+/// `TranslationTo<Dst>`, `Translation3DTo<Dst>`, `TransformTo<Dst>`,
+/// `Transform3DTo<Dst>`, `RotationTo<Dst>` and `Rotation3DTo<Dst>` for mappings from it
+/// to another space.
+#[macro_export]
+macro_rules! declare_coordinate_space {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $space:ident;
+        $(#[$mod_attr:meta])*
+        $mod_vis:vis mod $module:ident($t:ty);
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $space;
+
+        impl $crate::UnitName for $space {
+            const NAME: &'static str = stringify!($space);
+        }
+
+        $(#[$mod_attr])*
+        #[allow(dead_code, unused_imports)]
+        $mod_vis mod $module {
+            use super::*;
+
+            pub type Length = $crate::Length<$t, super::$space>;
+            pub type Point = $crate::Point2D<$t, super::$space>;
+            pub type Point3D = $crate::Point3D<$t, super::$space>;
+            pub type Vector = $crate::Vector2D<$t, super::$space>;
+            pub type Vector3D = $crate::Vector3D<$t, super::$space>;
+            pub type HomogeneousVector = $crate::HomogeneousVector<$t, super::$space>;
+            pub type Size = $crate::Size2D<$t, super::$space>;
+            pub type Size3D = $crate::Size3D<$t, super::$space>;
+            pub type Rect = $crate::Rect<$t, super::$space>;
+            pub type Box2D = $crate::Box2D<$t, super::$space>;
+            pub type Box3D = $crate::Box3D<$t, super::$space>;
+            pub type SideOffsets = $crate::SideOffsets2D<$t, super::$space>;
+            pub type Translation = $crate::Translation2D<$t, super::$space, super::$space>;
+            pub type Translation3D = $crate::Translation3D<$t, super::$space, super::$space>;
+
+            pub type ScaleTo<Dst> = $crate::Scale<$t, super::$space, Dst>;
+            pub type TranslationTo<Dst> = $crate::Translation2D<$t, super::$space, Dst>;
+            pub type Translation3DTo<Dst> = $crate::Translation3D<$t, super::$space, Dst>;
+            pub type TransformTo<Dst> = $crate::Transform2D<$t, super::$space, Dst>;
+            pub type Transform3DTo<Dst> = $crate::Transform3D<$t, super::$space, Dst>;
+            pub type RotationTo<Dst> = $crate::Rotation2D<$t, super::$space, Dst>;
+            pub type Rotation3DTo<Dst> = $crate::Rotation3D<$t, super::$space, Dst>;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{point2, vec2};
+
+    /// A fixed-point scalar declared next to the spaces, to check that the aliases
+    /// resolve it.
+    type Au = i32;
+
+    declare_coordinate_space! {
+        struct WorldSpace;
+        mod world(f64);
+    }
+
+    declare_coordinate_space! {
+        pub(crate) struct ScreenSpace;
+        pub(crate) mod screen(Au);
+    }
+
+    #[test]
+    fn test_aliases() {
+        let to_screen: world::TransformTo<ScreenSpace> =
+            world::TransformTo::translation(1.0, 2.0).then_scale(2.0, 2.0);
+        let p: world::Point = point2(1.0, 1.0);
+        let q = to_screen.transform_point(p).round().to_i32();
+        let _: screen::Point = q;
+        assert_eq!(q, point2(4, 6));
+        assert_eq!(
+            world::Vector::new(3.0, 4.0).length(),
+            world::Length::new(5.0).get()
+        );
+        assert_eq!(
+            screen::Rect::zero().translate(vec2(1, 1)).origin,
+            point2(1, 1)
+        );
+        assert_eq!(format!("{:?}", WorldSpace), "WorldSpace");
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`proptest`](mod@proptest) strategies for the euclid types.
+//!
+//! Each function builds a strategy for a type out of a strategy for its scalar
+//! components, like a range, [`finite`] or [`non_negative`]. Boxes are generated with
+//! `min <= max` on every axis, and rotations are normalized.
+//!
+//! ```
+//! use euclid::{strategy, UnknownUnit};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     // Usually marked `#[test]`.
+//!     fn contains_center(b in strategy::box2d::<f32, UnknownUnit>(-1.0e6f32..1.0e6)) {
+//!         prop_assert!(b.contains_inclusive(b.center()));
+//!     }
+//! }
+//! # contains_center();
+//! ```
+
+use crate::{Angle, Box2D, Box3D, Circle, HomogeneousVector, Length, LineSegment2D, Point2D};
+use crate::{Point3D, Ray2D, Ray3D, Rect, RigidTransform3D, Rotation2D, Rotation3D, Scale};
+use crate::{SideOffsets2D, Size2D, Size3D, Sphere, Transform2D, Transform3D, Translation2D};
+use crate::{Translation3D, Triangle2D, Triangle3D, Vector2D, Vector3D};
+
+use core::fmt::Debug;
+use core::mem::swap;
+use num_traits::real::Real;
+use num_traits::{One, Zero};
+use proptest::prelude::*;
+
+/// Scalars with strategies restricted to finite or non-negative values.
+pub trait Scalar: Debug + Sized {
+    /// Generates any finite value, of either sign.
+    fn finite() -> BoxedStrategy<Self>;
+    /// Generates any finite value that is not negative.
+    fn non_negative() -> BoxedStrategy<Self>;
+}
+
+macro_rules! float_scalar {
+    ($($ty:ident),*) => {$(
+        impl Scalar for $ty {
+            fn finite() -> BoxedStrategy<Self> {
+                use proptest::num::$ty::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+                (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).boxed()
+            }
+
+            fn non_negative() -> BoxedStrategy<Self> {
+                use proptest::num::$ty::{NORMAL, POSITIVE, SUBNORMAL, ZERO};
+                (POSITIVE | NORMAL | SUBNORMAL | ZERO).boxed()
+            }
+        }
+    )*};
+}
+
+macro_rules! int_scalar {
+    ($($ty:ident),*) => {$(
+        impl Scalar for $ty {
+            fn finite() -> BoxedStrategy<Self> {
+                any::<$ty>().boxed()
+            }
+
+            fn non_negative() -> BoxedStrategy<Self> {
+                (0..=$ty::MAX).boxed()
+            }
+        }
+    )*};
+}
+
+float_scalar!(f32, f64);
+int_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Finite scalars, excluding NaN and infinities.
+pub fn finite<T: Scalar>() -> BoxedStrategy<T> {
+    T::finite()
+}
+
+/// Finite scalars that are not negative, for sizes and radii.
+pub fn non_negative<T: Scalar>() -> BoxedStrategy<T> {
+    T::non_negative()
+}
+
+fn ordered<T: PartialOrd>(min: &mut T, max: &mut T) {
+    if *min > *max {
+        swap(min, max);
+    }
+}
+
+/// Angles with a value in radians generated from `radians`.
+pub fn angle<T: Debug>(radians: impl Strategy<Value = T>) -> impl Strategy<Value = Angle<T>> {
+    radians.prop_map(Angle::radians)
+}
+
+/// Lengths generated from `scalar`.
+pub fn length<T: Debug, U>(
+    scalar: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Length<T, U>> {
+    scalar.prop_map(Length::new)
+}
+
+/// 2D points with coordinates generated from `scalar`.
+pub fn point2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Point2D<T, U>> {
+    (scalar.clone(), scalar).prop_map(|(x, y)| Point2D::new(x, y))
+}
+
+/// 3D points with coordinates generated from `scalar`.
+pub fn point3d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Point3D<T, U>> {
+    (scalar.clone(), scalar.clone(), scalar).prop_map(|(x, y, z)| Point3D::new(x, y, z))
+}
+
+/// 2D vectors with components generated from `scalar`.
+pub fn vector2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Vector2D<T, U>> {
+    (scalar.clone(), scalar).prop_map(|(x, y)| Vector2D::new(x, y))
+}
+
+/// 3D vectors with components generated from `scalar`.
+pub fn vector3d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Vector3D<T, U>> {
+    (scalar.clone(), scalar.clone(), scalar).prop_map(|(x, y, z)| Vector3D::new(x, y, z))
+}
+
+/// Homogeneous vectors with components generated from `scalar`.
+pub fn homogeneous_vector<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = HomogeneousVector<T, U>> {
+    (scalar.clone(), scalar.clone(), scalar.clone(), scalar)
+        .prop_map(|(x, y, z, w)| HomogeneousVector::new(x, y, z, w))
+}
+
+/// 2D sizes with dimensions generated from `scalar`.
+pub fn size2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Size2D<T, U>> {
+    (scalar.clone(), scalar).prop_map(|(w, h)| Size2D::new(w, h))
+}
+
+/// 3D sizes with dimensions generated from `scalar`.
+pub fn size3d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Size3D<T, U>> {
+    (scalar.clone(), scalar.clone(), scalar).prop_map(|(w, h, d)| Size3D::new(w, h, d))
+}
+
+/// Rectangles with an origin generated from `origin` and a size from `size`.
+///
+/// Use [`non_negative`] for the size to only generate rectangles that are not
+/// negative.
+pub fn rect<T: Debug, U>(
+    origin: impl Strategy<Value = T> + Clone,
+    size: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Rect<T, U>> {
+    (point2d(origin), size2d(size)).prop_map(|(origin, size)| Rect::new(origin, size))
+}
+
+/// Boxes with `min <= max` on every axis.
+pub fn box2d<T: Debug + PartialOrd, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Box2D<T, U>> {
+    (point2d(scalar.clone()), point2d(scalar)).prop_map(|(min, max)| {
+        let mut b = Box2D::new(min, max);
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        b
+    })
+}
+
+/// Boxes with `min <= max` on every axis.
+pub fn box3d<T: Debug + PartialOrd, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Box3D<T, U>> {
+    (point3d(scalar.clone()), point3d(scalar)).prop_map(|(min, max)| {
+        let mut b = Box3D::new(min, max);
+        ordered(&mut b.min.x, &mut b.max.x);
+        ordered(&mut b.min.y, &mut b.max.y);
+        ordered(&mut b.min.z, &mut b.max.z);
+        b
+    })
+}
+
+/// Side offsets with each side generated from `scalar`.
+pub fn side_offsets2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = SideOffsets2D<T, U>> {
+    (scalar.clone(), scalar.clone(), scalar.clone(), scalar)
+        .prop_map(|(top, right, bottom, left)| SideOffsets2D::new(top, right, bottom, left))
+}
+
+/// Circles with a center generated from `center` and a radius from `radius`.
+pub fn circle<T: Debug, U>(
+    center: impl Strategy<Value = T> + Clone,
+    radius: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Circle<T, U>> {
+    (point2d(center), radius).prop_map(|(center, radius)| Circle::new(center, radius))
+}
+
+/// Spheres with a center generated from `center` and a radius from `radius`.
+pub fn sphere<T: Debug, U>(
+    center: impl Strategy<Value = T> + Clone,
+    radius: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Sphere<T, U>> {
+    (point3d(center), radius).prop_map(|(center, radius)| Sphere::new(center, radius))
+}
+
+/// Line segments with endpoints generated from `scalar`.
+pub fn line_segment2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = LineSegment2D<T, U>> {
+    (point2d(scalar.clone()), point2d(scalar)).prop_map(|(from, to)| LineSegment2D::new(from, to))
+}
+
+/// 2D triangles with vertices generated from `scalar`.
+pub fn triangle2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Triangle2D<T, U>> {
+    (
+        point2d(scalar.clone()),
+        point2d(scalar.clone()),
+        point2d(scalar),
+    )
+        .prop_map(|(a, b, c)| Triangle2D::new(a, b, c))
+}
+
+/// 3D triangles with vertices generated from `scalar`.
+pub fn triangle3d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Triangle3D<T, U>> {
+    (
+        point3d(scalar.clone()),
+        point3d(scalar.clone()),
+        point3d(scalar),
+    )
+        .prop_map(|(a, b, c)| Triangle3D::new(a, b, c))
+}
+
+/// 2D rays with an origin and a direction generated from `scalar`.
+pub fn ray2d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Ray2D<T, U>> {
+    (point2d(scalar.clone()), vector2d(scalar))
+        .prop_map(|(origin, direction)| Ray2D::new(origin, direction))
+}
+
+/// 3D rays with an origin and a direction generated from `scalar`.
+pub fn ray3d<T: Debug, U>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Ray3D<T, U>> {
+    (point3d(scalar.clone()), vector3d(scalar))
+        .prop_map(|(origin, direction)| Ray3D::new(origin, direction))
+}
+
+/// Scale factors generated from `scalar`.
+pub fn scale<T: Debug, Src, Dst>(
+    scalar: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Scale<T, Src, Dst>> {
+    scalar.prop_map(Scale::new)
+}
+
+/// 2D translations with components generated from `scalar`.
+pub fn translation2d<T: Debug, Src, Dst>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Translation2D<T, Src, Dst>> {
+    (scalar.clone(), scalar).prop_map(|(x, y)| Translation2D::new(x, y))
+}
+
+/// 3D translations with components generated from `scalar`.
+pub fn translation3d<T: Debug, Src, Dst>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Translation3D<T, Src, Dst>> {
+    (scalar.clone(), scalar.clone(), scalar).prop_map(|(x, y, z)| Translation3D::new(x, y, z))
+}
+
+/// 2D rotations with an angle in radians generated from `radians`.
+pub fn rotation2d<T: Debug, Src, Dst>(
+    radians: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Rotation2D<T, Src, Dst>> {
+    radians.prop_map(Rotation2D::radians)
+}
+
+/// Normalized rotations, from quaternions with components generated by `scalar`.
+///
+/// Quaternions that are too small to be normalized accurately are rejected. Use a
+/// symmetric range such as `-1.0..1.0` to cover all orientations.
+pub fn rotation3d<T: Real + Debug, Src, Dst>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Rotation3D<T, Src, Dst>> {
+    (scalar.clone(), scalar.clone(), scalar.clone(), scalar).prop_filter_map(
+        "quaternion too small to normalize",
+        |(i, j, k, r)| {
+            let q = Rotation3D::quaternion(i, j, k, r);
+            if q.norm() > T::epsilon().sqrt() {
+                Some(q.normalize())
+            } else {
+                None
+            }
+        },
+    )
+}
+
+/// Rigid transforms, with a rotation generated by [`rotation3d`] from `rotation` and a
+/// translation generated from `translation`.
+pub fn rigid_transform3d<T: Real + Debug, Src, Dst>(
+    rotation: impl Strategy<Value = T> + Clone,
+    translation: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = RigidTransform3D<T, Src, Dst>> {
+    (rotation3d(rotation), vector3d(translation))
+        .prop_map(|(rotation, translation)| RigidTransform3D::new(rotation, translation))
+}
+
+/// 2D transforms with all six terms generated from `scalar`.
+pub fn transform2d<T, Src, Dst>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Transform2D<T, Src, Dst>>
+where
+    T: Copy + Debug + PartialEq + One + Zero,
+{
+    proptest::array::uniform6(scalar)
+        .prop_map(|[m11, m12, m21, m22, m31, m32]| Transform2D::new(m11, m12, m21, m22, m31, m32))
+}
+
+/// 3D transforms with all sixteen terms generated from `scalar`.
+pub fn transform3d<T, Src, Dst>(
+    scalar: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Transform3D<T, Src, Dst>>
+where
+    T: Copy + Debug + PartialEq + One + Zero,
+{
+    proptest::array::uniform16(scalar).prop_map(|m| {
+        Transform3D::new(
+            m[0], m[1], m[2], m[3], m[4], m[5], m[6], m[7], m[8], m[9], m[10], m[11], m[12], m[13],
+            m[14], m[15],
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approxeq::ApproxEq;
+    use crate::default;
+
+    #[test]
+    fn test_finite_signs() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = finite::<f32>();
+        let values: Vec<f32> = (0..1000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect();
+        assert!(values.iter().any(|v| *v < 0.0));
+        assert!(values.iter().any(|v| *v > 0.0));
+    }
+
+    proptest! {
+        #[test]
+        fn test_finite(p in point3d::<f32, crate::UnknownUnit>(finite())) {
+            prop_assert!(p.x.is_finite() && p.y.is_finite() && p.z.is_finite());
+        }
+
+        #[test]
+        fn test_non_negative_rect(r in rect::<f64, crate::UnknownUnit>(finite(), non_negative())) {
+            prop_assert!(r.origin.x.is_finite() && r.origin.y.is_finite());
+            prop_assert!(r.size.width >= 0.0 && r.size.height >= 0.0);
+            prop_assert!(r.size.width.is_finite() && r.size.height.is_finite());
+        }
+
+        #[test]
+        fn test_ordered_boxes(b in box3d::<i32, crate::UnknownUnit>(finite())) {
+            prop_assert!(!b.is_negative());
+        }
+
+        #[test]
+        fn test_unit_rotations(r in rotation3d::<f32, crate::UnknownUnit, crate::UnknownUnit>(-1.0f32..1.0)) {
+            let r: default::Rotation3D<f32> = r;
+            prop_assert!(r.norm().approx_eq_eps(&1.0, &1.0e-5));
+        }
+    }
+}
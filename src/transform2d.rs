@@ -11,23 +11,29 @@
 
 use super::{Angle, UnknownUnit};
 use crate::approxeq::ApproxEq;
+use crate::batch::SimdScalar;
 use crate::box2d::Box2D;
-use crate::num::{One, Zero};
+use crate::decomposition::Decomposition2D;
+use crate::error::Error;
+use crate::matrix::Matrix;
+use crate::num::{One, RealScalar, Zero};
 use crate::point::{point2, Point2D};
 use crate::rect::Rect;
+use crate::rotation::Rotation2D;
+use crate::scale::Scale;
 use crate::transform3d::Transform3D;
+use crate::translation::Translation2D;
 use crate::trig::Trig;
+use crate::validated::finite;
 use crate::vector::{vec2, Vector2D};
 use core::cmp::{Eq, PartialEq};
 use core::fmt;
 use core::hash::Hash;
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-#[cfg(feature = "mint")]
-use mint;
 use num_traits::NumCast;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -59,6 +65,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// The translation terms are `m31` and `m32`.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -144,7 +154,26 @@ where
     }
 }
 
+macro_rules! transform_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<Src, Dst> Transform2D<$ty, Src, Dst> {
+            /// The identity transform, see [`Transform2D::identity`].
+            pub const IDENTITY: Self = Transform2D::new($one, $zero, $zero, $one, $zero, $zero);
+        }
+    };
+}
+
+primitive_consts!(transform_consts);
+
 impl<T, Src, Dst> Transform2D<T, Src, Dst> {
+    /// Views the terms as a 3x2 matrix.
+    #[inline]
+    fn matrix(&self) -> &Matrix<T, 3, 2> {
+        // SAFETY: `Transform2D` is `#[repr(C)]` with its 6 terms in row-major order and
+        // zero-sized units, as `Matrix::view` requires.
+        unsafe { Matrix::view(self) }
+    }
+
     /// Create a transform specifying its components in using the column-major-column-vector
     /// matrix notation.
     ///
@@ -274,6 +303,54 @@ impl<T: Copy, Src, Dst> Transform2D<T, Src, Dst> {
         )
     }
 
+    /// Cast into a tuple of the rows of `to_arrays`.
+    ///
+    /// ```
+    /// use euclid::default::Transform2D;
+    ///
+    /// let t = Transform2D::translation(5, 6);
+    /// let (_, _, (tx, ty)) = t.to_tuple();
+    /// assert_eq!((tx, ty), (5, 6));
+    /// assert_eq!(Transform2D::from_tuple(t.to_tuple()), t);
+    /// ```
+    #[inline]
+    pub fn to_tuple(&self) -> ((T, T), (T, T), (T, T)) {
+        (
+            (self.m11, self.m12),
+            (self.m21, self.m22),
+            (self.m31, self.m32),
+        )
+    }
+
+    /// Create a transform from a tuple of rows, in the same order as `from_arrays`.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn from_tuple(rows: ((T, T), (T, T), (T, T))) -> Self {
+        let ((m11, m12), (m21, m22), (m31, m32)) = rows;
+        Self::new(
+            m11, m12,
+            m21, m22,
+            m31, m32,
+        )
+    }
+
+    /// Returns the terms `m{i}1` and `m{i}2` of the given zero-based row, that is
+    /// an element of `to_arrays`.
+    ///
+    /// Panics if `row` is greater than 2.
+    #[inline]
+    pub fn row(&self, row: usize) -> [T; 2] {
+        self.matrix().row("Transform2D", row)
+    }
+
+    /// Returns the terms `m1{j}`, `m2{j}` and `m3{j}` of the given zero-based column.
+    ///
+    /// Panics if `column` is greater than 1.
+    #[inline]
+    pub fn column(&self, column: usize) -> [T; 3] {
+        self.matrix().column("Transform2D", column)
+    }
+
     /// Drop the units, preserving only the numeric value.
     #[inline]
     #[rustfmt::skip]
@@ -389,18 +466,74 @@ where
     /// Returns the multiplication of the two matrices such that mat's transformation
     /// applies after self's transformation.
     #[must_use]
-    #[rustfmt::skip]
-    pub fn then<NewDst>(&self, mat: &Transform2D<T, Dst, NewDst>) -> Transform2D<T, Src, NewDst> {
-        Transform2D::new(
-            self.m11 * mat.m11 + self.m12 * mat.m21,
-            self.m11 * mat.m12 + self.m12 * mat.m22,
+    pub fn then<NewDst>(&self, mat: &Transform2D<T, Dst, NewDst>) -> Transform2D<T, Src, NewDst>
+    where
+        T: SimdScalar,
+    {
+        Transform2D::from_arrays(T::affine2_then(&self.matrix().0, &mat.matrix().0))
+    }
+}
+
+/// Methods for chaining transformations into another space
+///
+/// Unlike `then_scale`, `then_rotate` and `then_translate`, these take the typed
+/// transformation to apply, whose destination becomes the destination of the result.
+/// Each step reads in the order it is applied, and a step given in the wrong order
+/// fails to type-check instead of silently producing the reversed product.
+///
+/// ```
+/// use euclid::{point2, Angle, Rotation2D, Scale, Transform2D, Translation2D};
+///
+/// struct Layout;
+/// struct Device;
+/// struct Screen;
+///
+/// let to_device: Scale<f32, Layout, Device> = Scale::new(2.0);
+/// let to_screen: Translation2D<f32, Device, Screen> = Translation2D::new(10.0, 0.0);
+///
+/// let transform = Transform2D::identity()
+///     .then_rotate(Angle::frac_pi_2())
+///     .then_scale_by(to_device)
+///     .then_translate_by(to_screen);
+///
+/// let p = transform.transform_point(point2::<_, Layout>(1.0, 0.0));
+/// assert!((p.x - 10.0).abs() < 1e-6 && (p.y - 2.0).abs() < 1e-6);
+/// ```
+impl<T, Src, Dst> Transform2D<T, Src, Dst>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One + SimdScalar,
+{
+    /// Applies a scale after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_scale_by<NewDst>(
+        &self,
+        scale: Scale<T, Dst, NewDst>,
+    ) -> Transform2D<T, Src, NewDst> {
+        self.then(&Transform2D::scale(scale.0, scale.0))
+    }
 
-            self.m21 * mat.m11 + self.m22 * mat.m21,
-            self.m21 * mat.m12 + self.m22 * mat.m22,
+    /// Applies a translation after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_translate_by<NewDst>(
+        &self,
+        translation: Translation2D<T, Dst, NewDst>,
+    ) -> Transform2D<T, Src, NewDst> {
+        self.then(&translation.to_transform())
+    }
 
-            self.m31 * mat.m11 + self.m32 * mat.m21 + mat.m31,
-            self.m31 * mat.m12 + self.m32 * mat.m22 + mat.m32,
-        )
+    /// Applies a rotation after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_rotate_by<NewDst>(
+        &self,
+        rotation: Rotation2D<T, Dst, NewDst>,
+    ) -> Transform2D<T, Src, NewDst>
+    where
+        T: Sub<Output = T> + Trig,
+    {
+        self.then(&Transform2D::rotation(rotation.get_angle()))
     }
 }
 
@@ -434,7 +567,7 @@ where
     #[must_use]
     pub fn then_translate(&self, v: Vector2D<T, Dst>) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         self.then(&Transform2D::translation(v.x, v.y))
     }
@@ -444,7 +577,7 @@ where
     #[must_use]
     pub fn pre_translate(&self, v: Vector2D<T, Src>) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         Transform2D::translation(v.x, v.y).then(self)
     }
@@ -469,17 +602,41 @@ where
         )
     }
 
+    /// Returns a rotation transform, using the approximate trigonometry of
+    /// [`Angle::fast_sin_cos`].
+    #[cfg(feature = "fast-math")]
+    #[inline]
+    #[rustfmt::skip]
+    pub fn rotation_fast(theta: Angle<T>) -> Self
+    where
+        T: num_traits::Float + num_traits::FloatConst,
+    {
+        let _0 = Zero::zero();
+        let (sin, cos) = theta.fast_sin_cos();
+        Transform2D::new(
+            cos, sin,
+            _0 - sin, cos,
+            _0, _0
+        )
+    }
+
     /// Applies a rotation after self's transformation and returns the resulting transform.
     #[inline]
     #[must_use]
-    pub fn then_rotate(&self, theta: Angle<T>) -> Self {
+    pub fn then_rotate(&self, theta: Angle<T>) -> Self
+    where
+        T: SimdScalar,
+    {
         self.then(&Transform2D::rotation(theta))
     }
 
     /// Applies a rotation before self's transformation and returns the resulting transform.
     #[inline]
     #[must_use]
-    pub fn pre_rotate(&self, theta: Angle<T>) -> Self {
+    pub fn pre_rotate(&self, theta: Angle<T>) -> Self
+    where
+        T: SimdScalar,
+    {
         Transform2D::rotation(theta).then(self)
     }
 }
@@ -513,7 +670,7 @@ impl<T, Src, Dst> Transform2D<T, Src, Dst> {
     #[must_use]
     pub fn then_scale(&self, x: T, y: T) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T> + Zero,
+        T: Copy + Add<Output = T> + Mul<Output = T> + Zero + SimdScalar,
     {
         self.then(&Transform2D::scale(x, y))
     }
@@ -537,26 +694,22 @@ impl<T, Src, Dst> Transform2D<T, Src, Dst> {
 /// Methods for apply transformations to objects
 impl<T, Src, Dst> Transform2D<T, Src, Dst>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T>,
+    T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
 {
     /// Returns the given point transformed by this transform.
     #[inline]
     #[must_use]
     pub fn transform_point(&self, point: Point2D<T, Src>) -> Point2D<T, Dst> {
-        Point2D::new(
-            point.x * self.m11 + point.y * self.m21 + self.m31,
-            point.x * self.m12 + point.y * self.m22 + self.m32,
-        )
+        let [x, y] = T::affine2_point(&self.matrix().0, [point.x, point.y]);
+        Point2D::new(x, y)
     }
 
     /// Returns the given vector transformed by this matrix.
     #[inline]
     #[must_use]
     pub fn transform_vector(&self, vec: Vector2D<T, Src>) -> Vector2D<T, Dst> {
-        vec2(
-            vec.x * self.m11 + vec.y * self.m21,
-            vec.x * self.m12 + vec.y * self.m22,
-        )
+        let [x, y] = T::affine2_vector(&self.matrix().0, [vec.x, vec.y]);
+        vec2(x, y)
     }
 
     /// Returns a rectangle that encompasses the result of transforming the given rectangle by this
@@ -569,28 +722,67 @@ where
     {
         let min = rect.min();
         let max = rect.max();
-        Rect::from_points(&[
+        Box2D::from_four_points(
             self.transform_point(min),
             self.transform_point(max),
             self.transform_point(point2(max.x, min.y)),
             self.transform_point(point2(min.x, max.y)),
-        ])
+        )
+        .to_rect()
     }
 
     /// Returns a box that encompasses the result of transforming the given box by this
     /// transform.
+    ///
+    /// The corners of the box are transformed, and the result is their bounding box.
     #[inline]
     #[must_use]
+    #[doc(alias = "transform_box")]
     pub fn outer_transformed_box(&self, b: &Box2D<T, Src>) -> Box2D<T, Dst>
     where
         T: Sub<Output = T> + Zero + PartialOrd,
     {
-        Box2D::from_points(&[
+        Box2D::from_four_points(
             self.transform_point(b.min),
             self.transform_point(b.max),
             self.transform_point(point2(b.max.x, b.min.y)),
             self.transform_point(point2(b.min.x, b.max.y)),
-        ])
+        )
+    }
+}
+
+/// Methods for transforming slices in place
+impl<T, U> Transform2D<T, U, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Transforms each point of the slice in place.
+    ///
+    /// Equivalent to calling `transform_point` on each element, in a loop that the
+    /// compiler can vectorize.
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point2D<T, U>]) {
+        let (m11, m12, m21, m22, m31, m32) =
+            (self.m11, self.m12, self.m21, self.m22, self.m31, self.m32);
+        for p in points {
+            let (x, y) = (p.x, p.y);
+            p.x = x * m11 + y * m21 + m31;
+            p.y = x * m12 + y * m22 + m32;
+        }
+    }
+
+    /// Transforms each vector of the slice in place.
+    ///
+    /// Equivalent to calling `transform_vector` on each element, in a loop that the
+    /// compiler can vectorize.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [Vector2D<T, U>]) {
+        let (m11, m12, m21, m22) = (self.m11, self.m12, self.m21, self.m22);
+        for v in vectors {
+            let (x, y) = (v.x, v.y);
+            v.x = x * m11 + y * m21;
+            v.y = x * m12 + y * m22;
+        }
     }
 }
 
@@ -611,26 +803,108 @@ where
 
     /// Returns the inverse transform if possible.
     #[must_use]
-    pub fn inverse(&self) -> Option<Transform2D<T, Dst, Src>> {
-        let det = self.determinant();
-
+    pub fn inverse(&self) -> Option<Transform2D<T, Dst, Src>>
+    where
+        T: Add<Output = T>,
+    {
+        validate_invariant!(
+            finite(&self.to_array()),
+            "Transform2D::inverse: non-finite component"
+        );
         let _0: T = Zero::zero();
         let _1: T = One::one();
 
-        if det == _0 {
+        // The 3x3 matrix of the affine transform, whose inverse is also affine.
+        let [[m11, m12], [m21, m22], [m31, m32]] = self.matrix().0;
+        let inv = Matrix([[m11, m12, _0], [m21, m22, _0], [m31, m32, _1]]).inverse()?;
+        let [[m11, m12, _], [m21, m22, _], [m31, m32, _]] = inv.0;
+        let m = Transform2D::new(m11, m12, m21, m22, m31, m32);
+        debug_check_nan!("Transform2D::inverse", m.to_array());
+        Some(m)
+    }
+
+    /// Returns the inverse transform, or an error with the determinant if there is none.
+    pub fn inverse_checked(&self) -> Result<Transform2D<T, Dst, Src>, Error<T>>
+    where
+        T: Add<Output = T>,
+    {
+        self.inverse().ok_or_else(|| Error::NonInvertible {
+            determinant: self.determinant(),
+        })
+    }
+}
+
+/// Decomposition into components that can be interpolated
+impl<T: RealScalar, Src, Dst> Transform2D<T, Src, Dst> {
+    /// Breaks this transform down into a scale, a skew, a rotation and a translation.
+    ///
+    /// This is the 2d case of the "unmatrix" decomposition used for CSS transform
+    /// interpolation. The scales are positive unless the transform is a reflection, which
+    /// flips the x scale if `m11 < m22`, and the y scale otherwise.
+    /// Returns `None` if the transform is not invertible.
+    ///
+    /// ```
+    /// use euclid::default::Transform2D;
+    /// use euclid::approxeq::ApproxEq;
+    /// use euclid::{vec2, Angle};
+    ///
+    /// let t = Transform2D::scale(2.0, 3.0)
+    ///     .then_rotate(Angle::degrees(30.0))
+    ///     .then_translate(vec2(10.0, 20.0));
+    /// let d = t.decompose().unwrap();
+    /// assert!(d.angle.approx_eq(&Angle::degrees(30.0)));
+    /// assert!(d.scale.approx_eq(&vec2(2.0, 3.0)));
+    /// assert!(Transform2D::recompose(&d).approx_eq(&t));
+    /// ```
+    pub fn decompose(&self) -> Option<Decomposition2D<T, Src, Dst>> {
+        let (a, b, c, d) = (self.m11, self.m12, self.m21, self.m22);
+        let det = a * d - b * c;
+        if det == T::zero() {
             return None;
         }
 
-        let inv_det = _1 / det;
-        Some(Transform2D::new(
-            inv_det * self.m22,
-            inv_det * (_0 - self.m12),
-            inv_det * (_0 - self.m21),
-            inv_det * self.m11,
-            inv_det * (self.m21 * self.m32 - self.m22 * self.m31),
-            inv_det * (self.m31 * self.m12 - self.m11 * self.m32),
+        // Normalize the first row, which gives the x scale.
+        let mut scale_x = a.hypot(b);
+        let (mut row0_x, mut row0_y) = (a / scale_x, b / scale_x);
+
+        // Take the shear off the second row, which leaves the y scale.
+        let mut skew = row0_x * c + row0_y * d;
+        let mut scale_y = (c - skew * row0_x).hypot(d - skew * row0_y);
+        skew = skew / scale_y;
+
+        // A negative determinant means that one of the axes is flipped.
+        if det < T::zero() {
+            if a < d {
+                scale_x = -scale_x;
+                row0_x = -row0_x;
+                row0_y = -row0_y;
+            } else {
+                scale_y = -scale_y;
+            }
+            skew = -skew;
+        }
+
+        Some(Decomposition2D::new(
+            vec2(self.m31, self.m32),
+            Angle::radians(row0_y.atan2(row0_x)),
+            skew,
+            vec2(scale_x, scale_y),
         ))
     }
+
+    /// Builds a transform from its decomposition, see [`decompose`](Self::decompose).
+    pub fn recompose(d: &Decomposition2D<T, Src, Dst>) -> Self {
+        let (sin, cos) = d.angle.sin_cos();
+        let (sx, sy) = (d.scale.x, d.scale.y);
+        Transform2D::new(
+            cos * sx,
+            sin * sx,
+            (cos * d.skew - sin) * sy,
+            (sin * d.skew + cos) * sy,
+            d.translation.x,
+            d.translation.y,
+        )
+    }
 }
 
 impl<T, Src, Dst> Default for Transform2D<T, Src, Dst>
@@ -697,6 +971,77 @@ impl<T, Src, Dst> From<Transform2D<T, Src, Dst>> for mint::RowMatrix3x2<T> {
     }
 }
 
+/// `Transform2D` uses the row vector convention while nalgebra uses column vectors, so the
+/// conversion transposes the matrix. The projective last row of the nalgebra matrix is
+/// ignored.
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar + Copy, Src, Dst> From<nalgebra::Matrix3<T>> for Transform2D<T, Src, Dst> {
+    fn from(m: nalgebra::Matrix3<T>) -> Self {
+        let [[m11, m12, _], [m21, m22, _], [m31, m32, _]]: [[T; 3]; 3] = m.into();
+        Transform2D::new(m11, m12, m21, m22, m31, m32)
+    }
+}
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar + Copy + Zero + One, Src, Dst> From<Transform2D<T, Src, Dst>>
+    for nalgebra::Matrix3<T>
+{
+    fn from(t: Transform2D<T, Src, Dst>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        nalgebra::Matrix3::from([
+            [t.m11, t.m12, zero],
+            [t.m21, t.m22, zero],
+            [t.m31, t.m32, one],
+        ])
+    }
+}
+
+/// Same as `Transform2D::from_array`.
+impl<T: Copy, Src, Dst> From<[T; 6]> for Transform2D<T, Src, Dst> {
+    fn from(array: [T; 6]) -> Self {
+        Transform2D::from_array(array)
+    }
+}
+
+/// Same as `Transform2D::to_array`.
+impl<T: Copy, Src, Dst> From<Transform2D<T, Src, Dst>> for [T; 6] {
+    fn from(t: Transform2D<T, Src, Dst>) -> Self {
+        t.to_array()
+    }
+}
+
+/// Same as `Transform2D::from_arrays`.
+impl<T: Copy, Src, Dst> From<[[T; 2]; 3]> for Transform2D<T, Src, Dst> {
+    fn from(array: [[T; 2]; 3]) -> Self {
+        Transform2D::from_arrays(array)
+    }
+}
+
+/// Same as `Transform2D::to_arrays`.
+impl<T: Copy, Src, Dst> From<Transform2D<T, Src, Dst>> for [[T; 2]; 3] {
+    fn from(t: Transform2D<T, Src, Dst>) -> Self {
+        t.to_arrays()
+    }
+}
+
+/// Indexes the terms by zero-based `(row, column)`, so that `t[(0, 1)]` is `t.m12`.
+///
+/// Panics if the row is greater than 2 or the column is greater than 1.
+impl<T, Src, Dst> Index<(usize, usize)> for Transform2D<T, Src, Dst> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.matrix().term("Transform2D", row, column)
+    }
+}
+
+impl<T, Src, Dst> IndexMut<(usize, usize)> for Transform2D<T, Src, Dst> {
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        // SAFETY: see `Transform2D::matrix`.
+        unsafe { Matrix::<T, 3, 2>::view_mut(self) }.term_mut("Transform2D", row, column)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -845,6 +1190,16 @@ mod test {
         assert_eq!(v1, m1.transform_vector(v1));
     }
 
+    #[test]
+    pub fn test_outer_transformed_box() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(2.0, 1.0));
+        let m = Mat::rotation(rad(FRAC_PI_2)).then_translate(vec2(1.0, 1.0));
+        let result = m.outer_transformed_box(&b);
+        assert!(result.min.approx_eq(&point2(0.0, 1.0)));
+        assert!(result.max.approx_eq(&point2(1.0, 3.0)));
+        assert_eq!(m.outer_transformed_rect(&b.to_rect()), result.to_rect());
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -854,4 +1209,135 @@ mod test {
 
         assert_eq!(m1, m2);
     }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    pub fn test_nalgebra() {
+        let m1 = Mat::rotation(rad(FRAC_PI_2)).then_translate(vec2(1.0, 2.0));
+        let nm: nalgebra::Matrix3<_> = m1.into();
+        let p = nm.transform_point(&nalgebra::Point2::new(3.0, 4.0));
+        assert!(m1
+            .transform_point(point2(3.0, 4.0))
+            .approx_eq(&point2(p.x, p.y)));
+        assert_eq!(Mat::from(nm), m1);
+    }
+
+    #[test]
+    pub fn test_array_conversions() {
+        let m = Mat::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let array: [f32; 6] = m.into();
+        let arrays: [[f32; 2]; 3] = m.into();
+        assert_eq!(array, m.to_array());
+        assert_eq!(arrays, m.to_arrays());
+        assert_eq!(Mat::from(array), m);
+        assert_eq!(Mat::from(arrays), m);
+    }
+
+    #[cfg(feature = "fast-math")]
+    #[test]
+    pub fn test_rotation_fast() {
+        assert_eq!(Mat::rotation_fast(rad(0.0)), Mat::identity());
+        for i in -10..10 {
+            let angle = rad(i as f32 * 0.7);
+            assert!(Mat::rotation_fast(angle).approx_eq(&Mat::rotation(angle)));
+        }
+    }
+
+    #[test]
+    pub fn test_transform_slices() {
+        let m = Mat::rotation(rad(FRAC_PI_2)).then_translate(vec2(1.0, 2.0));
+        let mut points = [point2(1.0, 0.0), point2(3.0, -4.0), point2(0.5, 7.0)];
+        let expected = points.map(|p| m.transform_point(p));
+        m.transform_points(&mut points);
+        assert_eq!(points, expected);
+
+        let mut vectors = [vec2(1.0, 0.0), vec2(3.0, -4.0)];
+        let expected = vectors.map(|v| m.transform_vector(v));
+        m.transform_vectors(&mut vectors);
+        assert_eq!(vectors, expected);
+    }
+
+    #[test]
+    pub fn test_index() {
+        let mut m = Mat::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(m[(0, 1)], m.m12);
+        assert_eq!(m[(2, 0)], m.m31);
+        assert_eq!(m.row(1), [3.0, 4.0]);
+        assert_eq!(m.column(1), [2.0, 4.0, 6.0]);
+        m[(2, 1)] = 7.0;
+        assert_eq!(m.m32, 7.0);
+    }
+
+    #[test]
+    pub fn test_decompose() {
+        type M = default::Transform2D<f64>;
+        let rad = Angle::radians;
+
+        let t = M::scale(2.0, 3.0)
+            .then(&M::new(1.0, 0.0, 0.5, 1.0, 0.0, 0.0))
+            .then_rotate(rad(2.5))
+            .then_translate(vec2(10.0, -20.0));
+        let d = t.decompose().unwrap();
+        assert!(d.translation.approx_eq(&vec2(10.0, -20.0)));
+        assert!(d.angle.approx_eq(&rad(2.5)));
+        assert!(d.skew.approx_eq(&0.5));
+        assert!(d.scale.approx_eq(&vec2(2.0, 3.0)));
+        assert!(M::recompose(&d).approx_eq(&t));
+
+        // A reflection flips the x scale if m11 < m22, and the y scale otherwise.
+        let t = M::scale(-1.0, 1.0).then_rotate(rad(0.5));
+        let d = t.decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec2(-1.0, 1.0)));
+        assert!(M::recompose(&d).approx_eq(&t));
+        let t = M::scale(1.0, -1.0).then(&M::new(1.0, 0.0, 0.5, 1.0, 0.0, 0.0));
+        let d = t.decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec2(1.0, -1.0)));
+        assert!(d.skew.approx_eq(&0.5));
+        assert!(M::recompose(&d).approx_eq(&t));
+
+        // Like CSS, halfway from `none` to `matrix(0, 2, -2, 0, 10, 20)` is a rotation by
+        // 45 degrees with a scale of 1.5, and the translation halfway.
+        let a = M::identity().decompose().unwrap();
+        let b = M::new(0.0, 2.0, -2.0, 0.0, 10.0, 20.0).decompose().unwrap();
+        let mid = M::recompose(&a.lerp(&b, 0.5));
+        let s = 1.5 * core::f64::consts::FRAC_1_SQRT_2;
+        assert!(mid.approx_eq(&M::new(s, s, -s, s, 5.0, 10.0)));
+
+        assert_eq!(M::scale(0.0, 1.0).decompose(), None);
+        assert!(M::recompose(&M::identity().decompose().unwrap()).approx_eq(&M::identity()));
+        assert_eq!(
+            Decomposition2D::default(),
+            M::identity().decompose().unwrap()
+        );
+
+        // Halfway between two rotations goes along the shortest arc.
+        let a = M::rotation(rad(3.0)).decompose().unwrap();
+        let b = M::rotation(rad(-3.0)).decompose().unwrap();
+        let mid = M::recompose(&a.lerp(&b, 0.5));
+        assert!(mid.approx_eq(&M::rotation(rad(core::f64::consts::PI))));
+    }
+
+    #[test]
+    pub fn test_then_by() {
+        struct A;
+        struct B;
+        struct C;
+        struct D;
+        let s: Scale<f32, A, B> = Scale::new(2.0);
+        let r: Rotation2D<f32, B, C> = Rotation2D::new(rad(FRAC_PI_2));
+        let t: Translation2D<f32, C, D> = Translation2D::new(1.0, 2.0);
+
+        let m = Transform2D::identity()
+            .then_scale_by(s)
+            .then_rotate_by(r)
+            .then_translate_by(t);
+        let expected = Mat::scale(2.0, 2.0)
+            .then_rotate(rad(FRAC_PI_2))
+            .then_translate(vec2(1.0, 2.0));
+        assert!(m.to_untyped().approx_eq(&expected));
+
+        let p = point2::<f32, A>(1.0, 0.0);
+        let q = t.transform_point(r.transform_point(s.transform_point(p)));
+        assert!(m.transform_point(p).approx_eq(&q));
+    }
 }
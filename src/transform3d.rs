@@ -11,27 +11,33 @@
 
 use super::{Angle, UnknownUnit};
 use crate::approxeq::ApproxEq;
+use crate::batch::{self, SimdScalar};
 use crate::box2d::Box2D;
 use crate::box3d::Box3D;
+use crate::decomposition::Decomposition3D;
+use crate::error::Error;
+use crate::flagged::TransformFlags;
 use crate::homogen::HomogeneousVector;
-use crate::num::{One, Zero};
+use crate::matrix::{pseudo_inverse, Matrix};
+use crate::num::{ConstZeroOne, One, RealScalar, Zero};
 use crate::point::{point2, point3, Point2D, Point3D};
 use crate::rect::Rect;
+use crate::rotation::Rotation3D;
 use crate::scale::Scale;
 use crate::transform2d::Transform2D;
+use crate::translation::Translation3D;
 use crate::trig::Trig;
+use crate::validated::finite;
 use crate::vector::{vec2, vec3, Vector2D, Vector3D};
 
 use core::cmp::{Eq, PartialEq};
 use core::fmt;
 use core::hash::Hash;
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
-#[cfg(feature = "mint")]
-use mint;
 use num_traits::NumCast;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -59,7 +65,20 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 /// The translation terms are `m41`, `m42` and `m43`.
+///
+/// # Memory layout
+///
+/// `Transform3D` is `#[repr(C)]` and its units are zero-sized, so it is guaranteed to
+/// have the same memory layout as the `[T; 16]` returned by [`Transform3D::to_array`]:
+/// the sixteen terms in `m11, m12, ..., m44` order. This is the column-major layout
+/// expected by OpenGL, Vulkan, Metal and WGSL `mat4x4` uniforms, so a
+/// `&[Transform3D<f32, Src, Dst>]` can be uploaded as is, without transposition.
+/// See [`Transform3D::as_array`] and [`Transform3D::slice_as_arrays`].
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -190,7 +209,33 @@ where
     }
 }
 
+impl<T: ConstZeroOne, Src, Dst> Transform3D<T, Src, Dst> {
+    /// The identity transform, see [`Transform3D::identity`].
+    #[rustfmt::skip]
+    pub const IDENTITY: Self = Transform3D::new(
+        T::ONE,  T::ZERO, T::ZERO, T::ZERO,
+        T::ZERO, T::ONE,  T::ZERO, T::ZERO,
+        T::ZERO, T::ZERO, T::ONE,  T::ZERO,
+        T::ZERO, T::ZERO, T::ZERO, T::ONE,
+    );
+}
+
 impl<T, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Views the terms as a 4x4 matrix.
+    #[inline]
+    fn matrix(&self) -> &Matrix<T, 4, 4> {
+        // SAFETY: `Transform3D` is `#[repr(C)]` with its 16 terms in row-major order and
+        // zero-sized units, as `Matrix::view` requires.
+        unsafe { Matrix::view(self) }
+    }
+
+    /// Mutable version of `matrix`.
+    #[inline]
+    fn matrix_mut(&mut self) -> &mut Matrix<T, 4, 4> {
+        // SAFETY: see `matrix`.
+        unsafe { Matrix::view_mut(self) }
+    }
+
     /// Create a transform specifying all of it's component as a 4 by 4 matrix.
     ///
     /// Components are specified following column-major-column-vector matrix notation.
@@ -275,6 +320,60 @@ impl<T, Src, Dst> Transform3D<T, Src, Dst> {
             && self.m33 == _1
             && self.m44 == _1
     }
+
+    /// Returns `true` if this transform can be represented with a `Transform2D`, allowing
+    /// each of the 3d terms to differ from its 2d value by up to `eps`.
+    ///
+    /// Transforms that are 2d in theory often pick up small 3d terms from rounding errors,
+    /// for example after being combined with a 3d rotation and its inverse.
+    #[inline]
+    pub fn is_2d_eps(&self, eps: &T) -> bool
+    where
+        T: Zero + One + ApproxEq<T>,
+    {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        self.m31.approx_eq_eps(&_0, eps)
+            && self.m32.approx_eq_eps(&_0, eps)
+            && self.m13.approx_eq_eps(&_0, eps)
+            && self.m23.approx_eq_eps(&_0, eps)
+            && self.m43.approx_eq_eps(&_0, eps)
+            && self.m14.approx_eq_eps(&_0, eps)
+            && self.m24.approx_eq_eps(&_0, eps)
+            && self.m34.approx_eq_eps(&_0, eps)
+            && self.m33.approx_eq_eps(&_1, eps)
+            && self.m44.approx_eq_eps(&_1, eps)
+    }
+
+    /// Classifies the transform, so that callers can skip work for the common
+    /// identity and translation cases.
+    ///
+    /// See [`FlaggedTransform3D`](crate::FlaggedTransform3D), which caches the result.
+    pub fn flags(&self) -> TransformFlags
+    where
+        T: Zero + One + PartialEq,
+    {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        if self.m14 != _0 || self.m24 != _0 || self.m34 != _0 || self.m44 != _1 {
+            return TransformFlags::HAS_PERSPECTIVE;
+        }
+
+        let mut flags = TransformFlags::empty();
+        if self.is_2d() {
+            flags = flags | TransformFlags::AFFINE_2D;
+        }
+        #[rustfmt::skip]
+        let linear_is_identity =
+            self.m11 == _1 && self.m12 == _0 && self.m13 == _0 &&
+            self.m21 == _0 && self.m22 == _1 && self.m23 == _0 &&
+            self.m31 == _0 && self.m32 == _0 && self.m33 == _1;
+        if linear_is_identity {
+            flags = flags | TransformFlags::TRANSLATION_ONLY;
+            if self.m41 == _0 && self.m42 == _0 && self.m43 == _0 {
+                flags = flags | TransformFlags::IDENTITY;
+            }
+        }
+        flags
+    }
 }
 
 impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
@@ -376,6 +475,79 @@ impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
         )
     }
 
+    /// Cast into a tuple of the rows of `to_arrays`.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn to_tuple(&self) -> ((T, T, T, T), (T, T, T, T), (T, T, T, T), (T, T, T, T)) {
+        (
+            (self.m11, self.m12, self.m13, self.m14),
+            (self.m21, self.m22, self.m23, self.m24),
+            (self.m31, self.m32, self.m33, self.m34),
+            (self.m41, self.m42, self.m43, self.m44),
+        )
+    }
+
+    /// Create a transform from a tuple of rows, in the same order as `from_arrays`.
+    #[inline]
+    #[rustfmt::skip]
+    #[allow(clippy::type_complexity)]
+    pub fn from_tuple(rows: ((T, T, T, T), (T, T, T, T), (T, T, T, T), (T, T, T, T))) -> Self {
+        let (
+            (m11, m12, m13, m14),
+            (m21, m22, m23, m24),
+            (m31, m32, m33, m34),
+            (m41, m42, m43, m44),
+        ) = rows;
+        Self::new(
+            m11, m12, m13, m14,
+            m21, m22, m23, m24,
+            m31, m32, m33, m34,
+            m41, m42, m43, m44,
+        )
+    }
+
+    /// Returns the terms `m{i}1` to `m{i}4` of the given zero-based row, that is
+    /// an element of `to_arrays`.
+    ///
+    /// Panics if `row` is greater than 3.
+    #[inline]
+    pub fn row(&self, row: usize) -> [T; 4] {
+        self.matrix().row("Transform3D", row)
+    }
+
+    /// Returns the terms `m1{j}` to `m4{j}` of the given zero-based column, that is
+    /// an element of `to_arrays_transposed`.
+    ///
+    /// Panics if `column` is greater than 3.
+    #[inline]
+    pub fn column(&self, column: usize) -> [T; 4] {
+        self.matrix().column("Transform3D", column)
+    }
+
+    /// Returns a reference to this transform's terms as an array, in the same
+    /// order as `Transform3D::to_array`.
+    ///
+    /// This is a view rather than a copy, see the memory layout section of the
+    /// `Transform3D` documentation.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 16] {
+        // SAFETY: `Transform3D` is `#[repr(C)]` with 16 fields of type `T`, which can't
+        // have padding between them, and a zero-sized unit, so it has the layout of
+        // `[T; 16]`.
+        unsafe { &*(self as *const Self as *const [T; 16]) }
+    }
+
+    /// Reinterprets a slice of transforms as a slice of arrays, in the same
+    /// order as `Transform3D::to_array`, without copying.
+    #[inline]
+    pub fn slice_as_arrays(transforms: &[Self]) -> &[[T; 16]] {
+        // SAFETY: `Transform3D` has the layout of `[T; 16]`, see `as_array`, so a slice
+        // of `len` transforms has the layout of a slice of `len` arrays.
+        unsafe {
+            core::slice::from_raw_parts(transforms.as_ptr() as *const [T; 16], transforms.len())
+        }
+    }
+
     /// Tag a unitless value with units.
     #[inline]
     #[rustfmt::skip]
@@ -433,6 +605,42 @@ impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
     pub fn to_2d(&self) -> Transform2D<T, Src, Dst> {
         Transform2D::new(self.m11, self.m12, self.m21, self.m22, self.m41, self.m42)
     }
+
+    /// Returns the 2d transform picking the relevant terms from this transform, or
+    /// `None` if the 3d terms are not within the default epsilon of their 2d values.
+    ///
+    /// See [`is_2d_eps`](Self::is_2d_eps).
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    /// use euclid::Angle;
+    ///
+    /// let r = Transform3D::rotation(1.0, 0.0, 0.0, Angle::radians(0.3));
+    /// let t = r.then(&r.inverse().unwrap()).then(&Transform3D::scale(2.0, 2.0, 1.0));
+    /// assert!(t.try_to_2d().is_some());
+    /// assert!(r.try_to_2d().is_none());
+    /// ```
+    #[inline]
+    pub fn try_to_2d(&self) -> Option<Transform2D<T, Src, Dst>>
+    where
+        T: Zero + One + ApproxEq<T>,
+    {
+        self.try_to_2d_eps(&T::approx_epsilon())
+    }
+
+    /// Returns the 2d transform picking the relevant terms from this transform, or
+    /// `None` if the 3d terms are not within `eps` of their 2d values.
+    #[inline]
+    pub fn try_to_2d_eps(&self, eps: &T) -> Option<Transform2D<T, Src, Dst>>
+    where
+        T: Zero + One + ApproxEq<T>,
+    {
+        if self.is_2d_eps(eps) {
+            Some(self.to_2d())
+        } else {
+            None
+        }
+    }
 }
 
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
@@ -531,29 +739,92 @@ where
     ///
     /// Assuming row vectors, this is equivalent to self * mat
     #[must_use]
-    #[rustfmt::skip]
-    pub fn then<NewDst>(&self, other: &Transform3D<T, Dst, NewDst>) -> Transform3D<T, Src, NewDst> {
-        Transform3D::new(
-            self.m11 * other.m11  +  self.m12 * other.m21  +  self.m13 * other.m31  +  self.m14 * other.m41,
-            self.m11 * other.m12  +  self.m12 * other.m22  +  self.m13 * other.m32  +  self.m14 * other.m42,
-            self.m11 * other.m13  +  self.m12 * other.m23  +  self.m13 * other.m33  +  self.m14 * other.m43,
-            self.m11 * other.m14  +  self.m12 * other.m24  +  self.m13 * other.m34  +  self.m14 * other.m44,
-
-            self.m21 * other.m11  +  self.m22 * other.m21  +  self.m23 * other.m31  +  self.m24 * other.m41,
-            self.m21 * other.m12  +  self.m22 * other.m22  +  self.m23 * other.m32  +  self.m24 * other.m42,
-            self.m21 * other.m13  +  self.m22 * other.m23  +  self.m23 * other.m33  +  self.m24 * other.m43,
-            self.m21 * other.m14  +  self.m22 * other.m24  +  self.m23 * other.m34  +  self.m24 * other.m44,
-
-            self.m31 * other.m11  +  self.m32 * other.m21  +  self.m33 * other.m31  +  self.m34 * other.m41,
-            self.m31 * other.m12  +  self.m32 * other.m22  +  self.m33 * other.m32  +  self.m34 * other.m42,
-            self.m31 * other.m13  +  self.m32 * other.m23  +  self.m33 * other.m33  +  self.m34 * other.m43,
-            self.m31 * other.m14  +  self.m32 * other.m24  +  self.m33 * other.m34  +  self.m34 * other.m44,
-
-            self.m41 * other.m11  +  self.m42 * other.m21  +  self.m43 * other.m31  +  self.m44 * other.m41,
-            self.m41 * other.m12  +  self.m42 * other.m22  +  self.m43 * other.m32  +  self.m44 * other.m42,
-            self.m41 * other.m13  +  self.m42 * other.m23  +  self.m43 * other.m33  +  self.m44 * other.m43,
-            self.m41 * other.m14  +  self.m42 * other.m24  +  self.m43 * other.m34  +  self.m44 * other.m44,
-        )
+    pub fn then<NewDst>(&self, other: &Transform3D<T, Dst, NewDst>) -> Transform3D<T, Src, NewDst>
+    where
+        T: SimdScalar,
+    {
+        Transform3D::from_arrays(T::mat4_mul(&self.matrix().0, &other.matrix().0))
+    }
+
+    /// Same as `then`, but writes the result into `out` instead of returning it.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    ///
+    /// let a = Transform3D::translation(1.0, 2.0, 3.0);
+    /// let b = Transform3D::scale(2.0, 2.0, 2.0);
+    /// let mut out = Transform3D::identity();
+    /// a.mul_into(&b, &mut out);
+    /// assert_eq!(out, a.then(&b));
+    /// ```
+    #[inline]
+    pub fn mul_into<NewDst>(
+        &self,
+        other: &Transform3D<T, Dst, NewDst>,
+        out: &mut Transform3D<T, Src, NewDst>,
+    ) where
+        T: SimdScalar,
+    {
+        out.matrix_mut().0 = T::mat4_mul(&self.matrix().0, &other.matrix().0);
+    }
+}
+
+/// Methods for chaining transformations into another space
+///
+/// See the corresponding methods of `Transform2D`.
+impl<T, Src, Dst> Transform3D<T, Src, Dst>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One + SimdScalar,
+{
+    /// Applies a scale after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_scale_by<NewDst>(
+        &self,
+        scale: Scale<T, Dst, NewDst>,
+    ) -> Transform3D<T, Src, NewDst> {
+        self.then(&Transform3D::scale(scale.0, scale.0, scale.0))
+    }
+
+    /// Applies a translation after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_translate_by<NewDst>(
+        &self,
+        translation: Translation3D<T, Dst, NewDst>,
+    ) -> Transform3D<T, Src, NewDst> {
+        self.then(&translation.to_transform())
+    }
+
+    /// Applies a rotation after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_rotate_by<NewDst>(
+        &self,
+        rotation: Rotation3D<T, Dst, NewDst>,
+    ) -> Transform3D<T, Src, NewDst>
+    where
+        T: RealScalar,
+    {
+        self.then(&rotation.to_transform())
+    }
+}
+
+/// Methods for combining transformations in place
+impl<T, U> Transform3D<T, U, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
+{
+    /// Replaces `self` with `self.then(other)`.
+    #[inline]
+    pub fn post_mul_assign(&mut self, other: &Self) {
+        self.matrix_mut().0 = T::mat4_mul(&self.matrix().0, &other.matrix().0);
+    }
+
+    /// Replaces `self` with `other.then(self)`.
+    #[inline]
+    pub fn pre_mul_assign(&mut self, other: &Self) {
+        self.matrix_mut().0 = T::mat4_mul(&other.matrix().0, &self.matrix().0);
     }
 }
 
@@ -588,7 +859,7 @@ where
     #[must_use]
     pub fn pre_translate(&self, v: Vector3D<T, Src>) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         Transform3D::translation(v.x, v.y, v.z).then(self)
     }
@@ -597,7 +868,7 @@ where
     #[must_use]
     pub fn then_translate(&self, v: Vector3D<T, Dst>) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         self.then(&Transform3D::translation(v.x, v.y, v.z))
     }
@@ -656,13 +927,19 @@ where
 
     /// Returns a transform with a rotation applied after self's transformation.
     #[must_use]
-    pub fn then_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self {
+    pub fn then_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self
+    where
+        T: SimdScalar,
+    {
         self.then(&Transform3D::rotation(x, y, z, theta))
     }
 
     /// Returns a transform with a rotation applied before self's transformation.
     #[must_use]
-    pub fn pre_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self {
+    pub fn pre_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self
+    where
+        T: SimdScalar,
+    {
         Transform3D::rotation(x, y, z, theta).then(self)
     }
 }
@@ -699,7 +976,7 @@ where
     #[rustfmt::skip]
     pub fn pre_scale(&self, x: T, y: T, z: T) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         Transform3D::new(
             self.m11 * x, self.m12 * x, self.m13 * x, self.m14 * x,
@@ -713,7 +990,7 @@ where
     #[must_use]
     pub fn then_scale(&self, x: T, y: T, z: T) -> Self
     where
-        T: Copy + Add<Output = T> + Mul<Output = T>,
+        T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
     {
         self.then(&Transform3D::scale(x, y, z))
     }
@@ -722,7 +999,7 @@ where
 /// Methods for apply transformations to objects
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T>,
+    T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
 {
     /// Returns the homogeneous vector corresponding to the transformed 2d point.
     ///
@@ -732,11 +1009,7 @@ where
     pub fn transform_point2d_homogeneous(
         &self, p: Point2D<T, Src>
     ) -> HomogeneousVector<T, Dst> {
-        let x = p.x * self.m11 + p.y * self.m21 + self.m41;
-        let y = p.x * self.m12 + p.y * self.m22 + self.m42;
-        let z = p.x * self.m13 + p.y * self.m23 + self.m43;
-        let w = p.x * self.m14 + p.y * self.m24 + self.m44;
-
+        let [x, y, z, w] = T::mat4_point2d(&self.matrix().0, [p.x, p.y]);
         HomogeneousVector::new(x, y, z, w)
     }
 
@@ -749,13 +1022,11 @@ where
     where
         T: Div<Output = T> + Zero + PartialOrd,
     {
-        //Note: could use `transform_point2d_homogeneous()` but it would waste the calculus of `z`
-        let w = p.x * self.m14 + p.y * self.m24 + self.m44;
+        let [x, y, _, w] = T::mat4_point2d(&self.matrix().0, [p.x, p.y]);
         if w > T::zero() {
-            let x = p.x * self.m11 + p.y * self.m21 + self.m41;
-            let y = p.x * self.m12 + p.y * self.m22 + self.m42;
-
-            Some(Point2D::new(x / w, y / w))
+            let p = Point2D::new(x / w, y / w);
+            debug_check_nan!("Transform3D::transform_point2d", p.x, p.y);
+            Some(p)
         } else {
             None
         }
@@ -777,11 +1048,7 @@ where
     /// The input point must be use the unit Src, and the returned point has the unit Dst.
     #[inline]
     pub fn transform_point3d_homogeneous(&self, p: Point3D<T, Src>) -> HomogeneousVector<T, Dst> {
-        let x = p.x * self.m11 + p.y * self.m21 + p.z * self.m31 + self.m41;
-        let y = p.x * self.m12 + p.y * self.m22 + p.z * self.m32 + self.m42;
-        let z = p.x * self.m13 + p.y * self.m23 + p.z * self.m33 + self.m43;
-        let w = p.x * self.m14 + p.y * self.m24 + p.z * self.m34 + self.m44;
-
+        let [x, y, z, w] = T::mat4_point3d(&self.matrix().0, [p.x, p.y, p.z]);
         HomogeneousVector::new(x, y, z, w)
     }
 
@@ -802,11 +1069,8 @@ where
     /// The input point must be use the unit Src, and the returned point has the unit Dst.
     #[inline]
     pub fn transform_vector3d(&self, v: Vector3D<T, Src>) -> Vector3D<T, Dst> {
-        vec3(
-            v.x * self.m11 + v.y * self.m21 + v.z * self.m31,
-            v.x * self.m12 + v.y * self.m22 + v.z * self.m32,
-            v.x * self.m13 + v.y * self.m23 + v.z * self.m33,
-        )
+        let [x, y, z] = T::mat4_vector3d(&self.matrix().0, [v.x, v.y, v.z]);
+        vec3(x, y, z)
     }
 
     /// Returns a rectangle that encompasses the result of transforming the given rectangle by this
@@ -817,35 +1081,44 @@ where
     {
         let min = rect.min();
         let max = rect.max();
-        Some(Rect::from_points(&[
-            self.transform_point2d(min)?,
-            self.transform_point2d(max)?,
-            self.transform_point2d(point2(max.x, min.y))?,
-            self.transform_point2d(point2(min.x, max.y))?,
-        ]))
+        Some(
+            Box2D::from_four_points(
+                self.transform_point2d(min)?,
+                self.transform_point2d(max)?,
+                self.transform_point2d(point2(max.x, min.y))?,
+                self.transform_point2d(point2(min.x, max.y))?,
+            )
+            .to_rect(),
+        )
     }
 
     /// Returns a 2d box that encompasses the result of transforming the given box by this
     /// transform, if the transform makes sense for it, or `None` otherwise.
+    ///
+    /// With a projective transform, the result is `None` as soon as one of the corners
+    /// projects with a `w` that is not positive, which happens for points behind the
+    /// viewer. Splitting the box at the `w = 0` plane beforehand keeps the visible part.
+    #[doc(alias = "transform_box2d")]
     pub fn outer_transformed_box2d(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>>
     where
         T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd,
     {
-        Some(Box2D::from_points(&[
+        Some(Box2D::from_four_points(
             self.transform_point2d(b.min)?,
             self.transform_point2d(b.max)?,
             self.transform_point2d(point2(b.max.x, b.min.y))?,
             self.transform_point2d(point2(b.min.x, b.max.y))?,
-        ]))
+        ))
     }
 
     /// Returns a 3d box that encompasses the result of transforming the given box by this
     /// transform, if the transform makes sense for it, or `None` otherwise.
+    #[doc(alias = "transform_box3d")]
     pub fn outer_transformed_box3d(&self, b: &Box3D<T, Src>) -> Option<Box3D<T, Dst>>
     where
         T: Sub<Output = T> + Div<Output = T> + Zero + PartialOrd,
     {
-        Some(Box3D::from_points(&[
+        Some(Box3D::from_point_array(&[
             self.transform_point3d(point3(b.min.x, b.min.y, b.min.z))?,
             self.transform_point3d(point3(b.min.x, b.min.y, b.max.z))?,
             self.transform_point3d(point3(b.min.x, b.max.y, b.min.z))?,
@@ -858,6 +1131,119 @@ where
     }
 }
 
+/// Methods for transforming slices
+///
+/// With the `simd` feature, `f32` transforms load the matrix in vector registers once
+/// for the whole slice and use SSE2 or NEON instructions.
+impl<T, Src, Dst> Transform3D<T, Src, Dst>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
+{
+    /// Transforms each point of `points` into the element of `out` at the same index.
+    ///
+    /// Equivalent to calling `transform_point3d` on each element: the elements of `out`
+    /// are `None` for the points that the transform doesn't make sense for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices don't have the same length.
+    #[inline]
+    pub fn transform_points3d(
+        &self,
+        points: &[Point3D<T, Src>],
+        out: &mut [Option<Point3D<T, Dst>>],
+    ) where
+        T: Div<Output = T> + Zero + PartialOrd,
+    {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "Transform3D::transform_points3d: the slices have different lengths"
+        );
+        T::transform_points3d(self, points, out);
+    }
+
+    /// Transforms each vector of `vectors` into the element of `out` at the same index.
+    ///
+    /// Equivalent to calling `transform_vector3d` on each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices don't have the same length.
+    #[inline]
+    pub fn transform_vectors3d(&self, vectors: &[Vector3D<T, Src>], out: &mut [Vector3D<T, Dst>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "Transform3D::transform_vectors3d: the slices have different lengths"
+        );
+        T::transform_vectors3d(self, vectors, out);
+    }
+}
+
+/// Methods for transforming slices in place
+impl<T, U> Transform3D<T, U, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + SimdScalar,
+{
+    /// Transforms each point of the slice in place.
+    ///
+    /// Same as `transform_points3d`, with the points for which the transform doesn't
+    /// make sense left unchanged. Returns the number of such points.
+    #[inline]
+    pub fn transform_points3d_in_place(&self, points: &mut [Point3D<T, U>]) -> usize
+    where
+        T: Div<Output = T> + Zero + PartialOrd,
+    {
+        let mut failed = 0;
+        let mut out = [None; batch::CHUNK];
+        for chunk in points.chunks_mut(batch::CHUNK) {
+            let out = &mut out[..chunk.len()];
+            self.transform_points3d(chunk, out);
+            for (p, o) in chunk.iter_mut().zip(out.iter()) {
+                match *o {
+                    Some(transformed) => *p = transformed,
+                    None => failed += 1,
+                }
+            }
+        }
+        failed
+    }
+
+    /// Transforms each vector of the slice in place.
+    ///
+    /// Same as `transform_vectors3d`.
+    #[inline]
+    pub fn transform_vectors3d_in_place(&self, vectors: &mut [Vector3D<T, U>]) {
+        batch::in_place(vectors, |vectors, out| {
+            self.transform_vectors3d(vectors, out);
+        });
+    }
+}
+
+/// The range of depths that a projection maps the near and far planes to.
+///
+/// See [`Transform3D::orthographic`], [`Transform3D::frustum`] and
+/// [`Transform3D::perspective_fov`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// Depths between -1 and 1, as in OpenGL.
+    NegativeOneToOne,
+    /// Depths between 0 and 1, as in WebGPU, Vulkan, Metal and Direct3D.
+    ZeroToOne,
+}
+
+/// The orientation of a view space, in which the x axis points right and the y axis up.
+///
+/// See [`Transform3D::look_at`] and [`Rotation3D::look_to`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// The view looks towards negative z, as in OpenGL.
+    RightHanded,
+    /// The view looks towards positive z, as in Direct3D.
+    LeftHanded,
+}
+
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
 where
     T: Copy
@@ -889,6 +1275,110 @@ where
         )
     }
 
+    /// Create an orthographic projection transform, mapping the box between the
+    /// `left`, `right`, `bottom` and `top` planes and the `near` and `far` planes to the
+    /// clip space cube.
+    ///
+    /// The view space is right-handed, looking towards negative z, so that `near` and
+    /// `far` are distances along the negative z axis. With [`DepthRange::NegativeOneToOne`],
+    /// this is the same as [`Transform3D::ortho`].
+    #[rustfmt::skip]
+    pub fn orthographic(
+        left: T, right: T,
+        bottom: T, top: T,
+        near: T, far: T,
+        depth: DepthRange,
+    ) -> Self {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        let _2 = _1 + _1;
+        let (sz, tz) = match depth {
+            DepthRange::NegativeOneToOne => (-_2 / (far - near), -((far + near) / (far - near))),
+            DepthRange::ZeroToOne => (-_1 / (far - near), -(near / (far - near))),
+        };
+
+        Transform3D::new(
+            _2 / (right - left), _0, _0, _0,
+            _0, _2 / (top - bottom), _0, _0,
+            _0, _0, sz, _0,
+            -((right + left) / (right - left)), -((top + bottom) / (top - bottom)), tz, _1,
+        )
+    }
+
+    /// Create a perspective projection transform, mapping the frustum with its apex at
+    /// the origin, its sides going through the `left`, `right`, `bottom` and `top`
+    /// coordinates on the near plane, and cut by the `near` and `far` planes to the clip
+    /// space cube.
+    ///
+    /// The view space is right-handed, looking towards negative z, so that `near` and
+    /// `far` are positive distances along the negative z axis, as with `glFrustum`.
+    /// Points must be divided by their w coordinate after the transformation, which
+    /// [`Transform3D::transform_point3d`] does.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    /// use euclid::{point3, DepthRange};
+    ///
+    /// let t = Transform3D::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0, DepthRange::ZeroToOne);
+    /// assert_eq!(t.transform_point3d(point3(1.0, -1.0, -1.0)), Some(point3(1.0, -1.0, 0.0)));
+    /// assert_eq!(t.transform_point3d(point3(0.0, 5.0, -10.0)), Some(point3(0.0, 0.5, 1.0)));
+    /// ```
+    #[rustfmt::skip]
+    pub fn frustum(
+        left: T, right: T,
+        bottom: T, top: T,
+        near: T, far: T,
+        depth: DepthRange,
+    ) -> Self {
+        let _2 = T::one() + T::one();
+        Self::perspective_projection(
+            _2 * near / (right - left),
+            _2 * near / (top - bottom),
+            (right + left) / (right - left),
+            (top + bottom) / (top - bottom),
+            near, far, depth,
+        )
+    }
+
+    /// Create a perspective projection transform from the vertical field of view, and
+    /// the aspect ratio of the viewport, its width divided by its height.
+    ///
+    /// This is a [`Transform3D::frustum`] centered on the negative z axis. It is not
+    /// related to the CSS [`Transform3D::perspective`].
+    pub fn perspective_fov(fov_y: Angle<T>, aspect: T, near: T, far: T, depth: DepthRange) -> Self
+    where
+        T: Trig,
+    {
+        let _0 = T::zero();
+        let _1 = T::one();
+        let focal = _1 / (fov_y.radians / (_1 + _1)).tan();
+        Self::perspective_projection(focal / aspect, focal, _0, _0, near, far, depth)
+    }
+
+    #[rustfmt::skip]
+    fn perspective_projection(
+        sx: T, sy: T,
+        cx: T, cy: T,
+        near: T, far: T,
+        depth: DepthRange,
+    ) -> Self {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        let _2 = _1 + _1;
+        let (sz, tz) = match depth {
+            DepthRange::NegativeOneToOne => (
+                -((far + near) / (far - near)),
+                -(_2 * far * near / (far - near)),
+            ),
+            DepthRange::ZeroToOne => (-(far / (far - near)), -(far * near / (far - near))),
+        };
+
+        Transform3D::new(
+            sx, _0, _0, _0,
+            _0, sy, _0, _0,
+            cx, cy, sz, -_1,
+            _0, _0, tz, _0,
+        )
+    }
+
     /// Check whether shapes on the XY plane with Z pointing towards the
     /// screen transformed by this matrix would be facing back.
     #[rustfmt::skip]
@@ -910,112 +1400,25 @@ where
 
     /// Returns the inverse transform if possible.
     pub fn inverse(&self) -> Option<Transform3D<T, Dst, Src>> {
-        let det = self.determinant();
-
-        if det == Zero::zero() {
-            return None;
-        }
-
-        // todo(gw): this could be made faster by special casing
-        // for simpler transform types.
-        #[rustfmt::skip]
-        let m = Transform3D::new(
-            self.m23*self.m34*self.m42 - self.m24*self.m33*self.m42 +
-            self.m24*self.m32*self.m43 - self.m22*self.m34*self.m43 -
-            self.m23*self.m32*self.m44 + self.m22*self.m33*self.m44,
-
-            self.m14*self.m33*self.m42 - self.m13*self.m34*self.m42 -
-            self.m14*self.m32*self.m43 + self.m12*self.m34*self.m43 +
-            self.m13*self.m32*self.m44 - self.m12*self.m33*self.m44,
-
-            self.m13*self.m24*self.m42 - self.m14*self.m23*self.m42 +
-            self.m14*self.m22*self.m43 - self.m12*self.m24*self.m43 -
-            self.m13*self.m22*self.m44 + self.m12*self.m23*self.m44,
-
-            self.m14*self.m23*self.m32 - self.m13*self.m24*self.m32 -
-            self.m14*self.m22*self.m33 + self.m12*self.m24*self.m33 +
-            self.m13*self.m22*self.m34 - self.m12*self.m23*self.m34,
-
-            self.m24*self.m33*self.m41 - self.m23*self.m34*self.m41 -
-            self.m24*self.m31*self.m43 + self.m21*self.m34*self.m43 +
-            self.m23*self.m31*self.m44 - self.m21*self.m33*self.m44,
-
-            self.m13*self.m34*self.m41 - self.m14*self.m33*self.m41 +
-            self.m14*self.m31*self.m43 - self.m11*self.m34*self.m43 -
-            self.m13*self.m31*self.m44 + self.m11*self.m33*self.m44,
-
-            self.m14*self.m23*self.m41 - self.m13*self.m24*self.m41 -
-            self.m14*self.m21*self.m43 + self.m11*self.m24*self.m43 +
-            self.m13*self.m21*self.m44 - self.m11*self.m23*self.m44,
-
-            self.m13*self.m24*self.m31 - self.m14*self.m23*self.m31 +
-            self.m14*self.m21*self.m33 - self.m11*self.m24*self.m33 -
-            self.m13*self.m21*self.m34 + self.m11*self.m23*self.m34,
-
-            self.m22*self.m34*self.m41 - self.m24*self.m32*self.m41 +
-            self.m24*self.m31*self.m42 - self.m21*self.m34*self.m42 -
-            self.m22*self.m31*self.m44 + self.m21*self.m32*self.m44,
-
-            self.m14*self.m32*self.m41 - self.m12*self.m34*self.m41 -
-            self.m14*self.m31*self.m42 + self.m11*self.m34*self.m42 +
-            self.m12*self.m31*self.m44 - self.m11*self.m32*self.m44,
-
-            self.m12*self.m24*self.m41 - self.m14*self.m22*self.m41 +
-            self.m14*self.m21*self.m42 - self.m11*self.m24*self.m42 -
-            self.m12*self.m21*self.m44 + self.m11*self.m22*self.m44,
-
-            self.m14*self.m22*self.m31 - self.m12*self.m24*self.m31 -
-            self.m14*self.m21*self.m32 + self.m11*self.m24*self.m32 +
-            self.m12*self.m21*self.m34 - self.m11*self.m22*self.m34,
-
-            self.m23*self.m32*self.m41 - self.m22*self.m33*self.m41 -
-            self.m23*self.m31*self.m42 + self.m21*self.m33*self.m42 +
-            self.m22*self.m31*self.m43 - self.m21*self.m32*self.m43,
-
-            self.m12*self.m33*self.m41 - self.m13*self.m32*self.m41 +
-            self.m13*self.m31*self.m42 - self.m11*self.m33*self.m42 -
-            self.m12*self.m31*self.m43 + self.m11*self.m32*self.m43,
-
-            self.m13*self.m22*self.m41 - self.m12*self.m23*self.m41 -
-            self.m13*self.m21*self.m42 + self.m11*self.m23*self.m42 +
-            self.m12*self.m21*self.m43 - self.m11*self.m22*self.m43,
-
-            self.m12*self.m23*self.m31 - self.m13*self.m22*self.m31 +
-            self.m13*self.m21*self.m32 - self.m11*self.m23*self.m32 -
-            self.m12*self.m21*self.m33 + self.m11*self.m22*self.m33
+        validate_invariant!(
+            finite(&self.to_array()),
+            "Transform3D::inverse: non-finite component"
         );
+        let m = Transform3D::from_arrays(self.matrix().inverse()?.0);
+        debug_check_nan!("Transform3D::inverse", m.to_array());
+        Some(m)
+    }
 
-        let _1: T = One::one();
-        Some(m.mul_s(_1 / det))
+    /// Returns the inverse transform, or an error with the determinant if there is none.
+    pub fn inverse_checked(&self) -> Result<Transform3D<T, Dst, Src>, Error<T>> {
+        self.inverse().ok_or_else(|| Error::NonInvertible {
+            determinant: self.determinant(),
+        })
     }
 
     /// Compute the determinant of the transform.
-    #[rustfmt::skip]
     pub fn determinant(&self) -> T {
-        self.m14 * self.m23 * self.m32 * self.m41 -
-        self.m13 * self.m24 * self.m32 * self.m41 -
-        self.m14 * self.m22 * self.m33 * self.m41 +
-        self.m12 * self.m24 * self.m33 * self.m41 +
-        self.m13 * self.m22 * self.m34 * self.m41 -
-        self.m12 * self.m23 * self.m34 * self.m41 -
-        self.m14 * self.m23 * self.m31 * self.m42 +
-        self.m13 * self.m24 * self.m31 * self.m42 +
-        self.m14 * self.m21 * self.m33 * self.m42 -
-        self.m11 * self.m24 * self.m33 * self.m42 -
-        self.m13 * self.m21 * self.m34 * self.m42 +
-        self.m11 * self.m23 * self.m34 * self.m42 +
-        self.m14 * self.m22 * self.m31 * self.m43 -
-        self.m12 * self.m24 * self.m31 * self.m43 -
-        self.m14 * self.m21 * self.m32 * self.m43 +
-        self.m11 * self.m24 * self.m32 * self.m43 +
-        self.m12 * self.m21 * self.m34 * self.m43 -
-        self.m11 * self.m22 * self.m34 * self.m43 -
-        self.m13 * self.m22 * self.m31 * self.m44 +
-        self.m12 * self.m23 * self.m31 * self.m44 +
-        self.m13 * self.m21 * self.m32 * self.m44 -
-        self.m11 * self.m23 * self.m32 * self.m44 -
-        self.m12 * self.m21 * self.m33 * self.m44 +
-        self.m11 * self.m22 * self.m33 * self.m44
+        self.matrix().determinant()
     }
 
     /// Multiplies all of the transform's component by a scalar and returns the result.
@@ -1036,6 +1439,109 @@ where
     }
 }
 
+impl<T: RealScalar, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Create a view transform for a camera at `eye` looking at `target`, with `up`
+    /// pointing towards the top of the view.
+    ///
+    /// The camera is moved to the origin and looks towards negative z with
+    /// [`Handedness::RightHanded`], or positive z with [`Handedness::LeftHanded`].
+    /// `up` does not need to be perpendicular to the view direction, but it must not be
+    /// parallel to it, and `eye` must differ from `target`.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    /// use euclid::{point3, vec3, Handedness};
+    ///
+    /// let (eye, target, up) = (point3(0.0, 0.0, 5.0), point3(0.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+    /// let view = Transform3D::look_at(eye, target, up, Handedness::RightHanded);
+    /// assert_eq!(view.transform_point3d(point3(0.0, 1.0, 0.0)), Some(point3(0.0, 1.0, -5.0)));
+    /// ```
+    pub fn look_at(
+        eye: Point3D<T, Src>,
+        target: Point3D<T, Src>,
+        up: Vector3D<T, Src>,
+        handedness: Handedness,
+    ) -> Self {
+        Self::look_to(eye, target - eye, up, handedness)
+    }
+
+    /// Create a view transform for a camera at `eye` looking in `direction`, with `up`
+    /// pointing towards the top of the view.
+    ///
+    /// This is the same as [`look_at`](Self::look_at) with `eye + direction` as target.
+    pub fn look_to(
+        eye: Point3D<T, Src>,
+        direction: Vector3D<T, Src>,
+        up: Vector3D<T, Src>,
+        handedness: Handedness,
+    ) -> Self {
+        let rotation: Rotation3D<T, Src, Dst> = Rotation3D::look_to(direction, up, handedness);
+        Transform3D::translation(-eye.x, -eye.y, -eye.z).then(&rotation.to_transform())
+    }
+}
+
+/// Inversion of ill-conditioned transforms
+impl<T: RealScalar, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Returns an estimate of the condition number of this transform, or infinity if it
+    /// is not invertible.
+    ///
+    /// This is the product of the maximum absolute row sums of the matrix and its inverse.
+    /// The inverse loses about `log10(condition)` significant digits to rounding, so it
+    /// becomes unreliable as the condition number approaches `1 / T::epsilon()`.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    ///
+    /// assert_eq!(Transform3D::<f32>::identity().condition_number(), 1.0);
+    /// assert_eq!(Transform3D::scale(1.0, 1e-3, 1.0).condition_number(), 1e3);
+    /// assert_eq!(Transform3D::scale(1.0, 0.0, 1.0).condition_number(), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self) -> T {
+        match self.inverse() {
+            Some(inverse) => self.condition_number_with(&inverse),
+            None => T::infinity(),
+        }
+    }
+
+    /// Returns the condition number of this transform given its `inverse`.
+    fn condition_number_with(&self, inverse: &Transform3D<T, Dst, Src>) -> T {
+        let norm = |m: [[T; 4]; 4]| {
+            m.iter().fold(T::zero(), |max, row| {
+                max.max(row.iter().fold(T::zero(), |sum, v| sum + v.abs()))
+            })
+        };
+        norm(self.to_arrays()) * norm(inverse.to_arrays())
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of this transform.
+    ///
+    /// This is the inverse for invertible transforms. Otherwise, it maps points back as
+    /// well as possible: for example, the pseudo-inverse of a transform that flattens
+    /// everything onto a plane maps the plane back and ignores the flattened dimension.
+    /// Directions that are squashed by more than about `T::epsilon()` relative to the
+    /// others are treated as flattened.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    /// use euclid::point3;
+    ///
+    /// let flatten = Transform3D::scale(2.0, 2.0, 0.0);
+    /// assert!(flatten.inverse().is_none());
+    /// let p = flatten.pseudo_inverse().transform_point3d(point3(4.0, 6.0, 0.0));
+    /// assert_eq!(p, Some(point3(2.0, 3.0, 0.0)));
+    /// ```
+    pub fn pseudo_inverse(&self) -> Transform3D<T, Dst, Src> {
+        if let Some(inverse) = self.inverse() {
+            let n = T::from(4).unwrap();
+            if self.condition_number_with(&inverse) * n * T::epsilon() < T::one() {
+                return inverse;
+            }
+        }
+        let m = Matrix(self.to_arrays());
+        Transform3D::from_arrays(pseudo_inverse(&m).0)
+    }
+}
+
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
 where
     T: Copy + Mul<Output = T> + Div<Output = T> + Zero + One + PartialEq,
@@ -1044,7 +1550,7 @@ where
     pub fn project_to_2d(&self) -> Self {
         let (_0, _1): (T, T) = (Zero::zero(), One::one());
 
-        let mut result = self.clone();
+        let mut result = *self;
 
         result.m31 = _0;
         result.m32 = _0;
@@ -1148,6 +1654,141 @@ impl<T: ApproxEq<T>, Src, Dst> ApproxEq<T> for Transform3D<T, Src, Dst> {
     }
 }
 
+/// Decomposition into components that can be interpolated
+impl<T: RealScalar, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Breaks this transform down into a scale, a skew, a rotation, a translation and a
+    /// perspective, following the CSS Transforms Module Level 2.
+    ///
+    /// Returns `None` if the transform is not invertible, or if `m44` is zero.
+    ///
+    /// ```
+    /// use euclid::default::Transform3D;
+    /// use euclid::approxeq::ApproxEq;
+    /// use euclid::{vec3, Angle};
+    ///
+    /// let t = Transform3D::scale(2.0, 3.0, 4.0)
+    ///     .then_rotate(0.0, 0.0, 1.0, Angle::degrees(30.0))
+    ///     .then_translate(vec3(10.0, 20.0, 30.0));
+    /// let d = t.decompose().unwrap();
+    /// assert!(d.scale.approx_eq(&vec3(2.0, 3.0, 4.0)));
+    /// assert!(d.translation.approx_eq(&vec3(10.0, 20.0, 30.0)));
+    /// assert!(Transform3D::recompose(&d).approx_eq(&t));
+    /// ```
+    #[rustfmt::skip]
+    pub fn decompose(&self) -> Option<Decomposition3D<T, Src, Dst>> {
+        let _0 = T::zero();
+        let _1 = T::one();
+        if self.m44 == _0 {
+            return None;
+        }
+        let m = self.to_untyped().to_array().map(|v| v / self.m44);
+        let m = Transform3D::<T, UnknownUnit, UnknownUnit>::from_array(m);
+
+        // The transform without its perspective, which must be invertible.
+        let mut affine = m;
+        affine.m14 = _0;
+        affine.m24 = _0;
+        affine.m34 = _0;
+        affine.m44 = _1;
+        let inverse = affine.inverse()?;
+
+        // The perspective is the vector that the affine part maps to the last column.
+        let perspective = if m.m14 != _0 || m.m24 != _0 || m.m34 != _0 {
+            let c = [m.m14, m.m24, m.m34, m.m44];
+            let row = |r: [T; 4]| r[0] * c[0] + r[1] * c[1] + r[2] * c[2] + r[3] * c[3];
+            let inv = inverse.to_arrays();
+            HomogeneousVector::new(row(inv[0]), row(inv[1]), row(inv[2]), row(inv[3]))
+        } else {
+            HomogeneousVector::new(_0, _0, _0, _1)
+        };
+
+        // Gram-Schmidt on the rows of the 3x3 part gives the scale and the skew.
+        let mut row = [
+            vec3::<T, UnknownUnit>(m.m11, m.m12, m.m13),
+            vec3(m.m21, m.m22, m.m23),
+            vec3(m.m31, m.m32, m.m33),
+        ];
+        let mut scale = vec3(_0, _0, _0);
+        let mut skew = vec3(_0, _0, _0);
+
+        scale.x = row[0].length();
+        row[0] /= scale.x;
+
+        skew.x = row[0].dot(row[1]);
+        row[1] -= row[0] * skew.x;
+        scale.y = row[1].length();
+        row[1] /= scale.y;
+        skew.x = skew.x / scale.y;
+
+        skew.y = row[0].dot(row[2]);
+        row[2] -= row[0] * skew.y;
+        skew.z = row[1].dot(row[2]);
+        row[2] -= row[1] * skew.z;
+        scale.z = row[2].length();
+        row[2] /= scale.z;
+        skew.y = skew.y / scale.z;
+        skew.z = skew.z / scale.z;
+
+        // Flip the coordinate system if needed, so that the rows form a rotation.
+        if row[0].dot(row[1].cross(row[2])) < _0 {
+            scale = -scale;
+            row = row.map(|r| -r);
+        }
+
+        let rotation = Transform3D::new(
+            row[0].x, row[0].y, row[0].z, _0,
+            row[1].x, row[1].y, row[1].z, _0,
+            row[2].x, row[2].y, row[2].z, _0,
+            _0, _0, _0, _1,
+        );
+
+        Some(Decomposition3D::new(
+            vec3(m.m41, m.m42, m.m43),
+            Rotation3D::from_rotation_matrix(&rotation).normalize(),
+            skew,
+            scale,
+            perspective,
+        ))
+    }
+
+    /// Builds a transform from its decomposition, see [`decompose`](Self::decompose).
+    #[rustfmt::skip]
+    pub fn recompose(d: &Decomposition3D<T, Src, Dst>) -> Self {
+        let _0 = T::zero();
+        let _1 = T::one();
+        let (p, k) = (d.perspective, d.skew);
+
+        let skew = Transform3D::new(
+            _1, _0, _0, _0,
+            k.x, _1, _0, _0,
+            k.y, k.z, _1, _0,
+            _0, _0, _0, _1,
+        );
+        let perspective = Transform3D::new(
+            _1, _0, _0, p.x,
+            _0, _1, _0, p.y,
+            _0, _0, _1, p.z,
+            _0, _0, _0, p.w,
+        );
+        let m = Transform3D::<T, UnknownUnit, UnknownUnit>::scale(d.scale.x, d.scale.y, d.scale.z)
+            .then(&skew)
+            .then(&d.rotation.to_transform())
+            .then_translate(d.translation.to_untyped())
+            .then(&perspective);
+        Transform3D::from_untyped(&m)
+    }
+
+    /// Interpolates between two transforms by interpolating their decompositions, see
+    /// [`Decomposition3D::lerp`].
+    ///
+    /// Returns `None` if either transform can't be decomposed.
+    pub fn interpolate(&self, other: &Self, t: T) -> Option<Self> {
+        let a = self.decompose()?;
+        let b = other.decompose()?;
+        Some(Self::recompose(&a.lerp(&b, t)))
+    }
+}
+
 impl<T, Src, Dst> Default for Transform3D<T, Src, Dst>
 where
     T: Zero + One,
@@ -1158,6 +1799,12 @@ where
     }
 }
 
+impl<T: Copy, Src, Dst> AsRef<[T; 16]> for Transform3D<T, Src, Dst> {
+    fn as_ref(&self) -> &[T; 16] {
+        self.as_array()
+    }
+}
+
 impl<T, Src, Dst> fmt::Debug for Transform3D<T, Src, Dst>
 where
     T: Copy + fmt::Debug + PartialEq + One + Zero,
@@ -1197,14 +1844,90 @@ impl<T, Src, Dst> From<Transform3D<T, Src, Dst>> for mint::RowMatrix4<T> {
     }
 }
 
+/// `Transform3D` uses the row vector convention while nalgebra uses column vectors, so the
+/// conversion transposes the matrix. The elements end up in the same order in memory.
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar + Copy, Src, Dst> From<nalgebra::Matrix4<T>> for Transform3D<T, Src, Dst> {
+    fn from(m: nalgebra::Matrix4<T>) -> Self {
+        Transform3D::from_arrays(m.into())
+    }
+}
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar + Copy, Src, Dst> From<Transform3D<T, Src, Dst>> for nalgebra::Matrix4<T> {
+    fn from(t: Transform3D<T, Src, Dst>) -> Self {
+        nalgebra::Matrix4::from(t.to_arrays())
+    }
+}
+
+/// `Transform3D` uses the row vector convention while glam uses column vectors, so the
+/// conversion transposes the matrix. The elements end up in the same order in memory.
+#[cfg(feature = "glam")]
+impl<Src, Dst> From<glam::Mat4> for Transform3D<f32, Src, Dst> {
+    fn from(m: glam::Mat4) -> Self {
+        Transform3D::from_array(m.to_cols_array())
+    }
+}
+#[cfg(feature = "glam")]
+impl<Src, Dst> From<Transform3D<f32, Src, Dst>> for glam::Mat4 {
+    fn from(t: Transform3D<f32, Src, Dst>) -> Self {
+        glam::Mat4::from_cols_array(&t.to_array())
+    }
+}
+
+/// Same as `Transform3D::from_array`.
+impl<T: Copy, Src, Dst> From<[T; 16]> for Transform3D<T, Src, Dst> {
+    fn from(array: [T; 16]) -> Self {
+        Transform3D::from_array(array)
+    }
+}
+
+/// Same as `Transform3D::to_array`.
+impl<T: Copy, Src, Dst> From<Transform3D<T, Src, Dst>> for [T; 16] {
+    fn from(t: Transform3D<T, Src, Dst>) -> Self {
+        t.to_array()
+    }
+}
+
+/// Same as `Transform3D::from_arrays`.
+impl<T: Copy, Src, Dst> From<[[T; 4]; 4]> for Transform3D<T, Src, Dst> {
+    fn from(array: [[T; 4]; 4]) -> Self {
+        Transform3D::from_arrays(array)
+    }
+}
+
+/// Same as `Transform3D::to_arrays`.
+impl<T: Copy, Src, Dst> From<Transform3D<T, Src, Dst>> for [[T; 4]; 4] {
+    fn from(t: Transform3D<T, Src, Dst>) -> Self {
+        t.to_arrays()
+    }
+}
+
+/// Indexes the terms by zero-based `(row, column)`, so that `t[(0, 1)]` is `t.m12`.
+///
+/// Panics if the row is greater than 3 or the column is greater than 3.
+impl<T, Src, Dst> Index<(usize, usize)> for Transform3D<T, Src, Dst> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.matrix().term("Transform3D", row, column)
+    }
+}
+
+impl<T, Src, Dst> IndexMut<(usize, usize)> for Transform3D<T, Src, Dst> {
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        self.matrix_mut().term_mut("Transform3D", row, column)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::approxeq::ApproxEq;
     use crate::default;
-    use crate::{point2, point3};
+    use crate::{point2, point3, To2D};
 
-    use core::f32::consts::{FRAC_PI_2, PI};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
     type Mf32 = default::Transform3D<f32>;
 
@@ -1213,6 +1936,74 @@ mod tests {
         Angle::radians(v)
     }
 
+    #[test]
+    pub fn test_tuple() {
+        let m = Mf32::rotation(0.0, 1.0, 0.0, rad(1.0)).then_translate(vec3(1.0, 2.0, 3.0));
+        let (_, _, _, (tx, ty, tz, w)) = m.to_tuple();
+        assert_eq!((tx, ty, tz, w), (1.0, 2.0, 3.0, 1.0));
+        assert_eq!(Mf32::from_tuple(m.to_tuple()), m);
+    }
+
+    #[test]
+    pub fn test_outer_transformed_box() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 1.0, 1.0));
+        let m = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 1.0, 1.0));
+        let result = m.outer_transformed_box3d(&b).unwrap();
+        assert!(result.min.approx_eq(&point3(0.0, 1.0, 1.0)));
+        assert!(result.max.approx_eq(&point3(1.0, 3.0, 2.0)));
+        assert_eq!(m.outer_transformed_box2d(&b.to_2d()), Some(result.to_2d()));
+
+        // Half of the plane ends up behind the viewer.
+        let m = Mf32::rotation(0.0, 1.0, 0.0, rad(FRAC_PI_2)).then(&Mf32::perspective(1.0));
+        let small = Box2D::new(point2(0.0, 0.0), point2(0.1, 0.1));
+        let large = Box2D::new(point2(-5.0, -5.0), point2(5.0, 5.0));
+        assert!(m.outer_transformed_box2d(&small).is_some());
+        assert_eq!(m.outer_transformed_box2d(&large), None);
+        assert_eq!(m.outer_transformed_rect(&large.to_rect()), None);
+    }
+
+    #[test]
+    pub fn test_then_by() {
+        struct A;
+        struct B;
+        let s: Scale<f32, A, B> = Scale::new(2.0);
+        let t: Translation3D<f32, B, B> = Translation3D::new(1.0, 2.0, 3.0);
+        let r: Rotation3D<f32, B, A> = Rotation3D::around_z(Angle::radians(FRAC_PI_2));
+
+        let m = Transform3D::<f32, A, A>::identity()
+            .then_scale_by(s)
+            .then_translate_by(t)
+            .then_rotate_by(r);
+        let expected = Mf32::scale(2.0, 2.0, 2.0)
+            .then_translate(vec3(1.0, 2.0, 3.0))
+            .then_rotate(0.0, 0.0, 1.0, Angle::radians(FRAC_PI_2));
+        assert!(m.to_untyped().approx_eq(&expected));
+    }
+
+    #[test]
+    pub fn test_consts() {
+        const TABLE: [Mf32; 2] = [
+            Mf32::IDENTITY,
+            Mf32::new(
+                0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+            ),
+        ];
+        const ORIGIN: default::Point3D<f32> = default::Point3D::<f32>::ORIGIN;
+        const RIGHT: default::Vector3D<f32> = vec3(1.0, 0.0, 0.0);
+        assert_eq!(TABLE[0], Mf32::identity());
+        assert_eq!(TABLE[0].transform_point3d(ORIGIN), Some(ORIGIN));
+        assert!(TABLE[1].approx_eq(&Mf32::rotation(0.0, 0.0, 1.0, Angle::<f32>::FRAC_PI_2)));
+        assert_eq!(TABLE[1].transform_vector3d(RIGHT), vec3(0.0, 1.0, 0.0));
+        assert_eq!(
+            default::Transform3D::<i32>::IDENTITY,
+            default::Transform3D::identity()
+        );
+        assert_eq!(
+            default::Transform2D::<f64>::IDENTITY,
+            default::Transform2D::identity()
+        );
+    }
+
     #[test]
     pub fn test_translation() {
         let t1 = Mf32::translation(1.0, 2.0, 3.0);
@@ -1312,11 +2103,71 @@ mod tests {
         assert!(result.approx_eq(&expected));
     }
 
+    #[test]
+    pub fn test_projections() {
+        use crate::DepthRange::{NegativeOneToOne, ZeroToOne};
+
+        let (left, right, bottom, top) = (-2.0f32, 4.0f32, -1.0f32, 3.0f32);
+        let (near, far) = (0.5f32, 20.0f32);
+        assert_eq!(
+            Mf32::orthographic(left, right, bottom, top, near, far, NegativeOneToOne),
+            Mf32::ortho(left, right, bottom, top, near, far)
+        );
+
+        // The corners of the near and far planes of each volume map to the clip space cube.
+        for (depth, min_z) in [(NegativeOneToOne, -1.0), (ZeroToOne, 0.0)] {
+            let ortho = Mf32::orthographic(left, right, bottom, top, near, far, depth);
+            let frustum = Mf32::frustum(left, right, bottom, top, near, far, depth);
+            let ratio = far / near;
+            let corners = [
+                (
+                    ortho,
+                    point3(left, bottom, -near),
+                    point3(-1.0, -1.0, min_z),
+                ),
+                (ortho, point3(right, top, -far), point3(1.0, 1.0, 1.0)),
+                (
+                    frustum,
+                    point3(left, bottom, -near),
+                    point3(-1.0, -1.0, min_z),
+                ),
+                (frustum, point3(right, top, -near), point3(1.0, 1.0, min_z)),
+                (
+                    frustum,
+                    point3(left * ratio, top * ratio, -far),
+                    point3(-1.0, 1.0, 1.0),
+                ),
+            ];
+            for (t, p, expected) in corners {
+                assert!(t.transform_point3d(p).unwrap().approx_eq(&expected));
+            }
+
+            let fov = Mf32::perspective_fov(Angle::degrees(90.0), 2.0, near, far, depth);
+            let symmetric = Mf32::frustum(-2.0 * near, 2.0 * near, -near, near, near, far, depth);
+            assert!(fov.approx_eq(&symmetric));
+            // Points behind the viewer can't be projected.
+            assert_eq!(fov.transform_point3d(point3(0.0, 0.0, 1.0)), None);
+        }
+    }
+
     #[test]
     pub fn test_is_2d() {
         assert!(Mf32::identity().is_2d());
         assert!(Mf32::rotation(0.0, 0.0, 1.0, rad(0.7854)).is_2d());
         assert!(!Mf32::rotation(0.0, 1.0, 0.0, rad(0.7854)).is_2d());
+
+        let r = Mf32::rotation(0.0, 1.0, 0.0, rad(FRAC_PI_4));
+        let mut t = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_4));
+        t.m13 = 1e-7;
+        t.m33 = 1.0 - 1e-7;
+        assert!(!t.is_2d());
+        assert!(!r.is_2d_eps(&0.1));
+        assert!(t.is_2d_eps(&1e-5));
+        let t2 = t.try_to_2d().unwrap();
+        assert!(t2.approx_eq(&Transform2D::rotation(rad(FRAC_PI_4))));
+        assert_eq!(r.try_to_2d(), None);
+        assert_eq!(Mf32::perspective(100.0).try_to_2d_eps(&0.001), None);
+        assert!(Mf32::perspective(100.0).try_to_2d_eps(&0.1).is_some());
     }
 
     #[test]
@@ -1519,6 +2370,37 @@ mod tests {
         assert_eq!(None, m.transform_point2d(p));
     }
 
+    #[test]
+    pub fn test_as_array() {
+        let transforms = [Mf32::translation(1.0, 2.0, 3.0), Mf32::scale(4.0, 5.0, 6.0)];
+        assert_eq!(transforms[0].as_array(), &transforms[0].to_array());
+        assert_eq!(transforms[0].as_array()[12..15], [1.0, 2.0, 3.0]);
+
+        let arrays = Mf32::slice_as_arrays(&transforms);
+        assert_eq!(arrays.len(), 2);
+        assert_eq!(arrays[1], transforms[1].to_array());
+    }
+
+    #[test]
+    pub fn test_index() {
+        let mut m = Mf32::translation(1.0, 2.0, 3.0);
+        assert_eq!(m[(3, 1)], 2.0);
+        assert_eq!(m.row(3), [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(m.column(0), [1.0, 0.0, 0.0, 1.0]);
+        for (i, row) in m.to_arrays().iter().enumerate() {
+            assert_eq!(&m.row(i), row);
+        }
+        m[(0, 3)] = 5.0;
+        assert_eq!(m.m14, 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_index_out_of_bounds() {
+        let m = Mf32::identity();
+        let _ = m[(4, 0)];
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -1528,4 +2410,290 @@ mod tests {
 
         assert_eq!(m1, m2);
     }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    pub fn test_nalgebra() {
+        let m1 = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 2.0, 3.0));
+        let nm: nalgebra::Matrix4<_> = m1.into();
+        let p = nm.transform_point(&nalgebra::Point3::new(4.0, 5.0, 6.0));
+        assert!(m1
+            .transform_point3d(point3(4.0, 5.0, 6.0))
+            .unwrap()
+            .approx_eq(&point3(p.x, p.y, p.z)));
+        assert_eq!(Mf32::from(nm), m1);
+    }
+
+    #[test]
+    pub fn test_decompose() {
+        type M = default::Transform3D<f64>;
+        let rad = Angle::radians;
+
+        #[rustfmt::skip]
+        let skew = M::new(
+            1.0, 0.0, 0.0, 0.0,
+            0.5, 1.0, 0.0, 0.0,
+            0.25, -0.5, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        );
+        let axis = vec3(1.0, 2.0, -1.0).normalize();
+        let t = M::scale(2.0, 3.0, 4.0)
+            .then(&skew)
+            .then_rotate(axis.x, axis.y, axis.z, rad(2.0))
+            .then_translate(vec3(10.0, -20.0, 0.0))
+            .then(&M::perspective(100.0));
+        let d = t.decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec3(2.0, 3.0, 4.0)));
+        assert!(d.skew.approx_eq(&vec3(0.5, 0.25, -0.5)));
+        assert!(d.translation.approx_eq(&vec3(10.0, -20.0, 0.0)));
+        let p = d.perspective;
+        assert!(p.to_point3d().unwrap().approx_eq(&point3(0.0, 0.0, -0.01)));
+        assert!(d
+            .rotation
+            .approx_eq(&Rotation3D::around_axis(axis, rad(2.0))));
+        assert!(M::recompose(&d).approx_eq(&t));
+
+        // The matrix is normalized by m44, and reflections are represented by negative
+        // scales.
+        let t = M::scale(-1.0, 2.0, 3.0).then_rotate(0.0, 1.0, 0.0, rad(0.5));
+        let d = t.decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec3(-1.0, -2.0, -3.0)));
+        assert!(M::recompose(&d).approx_eq(&t));
+        let mut halved = t;
+        halved.m44 = 0.5;
+        let d = halved.decompose().unwrap();
+        assert!(d.scale.approx_eq(&vec3(-2.0, -4.0, -6.0)));
+        assert!(M::recompose(&d).approx_eq(&t.then_scale(2.0, 2.0, 2.0)));
+
+        assert_eq!(M::scale(1.0, 0.0, 1.0).decompose(), None);
+        let mut projective = M::identity();
+        projective.m44 = 0.0;
+        assert_eq!(projective.decompose(), None);
+        assert_eq!(
+            M::identity().decompose().unwrap(),
+            crate::Decomposition3D::identity()
+        );
+    }
+
+    #[test]
+    pub fn test_interpolate() {
+        type M = default::Transform3D<f64>;
+        let rad = Angle::radians;
+
+        let a = M::identity();
+        let b = M::rotation(0.0, 0.0, 1.0, rad(core::f64::consts::FRAC_PI_2))
+            .then_translate(vec3(10.0, 0.0, 0.0));
+        let mid = a.interpolate(&b, 0.5).unwrap();
+        let expected = M::rotation(0.0, 0.0, 1.0, rad(core::f64::consts::FRAC_PI_4))
+            .then_translate(vec3(5.0, 0.0, 0.0));
+        assert!(mid.approx_eq(&expected));
+        assert!(a.interpolate(&b, 0.0).unwrap().approx_eq(&a));
+        assert!(a.interpolate(&b, 1.0).unwrap().approx_eq(&b));
+
+        let p = M::perspective(100.0);
+        let mid = a.interpolate(&p, 0.5).unwrap();
+        assert!(mid.approx_eq(&M::perspective(200.0)));
+
+        assert_eq!(a.interpolate(&M::scale(0.0, 1.0, 1.0), 0.5), None);
+    }
+
+    #[test]
+    pub fn test_pseudo_inverse() {
+        type M = default::Transform3D<f64>;
+
+        let m = M::rotation(1.0, 0.0, 0.0, Angle::radians(0.5))
+            .then_scale(2.0, 3.0, 4.0)
+            .then_translate(vec3(1.0, 2.0, 3.0));
+        assert!(m.pseudo_inverse().approx_eq(&m.inverse().unwrap()));
+        assert!(m.condition_number() > 1.0 && m.condition_number().is_finite());
+
+        // Flattening onto a tilted plane.
+        let r = M::rotation(0.0, 1.0, 0.0, Angle::radians(0.7));
+        let m = r.then_scale(1.0, 1.0, 0.0).then(&r);
+        assert_eq!(m.inverse(), None);
+        assert_eq!(m.condition_number(), f64::INFINITY);
+        let p = m.pseudo_inverse();
+        assert!(m.then(&p).then(&m).approx_eq(&m));
+        assert!(p.then(&m).then(&p).approx_eq(&p));
+
+        // Nearly singular transforms are treated as singular.
+        let m = M::scale(1.0, 1e-17, 1.0);
+        assert!(m.condition_number() > 1e16);
+        assert!(m.pseudo_inverse().approx_eq(&M::scale(1.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    pub fn test_pseudo_inverse_f32() {
+        type M = default::Transform3D<f32>;
+
+        // Well-conditioned anisotropic transforms are inverted exactly.
+        let m = M::scale(1000.0, 1000.0, 1.0).then_translate(vec3(5.0, 6.0, 7.0));
+        let p = m.transform_point3d(point3(1.0, 1.0, 1.0)).unwrap();
+        let back = m.pseudo_inverse().transform_point3d(p).unwrap();
+        assert!(back.approx_eq(&point3(1.0, 1.0, 1.0)));
+
+        // The singular part is dropped, the others are kept.
+        let m = M::scale(1000.0, 0.0, 0.5).then_translate(vec3(5.0, 6.0, 7.0));
+        let p = m.pseudo_inverse();
+        assert!(m.then(&p).then(&m).approx_eq_eps(&m, &1e-3));
+        assert!(p.then(&m).then(&p).approx_eq_eps(&p, &1e-3));
+    }
+
+    #[test]
+    pub fn test_look_at() {
+        use crate::Handedness::{LeftHanded, RightHanded};
+
+        let eye = point3(1.0, 2.0, 3.0);
+        let target = point3(4.0, 2.0, 3.0);
+        let up = vec3(0.0, 1.0, 0.0);
+
+        let rh = Mf32::look_at(eye, target, up, RightHanded);
+        let lh = Mf32::look_at(eye, target, up, LeftHanded);
+        assert!(rh
+            .transform_point3d(eye)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 0.0)));
+        assert!(rh
+            .transform_point3d(target)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, -3.0)));
+        assert!(lh
+            .transform_point3d(target)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 3.0)));
+
+        // The top of the view is towards +y, and the right towards +x. The world uses the
+        // same handedness as the view, so +z is to the right when looking towards +x with
+        // a right-handed view, and to the left with a left-handed one.
+        let above = eye + vec3(0.0, 1.0, 0.0);
+        let pos_z = eye + vec3(0.0, 0.0, 1.0);
+        let neg_z = eye + vec3(0.0, 0.0, -1.0);
+        assert!(rh
+            .transform_point3d(above)
+            .unwrap()
+            .approx_eq(&point3(0.0, 1.0, 0.0)));
+        assert!(rh
+            .transform_point3d(pos_z)
+            .unwrap()
+            .approx_eq(&point3(1.0, 0.0, 0.0)));
+        assert!(lh
+            .transform_point3d(above)
+            .unwrap()
+            .approx_eq(&point3(0.0, 1.0, 0.0)));
+        assert!(lh
+            .transform_point3d(neg_z)
+            .unwrap()
+            .approx_eq(&point3(1.0, 0.0, 0.0)));
+
+        assert!(Mf32::look_to(eye, target - eye, up, RightHanded).approx_eq(&rh));
+        // Only the part of up perpendicular to the view direction matters.
+        let tilted = vec3(0.5, 2.0, 0.0);
+        assert!(Mf32::look_at(eye, target, tilted, RightHanded).approx_eq(&rh));
+        assert!(Mf32::look_at(
+            point3(0.0, 0.0, 5.0),
+            point3(0.0, 0.0, 0.0),
+            up,
+            RightHanded
+        )
+        .approx_eq(&Mf32::translation(0.0, 0.0, -5.0)));
+    }
+
+    #[test]
+    pub fn test_transform_slices() {
+        let m = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 2.0, 3.0));
+        let mut points = [point3(4.0, 5.0, 6.0), point3(-1.0, 0.5, 2.0)];
+        let expected = points.map(|p| m.transform_point3d(p).unwrap());
+        let mut out = [None; 2];
+        m.transform_points3d(&points, &mut out);
+        assert_eq!(out, expected.map(Some));
+        assert_eq!(m.transform_points3d_in_place(&mut points), 0);
+        assert_eq!(points, expected);
+
+        let mut vectors = [vec3(4.0, 5.0, 6.0), vec3(-1.0, 0.5, 2.0)];
+        let expected = vectors.map(|v| m.transform_vector3d(v));
+        let mut out = [vec3(0.0, 0.0, 0.0); 2];
+        m.transform_vectors3d(&vectors, &mut out);
+        assert_eq!(out, expected);
+        m.transform_vectors3d_in_place(&mut vectors);
+        assert_eq!(vectors, expected);
+
+        // More points than a chunk of the in-place transform.
+        let mut points = [point3(1.0, 2.0, 3.0); 40];
+        for (n, p) in points.iter_mut().enumerate() {
+            p.x = n as f32;
+        }
+        let expected = points.map(|p| m.transform_point3d(p).unwrap());
+        assert_eq!(m.transform_points3d_in_place(&mut points), 0);
+        assert_eq!(points, expected);
+
+        // Points behind the camera fail individually, and are left unchanged in place.
+        let m = Mf32::perspective(1.0);
+        let mut points = [
+            point3(1.0, 2.0, -0.5),
+            point3(1.0, 2.0, 2.0),
+            point3(0.0, 0.0, 3.0),
+        ];
+        let mut out = [None; 3];
+        m.transform_points3d(&points, &mut out);
+        assert!(out[0].is_some() && out[1].is_none() && out[2].is_none());
+        assert_eq!(m.transform_points3d_in_place(&mut points), 2);
+        assert_eq!(
+            points[0],
+            m.transform_point3d(point3(1.0, 2.0, -0.5)).unwrap()
+        );
+        assert_eq!(points[1], point3(1.0, 2.0, 2.0));
+        assert_eq!(points[2], point3(0.0, 0.0, 3.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the slices have different lengths")]
+    pub fn test_transform_slices_length() {
+        let m = Mf32::identity();
+        m.transform_points3d(&[point3(1.0, 2.0, 3.0)], &mut []);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    pub fn test_glam() {
+        let m1 = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 2.0, 3.0));
+        let gm: glam::Mat4 = m1.into();
+        let p = gm.transform_point3(glam::Vec3::new(4.0, 5.0, 6.0));
+        assert!(m1
+            .transform_point3d(point3(4.0, 5.0, 6.0))
+            .unwrap()
+            .approx_eq(&point3(p.x, p.y, p.z)));
+        assert_eq!(Mf32::from(gm), m1);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    pub fn test_bytemuck() {
+        let transforms = [Mf32::identity(), Mf32::translation(1.0, 2.0, 3.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&transforms);
+
+        assert_eq!(floats.len(), 32);
+        assert_eq!(&floats[..16], &transforms[0].to_array()[..]);
+        assert_eq!(&floats[16..], &transforms[1].to_array()[..]);
+        assert_eq!(bytemuck::cast_slice::<f32, Mf32>(floats), &transforms[..]);
+    }
+
+    #[test]
+    fn test_mul_in_place() {
+        let a: default::Transform3D<f64> =
+            Transform3D::rotation(0.5, 1.0, 2.0, Angle::radians(0.7))
+                .then_translate(vec3(1.0, -2.0, 3.0));
+        let b = Transform3D::perspective(5.0).then_scale(2.0, 3.0, 0.5);
+
+        let mut out = Transform3D::identity();
+        a.mul_into(&b, &mut out);
+        assert_eq!(out, a.then(&b));
+
+        let mut m = a;
+        m.post_mul_assign(&b);
+        assert_eq!(m, a.then(&b));
+
+        let mut m = a;
+        m.pre_mul_assign(&b);
+        assert_eq!(m, b.then(&a));
+    }
 }
@@ -45,6 +45,10 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -108,6 +112,22 @@ where
     }
 }
 
+macro_rules! translation_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<Src, Dst> Translation2D<$ty, Src, Dst> {
+            /// The no-op translation, see [`Translation2D::identity`].
+            pub const IDENTITY: Self = Translation2D::new($zero, $zero);
+        }
+
+        impl<Src, Dst> Translation3D<$ty, Src, Dst> {
+            /// The no-op translation, see [`Translation3D::identity`].
+            pub const IDENTITY: Self = Translation3D::new($zero, $zero, $zero);
+        }
+    };
+}
+
+primitive_consts!(translation_consts);
+
 impl<T, Src, Dst> Translation2D<T, Src, Dst> {
     #[inline]
     pub const fn new(x: T, y: T) -> Self {
@@ -118,6 +138,12 @@ impl<T, Src, Dst> Translation2D<T, Src, Dst> {
         }
     }
 
+    /// Constructor taking a tuple with x and y.
+    #[inline]
+    pub fn from_tuple((x, y): (T, T)) -> Self {
+        Translation2D::new(x, y)
+    }
+
     #[inline]
     pub fn splat(v: T) -> Self
     where
@@ -154,8 +180,8 @@ impl<T, Src, Dst> Translation2D<T, Src, Dst> {
     where
         T: Zero + PartialEq,
     {
-        let _0 = T::zero();
-        self.x == _0 && self.y == _0
+        let zero = T::zero();
+        self.x == zero && self.y == zero
     }
 
     /// No-op, just cast the unit.
@@ -256,6 +282,17 @@ impl<T: Copy, Src, Dst> Translation2D<T, Src, Dst> {
     }
 }
 
+impl<T: Copy + Add<Output = T>, U> Translation2D<T, U, U> {
+    /// Translates each point of the slice in place.
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point2D<T, U>]) {
+        for p in points {
+            p.x = p.x + self.x;
+            p.y = p.y + self.y;
+        }
+    }
+}
+
 impl<T: NumCast + Copy, Src, Dst> Translation2D<T, Src, Dst> {
     /// Cast from one numeric representation to another, preserving the units.
     ///
@@ -293,6 +330,13 @@ impl<T: NumCast + Copy, Src, Dst> Translation2D<T, Src, Dst> {
         self.cast()
     }
 
+    /// Cast into an `f16` vector.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Translation2D<half::f16, Src, Dst> {
+        self.cast()
+    }
+
     /// Cast into an `usize` vector, truncating decimals if any.
     ///
     /// When casting from floating vector vectors, it is worth considering whether
@@ -370,6 +414,16 @@ impl<T: SubAssign, Src, Dst> SubAssign<Translation2D<T, Dst, Dst>> for Translati
     }
 }
 
+/// Negating a translation returns its [inverse](Translation2D::inverse).
+impl<T: Neg, Src, Dst> Neg for Translation2D<T, Src, Dst> {
+    type Output = Translation2D<T::Output, Dst, Src>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Translation2D::new(-self.x, -self.y)
+    }
+}
+
 impl<T, Src, Dst> From<Vector2D<T, Src>> for Translation2D<T, Src, Dst> {
     fn from(v: Vector2D<T, Src>) -> Self {
         Translation2D::new(v.x, v.y)
@@ -391,6 +445,30 @@ where
     }
 }
 
+impl<T, Src, Dst> From<[T; 2]> for Translation2D<T, Src, Dst> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Translation2D::new(x, y)
+    }
+}
+
+impl<T, Src, Dst> From<Translation2D<T, Src, Dst>> for [T; 2] {
+    fn from(t: Translation2D<T, Src, Dst>) -> Self {
+        [t.x, t.y]
+    }
+}
+
+impl<T, Src, Dst> From<(T, T)> for Translation2D<T, Src, Dst> {
+    fn from((x, y): (T, T)) -> Self {
+        Translation2D::new(x, y)
+    }
+}
+
+impl<T, Src, Dst> From<Translation2D<T, Src, Dst>> for (T, T) {
+    fn from(t: Translation2D<T, Src, Dst>) -> Self {
+        (t.x, t.y)
+    }
+}
+
 impl<T, Src, Dst> Default for Translation2D<T, Src, Dst>
 where
     T: Zero,
@@ -411,6 +489,10 @@ impl<T: fmt::Debug, Src, Dst> fmt::Debug for Translation2D<T, Src, Dst> {
 /// The main benefit of this type over a [`Vector3D`] is the ability to cast
 /// between source and destination spaces.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Translation3D<T, Src, Dst> {
     pub x: T,
     pub y: T,
@@ -513,6 +595,12 @@ impl<T, Src, Dst> Translation3D<T, Src, Dst> {
         }
     }
 
+    /// Constructor taking a tuple with x, y and z.
+    #[inline]
+    pub fn from_tuple((x, y, z): (T, T, T)) -> Self {
+        Translation3D::new(x, y, z)
+    }
+
     #[inline]
     pub fn splat(v: T) -> Self
     where
@@ -551,8 +639,8 @@ impl<T, Src, Dst> Translation3D<T, Src, Dst> {
     where
         T: Zero + PartialEq,
     {
-        let _0 = T::zero();
-        self.x == _0 && self.y == _0 && self.z == _0
+        let zero = T::zero();
+        self.x == zero && self.y == zero && self.z == zero
     }
 
     /// No-op, just cast the unit.
@@ -676,6 +764,18 @@ impl<T: Copy, Src, Dst> Translation3D<T, Src, Dst> {
     }
 }
 
+impl<T: Copy + Add<Output = T>, U> Translation3D<T, U, U> {
+    /// Translates each point of the slice in place.
+    #[inline]
+    pub fn transform_points3d(&self, points: &mut [Point3D<T, U>]) {
+        for p in points {
+            p.x = p.x + self.x;
+            p.y = p.y + self.y;
+            p.z = p.z + self.z;
+        }
+    }
+}
+
 impl<T: NumCast + Copy, Src, Dst> Translation3D<T, Src, Dst> {
     /// Cast from one numeric representation to another, preserving the units.
     ///
@@ -717,6 +817,13 @@ impl<T: NumCast + Copy, Src, Dst> Translation3D<T, Src, Dst> {
         self.cast()
     }
 
+    /// Cast into an `f16` vector.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Translation3D<half::f16, Src, Dst> {
+        self.cast()
+    }
+
     /// Cast into an `usize` vector, truncating decimals if any.
     ///
     /// When casting from floating vector vectors, it is worth considering whether
@@ -796,6 +903,16 @@ impl<T: SubAssign, Src, Dst> SubAssign<Translation3D<T, Dst, Dst>> for Translati
     }
 }
 
+/// Negating a translation returns its [inverse](Translation3D::inverse).
+impl<T: Neg, Src, Dst> Neg for Translation3D<T, Src, Dst> {
+    type Output = Translation3D<T::Output, Dst, Src>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Translation3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
 impl<T, Src, Dst> From<Vector3D<T, Src>> for Translation3D<T, Src, Dst> {
     fn from(v: Vector3D<T, Src>) -> Self {
         Translation3D::new(v.x, v.y, v.z)
@@ -817,6 +934,30 @@ where
     }
 }
 
+impl<T, Src, Dst> From<[T; 3]> for Translation3D<T, Src, Dst> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Translation3D::new(x, y, z)
+    }
+}
+
+impl<T, Src, Dst> From<Translation3D<T, Src, Dst>> for [T; 3] {
+    fn from(t: Translation3D<T, Src, Dst>) -> Self {
+        [t.x, t.y, t.z]
+    }
+}
+
+impl<T, Src, Dst> From<(T, T, T)> for Translation3D<T, Src, Dst> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Translation3D::new(x, y, z)
+    }
+}
+
+impl<T, Src, Dst> From<Translation3D<T, Src, Dst>> for (T, T, T) {
+    fn from(t: Translation3D<T, Src, Dst>) -> Self {
+        (t.x, t.y, t.z)
+    }
+}
+
 impl<T, Src, Dst> Default for Translation3D<T, Src, Dst>
 where
     T: Zero,
@@ -856,6 +997,16 @@ mod _2d {
         assert!((tx + inv_tx).is_identity());
     }
 
+    #[test]
+    fn transform_points() {
+        use crate::{default::Translation2D, point2};
+
+        let tx = Translation2D::new(10, -10);
+        let mut points = [point2(1, 2), point2(-3, 4)];
+        tx.transform_points(&mut points);
+        assert_eq!(points, [point2(11, -8), point2(7, -6)]);
+    }
+
     /// Operation tests
     mod ops {
         use crate::default::Translation2D;
@@ -935,6 +1086,14 @@ mod _2d {
             t -= Translation2D::new(0.0, 0.0);
             assert_eq!(t, Translation2D::new(0.0, 0.0));
         }
+
+        #[test]
+        pub fn test_neg() {
+            let t = Translation2D::new(1.0, -2.0);
+            assert_eq!(-t, Translation2D::new(-1.0, 2.0));
+            assert_eq!(-t, t.inverse());
+            assert!((t + -t).is_identity());
+        }
     }
 }
 
@@ -1041,5 +1200,12 @@ mod _3d {
             t -= Translation3D::new(0.0, 0.0, 0.0);
             assert_eq!(t, Translation3D::new(0.0, 0.0, 0.0));
         }
+
+        #[test]
+        pub fn test_neg() {
+            let t = Translation3D::new(1.0, -2.0, 3.0);
+            assert_eq!(-t, Translation3D::new(-1.0, 2.0, -3.0));
+            assert_eq!(-t, t.inverse());
+        }
     }
 }
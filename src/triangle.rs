@@ -0,0 +1,376 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use crate::box2d::Box2D;
+use crate::box3d::Box3D;
+use crate::num::*;
+use crate::point::{Point2D, Point3D};
+use crate::vector::Vector3D;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A 2d triangle defined by its three vertices.
+///
+/// The vertices can be in either winding order. With the y axis pointing down, a
+/// positive [`signed_area`] means that they go clockwise on screen.
+///
+/// ```
+/// use euclid::default::Triangle2D;
+/// use euclid::point2;
+///
+/// let t = Triangle2D::new(point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 4.0));
+/// assert_eq!(t.area(), 8.0);
+/// assert!(t.contains(point2(1.0, 1.0)));
+/// assert!(!t.contains(point2(3.0, 3.0)));
+/// assert_eq!(t.barycentric(point2(0.0, 2.0)), Some((0.5, 0.0, 0.5)));
+/// ```
+///
+/// [`signed_area`]: Self::signed_area
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Triangle2D<T, U> {
+    pub a: Point2D<T, U>,
+    pub b: Point2D<T, U>,
+    pub c: Point2D<T, U>,
+}
+
+/// A 3d triangle defined by its three vertices.
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Triangle3D<T, U> {
+    pub a: Point3D<T, U>,
+    pub b: Point3D<T, U>,
+    pub c: Point3D<T, U>,
+}
+
+macro_rules! triangle_impls {
+    ($name:ident, $point:ident, $box:ident, [$($field:ident),+]) => {
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                self.a.hash(h);
+                self.b.hash(h);
+                self.c.hash(h);
+            }
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                Self::new(self.a.clone(), self.b.clone(), self.c.clone())
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                self.a.eq(&other.a) && self.b.eq(&other.b) && self.c.eq(&other.c)
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.a)
+                    .field(&self.b)
+                    .field(&self.c)
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a, T, U> arbitrary::Arbitrary<'a> for $name<T, U>
+        where
+            T: arbitrary::Arbitrary<'a>,
+        {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok($name::new(
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                    arbitrary::Arbitrary::arbitrary(u)?,
+                ))
+            }
+        }
+
+        impl<T, U> $name<T, U> {
+            /// Constructor.
+            #[inline]
+            pub const fn new(a: $point<T, U>, b: $point<T, U>, c: $point<T, U>) -> Self {
+                $name { a, b, c }
+            }
+
+            /// Constructor taking the vertices as an array.
+            #[inline]
+            pub fn from_array([a, b, c]: [$point<T, U>; 3]) -> Self {
+                $name::new(a, b, c)
+            }
+
+            /// Returns the vertices as an array.
+            #[inline]
+            pub fn to_array(self) -> [$point<T, U>; 3] {
+                [self.a, self.b, self.c]
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// Returns the smallest box containing the triangle.
+            #[inline]
+            pub fn bounding_box(&self) -> $box<T, U>
+            where
+                T: Zero + PartialOrd,
+            {
+                $box::from_points([self.a, self.b, self.c])
+            }
+
+            /// Returns the average of the three vertices, which is the center of mass of the
+            /// triangle.
+            #[inline]
+            pub fn centroid(&self) -> $point<T, U>
+            where
+                T: One + Add<Output = T> + Div<Output = T>,
+            {
+                let three = T::one() + T::one() + T::one();
+                $point::new($((self.a.$field + self.b.$field + self.c.$field) / three),+)
+            }
+
+            /// Drop the units, preserving only the numeric value.
+            #[inline]
+            pub fn to_untyped(&self) -> $name<T, UnknownUnit> {
+                $name::new(self.a.to_untyped(), self.b.to_untyped(), self.c.to_untyped())
+            }
+
+            /// Tag a unitless value with units.
+            #[inline]
+            pub fn from_untyped(t: &$name<T, UnknownUnit>) -> Self {
+                $name::new(
+                    $point::from_untyped(t.a),
+                    $point::from_untyped(t.b),
+                    $point::from_untyped(t.c),
+                )
+            }
+        }
+
+        impl<T, U> From<[$point<T, U>; 3]> for $name<T, U> {
+            fn from(points: [$point<T, U>; 3]) -> Self {
+                $name::from_array(points)
+            }
+        }
+
+        impl<T, U> From<$name<T, U>> for [$point<T, U>; 3] {
+            fn from(t: $name<T, U>) -> Self {
+                t.to_array()
+            }
+        }
+    };
+}
+
+triangle_impls!(Triangle2D, Point2D, Box2D, [x, y]);
+triangle_impls!(Triangle3D, Point3D, Box3D, [x, y, z]);
+
+impl<T: Copy, U> Triangle2D<T, U> {
+    /// Returns twice the signed area of the triangle.
+    ///
+    /// This avoids the division, so that it is exact for integer coordinates.
+    #[inline]
+    pub fn double_signed_area(&self) -> T
+    where
+        T: Sub<Output = T> + Mul<Output = T>,
+    {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    /// Returns the signed area of the triangle, which is positive if the vertices go
+    /// from the x axis towards the y axis, and negative otherwise.
+    #[inline]
+    pub fn signed_area(&self) -> T
+    where
+        T: One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.double_signed_area() / (T::one() + T::one())
+    }
+
+    /// Returns `true` if the point is inside of the triangle or on its edges.
+    ///
+    /// Degenerate triangles, with all of their vertices on a line, contain no points.
+    pub fn contains(&self, p: Point2D<T, U>) -> bool
+    where
+        T: Zero + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+    {
+        let area = self.double_signed_area();
+        let zero = T::zero();
+        if area == zero {
+            return false;
+        }
+
+        let edges = [
+            (self.b - self.a).cross(p - self.a),
+            (self.c - self.b).cross(p - self.b),
+            (self.a - self.c).cross(p - self.c),
+        ];
+        if area > zero {
+            edges.iter().all(|e| *e >= zero)
+        } else {
+            edges.iter().all(|e| *e <= zero)
+        }
+    }
+}
+
+impl<T: RealScalar, U> Triangle2D<T, U> {
+    /// Returns the area of the triangle.
+    #[inline]
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// Returns the barycentric coordinates `(u, v, w)` of the point, such that
+    /// `p = a * u + b * v + c * w` and `u + v + w = 1`, or `None` if the triangle is
+    /// degenerate.
+    ///
+    /// The point is inside of the triangle when all three coordinates are between `0`
+    /// and `1`.
+    pub fn barycentric(&self, p: Point2D<T, U>) -> Option<(T, T, T)> {
+        let area = self.double_signed_area();
+        if area == T::zero() {
+            return None;
+        }
+
+        let v = (p - self.a).cross(self.c - self.a) / area;
+        let w = (self.b - self.a).cross(p - self.a) / area;
+        Some((T::one() - v - w, v, w))
+    }
+}
+
+impl<T: Copy, U> Triangle3D<T, U> {
+    /// Returns a vector perpendicular to the triangle, with a length of twice its area.
+    ///
+    /// The vector points towards the side from which the vertices go counter-clockwise
+    /// in a right-handed coordinate system.
+    #[inline]
+    pub fn normal(&self) -> Vector3D<T, U>
+    where
+        T: Sub<Output = T> + Mul<Output = T>,
+    {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+}
+
+impl<T: RealScalar, U> Triangle3D<T, U> {
+    /// Returns the area of the triangle.
+    #[inline]
+    pub fn area(&self) -> T {
+        self.normal().length() / (T::one() + T::one())
+    }
+
+    /// Returns the barycentric coordinates `(u, v, w)` of the projection of the point on
+    /// the plane of the triangle, or `None` if the triangle is degenerate.
+    ///
+    /// See [`Triangle2D::barycentric`].
+    pub fn barycentric(&self, p: Point3D<T, U>) -> Option<(T, T, T)> {
+        let n = self.normal();
+        let square_area = n.square_length();
+        if square_area == T::zero() {
+            return None;
+        }
+
+        let v = (p - self.a).cross(self.c - self.a).dot(n) / square_area;
+        let w = (self.b - self.a).cross(p - self.a).dot(n) / square_area;
+        Some((T::one() - v - w, v, w))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Box3D, Triangle2D, Triangle3D};
+    use crate::{point2, point3, vec3};
+
+    #[test]
+    fn test_triangle2d() {
+        let t = Triangle2D::new(point2(0, 0), point2(6, 0), point2(0, 3));
+        assert_eq!(t.double_signed_area(), 18);
+        assert_eq!(t.signed_area(), 9);
+        assert_eq!(Triangle2D::new(t.a, t.c, t.b).signed_area(), -9);
+        assert_eq!(t.centroid(), point2(2, 1));
+        assert_eq!(t.bounding_box(), Box2D::new(point2(0, 0), point2(6, 3)));
+        assert_eq!(Triangle2D::from(t.to_array()), t);
+
+        let reversed = Triangle2D::new(t.c, t.b, t.a);
+        for tri in [t, reversed] {
+            assert!(tri.contains(point2(1, 1)));
+            assert!(tri.contains(point2(0, 0)));
+            assert!(tri.contains(point2(4, 1)));
+            assert!(!tri.contains(point2(4, 2)));
+            assert!(!tri.contains(point2(-1, 0)));
+        }
+
+        let flat = Triangle2D::new(point2(0, 0), point2(1, 1), point2(2, 2));
+        assert!(!flat.contains(point2(1, 1)));
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let t = Triangle2D::new(point2(1.0, 1.0), point2(5.0, 2.0), point2(2.0, 6.0));
+        assert_eq!(t.barycentric(t.a), Some((1.0, 0.0, 0.0)));
+        assert_eq!(t.barycentric(t.b), Some((0.0, 1.0, 0.0)));
+        assert_eq!(t.barycentric(t.c), Some((0.0, 0.0, 1.0)));
+
+        let (u, v, w) = t.barycentric(t.centroid()).unwrap();
+        let third = 1.0 / 3.0;
+        assert!(u.approx_eq(&third) && v.approx_eq(&third) && w.approx_eq(&third));
+
+        let p = point2(3.0, 3.0);
+        let (u, v, w) = t.barycentric(p).unwrap();
+        let q = (t.a.to_vector() * u + t.b.to_vector() * v + t.c.to_vector() * w).to_point();
+        assert!(q.approx_eq(&p));
+        assert_eq!(Triangle2D::new(t.a, t.a, t.b).barycentric(p), None);
+    }
+
+    #[test]
+    fn test_triangle3d() {
+        let t = Triangle3D::new(
+            point3(0.0, 0.0, 2.0),
+            point3(4.0, 0.0, 2.0),
+            point3(0.0, 3.0, 2.0),
+        );
+        assert_eq!(t.normal(), vec3(0.0, 0.0, 12.0));
+        assert_eq!(t.area(), 6.0);
+        assert_eq!(
+            t.bounding_box(),
+            Box3D::new(point3(0.0, 0.0, 2.0), point3(4.0, 3.0, 2.0))
+        );
+
+        let flat = Triangle2D::new(point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 3.0));
+        let p = point3(1.0, 1.0, 7.0);
+        assert_eq!(t.barycentric(p), flat.barycentric(point2(1.0, 1.0)));
+        assert_eq!(t.barycentric(t.b), Some((0.0, 1.0, 0.0)));
+    }
+}
@@ -78,3 +78,32 @@ macro_rules! trig {
 
 trig!(f32);
 trig!(f64);
+
+/// Half-precision trigonometry is computed in `f32`.
+#[cfg(feature = "half")]
+impl Trig for half::f16 {
+    #[inline]
+    fn sin(self) -> Self {
+        half::f16::from_f32(Trig::sin(self.to_f32()))
+    }
+    #[inline]
+    fn cos(self) -> Self {
+        half::f16::from_f32(Trig::cos(self.to_f32()))
+    }
+    #[inline]
+    fn tan(self) -> Self {
+        half::f16::from_f32(Trig::tan(self.to_f32()))
+    }
+    #[inline]
+    fn fast_atan2(y: Self, x: Self) -> Self {
+        half::f16::from_f32(f32::fast_atan2(y.to_f32(), x.to_f32()))
+    }
+    #[inline]
+    fn degrees_to_radians(deg: Self) -> Self {
+        half::f16::from_f32(deg.to_f32().to_radians())
+    }
+    #[inline]
+    fn radians_to_degrees(rad: Self) -> Self {
+        half::f16::from_f32(rad.to_f32().to_degrees())
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Debug output that includes the names of the units.
+
+use crate::num::{One, Zero};
+use crate::{
+    Box2D, Box3D, HomogeneousVector, Length, Point2D, Point3D, Rect, Scale, SideOffsets2D, Size2D,
+    Size3D, Transform2D, Transform3D, Translation2D, Translation3D, UnknownUnit, Vector2D,
+    Vector3D,
+};
+
+use core::fmt;
+
+/// A unit with a name, shown by the [`with_unit_names`] debug output.
+///
+/// The plain `Debug` output of the euclid types leaves the units out, since they
+/// usually are uninhabited marker types. Units that implement this trait can be
+/// shown with [`DebugWithUnitNames::with_unit_names`] instead, which is what is
+/// needed when tracking down a value that ended up in the wrong space.
+///
+/// [`declare_coordinate_space!`](crate::declare_coordinate_space) implements it for
+/// the units it declares.
+///
+/// ```
+/// use euclid::{point2, DebugWithUnitNames, Point2D, UnitName};
+///
+/// struct DevicePixel;
+///
+/// impl UnitName for DevicePixel {
+///     const NAME: &'static str = "DevicePixel";
+/// }
+///
+/// let p: Point2D<f32, DevicePixel> = point2(10.0, 20.0);
+/// assert_eq!(format!("{:?}", p), "(10.0, 20.0)");
+/// assert_eq!(format!("{:?}", p.with_unit_names()), "Point2D<DevicePixel>(10.0, 20.0)");
+/// ```
+///
+/// [`with_unit_names`]: DebugWithUnitNames::with_unit_names
+pub trait UnitName {
+    /// The name of the unit.
+    const NAME: &'static str;
+}
+
+impl UnitName for UnknownUnit {
+    const NAME: &'static str = "UnknownUnit";
+}
+
+/// Types whose units can be shown in their debug output.
+pub trait DebugWithUnitNames {
+    /// Formats the value like `Debug` does, preceded by the type and its units.
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result;
+
+    /// Returns a wrapper whose `Debug` output includes the names of the units.
+    #[inline]
+    fn with_unit_names(&self) -> WithUnitNames<'_, Self> {
+        WithUnitNames(self)
+    }
+}
+
+/// The `Debug` output of a value, including the names of its units.
+///
+/// See [`DebugWithUnitNames::with_unit_names`].
+pub struct WithUnitNames<'a, T: ?Sized>(&'a T);
+
+impl<T: DebugWithUnitNames + ?Sized> fmt::Debug for WithUnitNames<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_with_unit_names(f)
+    }
+}
+
+fn write_type(f: &mut fmt::Formatter, name: &str, units: &[&str]) -> fmt::Result {
+    write!(f, "{}<", name)?;
+    for (i, unit) in units.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        f.write_str(unit)?;
+    }
+    f.write_str(">")
+}
+
+// Types whose debug output is already a parenthesized tuple.
+macro_rules! tuple_like {
+    ($($ty:ident),+) => {$(
+        impl<T: fmt::Debug, U: UnitName> DebugWithUnitNames for $ty<T, U> {
+            fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_type(f, stringify!($ty), &[U::NAME])?;
+                fmt::Debug::fmt(self, f)
+            }
+        }
+    )+};
+}
+
+tuple_like!(
+    Point2D,
+    Point3D,
+    Vector2D,
+    Vector3D,
+    HomogeneousVector,
+    SideOffsets2D
+);
+
+macro_rules! parenthesized {
+    ($($ty:ident),+) => {$(
+        impl<T: fmt::Debug, U: UnitName> DebugWithUnitNames for $ty<T, U> {
+            fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_type(f, stringify!($ty), &[U::NAME])?;
+                f.write_str("(")?;
+                fmt::Debug::fmt(self, f)?;
+                f.write_str(")")
+            }
+        }
+    )+};
+}
+
+parenthesized!(Length, Size2D, Size3D);
+
+impl<T: fmt::Debug, U: UnitName> DebugWithUnitNames for Rect<T, U> {
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Rect", &[U::NAME])?;
+        f.write_str("(")?;
+        fmt::Debug::fmt(&self.size, f)?;
+        f.write_str(" at ")?;
+        fmt::Debug::fmt(&self.origin, f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T: fmt::Debug, U: UnitName> DebugWithUnitNames for Box2D<T, U> {
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Box2D", &[U::NAME])?;
+        f.debug_tuple("").field(&self.min).field(&self.max).finish()
+    }
+}
+
+impl<T: fmt::Debug, U: UnitName> DebugWithUnitNames for Box3D<T, U> {
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Box3D", &[U::NAME])?;
+        f.debug_tuple("").field(&self.min).field(&self.max).finish()
+    }
+}
+
+impl<T: fmt::Debug, Src: UnitName, Dst: UnitName> DebugWithUnitNames for Scale<T, Src, Dst> {
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Scale", &[Src::NAME, Dst::NAME])?;
+        f.write_str("(")?;
+        fmt::Debug::fmt(&self.0, f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T: fmt::Debug, Src: UnitName, Dst: UnitName> DebugWithUnitNames
+    for Translation2D<T, Src, Dst>
+{
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Translation2D", &[Src::NAME, Dst::NAME])?;
+        f.debug_tuple("").field(&self.x).field(&self.y).finish()
+    }
+}
+
+impl<T: fmt::Debug, Src: UnitName, Dst: UnitName> DebugWithUnitNames
+    for Translation3D<T, Src, Dst>
+{
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Translation3D", &[Src::NAME, Dst::NAME])?;
+        f.debug_tuple("")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+impl<T, Src: UnitName, Dst: UnitName> DebugWithUnitNames for Transform2D<T, Src, Dst>
+where
+    T: Copy + fmt::Debug + PartialEq + One + Zero,
+{
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Transform2D", &[Src::NAME, Dst::NAME])?;
+        f.write_str("(")?;
+        fmt::Debug::fmt(self, f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T, Src: UnitName, Dst: UnitName> DebugWithUnitNames for Transform3D<T, Src, Dst>
+where
+    T: Copy + fmt::Debug + PartialEq + One + Zero,
+{
+    fn fmt_with_unit_names(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type(f, "Transform3D", &[Src::NAME, Dst::NAME])?;
+        f.write_str("(")?;
+        fmt::Debug::fmt(self, f)?;
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point2, rect, size2, vec3};
+
+    crate::declare_coordinate_space! {
+        struct Layout;
+        mod layout(f32);
+    }
+
+    crate::declare_coordinate_space! {
+        struct Device;
+        mod device(f32);
+    }
+
+    #[test]
+    fn test_with_unit_names() {
+        let p: layout::Point = point2(1.0, 2.0);
+        assert_eq!(
+            format!("{:?}", p.with_unit_names()),
+            "Point2D<Layout>(1.0, 2.0)"
+        );
+        let v: device::Vector3D = vec3(1.0, 2.0, 3.0);
+        assert_eq!(
+            format!("{:?}", v.with_unit_names()),
+            "Vector3D<Device>(1.0, 2.0, 3.0)"
+        );
+        let s: layout::Size = size2(3.0, 4.0);
+        assert_eq!(
+            format!("{:?}", s.with_unit_names()),
+            "Size2D<Layout>(3.0x4.0)"
+        );
+        let r: device::Rect = rect(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(
+            format!("{:?}", r.with_unit_names()),
+            "Rect<Device>(3.0x4.0 at (1.0, 2.0))"
+        );
+        assert_eq!(
+            format!("{:?}", r.to_box2d().with_unit_names()),
+            "Box2D<Device>((1.0, 2.0), (4.0, 6.0))"
+        );
+
+        let scale = layout::ScaleTo::<Device>::new(2.0);
+        assert_eq!(
+            format!("{:?}", scale.with_unit_names()),
+            "Scale<Layout, Device>(2.0)"
+        );
+        let t = layout::TransformTo::<Device>::identity();
+        assert_eq!(
+            format!("{:?}", t.with_unit_names()),
+            "Transform2D<Layout, Device>([I])"
+        );
+
+        let p: crate::default::Point2D<i32> = point2(1, 2);
+        assert_eq!(
+            format!("{:?}", p.with_unit_names()),
+            "Point2D<UnknownUnit>(1, 2)"
+        );
+    }
+}
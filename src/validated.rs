@@ -0,0 +1,485 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Validation of the invariants of the euclid types.
+//!
+//! Most euclid types accept any value, including NaN or infinite components, boxes
+//! whose `min` is greater than their `max`, negative sizes and quaternions that are
+//! not normalized. The [`Validate`] trait checks the documented invariants of a value
+//! on demand, with [`Validate::validate`] or [`Validate::checked`].
+//!
+//! # The `validate` feature
+//!
+//! With the `validate` feature, some operations also assert the invariants they rely
+//! on, and panic with a description of the violation, in release builds too:
+//!
+//! - the rotations of [`Rotation3D`] check that the quaternion is normalized, which is
+//!   otherwise only checked in debug builds,
+//! - [`Rect::inner_rect`] checks that the offsets fit in the rectangle, which is
+//!   otherwise only checked in debug builds,
+//! - the inversions of [`Transform2D`] and [`Transform3D`] check that the transform is
+//!   finite,
+//! - [`Box2D::from_points`], [`Box2D::intersection`] and [`Box2D::union`], their
+//!   [`Box3D`] counterparts, and the [`Rect`] methods that go through them, check that
+//!   the boxes they take or build have no NaN coordinate.
+//!
+//! The `const` constructors, like `Box2D::new` and `Size2D::new`, can't be checked, and
+//! boxes and rectangles with a negative size are legitimate empty values for the
+//! operations that take them, so values should be checked with [`Validate::checked`]
+//! where they enter the program.
+//!
+//! # Deserialization
+//!
+//! Deserializing a euclid type directly accepts any value. Deserializing a
+//! [`Validated`] wrapper instead rejects invalid values with a descriptive error.
+//!
+//! ```ignore
+//! let layout: Validated<Box2D<f32, LayoutSpace>> = serde_json::from_str(input)?;
+//! let bounds = layout.into_inner();
+//! ```
+
+use crate::approxeq::ApproxEq;
+use crate::num::{RealScalar, Zero};
+use crate::{
+    Box2D, Box3D, Point2D, Point3D, Rect, Rotation3D, Size2D, Size3D, Transform2D, Transform3D,
+    Vector2D, Vector3D,
+};
+
+use core::ops::{Deref, Sub};
+#[cfg(feature = "serde")]
+use serde::de::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A wrapper whose content has been checked with [`Validate`], including when
+/// deserialized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Validated<T>(T);
+
+impl<T: Validate> Validated<T> {
+    /// Wraps a value, returning an error if it is invalid.
+    pub fn new(value: T) -> Result<Self, &'static str> {
+        value.validate()?;
+        Ok(Validated(value))
+    }
+}
+
+impl<T> Validated<T> {
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Validated<T>
+where
+    T: Deserialize<'de> + Validate,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Validated::new(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Validated<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Checks the invariants of a value, returning a description of the first
+/// violation found.
+///
+/// ```
+/// use euclid::default::{Box2D, Size2D};
+/// use euclid::validated::Validate;
+/// use euclid::{point2, size2};
+///
+/// let size: Size2D<f32> = size2(-1.0, 2.0);
+/// assert_eq!(size.validate(), Err("invalid Size2D: negative component"));
+///
+/// let b: Box2D<f32> = Box2D::new(point2(0.0, 0.0), point2(1.0, f32::NAN));
+/// assert!(b.checked().is_err());
+/// ```
+pub trait Validate {
+    fn validate(&self) -> Result<(), &'static str>;
+
+    /// Returns the value if it is valid.
+    #[inline]
+    fn checked(self) -> Result<Self, &'static str>
+    where
+        Self: Sized,
+    {
+        self.validate()?;
+        Ok(self)
+    }
+}
+
+/// Scalars that can be checked for finiteness.
+pub trait ValidScalar: Copy + PartialOrd {
+    fn is_valid(self) -> bool;
+    fn is_negative(self) -> bool;
+}
+
+macro_rules! valid_float {
+    ($($ty:ty),*) => {$(
+        impl ValidScalar for $ty {
+            #[inline]
+            fn is_valid(self) -> bool {
+                self.is_finite()
+            }
+            #[inline]
+            fn is_negative(self) -> bool {
+                self < 0.0
+            }
+        }
+    )*};
+}
+
+macro_rules! valid_signed {
+    ($($ty:ty),*) => {$(
+        impl ValidScalar for $ty {
+            #[inline]
+            fn is_valid(self) -> bool {
+                true
+            }
+            #[inline]
+            fn is_negative(self) -> bool {
+                self < 0
+            }
+        }
+    )*};
+}
+
+macro_rules! valid_unsigned {
+    ($($ty:ty),*) => {$(
+        impl ValidScalar for $ty {
+            #[inline]
+            fn is_valid(self) -> bool {
+                true
+            }
+            #[inline]
+            fn is_negative(self) -> bool {
+                false
+            }
+        }
+    )*};
+}
+
+valid_float!(f32, f64);
+valid_signed!(i8, i16, i32, i64, i128, isize);
+valid_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Returns `true` if none of the values is NaN or infinite.
+///
+/// This only needs the operators that most euclid methods already require: `x - x`
+/// is zero for finite values, and NaN otherwise.
+#[allow(clippy::eq_op)]
+pub(crate) fn finite<T: Copy + Sub<Output = T> + PartialEq + Zero>(values: &[T]) -> bool {
+    values.iter().all(|&v| v - v == T::zero())
+}
+
+/// Returns `true` if none of the values is NaN, for the operations that only require
+/// `PartialOrd`.
+pub(crate) fn not_nan<T: PartialOrd>(values: &[T]) -> bool {
+    values.iter().all(|v| v.partial_cmp(v).is_some())
+}
+
+fn valid_point2d<T: ValidScalar, U>(p: &Point2D<T, U>) -> bool {
+    p.x.is_valid() && p.y.is_valid()
+}
+
+fn valid_point3d<T: ValidScalar, U>(p: &Point3D<T, U>) -> bool {
+    p.x.is_valid() && p.y.is_valid() && p.z.is_valid()
+}
+
+impl<T: ValidScalar, U> Validate for Point2D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !valid_point2d(self) {
+            return Err("invalid Point2D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Point3D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !valid_point3d(self) {
+            return Err("invalid Point3D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Vector2D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.x.is_valid() || !self.y.is_valid() {
+            return Err("invalid Vector2D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Vector3D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.x.is_valid() || !self.y.is_valid() || !self.z.is_valid() {
+            return Err("invalid Vector3D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Size2D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.width.is_valid() || !self.height.is_valid() {
+            return Err("invalid Size2D: non-finite component");
+        }
+        if self.width.is_negative() || self.height.is_negative() {
+            return Err("invalid Size2D: negative component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Size3D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.width.is_valid() || !self.height.is_valid() || !self.depth.is_valid() {
+            return Err("invalid Size3D: non-finite component");
+        }
+        if self.width.is_negative() || self.height.is_negative() || self.depth.is_negative() {
+            return Err("invalid Size3D: negative component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Rect<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !valid_point2d(&self.origin) {
+            return Err("invalid Rect: non-finite origin");
+        }
+        if !self.size.width.is_valid() || !self.size.height.is_valid() {
+            return Err("invalid Rect: non-finite size");
+        }
+        if self.size.width.is_negative() || self.size.height.is_negative() {
+            return Err("invalid Rect: negative size");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Box2D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !valid_point2d(&self.min) || !valid_point2d(&self.max) {
+            return Err("invalid Box2D: non-finite component");
+        }
+        if self.min.x > self.max.x || self.min.y > self.max.y {
+            return Err("invalid Box2D: min > max");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, U> Validate for Box3D<T, U> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !valid_point3d(&self.min) || !valid_point3d(&self.max) {
+            return Err("invalid Box3D: non-finite component");
+        }
+        if self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z {
+            return Err("invalid Box3D: min > max");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, Src, Dst> Validate for Transform2D<T, Src, Dst> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.to_array().iter().all(|v| v.is_valid()) {
+            return Err("invalid Transform2D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidScalar, Src, Dst> Validate for Transform3D<T, Src, Dst> {
+    fn validate(&self) -> Result<(), &'static str> {
+        if !self.to_array().iter().all(|v| v.is_valid()) {
+            return Err("invalid Transform3D: non-finite component");
+        }
+        Ok(())
+    }
+}
+
+impl<T, Src, Dst> Validate for Rotation3D<T, Src, Dst>
+where
+    T: ValidScalar + RealScalar + ApproxEq<T>,
+{
+    fn validate(&self) -> Result<(), &'static str> {
+        if ![self.i, self.j, self.k, self.r]
+            .iter()
+            .all(|v| v.is_valid())
+        {
+            return Err("invalid Rotation3D: non-finite component");
+        }
+        if !self.is_normalized() {
+            return Err("invalid Rotation3D: not normalized");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{point2, size2};
+
+    #[cfg(feature = "serde")]
+    extern crate serde_test;
+    #[cfg(feature = "serde")]
+    use self::serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Token};
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_size() {
+        let s: Validated<default::Size2D<f32>> = Validated::new(size2(1.0, 2.0)).unwrap();
+        assert_de_tokens(
+            &s.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F32(1.0),
+                Token::F32(2.0),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Compact<Validated<default::Size2D<f32>>>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::F32(-1.0),
+                Token::F32(2.0),
+                Token::TupleEnd,
+            ],
+            "invalid Size2D: negative component",
+        );
+        assert_de_tokens_error::<Compact<Validated<default::Size2D<f32>>>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::F32(f32::NAN),
+                Token::F32(2.0),
+                Token::TupleEnd,
+            ],
+            "invalid Size2D: non-finite component",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_box2d() {
+        let tokens = |min_x: f32, max_x: f32| {
+            [
+                Token::Struct {
+                    name: "Box2D",
+                    len: 2,
+                },
+                Token::Str("min"),
+                Token::Tuple { len: 2 },
+                Token::F32(min_x),
+                Token::F32(0.0),
+                Token::TupleEnd,
+                Token::Str("max"),
+                Token::Tuple { len: 2 },
+                Token::F32(max_x),
+                Token::F32(1.0),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ]
+        };
+
+        let b: Validated<default::Box2D<f32>> =
+            Validated::new(Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0))).unwrap();
+        assert_de_tokens(&b.compact(), &tokens(0.0, 1.0));
+        assert_de_tokens_error::<Compact<Validated<default::Box2D<f32>>>>(
+            &tokens(2.0, 1.0),
+            "invalid Box2D: min > max",
+        );
+        assert_de_tokens_error::<Compact<Validated<default::Box2D<f32>>>>(
+            &tokens(0.0, f32::INFINITY),
+            "invalid Box2D: non-finite component",
+        );
+    }
+
+    #[test]
+    fn test_rect() {
+        let r: default::Rect<i32> = Rect::new(point2(1, 2), size2(3, -4));
+        assert_eq!(Validated::new(r), Err("invalid Rect: negative size"));
+    }
+
+    #[test]
+    fn test_checked() {
+        let t = default::Transform3D::<f32>::scale(2.0, f32::INFINITY, 1.0);
+        assert_eq!(
+            t.checked(),
+            Err("invalid Transform3D: non-finite component")
+        );
+        let r = default::Rotation3D::quaternion(0.0, 0.0, 0.0, 2.0);
+        assert_eq!(r.checked(), Err("invalid Rotation3D: not normalized"));
+        assert!(r.normalize().checked().is_ok());
+        let p: default::Point2D<i32> = point2(1, 2);
+        assert_eq!(p.checked(), Ok(p));
+        assert!(finite(&[1.0, -2.0]) && !finite(&[1.0, f64::NAN]));
+    }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    #[should_panic(expected = "Rect::inner_rect: the offsets are larger than the rectangle")]
+    fn test_validate_rect() {
+        let r: default::Rect<i32> = Rect::new(point2(0, 0), size2(1, 1));
+        let _ = r.inner_rect(crate::SideOffsets2D::new_all_same(1));
+    }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    #[should_panic(expected = "Box2D::from_points: NaN component")]
+    fn test_validate_box() {
+        let _ = default::Box2D::from_points([point2(f32::NAN, 0.0), point2(1.0, 1.0)]);
+    }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    #[should_panic(expected = "Box2D::union: NaN component")]
+    fn test_validate_rect_union() {
+        let a: default::Rect<f32> = Rect::new(point2(0.0, 0.0), size2(1.0, 1.0));
+        let b: default::Rect<f32> = Rect::new(point2(f32::NAN, 0.0), size2(1.0, 1.0));
+        let _ = a.union(&b);
+    }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    #[should_panic(expected = "Transform2D::inverse: non-finite component")]
+    fn test_validate_transform() {
+        let _ = default::Transform2D::<f32>::scale(f32::INFINITY, 1.0).inverse();
+    }
+}
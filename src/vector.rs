@@ -9,11 +9,14 @@
 
 use super::UnknownUnit;
 use crate::approxeq::ApproxEq;
-use crate::approxord::{max, min};
+use crate::approxord::{abs_diff, max, min};
+use crate::batch::SimdScalar;
+use crate::cast::{cast_component, round_component, saturate_component, CastError, RoundingMode};
+use crate::error::Error;
 use crate::length::Length;
 use crate::num::*;
 use crate::point::{point2, point3, Point2D, Point3D};
-use crate::scale::Scale;
+use crate::scale::{Scale, Scale2D};
 use crate::size::{size2, size3, Size2D, Size3D};
 use crate::transform2d::Transform2D;
 use crate::transform3d::Transform3D;
@@ -24,19 +27,22 @@ use core::fmt;
 use core::hash::Hash;
 use core::iter::Sum;
 use core::marker::PhantomData;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-#[cfg(feature = "mint")]
-use mint;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
 use num_traits::real::Real;
-use num_traits::{Float, NumCast, Signed};
-#[cfg(feature = "serde")]
-use serde;
+use num_traits::{Bounded, Float, NumCast, Signed, WrappingAdd, WrappingMul, WrappingSub};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
 /// A 2d Vector tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Vector2D<T, U> {
     /// The `x` (traditionally, horizontal) coordinate.
     pub x: T,
@@ -47,6 +53,8 @@ pub struct Vector2D<T, U> {
 }
 
 mint_vec!(Vector2D[x, y] = Vector2);
+nalgebra_vec!(Vector2D[x, y] = Vector2);
+glam_vec!(Vector2D[x, y] = Vec2);
 
 impl<T: Copy, U> Copy for Vector2D<T, U> {}
 
@@ -69,7 +77,9 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (x, y) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None];
+        crate::serde_fields::deserialize(deserializer, "Vector2D", &["x", "y"], &mut values)?;
+        let [x, y] = values.map(Option::unwrap);
         Ok(Vector2D {
             x,
             y,
@@ -87,7 +97,7 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.x, &self.y).serialize(serializer)
+        crate::serde_fields::serialize(serializer, "Vector2D", &["x", "y"], &[&self.x, &self.y])
     }
 }
 
@@ -147,6 +157,22 @@ impl<T: Default, U> Default for Vector2D<T, U> {
     }
 }
 
+macro_rules! vector_consts {
+    ($ty:ty, $zero:expr, $one:expr) => {
+        impl<U> Vector2D<$ty, U> {
+            /// The vector with all components set to zero.
+            pub const ZERO: Self = vec2($zero, $zero);
+        }
+
+        impl<U> Vector3D<$ty, U> {
+            /// The vector with all components set to zero.
+            pub const ZERO: Self = vec3($zero, $zero, $zero);
+        }
+    };
+}
+
+primitive_consts!(vector_consts);
+
 impl<T, U> Vector2D<T, U> {
     /// Constructor, setting all components to zero.
     #[inline]
@@ -176,6 +202,12 @@ impl<T, U> Vector2D<T, U> {
         }
     }
 
+    /// Constructor taking a tuple with x and y.
+    #[inline]
+    pub fn from_tuple((x, y): (T, T)) -> Self {
+        Vector2D::new(x, y)
+    }
+
     /// Constructor setting all components to the same value.
     #[inline]
     pub fn splat(v: T) -> Self
@@ -271,6 +303,27 @@ impl<T, U> Vector2D<T, U> {
         vec2(self.x.abs(), self.y.abs())
     }
 
+    /// Computes the vector with the sign of each component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use euclid::vec2;
+    /// enum U {}
+    ///
+    /// assert_eq!(vec2::<_, U>(-3, 0).signum(), vec2(-1, 0));
+    /// assert_eq!(vec2::<_, U>(-0.5, 2.0).signum(), vec2(-1.0, 1.0));
+    /// ```
+    ///
+    /// The behavior for each component follows the scalar type's implementation of
+    /// `num_traits::Signed::signum`.
+    pub fn signum(self) -> Self
+    where
+        T: Signed,
+    {
+        vec2(self.x.signum(), self.y.signum())
+    }
+
     /// Dot product.
     #[inline]
     pub fn dot(self, other: Self) -> T
@@ -306,9 +359,21 @@ impl<T, U> Vector2D<T, U> {
     {
         vec2(self.x / other.x, self.y / other.y)
     }
+
+    /// Returns a reference to the components as an array of x and y.
+    ///
+    /// `Vector2D` is `#[repr(C)]` and its unit is zero-sized, so its memory layout is
+    /// guaranteed to be the same as `[T; 2]`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Self` is `#[repr(C)]` with 2 fields of type `T`, which have the size
+        // and alignment of `T` so there is no padding between them, and a zero-sized
+        // unit, so it has the layout of `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
 }
 
-impl<T: Copy, U> Vector2D<T, U> {
+impl<T: Clone, U> Vector2D<T, U> {
     /// Create a 3d vector from this one, using the specified z value.
     #[inline]
     pub fn extend(self, z: T) -> Vector3D<T, U> {
@@ -472,6 +537,18 @@ where
         onto * (self.dot(onto) / onto.square_length())
     }
 
+    /// Returns the component of this vector perpendicular to another one, that is
+    /// `self - self.project_onto_vector(onto)`.
+    ///
+    /// Rejecting from a nil vector will cause a division by zero.
+    #[inline]
+    pub fn reject_from_vector(self, onto: Self) -> Self
+    where
+        T: Sub<T, Output = T> + Div<T, Output = T>,
+    {
+        self - self.project_onto_vector(onto)
+    }
+
     /// Returns the signed angle between this vector and another vector.
     ///
     /// The returned angle is between -PI and PI.
@@ -484,14 +561,29 @@ where
 }
 
 impl<T: Float, U> Vector2D<T, U> {
-    /// Return the normalized vector even if the length is larger than the max value of Float.
+    /// Returns the vector length without overflowing or underflowing when squaring
+    /// very large or very small components.
+    ///
+    /// The components are scaled by the largest of their absolute values before
+    /// computing the length, like `hypot` does.
+    #[inline]
+    pub fn robust_length(self) -> T {
+        let scale = self.x.abs().max(self.y.abs());
+        if scale == T::zero() || scale.is_infinite() {
+            return scale;
+        }
+        (self / scale).length() * scale
+    }
+
+    /// Return the normalized vector even if the length is larger than the max value of Float,
+    /// or too small to be squared without underflowing.
     #[inline]
     #[must_use]
     pub fn robust_normalize(self) -> Self {
-        let length = self.length();
+        let length = self.robust_length();
         if length.is_infinite() {
             let scaled = self / T::max_value();
-            scaled / scaled.length()
+            scaled / scaled.robust_length()
         } else {
             self / length
         }
@@ -502,6 +594,14 @@ impl<T: Float, U> Vector2D<T, U> {
     pub fn is_finite(self) -> bool {
         self.x.is_finite() && self.y.is_finite()
     }
+
+    /// Computes the reciprocal of each component.
+    ///
+    /// A zero component becomes infinite.
+    #[inline]
+    pub fn recip(self) -> Self {
+        vec2(self.x.recip(), self.y.recip())
+    }
 }
 
 impl<T: Real, U> Vector2D<T, U> {
@@ -515,7 +615,9 @@ impl<T: Real, U> Vector2D<T, U> {
     #[inline]
     #[must_use]
     pub fn normalize(self) -> Self {
-        self / self.length()
+        let v = self / self.length();
+        debug_check_nan!("Vector2D::normalize", v.x, v.y);
+        v
     }
 
     /// Returns the vector with length of one unit.
@@ -533,6 +635,21 @@ impl<T: Real, U> Vector2D<T, U> {
         }
     }
 
+    /// Same as [`try_normalize`](Self::try_normalize), returning
+    /// [`Error::DegenerateInput`] for a vector of length zero.
+    #[inline]
+    pub fn normalize_checked(self) -> Result<Self, Error<T>> {
+        self.try_normalize().ok_or(Error::DegenerateInput)
+    }
+
+    /// Returns the vector with length of one unit, or `fallback` if the length
+    /// of the vector is zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
     /// Return this vector scaled to fit the provided length.
     #[inline]
     pub fn with_length(self, length: T) -> Self {
@@ -555,7 +672,9 @@ impl<T: Real, U> Vector2D<T, U> {
     pub fn with_min_length(self, min_length: T) -> Self {
         let square_length = self.square_length();
         if square_length < min_length * min_length {
-            return self * (min_length / square_length.sqrt());
+            let v = self * (min_length / square_length.sqrt());
+            debug_check_nan!("Vector2D::with_min_length", v.x, v.y);
+            return v;
         }
 
         self
@@ -591,9 +710,11 @@ where
     /// assert_eq!(from.lerp(to,  2.0), vec2(16.0, -18.0));
     /// ```
     #[inline]
-    pub fn lerp(self, other: Self, t: T) -> Self {
-        let one_t = T::one() - t;
-        self * one_t + other * t
+    pub fn lerp(self, other: Self, t: T) -> Self
+    where
+        T: SimdScalar,
+    {
+        vec2(T::lerp(self.x, other.x, t), T::lerp(self.y, other.y, t))
     }
 
     /// Returns a reflection vector using an incident ray and a surface normal.
@@ -617,6 +738,18 @@ impl<T: PartialOrd, U> Vector2D<T, U> {
         vec2(max(self.x, other.x), max(self.y, other.y))
     }
 
+    /// Returns the vector each component of which is the absolute difference of the
+    /// components of this vector and another.
+    ///
+    /// Unlike `(self - other).abs()`, this doesn't underflow for unsigned integers.
+    #[inline]
+    pub fn abs_diff(self, other: Self) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        vec2(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+
     /// Returns the vector each component of which is clamped by corresponding
     /// components of `start` and `end`.
     ///
@@ -691,6 +824,48 @@ impl<T: NumCast + Copy, U> Vector2D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Vector2D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Vector2D<NewT, U>, CastError> {
+        Ok(Vector2D::new(
+            cast_component(self.x, "x")?,
+            cast_component(self.y, "y")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Vector2D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Vector2D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Vector2D<NewT, U>> {
+        Some(Vector2D::new(
+            round_component(self.x, mode)?,
+            round_component(self.y, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Vector2D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Vector2D<NewT, U> {
+        Vector2D::new(saturate_component(self.x), saturate_component(self.y))
+    }
+
     // Convenience functions for common casts.
 
     /// Cast into an `f32` vector.
@@ -705,6 +880,13 @@ impl<T: NumCast + Copy, U> Vector2D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` vector.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Vector2D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` vector, truncating decimals if any.
     ///
     /// When casting from floating vector vectors, it is worth considering whether
@@ -754,6 +936,36 @@ impl<T: NumCast + Copy, U> Vector2D<T, U> {
     pub fn to_i64(self) -> Vector2D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an i64 vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Vector2D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an i64 vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Vector2D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an i64 vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(self) -> Vector2D<u64, U> {
+        self.cast()
+    }
 }
 
 impl<T: Neg, U> Neg for Vector2D<T, U> {
@@ -774,12 +986,12 @@ impl<T: Add, U> Add for Vector2D<T, U> {
     }
 }
 
-impl<T: Add + Copy, U> Add<&Self> for Vector2D<T, U> {
+impl<T: Add + Clone, U> Add<&Self> for Vector2D<T, U> {
     type Output = Vector2D<T::Output, U>;
 
     #[inline]
     fn add(self, other: &Self) -> Self::Output {
-        Vector2D::new(self.x + other.x, self.y + other.y)
+        Vector2D::new(self.x + other.x.clone(), self.y + other.y.clone())
     }
 }
 
@@ -789,16 +1001,16 @@ impl<T: Add<Output = T> + Zero, U> Sum for Vector2D<T, U> {
     }
 }
 
-impl<'a, T: 'a + Add<Output = T> + Copy + Zero, U: 'a> Sum<&'a Self> for Vector2D<T, U> {
+impl<'a, T: 'a + Add<Output = T> + Clone + Zero, U: 'a> Sum<&'a Self> for Vector2D<T, U> {
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
         iter.fold(Self::zero(), Add::add)
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> AddAssign for Vector2D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> AddAssign for Vector2D<T, U> {
     #[inline]
     fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
+        *self = self.clone() + other;
     }
 }
 
@@ -811,79 +1023,113 @@ impl<T: Sub, U> Sub for Vector2D<T, U> {
     }
 }
 
-impl<T: Copy + Sub<T, Output = T>, U> SubAssign<Vector2D<T, U>> for Vector2D<T, U> {
+impl<T: Clone + Sub<T, Output = T>, U> SubAssign<Vector2D<T, U>> for Vector2D<T, U> {
     #[inline]
     fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
+        *self = self.clone() - other;
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Vector2D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Vector2D<T, U> {
     type Output = Vector2D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        vec2(self.x * scale, self.y * scale)
+        vec2(self.x * scale.clone(), self.y * scale)
     }
 }
 
-impl<T: Copy + Mul<T, Output = T>, U> MulAssign<T> for Vector2D<T, U> {
+impl<T: Clone + Mul<T, Output = T>, U> MulAssign<T> for Vector2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
-        *self = *self * scale;
+        *self = self.clone() * scale;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Vector2D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Vector2D<T, U1> {
     type Output = Vector2D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        vec2(self.x * scale.0, self.y * scale.0)
+        vec2(self.x * scale.0.clone(), self.y * scale.0)
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Vector2D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Vector2D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x *= scale.0;
+        self.x *= scale.0.clone();
         self.y *= scale.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Vector2D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Vector2D<T, U> {
     type Output = Vector2D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        vec2(self.x / scale, self.y / scale)
+        vec2(self.x / scale.clone(), self.y / scale)
     }
 }
 
-impl<T: Copy + Div<T, Output = T>, U> DivAssign<T> for Vector2D<T, U> {
+impl<T: Clone + Div<T, Output = T>, U> DivAssign<T> for Vector2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
-        *self = *self / scale;
+        *self = self.clone() / scale;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Vector2D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Vector2D<T, U2> {
     type Output = Vector2D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        vec2(self.x / scale.0, self.y / scale.0)
+        vec2(self.x / scale.0.clone(), self.y / scale.0)
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Vector2D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Vector2D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x /= scale.0;
+        self.x /= scale.0.clone();
         self.y /= scale.0;
     }
 }
 
+impl<T: Mul, U1, U2> Mul<Scale2D<T, U1, U2>> for Vector2D<T, U1> {
+    type Output = Vector2D<T::Output, U2>;
+
+    #[inline]
+    fn mul(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        vec2(self.x * scale.x, self.y * scale.y)
+    }
+}
+
+impl<T: MulAssign, U> MulAssign<Scale2D<T, U, U>> for Vector2D<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.x *= scale.x;
+        self.y *= scale.y;
+    }
+}
+
+impl<T: Div, U1, U2> Div<Scale2D<T, U1, U2>> for Vector2D<T, U2> {
+    type Output = Vector2D<T::Output, U1>;
+
+    #[inline]
+    fn div(self, scale: Scale2D<T, U1, U2>) -> Self::Output {
+        vec2(self.x / scale.x, self.y / scale.y)
+    }
+}
+
+impl<T: DivAssign, U> DivAssign<Scale2D<T, U, U>> for Vector2D<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: Scale2D<T, U, U>) {
+        self.x /= scale.x;
+        self.y /= scale.y;
+    }
+}
+
 impl<T: Round, U> Round for Vector2D<T, U> {
     /// See [`Vector2D::round`].
     #[inline]
@@ -920,6 +1166,72 @@ impl<T: ApproxEq<T>, U> ApproxEq<Vector2D<T, U>> for Vector2D<T, U> {
     }
 }
 
+impl<T: Copy, U> Vector2D<T, U> {
+    /// Adds another vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_add(self, other: Vector2D<T, U>) -> Self
+    where
+        T: WrappingAdd,
+    {
+        vec2(self.x.wrapping_add(&other.x), self.y.wrapping_add(&other.y))
+    }
+
+    /// Subtracts another vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_sub(self, other: Vector2D<T, U>) -> Self
+    where
+        T: WrappingSub,
+    {
+        vec2(self.x.wrapping_sub(&other.x), self.y.wrapping_sub(&other.y))
+    }
+
+    /// Multiplies each component by a scalar, wrapping around at the boundary of the
+    /// integer type.
+    #[inline]
+    pub fn wrapping_mul(self, scale: T) -> Self
+    where
+        T: WrappingMul,
+    {
+        vec2(self.x.wrapping_mul(&scale), self.y.wrapping_mul(&scale))
+    }
+
+    /// Adds another vector, returning the wrapped result along with whether any component
+    /// overflowed.
+    #[inline]
+    pub fn overflowing_add(self, other: Vector2D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingAdd,
+    {
+        let (x, x_overflow) = self.x.overflowing_add(&other.x);
+        let (y, y_overflow) = self.y.overflowing_add(&other.y);
+        (vec2(x, y), x_overflow || y_overflow)
+    }
+
+    /// Subtracts another vector, returning the wrapped result along with whether any
+    /// component overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: Vector2D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingSub,
+    {
+        let (x, x_overflow) = self.x.overflowing_sub(&other.x);
+        let (y, y_overflow) = self.y.overflowing_sub(&other.y);
+        (vec2(x, y), x_overflow || y_overflow)
+    }
+
+    /// Multiplies each component by a scalar, returning the wrapped result along with
+    /// whether any component overflowed.
+    #[inline]
+    pub fn overflowing_mul(self, scale: T) -> (Self, bool)
+    where
+        T: OverflowingMul,
+    {
+        let (x, x_overflow) = self.x.overflowing_mul(&scale);
+        let (y, y_overflow) = self.y.overflowing_mul(&scale);
+        (vec2(x, y), x_overflow || y_overflow)
+    }
+}
+
 impl<T, U> From<Vector2D<T, U>> for [T; 2] {
     fn from(v: Vector2D<T, U>) -> Self {
         [v.x, v.y]
@@ -944,6 +1256,38 @@ impl<T, U> From<(T, T)> for Vector2D<T, U> {
     }
 }
 
+impl<T, U> AsRef<[T; 2]> for Vector2D<T, U> {
+    fn as_ref(&self) -> &[T; 2] {
+        self.as_array()
+    }
+}
+
+/// Indexes the x and y components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Vector2D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vector2D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Vector2D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vector2D index out of bounds: {}", index),
+        }
+    }
+}
+
 impl<T, U> From<Size2D<T, U>> for Vector2D<T, U> {
     fn from(s: Size2D<T, U>) -> Self {
         vec2(s.width, s.height)
@@ -952,6 +1296,10 @@ impl<T, U> From<Size2D<T, U>> for Vector2D<T, U> {
 
 /// A 3d Vector tagged with a unit.
 #[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Vector3D<T, U> {
     /// The `x` (traditionally, horizontal) coordinate.
     pub x: T,
@@ -964,6 +1312,8 @@ pub struct Vector3D<T, U> {
 }
 
 mint_vec!(Vector3D[x, y, z] = Vector3);
+nalgebra_vec!(Vector3D[x, y, z] = Vector3);
+glam_vec!(Vector3D[x, y, z] = Vec3);
 
 impl<T: Copy, U> Copy for Vector3D<T, U> {}
 
@@ -987,7 +1337,9 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let (x, y, z) = serde::Deserialize::deserialize(deserializer)?;
+        let mut values = [None, None, None];
+        crate::serde_fields::deserialize(deserializer, "Vector3D", &["x", "y", "z"], &mut values)?;
+        let [x, y, z] = values.map(Option::unwrap);
         Ok(Vector3D {
             x,
             y,
@@ -1006,7 +1358,12 @@ where
     where
         S: serde::Serializer,
     {
-        (&self.x, &self.y, &self.z).serialize(serializer)
+        crate::serde_fields::serialize(
+            serializer,
+            "Vector3D",
+            &["x", "y", "z"],
+            &[&self.x, &self.y, &self.z],
+        )
     }
 }
 
@@ -1101,6 +1458,12 @@ impl<T, U> Vector3D<T, U> {
             _unit: PhantomData,
         }
     }
+
+    /// Constructor taking a tuple with x, y and z.
+    #[inline]
+    pub fn from_tuple((x, y, z): (T, T, T)) -> Self {
+        Vector3D::new(x, y, z)
+    }
     /// Constructor setting all components to the same value.
     #[inline]
     pub fn splat(v: T) -> Self
@@ -1190,6 +1553,26 @@ impl<T, U> Vector3D<T, U> {
         vec3(self.x.abs(), self.y.abs(), self.z.abs())
     }
 
+    /// Computes the vector with the sign of each component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use euclid::vec3;
+    /// enum U {}
+    ///
+    /// assert_eq!(vec3::<_, U>(-3, 0, 7).signum(), vec3(-1, 0, 1));
+    /// ```
+    ///
+    /// The behavior for each component follows the scalar type's implementation of
+    /// `num_traits::Signed::signum`.
+    pub fn signum(self) -> Self
+    where
+        T: Signed,
+    {
+        vec3(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
     /// Dot product.
     #[inline]
     pub fn dot(self, other: Self) -> T
@@ -1198,9 +1581,21 @@ impl<T, U> Vector3D<T, U> {
     {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
+
+    /// Returns a reference to the components as an array of x, y and z.
+    ///
+    /// `Vector3D` is `#[repr(C)]` and its unit is zero-sized, so its memory layout is
+    /// guaranteed to be the same as `[T; 3]`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 3] {
+        // SAFETY: `Self` is `#[repr(C)]` with 3 fields of type `T`, which have the size
+        // and alignment of `T` so there is no padding between them, and a zero-sized
+        // unit, so it has the layout of `[T; 3]`.
+        unsafe { &*(self as *const Self as *const [T; 3]) }
+    }
 }
 
-impl<T: Copy, U> Vector3D<T, U> {
+impl<T: Clone, U> Vector3D<T, U> {
     /// Cross product.
     #[inline]
     pub fn cross(self, other: Self) -> Self
@@ -1208,12 +1603,24 @@ impl<T: Copy, U> Vector3D<T, U> {
         T: Sub<Output = T> + Mul<Output = T>,
     {
         vec3(
-            self.y * other.z - self.z * other.y,
-            self.z * other.x - self.x * other.z,
+            self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+            self.z * other.x.clone() - self.x.clone() * other.z,
             self.x * other.y - self.y * other.x,
         )
     }
 
+    /// Scalar triple product, that is `self.dot(b.cross(c))`.
+    ///
+    /// This is the signed volume of the parallelepiped spanned by the three vectors,
+    /// positive if they form a right-handed basis.
+    #[inline]
+    pub fn scalar_triple(self, b: Self, c: Self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.dot(b.cross(c))
+    }
+
     /// Returns the component-wise multiplication of the two vectors.
     #[inline]
     pub fn component_mul(self, other: Self) -> Self
@@ -1252,7 +1659,7 @@ impl<T: Copy, U> Vector3D<T, U> {
         vec2(self.x, self.z)
     }
 
-    /// Returns a 2d vector using this vector's x and z coordinates
+    /// Returns a 2d vector using this vector's y and z coordinates
     #[inline]
     pub fn yz(self) -> Vector2D<T, U> {
         vec2(self.y, self.z)
@@ -1393,17 +1800,44 @@ where
     {
         onto * (self.dot(onto) / onto.square_length())
     }
+
+    /// Returns the component of this vector perpendicular to another one, that is
+    /// `self - self.project_onto_vector(onto)`.
+    ///
+    /// Rejecting from a nil vector will cause a division by zero.
+    #[inline]
+    pub fn reject_from_vector(self, onto: Self) -> Self
+    where
+        T: Sub<T, Output = T> + Div<T, Output = T>,
+    {
+        self - self.project_onto_vector(onto)
+    }
 }
 
 impl<T: Float, U> Vector3D<T, U> {
-    /// Return the normalized vector even if the length is larger than the max value of Float.
+    /// Returns the vector length without overflowing or underflowing when squaring
+    /// very large or very small components.
+    ///
+    /// The components are scaled by the largest of their absolute values before
+    /// computing the length, like `hypot` does.
+    #[inline]
+    pub fn robust_length(self) -> T {
+        let scale = self.x.abs().max(self.y.abs()).max(self.z.abs());
+        if scale == T::zero() || scale.is_infinite() {
+            return scale;
+        }
+        (self / scale).length() * scale
+    }
+
+    /// Return the normalized vector even if the length is larger than the max value of Float,
+    /// or too small to be squared without underflowing.
     #[inline]
     #[must_use]
     pub fn robust_normalize(self) -> Self {
-        let length = self.length();
+        let length = self.robust_length();
         if length.is_infinite() {
             let scaled = self / T::max_value();
-            scaled / scaled.length()
+            scaled / scaled.robust_length()
         } else {
             self / length
         }
@@ -1414,6 +1848,14 @@ impl<T: Float, U> Vector3D<T, U> {
     pub fn is_finite(self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
+
+    /// Computes the reciprocal of each component.
+    ///
+    /// A zero component becomes infinite.
+    #[inline]
+    pub fn recip(self) -> Self {
+        vec3(self.x.recip(), self.y.recip(), self.z.recip())
+    }
 }
 
 impl<T: Real, U> Vector3D<T, U> {
@@ -1440,7 +1882,9 @@ impl<T: Real, U> Vector3D<T, U> {
     #[inline]
     #[must_use]
     pub fn normalize(self) -> Self {
-        self / self.length()
+        let v = self / self.length();
+        debug_check_nan!("Vector3D::normalize", v.x, v.y, v.z);
+        v
     }
 
     /// Returns the vector with length of one unit.
@@ -1458,6 +1902,21 @@ impl<T: Real, U> Vector3D<T, U> {
         }
     }
 
+    /// Same as [`try_normalize`](Self::try_normalize), returning
+    /// [`Error::DegenerateInput`] for a vector of length zero.
+    #[inline]
+    pub fn normalize_checked(self) -> Result<Self, Error<T>> {
+        self.try_normalize().ok_or(Error::DegenerateInput)
+    }
+
+    /// Returns the vector with length of one unit, or `fallback` if the length
+    /// of the vector is zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
     /// Return this vector capped to a maximum length.
     #[inline]
     pub fn with_max_length(self, max_length: T) -> Self {
@@ -1474,7 +1933,9 @@ impl<T: Real, U> Vector3D<T, U> {
     pub fn with_min_length(self, min_length: T) -> Self {
         let square_length = self.square_length();
         if square_length < min_length * min_length {
-            return self * (min_length / square_length.sqrt());
+            let v = self * (min_length / square_length.sqrt());
+            debug_check_nan!("Vector3D::with_min_length", v.x, v.y, v.z);
+            return v;
         }
 
         self
@@ -1510,9 +1971,15 @@ where
     /// assert_eq!(from.lerp(to,  2.0), vec3(16.0, -18.0,  1.0));
     /// ```
     #[inline]
-    pub fn lerp(self, other: Self, t: T) -> Self {
-        let one_t = T::one() - t;
-        self * one_t + other * t
+    pub fn lerp(self, other: Self, t: T) -> Self
+    where
+        T: SimdScalar,
+    {
+        vec3(
+            T::lerp(self.x, other.x, t),
+            T::lerp(self.y, other.y, t),
+            T::lerp(self.z, other.z, t),
+        )
     }
 
     /// Returns a reflection vector using an incident ray and a surface normal.
@@ -1544,6 +2011,22 @@ impl<T: PartialOrd, U> Vector3D<T, U> {
         )
     }
 
+    /// Returns the vector each component of which is the absolute difference of the
+    /// components of this vector and another.
+    ///
+    /// Unlike `(self - other).abs()`, this doesn't underflow for unsigned integers.
+    #[inline]
+    pub fn abs_diff(self, other: Self) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        vec3(
+            abs_diff(self.x, other.x),
+            abs_diff(self.y, other.y),
+            abs_diff(self.z, other.z),
+        )
+    }
+
     /// Returns the vector each component of which is clamped by corresponding
     /// components of `start` and `end`.
     ///
@@ -1626,6 +2109,54 @@ impl<T: NumCast + Copy, U> Vector3D<T, U> {
         }
     }
 
+    /// Fallible cast from one numeric representation to another, preserving the units.
+    ///
+    /// Unlike [`Vector3D::try_cast`], the error identifies the first component that could
+    /// not be cast and why.
+    pub fn cast_checked<NewT: NumCast>(self) -> Result<Vector3D<NewT, U>, CastError> {
+        Ok(Vector3D::new(
+            cast_component(self.x, "x")?,
+            cast_component(self.y, "y")?,
+            cast_component(self.z, "z")?,
+        ))
+    }
+
+    /// Rounds each component with the given mode and casts it to another numeric
+    /// representation, preserving the units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded component can't be represented by `NewT`.
+    #[inline]
+    pub fn cast_with_rounding<NewT: NumCast>(self, mode: RoundingMode) -> Vector3D<NewT, U> {
+        self.try_cast_with_rounding(mode).unwrap()
+    }
+
+    /// Fallible version of [`Vector3D::cast_with_rounding`].
+    pub fn try_cast_with_rounding<NewT: NumCast>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Vector3D<NewT, U>> {
+        Some(Vector3D::new(
+            round_component(self.x, mode)?,
+            round_component(self.y, mode)?,
+            round_component(self.z, mode)?,
+        ))
+    }
+
+    /// Cast from one numeric representation to another, preserving the units and
+    /// clamping each component to the range of `NewT`.
+    ///
+    /// Infinities become the minimum or maximum value of `NewT` and NaN becomes zero,
+    /// like with the `as` operator. Decimals are truncated as with [`Vector3D::cast`].
+    pub fn cast_saturating<NewT: NumCast + Bounded>(self) -> Vector3D<NewT, U> {
+        Vector3D::new(
+            saturate_component(self.x),
+            saturate_component(self.y),
+            saturate_component(self.z),
+        )
+    }
+
     // Convenience functions for common casts.
 
     /// Cast into an `f32` vector.
@@ -1640,6 +2171,13 @@ impl<T: NumCast + Copy, U> Vector3D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f16` vector.
+    #[cfg(feature = "half")]
+    #[inline]
+    pub fn to_f16(self) -> Vector3D<half::f16, U> {
+        self.cast()
+    }
+
     /// Cast into an `usize` vector, truncating decimals if any.
     ///
     /// When casting from floating vector vectors, it is worth considering whether
@@ -1689,6 +2227,36 @@ impl<T: NumCast + Copy, U> Vector3D<T, U> {
     pub fn to_i64(self) -> Vector3D<i64, U> {
         self.cast()
     }
+
+    /// Cast into an `u16` vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u16(self) -> Vector3D<u16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `i16` vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_i16(self) -> Vector3D<i16, U> {
+        self.cast()
+    }
+
+    /// Cast into an `u64` vector, truncating decimals if any.
+    ///
+    /// When casting from floating vector vectors, it is worth considering whether
+    /// to `round()`, `ceil()` or `floor()` before the cast in order to obtain
+    /// the desired conversion behavior.
+    #[inline]
+    pub fn to_u64(self) -> Vector3D<u64, U> {
+        self.cast()
+    }
 }
 
 impl<T: Neg, U> Neg for Vector3D<T, U> {
@@ -1709,12 +2277,16 @@ impl<T: Add, U> Add for Vector3D<T, U> {
     }
 }
 
-impl<'a, T: 'a + Add + Copy, U: 'a> Add<&Self> for Vector3D<T, U> {
+impl<T: Add + Clone, U> Add<&Self> for Vector3D<T, U> {
     type Output = Vector3D<T::Output, U>;
 
     #[inline]
     fn add(self, other: &Self) -> Self::Output {
-        vec3(self.x + other.x, self.y + other.y, self.z + other.z)
+        vec3(
+            self.x + other.x.clone(),
+            self.y + other.y.clone(),
+            self.z + other.z.clone(),
+        )
     }
 }
 
@@ -1724,16 +2296,16 @@ impl<T: Add<Output = T> + Zero, U> Sum for Vector3D<T, U> {
     }
 }
 
-impl<'a, T: 'a + Add<Output = T> + Copy + Zero, U: 'a> Sum<&'a Self> for Vector3D<T, U> {
+impl<'a, T: 'a + Add<Output = T> + Clone + Zero, U: 'a> Sum<&'a Self> for Vector3D<T, U> {
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
         iter.fold(Self::zero(), Add::add)
     }
 }
 
-impl<T: Copy + Add<T, Output = T>, U> AddAssign for Vector3D<T, U> {
+impl<T: Clone + Add<T, Output = T>, U> AddAssign for Vector3D<T, U> {
     #[inline]
     fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
+        *self = self.clone() + other;
     }
 }
 
@@ -1746,77 +2318,93 @@ impl<T: Sub, U> Sub for Vector3D<T, U> {
     }
 }
 
-impl<T: Copy + Sub<T, Output = T>, U> SubAssign<Vector3D<T, U>> for Vector3D<T, U> {
+impl<T: Clone + Sub<T, Output = T>, U> SubAssign<Vector3D<T, U>> for Vector3D<T, U> {
     #[inline]
     fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
+        *self = self.clone() - other;
     }
 }
 
-impl<T: Copy + Mul, U> Mul<T> for Vector3D<T, U> {
+impl<T: Clone + Mul, U> Mul<T> for Vector3D<T, U> {
     type Output = Vector3D<T::Output, U>;
 
     #[inline]
     fn mul(self, scale: T) -> Self::Output {
-        vec3(self.x * scale, self.y * scale, self.z * scale)
+        vec3(
+            self.x * scale.clone(),
+            self.y * scale.clone(),
+            self.z * scale,
+        )
     }
 }
 
-impl<T: Copy + Mul<T, Output = T>, U> MulAssign<T> for Vector3D<T, U> {
+impl<T: Clone + Mul<T, Output = T>, U> MulAssign<T> for Vector3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: T) {
-        *self = *self * scale;
+        *self = self.clone() * scale;
     }
 }
 
-impl<T: Copy + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Vector3D<T, U1> {
+impl<T: Clone + Mul, U1, U2> Mul<Scale<T, U1, U2>> for Vector3D<T, U1> {
     type Output = Vector3D<T::Output, U2>;
 
     #[inline]
     fn mul(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        vec3(self.x * scale.0, self.y * scale.0, self.z * scale.0)
+        vec3(
+            self.x * scale.0.clone(),
+            self.y * scale.0.clone(),
+            self.z * scale.0,
+        )
     }
 }
 
-impl<T: Copy + MulAssign, U> MulAssign<Scale<T, U, U>> for Vector3D<T, U> {
+impl<T: Clone + MulAssign, U> MulAssign<Scale<T, U, U>> for Vector3D<T, U> {
     #[inline]
     fn mul_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x *= scale.0;
-        self.y *= scale.0;
+        self.x *= scale.0.clone();
+        self.y *= scale.0.clone();
         self.z *= scale.0;
     }
 }
 
-impl<T: Copy + Div, U> Div<T> for Vector3D<T, U> {
+impl<T: Clone + Div, U> Div<T> for Vector3D<T, U> {
     type Output = Vector3D<T::Output, U>;
 
     #[inline]
     fn div(self, scale: T) -> Self::Output {
-        vec3(self.x / scale, self.y / scale, self.z / scale)
+        vec3(
+            self.x / scale.clone(),
+            self.y / scale.clone(),
+            self.z / scale,
+        )
     }
 }
 
-impl<T: Copy + Div<T, Output = T>, U> DivAssign<T> for Vector3D<T, U> {
+impl<T: Clone + Div<T, Output = T>, U> DivAssign<T> for Vector3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: T) {
-        *self = *self / scale;
+        *self = self.clone() / scale;
     }
 }
 
-impl<T: Copy + Div, U1, U2> Div<Scale<T, U1, U2>> for Vector3D<T, U2> {
+impl<T: Clone + Div, U1, U2> Div<Scale<T, U1, U2>> for Vector3D<T, U2> {
     type Output = Vector3D<T::Output, U1>;
 
     #[inline]
     fn div(self, scale: Scale<T, U1, U2>) -> Self::Output {
-        vec3(self.x / scale.0, self.y / scale.0, self.z / scale.0)
+        vec3(
+            self.x / scale.0.clone(),
+            self.y / scale.0.clone(),
+            self.z / scale.0,
+        )
     }
 }
 
-impl<T: Copy + DivAssign, U> DivAssign<Scale<T, U, U>> for Vector3D<T, U> {
+impl<T: Clone + DivAssign, U> DivAssign<Scale<T, U, U>> for Vector3D<T, U> {
     #[inline]
     fn div_assign(&mut self, scale: Scale<T, U, U>) {
-        self.x /= scale.0;
-        self.y /= scale.0;
+        self.x /= scale.0.clone();
+        self.y /= scale.0.clone();
         self.z /= scale.0;
     }
 }
@@ -1863,6 +2451,87 @@ impl<T: ApproxEq<T>, U> ApproxEq<Vector3D<T, U>> for Vector3D<T, U> {
     }
 }
 
+impl<T: Copy, U> Vector3D<T, U> {
+    /// Adds another vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_add(self, other: Vector3D<T, U>) -> Self
+    where
+        T: WrappingAdd,
+    {
+        vec3(
+            self.x.wrapping_add(&other.x),
+            self.y.wrapping_add(&other.y),
+            self.z.wrapping_add(&other.z),
+        )
+    }
+
+    /// Subtracts another vector, wrapping around at the boundary of the integer type.
+    #[inline]
+    pub fn wrapping_sub(self, other: Vector3D<T, U>) -> Self
+    where
+        T: WrappingSub,
+    {
+        vec3(
+            self.x.wrapping_sub(&other.x),
+            self.y.wrapping_sub(&other.y),
+            self.z.wrapping_sub(&other.z),
+        )
+    }
+
+    /// Multiplies each component by a scalar, wrapping around at the boundary of the
+    /// integer type.
+    #[inline]
+    pub fn wrapping_mul(self, scale: T) -> Self
+    where
+        T: WrappingMul,
+    {
+        vec3(
+            self.x.wrapping_mul(&scale),
+            self.y.wrapping_mul(&scale),
+            self.z.wrapping_mul(&scale),
+        )
+    }
+
+    /// Adds another vector, returning the wrapped result along with whether any component
+    /// overflowed.
+    #[inline]
+    pub fn overflowing_add(self, other: Vector3D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingAdd,
+    {
+        let (x, x_overflow) = self.x.overflowing_add(&other.x);
+        let (y, y_overflow) = self.y.overflowing_add(&other.y);
+        let (z, z_overflow) = self.z.overflowing_add(&other.z);
+        (vec3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+
+    /// Subtracts another vector, returning the wrapped result along with whether any
+    /// component overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: Vector3D<T, U>) -> (Self, bool)
+    where
+        T: OverflowingSub,
+    {
+        let (x, x_overflow) = self.x.overflowing_sub(&other.x);
+        let (y, y_overflow) = self.y.overflowing_sub(&other.y);
+        let (z, z_overflow) = self.z.overflowing_sub(&other.z);
+        (vec3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+
+    /// Multiplies each component by a scalar, returning the wrapped result along with
+    /// whether any component overflowed.
+    #[inline]
+    pub fn overflowing_mul(self, scale: T) -> (Self, bool)
+    where
+        T: OverflowingMul,
+    {
+        let (x, x_overflow) = self.x.overflowing_mul(&scale);
+        let (y, y_overflow) = self.y.overflowing_mul(&scale);
+        let (z, z_overflow) = self.z.overflowing_mul(&scale);
+        (vec3(x, y, z), x_overflow || y_overflow || z_overflow)
+    }
+}
+
 impl<T, U> From<Vector3D<T, U>> for [T; 3] {
     fn from(v: Vector3D<T, U>) -> Self {
         [v.x, v.y, v.z]
@@ -1887,21 +2556,73 @@ impl<T, U> From<(T, T, T)> for Vector3D<T, U> {
     }
 }
 
+impl<T, U> AsRef<[T; 3]> for Vector3D<T, U> {
+    fn as_ref(&self) -> &[T; 3] {
+        self.as_array()
+    }
+}
+
+/// Indexes the x, y and z components in order.
+///
+/// Panics if the index is out of bounds.
+impl<T, U> Index<usize> for Vector3D<T, U> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vector3D index out of bounds: {}", index),
+        }
+    }
+}
+
+impl<T, U> IndexMut<usize> for Vector3D<T, U> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vector3D index out of bounds: {}", index),
+        }
+    }
+}
+
 /// A 2d vector of booleans, useful for component-wise logic operations.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct BoolVector2D {
     pub x: bool,
     pub y: bool,
 }
 
 /// A 3d vector of booleans, useful for component-wise logic operations.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct BoolVector3D {
     pub x: bool,
     pub y: bool,
     pub z: bool,
 }
 
+// `bool` has invalid bit patterns so the boolean vectors can't be `Pod`, but
+// all-zeroes is a valid `false` in every component.
+#[cfg(feature = "bytemuck")]
+unsafe impl Zeroable for BoolVector2D {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl Zeroable for BoolVector3D {}
+
 impl BoolVector2D {
     /// Returns `true` if all components are `true` and `false` otherwise.
     #[inline]
@@ -1941,6 +2662,7 @@ impl BoolVector2D {
 
     /// Returns new vector with results of negation operation on each component.
     #[inline]
+    #[allow(clippy::should_implement_trait)]
     pub fn not(self) -> Self {
         BoolVector2D {
             x: !self.x,
@@ -2020,6 +2742,7 @@ impl BoolVector3D {
 
     /// Returns new vector with results of negation operation on each component.
     #[inline]
+    #[allow(clippy::should_implement_trait)]
     pub fn not(self) -> Self {
         BoolVector3D {
             x: !self.x,
@@ -2147,7 +2870,7 @@ pub const fn bvec3(x: bool, y: bool, z: bool) -> BoolVector3D {
 #[cfg(test)]
 mod vector2d {
     use crate::scale::Scale;
-    use crate::{default, vec2};
+    use crate::{default, vec2, vec3};
 
     #[cfg(feature = "mint")]
     use mint;
@@ -2184,22 +2907,35 @@ mod vector2d {
         let p0: Vec2 = Vec2::zero();
         let p1: Vec2 = vec2(4.0, 0.0);
         let p2: Vec2 = vec2(3.0, -4.0);
-        assert!(p0.normalize().x.is_nan() && p0.normalize().y.is_nan());
+        let n0 = crate::allow_nan(|| p0.normalize());
+        assert!(n0.x.is_nan() && n0.y.is_nan());
         assert_eq!(p1.normalize(), vec2(1.0, 0.0));
         assert_eq!(p2.normalize(), vec2(0.6, -0.8));
 
         let p3: Vec2 = vec2(::std::f32::MAX, ::std::f32::MAX);
         assert_ne!(
-            p3.normalize(),
+            crate::allow_nan(|| p3.normalize()),
             vec2(1.0 / 2.0f32.sqrt(), 1.0 / 2.0f32.sqrt())
         );
         assert_eq!(
             p3.robust_normalize(),
             vec2(1.0 / 2.0f32.sqrt(), 1.0 / 2.0f32.sqrt())
         );
+        assert!(p3.robust_length().is_infinite());
+
+        let big: Vec2 = vec2(3.0e30, 4.0e30);
+        let tiny: Vec2 = vec2(3.0e-30, 4.0e-30);
+        assert!(big.length().is_infinite());
+        assert_eq!(big.robust_length(), 5.0e30);
+        assert_eq!(tiny.length(), 0.0);
+        assert_eq!(tiny.robust_length(), 5.0e-30);
+        assert_eq!(tiny.robust_normalize(), vec2(0.6, 0.8));
+        assert_eq!(Vec2::zero().robust_length(), 0.0);
 
         let p4: Vec2 = Vec2::zero();
         assert!(p4.try_normalize().is_none());
+        assert_eq!(p4.normalize_or(vec2(1.0, 0.0)), vec2(1.0, 0.0));
+        assert_eq!(p1.normalize_or(vec2(0.0, 1.0)), vec2(1.0, 0.0));
         let p5: Vec2 = Vec2::new(f32::MIN_POSITIVE, f32::MIN_POSITIVE);
         assert!(p5.try_normalize().is_none());
 
@@ -2229,6 +2965,27 @@ mod vector2d {
         assert_eq!(result, vec2(2.0, 3.0));
     }
 
+    #[test]
+    pub fn test_clamp() {
+        let p1: Vec2 = vec2(1.0, -1.0);
+        let p2: Vec2 = vec2(2.0, 5.0);
+        let p3: Vec2 = vec2(-1.0, 2.0);
+
+        let result = p3.clamp(p1, p2);
+
+        assert_eq!(result, vec2(1.0, 2.0));
+    }
+
+    #[test]
+    pub fn test_signum_recip() {
+        let p: Vec2 = vec2(-2.0, 4.0);
+        let i: default::Vector2D<i32> = vec2(-2, 0);
+
+        assert_eq!(p.signum(), vec2(-1.0, 1.0));
+        assert_eq!(p.recip(), vec2(-0.5, 0.25));
+        assert_eq!(i.signum(), vec2(-1, 0));
+    }
+
     #[test]
     pub fn test_angle_from_x_axis() {
         use crate::approxeq::ApproxEq;
@@ -2309,6 +3066,19 @@ mod vector2d {
         assert!(v1.project_onto_vector(-v1).approx_eq(&v1));
     }
 
+    #[test]
+    pub fn test_reject_from_vector() {
+        use crate::approxeq::ApproxEq;
+
+        let v1: Vec2 = vec2(1.0, 2.0);
+        let x: Vec2 = vec2(1.0, 0.0);
+
+        assert!(v1.reject_from_vector(x).approx_eq(&vec2(0.0, 2.0)));
+        assert!(v1.reject_from_vector(x * 10.0).approx_eq(&vec2(0.0, 2.0)));
+        assert!(v1.reject_from_vector(v1).approx_eq(&vec2(0.0, 0.0)));
+        assert!((v1.reject_from_vector(x) + v1.project_onto_vector(x)).approx_eq(&v1));
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -2367,6 +3137,7 @@ mod vector2d {
     pub fn test_swizzling() {
         let p: default::Vector2D<i32> = vec2(1, 2);
         assert_eq!(p.yx(), vec2(2, 1));
+        assert_eq!(p.extend(3), vec3(1, 2, 3));
     }
 
     #[test]
@@ -2390,6 +3161,19 @@ mod vector3d {
 
     type Vec3 = default::Vector3D<f32>;
 
+    #[test]
+    pub fn test_wrapping() {
+        let v: default::Vector3D<i32> = vec3(i32::MAX, i32::MIN, 7);
+        assert_eq!(v.wrapping_add(vec3(1, 0, 1)), vec3(i32::MIN, i32::MIN, 8));
+        assert_eq!(v.wrapping_sub(vec3(0, 1, 0)), vec3(i32::MAX, i32::MAX, 7));
+        assert_eq!(v.wrapping_mul(2), vec3(-2, 0, 14));
+        assert_eq!(
+            v.overflowing_add(vec3(0, 0, 1)),
+            (vec3(i32::MAX, i32::MIN, 8), false)
+        );
+        assert_eq!(v.overflowing_mul(-1), (vec3(-i32::MAX, i32::MIN, -7), true));
+    }
+
     #[test]
     pub fn test_add() {
         let p1 = Vec3::new(1.0, 2.0, 3.0);
@@ -2425,22 +3209,33 @@ mod vector3d {
         assert_eq!(p3, vec3(-51.0, 105.0, -59.0));
     }
 
+    #[test]
+    pub fn test_scalar_triple() {
+        let x: Vec3 = vec3(1.0, 0.0, 0.0);
+        let y: Vec3 = vec3(0.0, 1.0, 0.0);
+        let z: Vec3 = vec3(0.0, 0.0, 2.0);
+
+        assert_eq!(x.scalar_triple(y, z), 2.0);
+        assert_eq!(y.scalar_triple(x, z), -2.0);
+        assert_eq!(x.scalar_triple(x, z), 0.0);
+    }
+
     #[test]
     pub fn test_normalize() {
+        use crate::approxeq::ApproxEq;
         use std::f32;
 
         let p0: Vec3 = Vec3::zero();
         let p1: Vec3 = vec3(0.0, -6.0, 0.0);
         let p2: Vec3 = vec3(1.0, 2.0, -2.0);
-        assert!(
-            p0.normalize().x.is_nan() && p0.normalize().y.is_nan() && p0.normalize().z.is_nan()
-        );
+        let n0 = crate::allow_nan(|| p0.normalize());
+        assert!(n0.x.is_nan() && n0.y.is_nan() && n0.z.is_nan());
         assert_eq!(p1.normalize(), vec3(0.0, -1.0, 0.0));
         assert_eq!(p2.normalize(), vec3(1.0 / 3.0, 2.0 / 3.0, -2.0 / 3.0));
 
         let p3: Vec3 = vec3(::std::f32::MAX, ::std::f32::MAX, 0.0);
         assert_ne!(
-            p3.normalize(),
+            crate::allow_nan(|| p3.normalize()),
             vec3(1.0 / 2.0f32.sqrt(), 1.0 / 2.0f32.sqrt(), 0.0)
         );
         assert_eq!(
@@ -2448,8 +3243,21 @@ mod vector3d {
             vec3(1.0 / 2.0f32.sqrt(), 1.0 / 2.0f32.sqrt(), 0.0)
         );
 
+        let k = 2.0f32.powi(100);
+        let big: Vec3 = vec3(2.0, 1.0, -2.0) * k;
+        let tiny: Vec3 = vec3(2.0, 1.0, -2.0) / k;
+        assert!(big.length().is_infinite());
+        assert_eq!(big.robust_length(), 3.0 * k);
+        assert_eq!(tiny.length(), 0.0);
+        assert_eq!(tiny.robust_length(), 3.0 / k);
+        assert!(tiny
+            .robust_normalize()
+            .approx_eq(&vec3(2.0 / 3.0, 1.0 / 3.0, -2.0 / 3.0)));
+
         let p4: Vec3 = Vec3::zero();
         assert!(p4.try_normalize().is_none());
+        assert_eq!(p4.normalize_or(vec3(0.0, 0.0, 1.0)), vec3(0.0, 0.0, 1.0));
+        assert_eq!(p1.normalize_or(vec3(1.0, 0.0, 0.0)), vec3(0.0, -1.0, 0.0));
         let p5: Vec3 = Vec3::new(f32::MIN_POSITIVE, f32::MIN_POSITIVE, f32::MIN_POSITIVE);
         assert!(p5.try_normalize().is_none());
 
@@ -2490,6 +3298,16 @@ mod vector3d {
         assert_eq!(result, vec3(1.0, 2.0, 10.0));
     }
 
+    #[test]
+    pub fn test_signum_recip() {
+        let p: Vec3 = vec3(-2.0, 4.0, 0.5);
+        let z: Vec3 = vec3(0.0, 1.0, 1.0);
+
+        assert_eq!(p.signum(), vec3(-1.0, 1.0, 1.0));
+        assert_eq!(p.recip(), vec3(-0.5, 0.25, 2.0));
+        assert!(z.recip().x.is_infinite());
+    }
+
     #[test]
     pub fn test_typed_scalar_mul() {
         enum Mm {}
@@ -2601,6 +3419,17 @@ mod vector3d {
         assert!(v1.project_onto_vector(v1 * 2.0).approx_eq(&v1));
         assert!(v1.project_onto_vector(-v1).approx_eq(&v1));
     }
+
+    #[test]
+    pub fn test_reject_from_vector() {
+        use crate::approxeq::ApproxEq;
+
+        let v1: Vec3 = vec3(1.0, 2.0, 3.0);
+        let x: Vec3 = vec3(1.0, 0.0, 0.0);
+
+        assert!(v1.reject_from_vector(x).approx_eq(&vec3(0.0, 2.0, 3.0)));
+        assert!(v1.reject_from_vector(v1).approx_eq(&vec3(0.0, 0.0, 0.0)));
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,360 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Structure-of-arrays companions of the point and vector types.
+//!
+//! A `Point2Dx4` holds four points as one array per axis, so that lane-wise
+//! arithmetic, containment tests and bounding box computations over large buffers
+//! are straightforward for the compiler to vectorize. Values are moved between the
+//! usual array-of-structures layout and the wide types with `gather` and `scatter`.
+//!
+//! ```
+//! use euclid::default::{Box2D, Point2D};
+//! use euclid::{point2, Point2Dx4};
+//!
+//! let points: [Point2D<f32>; 8] = [point2(0.0, 0.0); 8];
+//! let bounds = Box2D::new(point2(-1.0, -1.0), point2(1.0, 1.0));
+//! for chunk in points.chunks_exact(4) {
+//!     assert_eq!(Point2Dx4::gather(chunk).contained_by(&bounds), [true; 4]);
+//! }
+//! ```
+
+use crate::approxord::{max, min};
+use crate::{Box2D, Box3D, Point2D, Point3D, Vector2D, Vector3D};
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+
+macro_rules! wide_common {
+    ($(#[$attr:meta])* $name:ident => $aos:ident, $n:literal, [$($f:ident),+]) => {
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $name<T, U> {
+            $(pub $f: [T; $n],)+
+            #[doc(hidden)]
+            pub _unit: PhantomData<U>,
+        }
+
+        impl<T: Copy, U> Copy for $name<T, U> {}
+
+        impl<T: Clone, U> Clone for $name<T, U> {
+            fn clone(&self) -> Self {
+                $name {
+                    $($f: self.$f.clone(),)+
+                    _unit: PhantomData,
+                }
+            }
+        }
+
+        impl<T: PartialEq, U> PartialEq for $name<T, U> {
+            fn eq(&self, other: &Self) -> bool {
+                true $(&& self.$f == other.$f)+
+            }
+        }
+
+        impl<T: Eq, U> Eq for $name<T, U> {}
+
+        impl<T: Hash, U> Hash for $name<T, U> {
+            fn hash<H: Hasher>(&self, h: &mut H) {
+                $(self.$f.hash(h);)+
+            }
+        }
+
+        impl<T: fmt::Debug, U> fmt::Debug for $name<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple("")$(.field(&self.$f))+.finish()
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// The number of lanes.
+            pub const LANES: usize = $n;
+
+            /// Constructor setting all lanes to the same value.
+            #[inline]
+            pub fn splat(v: $aos<T, U>) -> Self {
+                $name {
+                    $($f: [v.$f; $n],)+
+                    _unit: PhantomData,
+                }
+            }
+
+            /// Loads the lanes from a slice of exactly `LANES` elements.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length of the slice is not `LANES`.
+            #[inline]
+            pub fn gather(items: &[$aos<T, U>]) -> Self {
+                assert_eq!(items.len(), $n, "gather needs exactly {} elements", $n);
+                let mut wide = Self::splat(items[0]);
+                for (i, item) in items.iter().enumerate() {
+                    $(wide.$f[i] = item.$f;)+
+                }
+                wide
+            }
+
+            /// Stores the lanes into a slice of exactly `LANES` elements.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length of the slice is not `LANES`.
+            #[inline]
+            pub fn scatter(&self, out: &mut [$aos<T, U>]) {
+                assert_eq!(out.len(), $n, "scatter needs exactly {} elements", $n);
+                for (i, item) in out.iter_mut().enumerate() {
+                    $(item.$f = self.$f[i];)+
+                }
+            }
+
+            /// Returns the value of lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> $aos<T, U> {
+                $aos::new($(self.$f[i]),+)
+            }
+
+            /// Sets the value of lane `i`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, v: $aos<T, U>) {
+                $(self.$f[i] = v.$f;)+
+            }
+
+            /// Returns the lane-wise minimum of the two values.
+            #[inline]
+            pub fn min(mut self, other: Self) -> Self
+            where
+                T: PartialOrd,
+            {
+                for i in 0..$n {
+                    $(self.$f[i] = min(self.$f[i], other.$f[i]);)+
+                }
+                self
+            }
+
+            /// Returns the lane-wise maximum of the two values.
+            #[inline]
+            pub fn max(mut self, other: Self) -> Self
+            where
+                T: PartialOrd,
+            {
+                for i in 0..$n {
+                    $(self.$f[i] = max(self.$f[i], other.$f[i]);)+
+                }
+                self
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>, U> Mul<T> for $name<T, U> {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, scale: T) -> Self {
+                for i in 0..$n {
+                    $(self.$f[i] = self.$f[i] * scale;)+
+                }
+                self
+            }
+        }
+
+        impl<T: Copy + Div<Output = T>, U> Div<T> for $name<T, U> {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, scale: T) -> Self {
+                for i in 0..$n {
+                    $(self.$f[i] = self.$f[i] / scale;)+
+                }
+                self
+            }
+        }
+    };
+}
+
+macro_rules! wide_op {
+    ($op:ident, $method:ident, $lhs:ident, $rhs:ident => $out:ident, $n:literal, [$($f:ident),+]) => {
+        impl<T: Copy + $op<Output = T>, U> $op<$rhs<T, U>> for $lhs<T, U> {
+            type Output = $out<T, U>;
+            #[inline]
+            fn $method(self, other: $rhs<T, U>) -> $out<T, U> {
+                let mut r = $out {
+                    $($f: self.$f,)+
+                    _unit: PhantomData,
+                };
+                for i in 0..$n {
+                    $(r.$f[i] = self.$f[i].$method(other.$f[i]);)+
+                }
+                r
+            }
+        }
+    };
+}
+
+macro_rules! wide_types {
+    ($point:ident, $vector:ident, $n:literal, $p:ident, $v:ident, $box:ident, [$($f:ident),+]) => {
+        wide_common!(
+            #[doc = concat!(stringify!($n), " `", stringify!($p), "`s stored as one array per axis.")]
+            $point => $p, $n, [$($f),+]
+        );
+        wide_common!(
+            #[doc = concat!(stringify!($n), " `", stringify!($v), "`s stored as one array per axis.")]
+            $vector => $v, $n, [$($f),+]
+        );
+
+        wide_op!(Add, add, $point, $vector => $point, $n, [$($f),+]);
+        wide_op!(Sub, sub, $point, $vector => $point, $n, [$($f),+]);
+        wide_op!(Sub, sub, $point, $point => $vector, $n, [$($f),+]);
+        wide_op!(Add, add, $vector, $vector => $vector, $n, [$($f),+]);
+        wide_op!(Sub, sub, $vector, $vector => $vector, $n, [$($f),+]);
+
+        impl<T: Copy, U> $point<T, U> {
+            /// Returns, for each lane, whether the box contains the point.
+            ///
+            #[doc = concat!("This has the same semantics as [`", stringify!($box), "::contains`].")]
+            #[inline]
+            pub fn contained_by(&self, b: &$box<T, U>) -> [bool; $n]
+            where
+                T: PartialOrd,
+            {
+                let mut r = [false; $n];
+                for (i, inside) in r.iter_mut().enumerate() {
+                    *inside = true $(& (b.min.$f <= self.$f[i]) & (self.$f[i] < b.max.$f))+;
+                }
+                r
+            }
+
+            /// Returns the smallest box containing all lanes.
+            #[inline]
+            pub fn bounding_box(&self) -> $box<T, U>
+            where
+                T: PartialOrd,
+            {
+                let mut b = $box::new(self.lane(0), self.lane(0));
+                for i in 1..$n {
+                    $(
+                        b.min.$f = min(b.min.$f, self.$f[i]);
+                        b.max.$f = max(b.max.$f, self.$f[i]);
+                    )+
+                }
+                b
+            }
+        }
+
+        impl<T: Copy, U> $vector<T, U> {
+            /// Returns the lane-wise dot product of the two vectors.
+            #[inline]
+            pub fn dot(&self, other: &Self) -> [T; $n]
+            where
+                T: Add<Output = T> + Mul<Output = T>,
+            {
+                let mut r = [self.x[0]; $n];
+                for (i, d) in r.iter_mut().enumerate() {
+                    *d = wide_types!(@sum $(self.$f[i] * other.$f[i]),+);
+                }
+                r
+            }
+
+            /// Returns the lane-wise squared length of the vectors.
+            #[inline]
+            pub fn square_length(&self) -> [T; $n]
+            where
+                T: Add<Output = T> + Mul<Output = T>,
+            {
+                self.dot(self)
+            }
+        }
+    };
+    (@sum $first:expr $(, $rest:expr)*) => { $first $(+ $rest)* };
+}
+
+wide_types!(Point2Dx4, Vector2Dx4, 4, Point2D, Vector2D, Box2D, [x, y]);
+wide_types!(Point2Dx8, Vector2Dx8, 8, Point2D, Vector2D, Box2D, [x, y]);
+wide_types!(
+    Point3Dx4,
+    Vector3Dx4,
+    4,
+    Point3D,
+    Vector3D,
+    Box3D,
+    [x, y, z]
+);
+wide_types!(
+    Point3Dx8,
+    Vector3Dx8,
+    8,
+    Point3D,
+    Vector3D,
+    Box3D,
+    [x, y, z]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default;
+    use crate::{point2, point3, vec2, vec3};
+
+    #[test]
+    fn test_gather_scatter() {
+        let points: [default::Point2D<i32>; 4] =
+            [point2(1, 2), point2(3, 4), point2(5, 6), point2(7, 8)];
+        let wide = Point2Dx4::gather(&points);
+        assert_eq!(wide.x, [1, 3, 5, 7]);
+        assert_eq!(wide.y, [2, 4, 6, 8]);
+        assert_eq!(wide.lane(2), point2(5, 6));
+
+        let mut out = [point2(0, 0); 4];
+        wide.scatter(&mut out);
+        assert_eq!(out, points);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gather_wrong_length() {
+        let points: [default::Point3D<f32>; 3] = [point3(0.0, 0.0, 0.0); 3];
+        Point3Dx4::gather(&points);
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut vectors = [vec3(0.0, 0.0, 0.0); 8];
+        let mut points = [point3(0.0, 0.0, 0.0); 8];
+        for i in 0..8 {
+            let f = i as f32;
+            vectors[i] = vec3(f, -f, 2.0 * f);
+            points[i] = point3(1.0, f, 0.5);
+        }
+        let v: Vector3Dx8<f32, crate::UnknownUnit> = Vector3Dx8::gather(&vectors);
+        let p = Point3Dx8::gather(&points);
+        for i in 0..8 {
+            assert_eq!((p + v).lane(i), points[i] + vectors[i]);
+            assert_eq!((p - p).lane(i), vec3(0.0, 0.0, 0.0));
+            assert_eq!((v * 2.0).lane(i), vectors[i] * 2.0);
+            assert_eq!(v.dot(&v)[i], vectors[i].dot(vectors[i]));
+        }
+    }
+
+    #[test]
+    fn test_containment_and_bounds() {
+        let b: default::Box2D<f32> = Box2D::new(point2(0.0, 0.0), point2(10.0, 10.0));
+        let p = Point2Dx4::gather(&[
+            point2(1.0, 1.0),
+            point2(10.0, 5.0),
+            point2(-1.0, 5.0),
+            point2(9.5, 0.0),
+        ]);
+        assert_eq!(p.contained_by(&b), [true, false, false, true]);
+        assert_eq!(
+            p.bounding_box(),
+            Box2D::new(point2(-1.0, 0.0), point2(10.0, 5.0))
+        );
+
+        let v: Vector2Dx4<f32, crate::UnknownUnit> = Vector2Dx4::splat(vec2(1.0, 2.0));
+        assert_eq!(v.min(v * 2.0), v);
+    }
+}